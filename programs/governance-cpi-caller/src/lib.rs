@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use governance_system::cpi::accounts::{GetGovernanceState, GetProposal};
+use governance_system::program::Governance;
+use governance_system::{
+    CreateProposalArgs, CreateProposalCpiAccounts, CreatedProposal, GovernanceStateSummary,
+    ProposalOutcome, ProposalStatus, ProposalSummary, ProposalType, PROPOSAL_OUTCOME_SEED,
+};
+
+declare_id!("G1EPgNocq8Li6yn34mf8rHj1PDAYYvyMx1SaVpfz2tPU");
+
+/// Proposer PDA seed (proposer of proposals created through CPI)
+pub const PROPOSER_SEED: &[u8] = b"proposer";
+
+/// Test program standing in for an integration that gates its logic on governance state
+#[program]
+pub mod governance_cpi_caller {
+    use super::*;
+
+    /// Read the governance state summary through CPI and pass it on as return data
+    pub fn read_governance_state(
+        ctx: Context<ReadGovernanceState>,
+    ) -> Result<GovernanceStateSummary> {
+        let cpi_accounts = GetGovernanceState {
+            governance_config: ctx.accounts.governance_config.to_account_info(),
+            rule_registry: ctx
+                .accounts
+                .rule_registry
+                .as_ref()
+                .map(|registry| registry.to_account_info()),
+        };
+        let summary = governance_system::cpi::get_governance_state(CpiContext::new(
+            ctx.accounts.governance_program.to_account_info(),
+            cpi_accounts,
+        ))?
+        .get();
+
+        msg!(
+            "Governance state: {} pending proposals, proposals paused: {}",
+            summary.pending_proposal_count,
+            summary.proposals_paused
+        );
+        Ok(summary)
+    }
+
+    /// Read a proposal summary through CPI and pass it on as return data
+    pub fn read_proposal_summary(
+        ctx: Context<ReadProposal>,
+        proposal_id: u64,
+    ) -> Result<ProposalSummary> {
+        let summary = governance_system::cpi::get_proposal_summary(
+            ctx.accounts.proposal_cpi_context(),
+            proposal_id,
+        )?
+        .get();
+
+        msg!(
+            "Proposal {}: {} yes of {} voting power",
+            summary.id,
+            summary.yes,
+            summary.total_power
+        );
+        Ok(summary)
+    }
+
+    /// Act only on a passed proposal, asking the governance program through CPI
+    pub fn require_has_passed(ctx: Context<ReadProposal>, proposal_id: u64) -> Result<()> {
+        let passed =
+            governance_system::cpi::has_passed(ctx.accounts.proposal_cpi_context(), proposal_id)?
+                .get();
+        require!(passed, CpiCallerError::ProposalNotPassed);
+
+        msg!("Proposal {} passed", proposal_id);
+        Ok(())
+    }
+
+    /// Act only on a passed proposal, reading its outcome account without CPI
+    pub fn require_proposal_passed(
+        ctx: Context<RequireProposalPassed>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        let outcome = &ctx.accounts.proposal_outcome;
+        require!(
+            outcome.status == ProposalStatus::Passed,
+            CpiCallerError::ProposalNotPassed
+        );
+
+        msg!(
+            "Proposal {} passed with {} of {} voting power",
+            proposal_id,
+            outcome.yes,
+            outcome.total_power
+        );
+        Ok(())
+    }
+
+    /// Create a configuration update proposal through CPI with the proposer PDA
+    /// The governance program's return data is checked and passed on as return data
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        proposal_id: u64,
+    ) -> Result<CreatedProposal> {
+        let seeds = &[PROPOSER_SEED, &[ctx.bumps.proposer]];
+        let signer_seeds = &[&seeds[..]];
+        let accounts = CreateProposalCpiAccounts {
+            proposal: ctx.accounts.proposal.to_account_info(),
+            governance_config: ctx.accounts.governance_config.to_account_info(),
+            proposer: ctx.accounts.proposer.to_account_info(),
+            proposer_policy: ctx.accounts.proposer_policy.to_account_info(),
+            proposer_state: ctx.accounts.proposer_state.to_account_info(),
+            proposer_token_account: ctx.accounts.proposer_token_account.to_account_info(),
+            deposit_mint: ctx.accounts.deposit_mint.to_account_info(),
+            governance_token_vault: ctx.accounts.governance_token_vault.to_account_info(),
+            deposit_escrow: None,
+            governance_authority: ctx.accounts.governance_authority.to_account_info(),
+            dispute_order: None,
+            governance_stats: ctx.accounts.governance_stats.to_account_info(),
+            cpi_caller_allowlist: ctx.accounts.cpi_caller_allowlist.to_account_info(),
+            instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        }
+        .into_accounts();
+        let created = governance_system::cpi::create_proposal(
+            CpiContext::new_with_signer(
+                ctx.accounts.governance_program.to_account_info(),
+                accounts,
+                signer_seeds,
+            ),
+            proposal_id,
+            "Proposal From Another Program".to_string(),
+            "This proposal was created through CPI.".to_string(),
+            ProposalType::ConfigUpdate,
+            CreateProposalArgs::default(),
+        )?
+        .get();
+        require!(
+            created.id == proposal_id && created.proposal == ctx.accounts.proposal.key(),
+            CpiCallerError::UnexpectedReturnData
+        );
+
+        msg!("Created proposal {} at {}", created.id, created.proposal);
+        Ok(created)
+    }
+}
+
+#[derive(Accounts)]
+pub struct ReadGovernanceState<'info> {
+    /// CHECK: Validated by the governance program
+    pub governance_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub rule_registry: Option<UncheckedAccount<'info>>,
+
+    pub governance_program: Program<'info, Governance>,
+}
+
+#[derive(Accounts)]
+pub struct ReadProposal<'info> {
+    /// CHECK: Validated by the governance program
+    pub proposal: UncheckedAccount<'info>,
+
+    pub governance_program: Program<'info, Governance>,
+}
+
+impl<'info> ReadProposal<'info> {
+    fn proposal_cpi_context(
+        &self,
+    ) -> CpiContext<'static, 'static, 'static, 'info, GetProposal<'info>> {
+        CpiContext::new(
+            self.governance_program.to_account_info(),
+            GetProposal {
+                proposal: self.proposal.to_account_info(),
+            },
+        )
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct RequireProposalPassed<'info> {
+    /// Outcome published by the governance program at finalization
+    #[account(
+        seeds = [PROPOSAL_OUTCOME_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal_outcome.bump,
+        seeds::program = governance_system::ID
+    )]
+    pub proposal_outcome: Account<'info, ProposalOutcome>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// Proposer of the created proposal
+    /// CHECK: System account PDA, only signs the governance CPI
+    #[account(
+        mut,
+        seeds = [PROPOSER_SEED],
+        bump
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Deposit source, owned by the proposer PDA
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub proposer_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub proposal: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub governance_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub proposer_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub proposer_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub deposit_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub governance_token_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub governance_stats: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub cpi_caller_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub governance_program: Program<'info, Governance>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Validated by the governance program
+    pub token_program: UncheckedAccount<'info>,
+}
+
+#[error_code]
+pub enum CpiCallerError {
+    #[msg("Proposal did not pass")]
+    ProposalNotPassed,
+    #[msg("Governance return data does not match the created proposal")]
+    UnexpectedReturnData,
+}
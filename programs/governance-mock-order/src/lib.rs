@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use governance_system::program::Governance;
+use governance_system::{
+    CreateProposalArgs, CreateProposalCpiAccounts, DisputeProposalData, ExecutionData, ProposalType,
+};
+
+declare_id!("F9qD1kWf3rFV5NAk4TQXTtz2fxvLZgTDWYXD1AW2cjs7");
+
+/// Order PDA seed
+pub const ORDER_SEED: &[u8] = b"order";
+
+/// Escalation authority PDA seed (proposer of escalated disputes, paying their deposits)
+pub const ESCALATION_AUTHORITY_SEED: &[u8] = b"escalation_authority";
+
+/// Mock marketplace order program: orders with the party layout dispute proposals are
+/// checked against
+#[program]
+pub mod governance_mock_order {
+    use super::*;
+
+    /// Create an order between the signing user and a merchant
+    pub fn create_order(ctx: Context<CreateOrder>, order_id: u64, merchant: Pubkey) -> Result<()> {
+        let order = &mut ctx.accounts.order;
+        order.user = ctx.accounts.user.key();
+        order.merchant = merchant;
+        order.order_id = order_id;
+        order.bump = ctx.bumps.order;
+
+        msg!("Order {} created", order_id);
+        Ok(())
+    }
+
+    /// Escalate an order to a dispute arbitration proposal, created through CPI with the
+    /// escalation authority as proposer
+    pub fn escalate_dispute(
+        ctx: Context<EscalateDispute>,
+        proposal_id: u64,
+        evidence_urls: Vec<String>,
+        requested_resolution: String,
+    ) -> Result<()> {
+        let order = &ctx.accounts.order;
+        let execution_data = ExecutionData::Dispute(DisputeProposalData {
+            user_address: order.user,
+            merchant_address: order.merchant,
+            order_address: order.key(),
+            dispute_type: "buyer_escalation".to_string(),
+            evidence_urls,
+            requested_resolution,
+            arbitration_decision: None,
+            proposed_decision: None,
+        });
+
+        let seeds = &[ESCALATION_AUTHORITY_SEED, &[ctx.bumps.escalation_authority]];
+        let signer_seeds = &[&seeds[..]];
+        let accounts = CreateProposalCpiAccounts {
+            proposal: ctx.accounts.proposal.to_account_info(),
+            governance_config: ctx.accounts.governance_config.to_account_info(),
+            proposer: ctx.accounts.escalation_authority.to_account_info(),
+            proposer_policy: ctx.accounts.proposer_policy.to_account_info(),
+            proposer_state: ctx.accounts.proposer_state.to_account_info(),
+            proposer_token_account: ctx.accounts.escalation_token_account.to_account_info(),
+            deposit_mint: ctx.accounts.deposit_mint.to_account_info(),
+            governance_token_vault: ctx.accounts.governance_token_vault.to_account_info(),
+            deposit_escrow: None,
+            governance_authority: ctx.accounts.governance_authority.to_account_info(),
+            dispute_order: Some(order.to_account_info()),
+            governance_stats: ctx.accounts.governance_stats.to_account_info(),
+            cpi_caller_allowlist: ctx.accounts.cpi_caller_allowlist.to_account_info(),
+            instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        }
+        .into_accounts();
+        let created = governance_system::cpi::create_proposal(
+            CpiContext::new_with_signer(
+                ctx.accounts.governance_program.to_account_info(),
+                accounts,
+                signer_seeds,
+            ),
+            proposal_id,
+            format!("Order {} dispute", order.order_id),
+            "The buyer escalated this order to committee arbitration.".to_string(),
+            ProposalType::DisputeArbitration,
+            CreateProposalArgs {
+                execution_data: Some(execution_data),
+                ..Default::default()
+            },
+        )?
+        .get();
+
+        msg!(
+            "Order {} escalated as proposal {}",
+            order.order_id,
+            created.id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(order_id: u64)]
+pub struct CreateOrder<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = 8 + Order::INIT_SPACE,
+        seeds = [ORDER_SEED, user.key().as_ref(), order_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub order: Account<'info, Order>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EscalateDispute<'info> {
+    /// Escalated order
+    #[account(has_one = user)]
+    pub order: Account<'info, Order>,
+
+    /// Buyer escalating the order
+    pub user: Signer<'info>,
+
+    /// Proposer of the dispute proposal
+    /// CHECK: System account PDA, only signs the governance CPI
+    #[account(
+        mut,
+        seeds = [ESCALATION_AUTHORITY_SEED],
+        bump
+    )]
+    pub escalation_authority: UncheckedAccount<'info>,
+
+    /// Deposit source, owned by the escalation authority
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub escalation_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub proposal: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub governance_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub proposer_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub proposer_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub deposit_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub governance_token_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    #[account(mut)]
+    pub governance_stats: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub cpi_caller_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the governance program
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub governance_program: Program<'info, Governance>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Validated by the governance program
+    pub token_program: UncheckedAccount<'info>,
+}
+
+/// Marketplace order; the parties come first, as governance's order_interface expects
+#[account]
+#[derive(InitSpace)]
+pub struct Order {
+    /// Buyer
+    pub user: Pubkey,
+    /// Merchant
+    pub merchant: Pubkey,
+    /// Order ID
+    pub order_id: u64,
+    /// PDA bump
+    pub bump: u8,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use governance_system::adapter_interface::ExecutionAdapterArgs;
+use governance_system::{ProposalType, ADAPTER_AUTHORITY_SEED};
+
+declare_id!("CrLxSBXjEpLFkuqThf1jttGjtUvqdMUF6TXgEWs3rKNK");
+
+/// Adapter call record PDA seed
+pub const ADAPTER_CALL_SEED: &[u8] = b"adapter_call";
+
+/// Reference execution adapter: records every proposal execution handed to it
+#[program]
+pub mod governance_reference_adapter {
+    use super::*;
+
+    /// Adapter entry point invoked by execute_proposal
+    /// Remaining governance accounts: adapter call record, payer, system program
+    pub fn execute_governance_proposal(
+        ctx: Context<ExecuteGovernanceProposal>,
+        args: ExecutionAdapterArgs,
+    ) -> Result<()> {
+        let adapter_call = &mut ctx.accounts.adapter_call;
+        adapter_call.proposal = ctx.accounts.proposal.key();
+        adapter_call.proposal_id = args.proposal_id;
+        adapter_call.proposal_type = args.proposal_type;
+        adapter_call.execution_data_len = args.execution_data.len() as u32;
+        adapter_call.called_at = Clock::get()?.unix_timestamp;
+        adapter_call.bump = ctx.bumps.adapter_call;
+
+        msg!("Recorded execution of proposal {}", args.proposal_id);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ExecuteGovernanceProposal<'info> {
+    /// CHECK: Executed proposal, owned by the governance program
+    #[account(owner = governance_system::ID)]
+    pub proposal: UncheckedAccount<'info>,
+
+    /// Governance adapter authority PDA, signing every adapter call
+    #[account(
+        seeds = [ADAPTER_AUTHORITY_SEED],
+        bump,
+        seeds::program = governance_system::ID
+    )]
+    pub adapter_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AdapterCall::INIT_SPACE,
+        seeds = [ADAPTER_CALL_SEED, proposal.key().as_ref()],
+        bump
+    )]
+    pub adapter_call: Account<'info, AdapterCall>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record of one adapter call
+#[account]
+#[derive(InitSpace)]
+pub struct AdapterCall {
+    /// Executed proposal account
+    pub proposal: Pubkey,
+    /// Executed proposal ID
+    pub proposal_id: u64,
+    /// Executed proposal type
+    pub proposal_type: ProposalType,
+    /// Length of the execution data passed by governance
+    pub execution_data_len: u32,
+    /// Call timestamp
+    pub called_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
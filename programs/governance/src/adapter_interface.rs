@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::state::ProposalType;
+
+/// Instruction discriminator of adapter calls: sha256("global:execute_governance_proposal")[..8]
+pub const EXECUTION_ADAPTER_DISCRIMINATOR: [u8; 8] = [54, 81, 227, 205, 134, 185, 125, 80];
+
+/// Arguments passed to an execution adapter
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ExecutionAdapterArgs {
+    /// Executed proposal ID
+    pub proposal_id: u64,
+    /// Executed proposal type
+    pub proposal_type: ProposalType,
+    /// Borsh-encoded ExecutionData (empty when the proposal has none)
+    pub execution_data: Vec<u8>,
+}
+
+/// Build the adapter call for an executed proposal
+/// Data: EXECUTION_ADAPTER_DISCRIMINATOR followed by the borsh-encoded arguments
+/// Accounts: the proposal (read-only), the adapter authority PDA (signer), then every
+/// account passed to execute_proposal as remaining accounts, in order
+pub fn execution_adapter_instruction(
+    adapter: Pubkey,
+    proposal: Pubkey,
+    adapter_authority: Pubkey,
+    args: &ExecutionAdapterArgs,
+    remaining_accounts: &[AccountInfo],
+) -> Result<Instruction> {
+    let mut data = EXECUTION_ADAPTER_DISCRIMINATOR.to_vec();
+    args.serialize(&mut data)?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(proposal, false),
+        AccountMeta::new_readonly(adapter_authority, true),
+    ];
+    accounts.extend(remaining_accounts.iter().map(|account| AccountMeta {
+        pubkey: account.key(),
+        is_signer: account.is_signer,
+        is_writable: account.is_writable,
+    }));
+
+    Ok(Instruction {
+        program_id: adapter,
+        accounts,
+        data,
+    })
+}
@@ -2,3 +2,7 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+/// Maximum number of documents in the rule registry
+#[constant]
+pub const MAX_RULE_DOCUMENTS: u32 = 50;
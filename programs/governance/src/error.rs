@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+/// Error codes are part of the program ABI: append new variants at the end, after the
+/// last section, so existing codes never shift
 #[error_code]
 pub enum GovernanceError {
     // Governance system initialization errors
@@ -11,16 +13,42 @@ pub enum GovernanceError {
     InvalidAuthority,
     #[msg("Unauthorized operation")]
     Unauthorized,
+    #[msg("No authority transfer pending")]
+    NoPendingAuthorityTransfer,
+    #[msg("Instruction requires admin set approval while multisig mode is enabled")]
+    AdminMultisigRequired,
+    #[msg("Not an admin")]
+    NotAdmin,
+    #[msg("Invalid admin set")]
+    InvalidAdminSet,
+    #[msg("Admin action already approved by this admin")]
+    AdminActionAlreadyApproved,
+    #[msg("Admin action already executed")]
+    AdminActionAlreadyExecuted,
+    #[msg("Insufficient admin approvals")]
+    InsufficientAdminApprovals,
 
     // Configuration related errors
     #[msg("Invalid threshold value")]
     InvalidThreshold,
+    #[msg("Approval threshold must be between 1 and 9999 basis points")]
+    ApprovalThresholdOutOfRange,
+    #[msg("Veto threshold is below the minimum of 500 basis points")]
+    VetoThresholdTooLow,
     #[msg("Invalid fee rate")]
     InvalidFeeRate,
     #[msg("Invalid voting period")]
     InvalidVotingPeriod,
     #[msg("Math overflow")]
     MathOverflow,
+    #[msg("Deposit slash rates must not decrease for worse outcomes")]
+    InvalidSlashSchedule,
+    #[msg("Test mode cannot be re-enabled on a production deployment")]
+    TestModeLocked,
+    #[msg("Governance configuration must be migrated to the current schema version")]
+    ConfigMigrationRequired,
+    #[msg("Governance configuration is already at the current schema version")]
+    ConfigAlreadyMigrated,
 
     // Proposal related errors
     #[msg("Proposal not found")]
@@ -39,12 +67,38 @@ pub enum GovernanceError {
     InvalidProposalTitleLength,
     #[msg("Invalid proposal description length")]
     InvalidProposalDescriptionLength,
+    #[msg("Invalid proposal title encoding")]
+    InvalidProposalTitleEncoding,
+    #[msg("Invalid proposal description encoding")]
+    InvalidProposalDescriptionEncoding,
     #[msg("Insufficient proposal deposit")]
     InsufficientProposalDeposit,
     #[msg("Proposal not finalized")]
     ProposalNotFinalized,
     #[msg("Proposal not vetoed")]
     ProposalNotVetoed,
+    #[msg("Invalid proposal dependency")]
+    InvalidProposalDependency,
+    #[msg("Dependency proposal not executed")]
+    DependencyNotExecuted,
+    #[msg("Proposal not eligible for resubmission")]
+    ProposalNotResubmittable,
+    #[msg("Proposal already resubmitted")]
+    ProposalAlreadyResubmitted,
+    #[msg("Proposal ID does not match next proposal counter")]
+    ProposalIdMismatch,
+    #[msg("Pending proposals exist")]
+    PendingProposalsExist,
+    #[msg("Maximum number of active proposals reached")]
+    ActiveProposalLimitReached,
+    #[msg("Proposal counter may only advance by up to 1000")]
+    InvalidProposalCounter,
+    #[msg("Remaining accounts do not match the crank entries")]
+    InvalidCrankAccounts,
+    #[msg("Proposal is not terminal or its deposit is not settled")]
+    ProposalNotArchivable,
+    #[msg("Proposal archive required unless skip_archive is set")]
+    ProposalArchiveRequired,
 
     // Voting related errors
     #[msg("Already voted")]
@@ -59,26 +113,86 @@ pub enum GovernanceError {
     InvalidVoteType,
     #[msg("Cannot revoke vote")]
     CannotRevokeVote,
+    #[msg("Vote message signature missing or invalid")]
+    InvalidVoteSignature,
+    #[msg("Vote message expired")]
+    VoteMessageExpired,
 
     // Committee member management errors
     #[msg("Committee is full")]
     CommitteeFull,
+    #[msg("Invalid committee size or active proposal limit")]
+    InvalidGovernanceLimit,
     #[msg("Member already exists")]
     MemberAlreadyExists,
     #[msg("Member not found")]
     MemberNotFound,
     #[msg("Not a committee member")]
     NotCommitteeMember,
+    #[msg("Committee member is suspended")]
+    MemberSuspended,
+    #[msg("Member has not missed enough finalized proposals to be flagged inactive")]
+    InsufficientMissedProposals,
+    #[msg("Missed proposal evidence is invalid")]
+    InvalidInactivityEvidence,
+    #[msg("Member has not staked the required committee tokens")]
+    MemberStakeRequired,
+    #[msg("Member stake is still locked")]
+    MemberStakeLocked,
+    #[msg("Invalid committee member display name")]
+    InvalidMemberDisplayName,
+    #[msg("Committee member term has expired")]
+    MemberTermExpired,
+    #[msg("Committee member term must end in the future")]
+    InvalidMemberTerm,
+    #[msg("Invalid committee member alternate")]
+    InvalidAlternate,
+    #[msg("Primary committee member is still active")]
+    PrimaryMemberActive,
+    #[msg("Alternate took over the seat after the proposal was created")]
+    AlternateNotInSnapshot,
+    #[msg("Invalid committee change")]
+    InvalidCommitteeChange,
+    #[msg("Committee changes require a governance proposal outside test mode")]
+    CommitteeChangeRequiresProposal,
+    #[msg("Committee member accounts do not match the committee")]
+    InvalidCommitteeAccounts,
+    #[msg("Legacy committee members must be migrated first")]
+    CommitteeMigrationRequired,
+    #[msg("Participation bonus must be at most 100% with a window of 1 to 64 proposals")]
+    InvalidParticipationBonus,
+
+    // Proposer policy errors
+    #[msg("Proposer is banned")]
+    ProposerBanned,
+    #[msg("Proposer not on allowlist")]
+    ProposerNotAllowed,
+    #[msg("Proposer already listed")]
+    ProposerAlreadyListed,
+    #[msg("Proposer not listed")]
+    ProposerNotListed,
+    #[msg("Proposer list is full")]
+    ProposerListFull,
 
     // Rule management errors
     #[msg("Rule registry not found")]
     RuleRegistryNotFound,
     #[msg("Rule document not found")]
     RuleDocumentNotFound,
+    #[msg("Rule registry name is too long")]
+    InvalidRuleRegistryName,
+    #[msg("Rule registry authority is out of sync with the governance authority")]
+    RuleRegistryAuthorityMismatch,
     #[msg("Duplicate rule document")]
     DuplicateRuleDocument,
     #[msg("Too many rule documents")]
     TooManyRuleDocuments,
+    #[msg("Rule document page exceeds the return data limit; request fewer documents")]
+    RuleDocumentPageTooLarge,
+    #[msg("Rule document history exceeds the return data limit")]
+    RuleDocumentHistoryTooLarge,
+    #[msg("Unknown rule category; set allow_custom_category to add a custom one")]
+    UnknownRuleCategory,
     #[msg("Invalid category length")]
     InvalidCategoryLength,
     #[msg("Invalid title length")]
@@ -91,6 +205,14 @@ pub enum GovernanceError {
     InvalidUrlFormat,
     #[msg("Invalid hash format")]
     InvalidHashFormat,
+    #[msg("Rule document effective date is too far in the future")]
+    InvalidEffectiveDate,
+    #[msg("Rule document batch must hold between 1 and 10 documents")]
+    InvalidRuleDocumentBatch,
+    #[msg("Too many category admins")]
+    TooManyCategoryAdmins,
+    #[msg("Category admin not found")]
+    CategoryAdminNotFound,
 
     // Slash proposal errors
     #[msg("Invalid merchant address")]
@@ -103,8 +225,12 @@ pub enum GovernanceError {
     InvalidViolationType,
     #[msg("Too many evidence URLs")]
     TooManyEvidenceUrls,
+    #[msg("Insufficient evidence URLs")]
+    InsufficientEvidenceUrls,
     #[msg("Invalid slash amount")]
     InvalidSlashAmount,
+    #[msg("Merchant slash history account required")]
+    MerchantSlashHistoryRequired,
 
     // Dispute arbitration errors
     #[msg("Invalid dispute parties")]
@@ -121,12 +247,52 @@ pub enum GovernanceError {
     // Token related errors
     #[msg("Invalid token mint")]
     InvalidTokenMint,
+    #[msg("Committee token mint is not governed by this program or allowlisted")]
+    CommitteeMintNotAllowed,
+    #[msg("Token mint has too many decimals")]
+    InvalidMintDecimals,
     #[msg("Insufficient token balance")]
     InsufficientTokenBalance,
     #[msg("Token transfer failed")]
     TokenTransferFailed,
     #[msg("Invalid token account")]
     InvalidTokenAccount,
+    #[msg("Deposit already settled")]
+    DepositAlreadySettled,
+    #[msg("Refund claim account required when the refund cannot be pushed")]
+    RefundClaimRequired,
+    #[msg("Proposal outcome account required at finalization")]
+    ProposalOutcomeRequired,
+    #[msg("Proposal index account required while the proposal index is enabled")]
+    ProposalIndexRequired,
+    #[msg("Proposal index holds the maximum number of pending proposals")]
+    ProposalIndexFull,
+    #[msg("Deposit mint not accepted")]
+    DepositMintNotAccepted,
+    #[msg("Too many deposit mints")]
+    TooManyDepositMints,
+    #[msg("Insufficient withdrawable fees")]
+    InsufficientWithdrawableFees,
+    #[msg("Vault balance is below locked deposits")]
+    VaultInsolvent,
+    #[msg("Fee recipient accounts must be the committee members' token accounts")]
+    InvalidFeeRecipients,
+    #[msg("No committee participation to weight fee distribution")]
+    NoFeeDistributionWeight,
+    #[msg("Donation memo too long")]
+    DonationMemoTooLong,
+    #[msg("Deposits or treasury funds are still outstanding")]
+    OutstandingFunds,
+    #[msg("Token vault is not empty")]
+    VaultNotEmpty,
+    #[msg("Deposit escrow accounts must be supplied exactly for escrowed deposits")]
+    DepositEscrowMismatch,
+    #[msg("Token accounts required for SPL token deposits")]
+    TokenAccountsRequired,
+    #[msg("Native deposit vault and proposer accounts required for native SOL deposits")]
+    NativeDepositAccountsRequired,
+    #[msg("Memo program account required while deposit memos are enabled")]
+    MemoProgramRequired,
 
     // Mathematical operation errors
     #[msg("Arithmetic overflow")]
@@ -165,6 +331,12 @@ pub enum GovernanceError {
     InvalidExecutionData,
     #[msg("CPI call failed")]
     CpiCallFailed,
+    #[msg("Upgrade buffer is not an upgradeable loader buffer owned by the governance authority")]
+    InvalidUpgradeBuffer,
+    #[msg("Program upgrade accounts missing or mismatched")]
+    InvalidProgramUpgradeAccounts,
+    #[msg("Invalid execution adapter")]
+    InvalidExecutionAdapter,
 
     // General errors
     #[msg("Invalid input")]
@@ -173,4 +345,88 @@ pub enum GovernanceError {
     OperationNotAllowed,
     #[msg("Feature not implemented")]
     FeatureNotImplemented,
+
+    // Appended variants (keep new errors below, in order of addition)
+    #[msg("Governance is paused")]
+    GovernancePaused,
+    #[msg("Proposer cooldown is still active")]
+    ProposalCooldownActive,
+    #[msg("Proposal deposit exceeds the maximum")]
+    DepositTooLarge,
+    #[msg("Governance configuration changed since the expected version")]
+    StaleConfigVersion,
+    #[msg("Vote account passed more than once")]
+    DuplicateVoteAccount,
+    #[msg("Committee has too few members")]
+    CommitteeTooSmall,
+    #[msg("Proposal counter update reason too long")]
+    ProposalCounterReasonTooLong,
+    #[msg("Donation amount must be greater than zero")]
+    InvalidDonationAmount,
+    #[msg("Execution result too long")]
+    ExecutionResultTooLong,
+    #[msg("Deposit settlement does not add up to the deposit")]
+    DepositSettlementMismatch,
+    #[msg("Maximum slash deposit multiplier must be at least 10000 basis points")]
+    InvalidSlashDepositMultiplier,
+    #[msg("Inactivity threshold must be at least one proposal")]
+    InvalidInactivityThreshold,
+    #[msg("Duration must not be negative")]
+    InvalidDuration,
+    #[msg("Membership staking is not enabled")]
+    MemberStakeNotRequired,
+    #[msg("Committee member is already active")]
+    MemberAlreadyActive,
+    #[msg("Committee member is not suspended for inactivity")]
+    MemberNotSuspendedForInactivity,
+    #[msg("The primary deposit mint cannot be removed")]
+    PrimaryDepositMintRemoval,
+    #[msg("Instruction is only available in test mode")]
+    TestModeRequired,
+    #[msg("Invalid arbitration decision")]
+    InvalidArbitrationDecision,
+    #[msg("Proposer state account does not belong to the proposer")]
+    InvalidProposerState,
+    #[msg("Token lockups are disabled")]
+    LockupsDisabled,
+    #[msg("Lockup multiplier must be between 1x and 2x with a non-negative maximum duration")]
+    InvalidLockupParameters,
+    #[msg("Lock duration must be positive and at most the maximum lock duration")]
+    InvalidLockDuration,
+    #[msg("Locked tokens cannot be withdrawn before the unlock time")]
+    TokensStillLocked,
+    #[msg("Lock amount must be positive")]
+    InvalidLockAmount,
+    #[msg("Configuration update proposals may not enable test mode")]
+    ProposalEnablesTestMode,
+    #[msg("Approval and veto thresholds together may not exceed 100%")]
+    ConflictingThresholds,
+    #[msg("Proposer balance did not drop by exactly the deposit")]
+    DepositBalanceMismatch,
+    #[msg("Queued configuration update cannot be applied before its eta")]
+    ConfigUpdateTimelocked,
+    #[msg("Proposal options must be 2 to 5 distinct labels of at most 32 bytes, without execution data")]
+    InvalidProposalOptions,
+    #[msg("Vote type does not match the proposal's options")]
+    InvalidVoteChoice,
+    #[msg("Recused members must be at most 5 distinct current committee members")]
+    InvalidRecusedMembers,
+    #[msg("Voter is recused from this proposal")]
+    VoterRecused,
+    #[msg("Total voting power exceeds the committee token supply")]
+    VotingPowerExceedsSupply,
+    #[msg("Calling program is not allowed to create proposals through CPI")]
+    CpiCallerNotAllowed,
+    #[msg("Invalid CPI caller")]
+    InvalidCpiCaller,
+    #[msg("Signed message nonce does not match the member's current nonce")]
+    InvalidVoteNonce,
+    #[msg("Heartbeat interval has not passed since the last heartbeat")]
+    HeartbeatTooSoon,
+    #[msg("No execution adapter change is queued for this proposal type")]
+    ExecutionAdapterNotQueued,
+    #[msg("Pending config update account must be supplied exactly for configuration update actions")]
+    PendingConfigUpdateMismatch,
+    #[msg("Admin action does not match this instruction call")]
+    AdminActionMismatch,
 }
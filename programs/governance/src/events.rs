@@ -0,0 +1,281 @@
+//! Governance lifecycle events
+//! Every event here starts with schema_version and governance_config, so indexers
+//! following several deployments can tell both the layout and the deployment apart
+
+use anchor_lang::prelude::*;
+
+use crate::instructions::common::GOVERNANCE_CONFIG_SEED;
+use crate::instructions::treasury::{TransferDirection, TreasuryTransferReason};
+use crate::state::*;
+
+/// Layout version of the events in this module
+/// Bump it whenever a field is added to, removed from or reordered in one of them
+#[constant]
+pub const EVENT_SCHEMA_VERSION: u8 = 3;
+
+/// Governance config address, derived from the config PDA's stored bump
+pub fn governance_config_address(bump: u8) -> Result<Pubkey> {
+    let address = Pubkey::create_program_address(&[GOVERNANCE_CONFIG_SEED, &[bump]], &crate::ID)
+        .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+    Ok(address)
+}
+
+/// Governance config address for helpers that do not receive the config account
+pub fn derive_governance_config_address() -> Pubkey {
+    Pubkey::find_program_address(&[GOVERNANCE_CONFIG_SEED], &crate::ID).0
+}
+
+/// Event emitted when a proposal is created or resubmitted
+#[event]
+pub struct ProposalCreated {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// New proposal ID
+    pub proposal_id: u64,
+    /// Proposer
+    pub proposer: Pubkey,
+    /// Proposal type
+    pub proposal_type: ProposalType,
+    /// Deposit mint
+    pub deposit_mint: Pubkey,
+    /// Deposit amount
+    pub deposit_amount: u64,
+    /// End of the voting period
+    pub voting_end: i64,
+    /// Rejected proposal this one resubmits, if any
+    pub resubmission_of: Option<u64>,
+    /// Creation timestamp
+    pub timestamp: i64,
+    /// Proposer's passed, rejected, vetoed and cancelled proposals at creation
+    pub proposer_reputation: ProposerReputation,
+}
+
+/// Event emitted when a committee member casts a vote
+#[event]
+pub struct VoteCast {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Proposal voted on
+    pub proposal_id: u64,
+    /// Voter
+    pub voter: Pubkey,
+    /// Vote account (PDA of the proposal and voter)
+    pub vote: Pubkey,
+    /// Vote type
+    pub vote_type: VoteType,
+    /// Token balance snapshot (voting power is calculated at finalization)
+    pub token_balance: u64,
+    /// Vote timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a vote account is closed
+#[event]
+pub struct VoteClosed {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Proposal the vote was cast on
+    pub proposal_id: u64,
+    /// Voter
+    pub voter: Pubkey,
+    /// Closed vote account
+    pub vote: Pubkey,
+    /// Signer that closed the vote (receives the account rent)
+    pub closed_by: Pubkey,
+    /// Closing timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a proposal is finalized
+#[event]
+pub struct ProposalFinalized {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Finalized proposal
+    pub proposal_id: u64,
+    /// Status the proposal was finalized with
+    pub status: ProposalStatus,
+    /// Yes votes
+    pub yes_votes: u64,
+    /// No votes
+    pub no_votes: u64,
+    /// Abstain votes
+    pub abstain_votes: u64,
+    /// Veto votes
+    pub veto_votes: u64,
+    /// Committee voting power the proposal was finalized against
+    pub total_voting_power: u64,
+    /// Finalization timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a passed proposal is executed
+#[event]
+pub struct ProposalExecuted {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Executed proposal
+    pub proposal_id: u64,
+    /// Proposal type
+    pub proposal_type: ProposalType,
+    /// Execution timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted on every change in committee composition, for indexers grouping
+/// proposals and tallies by committee epoch
+#[event]
+pub struct CommitteeChanged {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Committee epoch after the change
+    pub epoch: u64,
+    /// Kind of change
+    pub kind: CommitteeChangeKind,
+    /// Member whose seat changed
+    pub member: Pubkey,
+    /// Committee size after the change
+    pub member_count: u8,
+    /// Change timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a configuration update is applied
+/// Only changed fields are set, with matching previous and new values
+#[event]
+pub struct GovernanceConfigUpdated {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Signer that applied the update
+    pub updater: Pubkey,
+    /// Previous values of the changed fields
+    pub previous: GovernanceConfigUpdate,
+    /// New values of the changed fields
+    pub new: GovernanceConfigUpdate,
+    /// Pending proposals at the time of the update, which keep the fee rate and deposit
+    /// slash schedule they were created with
+    pub pending_proposal_count: u64,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Ledger event emitted for every movement into, out of, or within a governance vault
+#[event]
+pub struct TreasuryTransfer {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Vault token mint
+    pub mint: Pubkey,
+    /// Movement direction
+    pub direction: TransferDirection,
+    /// Movement reason
+    pub reason: TreasuryTransferReason,
+    /// Proposal the movement belongs to, if any
+    pub proposal_id: Option<u64>,
+    /// Token account on the other side (the mint for burns, the vault itself
+    /// for internal attributions)
+    pub counterparty: Pubkey,
+    /// Moved amount
+    pub amount: u64,
+    /// Vault (or proposal escrow) balance after the movement
+    pub vault_balance_after: u64,
+    /// Movement timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a document is added to a rule registry
+#[event]
+pub struct RuleDocumentAdded {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Rule registry
+    pub rule_registry: Pubkey,
+    /// New document ID
+    pub document_id: u32,
+    /// Document category
+    pub category: String,
+    /// Document hash
+    pub hash: String,
+    /// Registry version after the addition
+    pub registry_version: u32,
+    /// Addition timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a rule document is updated
+#[event]
+pub struct RuleDocumentUpdated {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Rule registry
+    pub rule_registry: Pubkey,
+    /// Updated document ID
+    pub document_id: u32,
+    /// Document URL after the update
+    pub url: String,
+    /// Document hash after the update
+    pub hash: String,
+    /// Registry version after the update
+    pub registry_version: u32,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a rule document is removed
+#[event]
+pub struct RuleDocumentRemoved {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Rule registry
+    pub rule_registry: Pubkey,
+    /// Removed document ID
+    pub document_id: u32,
+    /// Registry version after the removal
+    pub registry_version: u32,
+    /// Removal timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted by touch_governance, at most once per heartbeat_interval
+/// Lets indexers tell a quiet deployment from a stalled indexer
+#[event]
+pub struct Heartbeat {
+    /// Event layout version (EVENT_SCHEMA_VERSION)
+    pub schema_version: u8,
+    /// Governance config of the deployment
+    pub governance_config: Pubkey,
+    /// Last assigned proposal ID
+    pub proposal_counter: u64,
+    /// Proposals still in Pending status
+    pub pending_proposal_count: u64,
+    /// Committee composition counter
+    pub committee_epoch: u64,
+    /// Mint of the reported token vault
+    pub vault_mint: Pubkey,
+    /// Token vault balance
+    pub vault_balance: u64,
+    /// Heartbeat timestamp
+    pub timestamp: i64,
+}
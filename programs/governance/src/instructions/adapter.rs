@@ -0,0 +1,336 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::adapter_interface::{execution_adapter_instruction, ExecutionAdapterArgs};
+use crate::error::GovernanceError;
+use crate::instructions::admin::authorize_admin_call;
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Create execution adapter registry
+#[derive(Accounts)]
+pub struct CreateExecutionAdapterRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ExecutionAdapterRegistry::INIT_SPACE,
+        seeds = [EXECUTION_ADAPTER_REGISTRY_SEED],
+        bump
+    )]
+    pub execution_adapter_registry: Account<'info, ExecutionAdapterRegistry>,
+
+    /// Governance configuration account for permission verification
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can create the registry
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Create execution adapter registry handler
+pub fn create_execution_adapter_registry(
+    ctx: Context<CreateExecutionAdapterRegistry>,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CreateExecutionAdapterRegistry,
+    )?;
+
+    let registry = &mut ctx.accounts.execution_adapter_registry;
+    registry.adapters = Vec::new();
+    registry.pending_adapters = Vec::new();
+    registry.updated_at = Clock::get()?.unix_timestamp;
+    registry.bump = ctx.bumps.execution_adapter_registry;
+
+    msg!("Execution adapter registry created");
+    Ok(())
+}
+
+/// Update execution adapter registry
+#[derive(Accounts)]
+pub struct UpdateExecutionAdapterRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [EXECUTION_ADAPTER_REGISTRY_SEED],
+        bump = execution_adapter_registry.bump
+    )]
+    pub execution_adapter_registry: Account<'info, ExecutionAdapterRegistry>,
+
+    /// Governance configuration account for permission verification
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can register adapters
+    #[account(
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Event emitted when the adapter for a proposal type changes
+#[event]
+pub struct ExecutionAdapterUpdated {
+    /// Proposal type
+    pub proposal_type: ProposalType,
+    /// Previously registered adapter
+    pub previous_adapter: Option<Pubkey>,
+    /// Newly registered adapter (None: proposals of the type execute log-only)
+    pub adapter: Option<Pubkey>,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Set execution adapter handler
+/// None removes the registration; immediate changes are test mode only (otherwise
+/// queue_execution_adapter)
+pub fn set_execution_adapter(
+    ctx: Context<UpdateExecutionAdapterRegistry>,
+    proposal_type: ProposalType,
+    adapter: Option<Pubkey>,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetExecutionAdapter {
+            proposal_type: proposal_type.clone(),
+            adapter,
+        },
+    )?;
+
+    require!(
+        ctx.accounts.governance_config.test_mode,
+        GovernanceError::TestModeRequired
+    );
+    let registry = &mut ctx.accounts.execution_adapter_registry;
+    let previous_adapter = registry.set_adapter(proposal_type.clone(), adapter)?;
+
+    emit!(ExecutionAdapterUpdated {
+        proposal_type: proposal_type.clone(),
+        previous_adapter,
+        adapter,
+        timestamp: registry.updated_at,
+    });
+
+    msg!(
+        "Execution adapter for {:?} set to {:?}",
+        proposal_type,
+        adapter
+    );
+    Ok(())
+}
+
+/// Event emitted when an adapter registration change is queued
+#[event]
+pub struct ExecutionAdapterQueued {
+    /// Proposal type
+    pub proposal_type: ProposalType,
+    /// Adapter to register once applied (None: the registration is removed)
+    pub adapter: Option<Pubkey>,
+    /// Earliest time the change can be applied
+    pub eta: i64,
+    /// Queue timestamp
+    pub timestamp: i64,
+}
+
+/// Queue execution adapter handler
+/// The change waits out config_timelock, like a queued configuration update
+pub fn queue_execution_adapter(
+    ctx: Context<UpdateExecutionAdapterRegistry>,
+    proposal_type: ProposalType,
+    adapter: Option<Pubkey>,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::QueueExecutionAdapter {
+            proposal_type: proposal_type.clone(),
+            adapter,
+        },
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let eta = timestamp
+        .checked_add(ctx.accounts.governance_config.config_timelock)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    let registry = &mut ctx.accounts.execution_adapter_registry;
+    registry.queue_adapter(proposal_type.clone(), adapter, eta)?;
+    registry.updated_at = timestamp;
+
+    emit!(ExecutionAdapterQueued {
+        proposal_type: proposal_type.clone(),
+        adapter,
+        eta,
+        timestamp,
+    });
+
+    msg!(
+        "Execution adapter for {:?} queued as {:?} until {}",
+        proposal_type,
+        adapter,
+        eta
+    );
+    Ok(())
+}
+
+/// Apply a queued adapter registration change once its timelock has run out
+#[derive(Accounts)]
+pub struct ApplyExecutionAdapter<'info> {
+    #[account(
+        mut,
+        seeds = [EXECUTION_ADAPTER_REGISTRY_SEED],
+        bump = execution_adapter_registry.bump
+    )]
+    pub execution_adapter_registry: Account<'info, ExecutionAdapterRegistry>,
+}
+
+/// Apply execution adapter handler
+/// Anyone may apply a queued change after its eta
+pub fn apply_execution_adapter(
+    ctx: Context<ApplyExecutionAdapter>,
+    proposal_type: ProposalType,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.execution_adapter_registry;
+    let pending = registry.take_pending_adapter(&proposal_type, Clock::get()?.unix_timestamp)?;
+    let previous_adapter = registry.set_adapter(proposal_type.clone(), pending.program_id)?;
+
+    emit!(ExecutionAdapterUpdated {
+        proposal_type: proposal_type.clone(),
+        previous_adapter,
+        adapter: pending.program_id,
+        timestamp: registry.updated_at,
+    });
+
+    msg!(
+        "Queued execution adapter for {:?} applied: {:?}",
+        proposal_type,
+        pending.program_id
+    );
+    Ok(())
+}
+
+/// Event emitted when an executed proposal is handed to its adapter
+#[event]
+pub struct ExecutionAdapterInvoked {
+    /// Proposal ID
+    pub proposal_id: u64,
+    /// Adapter program
+    pub adapter: Pubkey,
+    /// Execution timestamp
+    pub timestamp: i64,
+}
+
+/// Invoke the adapter registered for the proposal's type, if any
+/// Returns the adapter that was invoked; without a registration execution stays log-only
+pub(crate) fn invoke_execution_adapter<'info>(
+    registry_account: &AccountInfo<'info>,
+    proposal: &AccountLoader<'info, Proposal>,
+    adapter_program: Option<&AccountInfo<'info>>,
+    adapter_authority: Option<&AccountInfo<'info>>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Option<Pubkey>> {
+    // Copied out so that no borrow of the proposal is held during the CPI
+    let args = {
+        let proposal = proposal.load()?;
+        ExecutionAdapterArgs {
+            proposal_id: proposal.id,
+            proposal_type: proposal.proposal_type()?,
+            execution_data: proposal.execution_data_bytes().to_vec(),
+        }
+    };
+    let Some(adapter) = ExecutionAdapterRegistry::try_load(registry_account)?
+        .and_then(|registry| registry.adapter_for(&args.proposal_type))
+    else {
+        return Ok(None);
+    };
+
+    let adapter_program = adapter_program.ok_or(GovernanceError::InvalidExecutionAdapter)?;
+    let adapter_authority = adapter_authority.ok_or(GovernanceError::InvalidExecutionAdapter)?;
+    // Adapters are signed for by their own PDA: the governance authority owns the vaults,
+    // and remaining accounts are forwarded to the adapter as given
+    let (authority_address, authority_bump) =
+        Pubkey::find_program_address(&[ADAPTER_AUTHORITY_SEED], &crate::ID);
+    require!(
+        adapter_program.key() == adapter
+            && adapter_program.executable
+            && adapter_authority.key() == authority_address,
+        GovernanceError::InvalidExecutionAdapter
+    );
+
+    let instruction = execution_adapter_instruction(
+        adapter,
+        proposal.key(),
+        authority_address,
+        &args,
+        remaining_accounts,
+    )?;
+
+    let mut account_infos = vec![
+        proposal.to_account_info(),
+        adapter_authority.clone(),
+        adapter_program.clone(),
+    ];
+    account_infos.extend_from_slice(remaining_accounts);
+    invoke_signed(
+        &instruction,
+        &account_infos,
+        &[&[ADAPTER_AUTHORITY_SEED, &[authority_bump]]],
+    )?;
+
+    emit!(ExecutionAdapterInvoked {
+        proposal_id: args.proposal_id,
+        adapter,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(Some(adapter))
+}
@@ -0,0 +1,525 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::GovernanceError;
+use crate::instructions::common::*;
+use crate::instructions::initialize::{
+    add_committee_member_account, queue_governance_config_update,
+};
+use crate::instructions::treasury::{
+    record_fee_withdrawal, require_withdrawable, transfer_treasury_withdrawal,
+};
+use crate::state::*;
+
+/// Initialize admin set
+#[derive(Accounts)]
+pub struct InitializeAdminSet<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AdminSet::INIT_SPACE,
+        seeds = [ADMIN_SET_SEED],
+        bump
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can create the admin set (also pays rent)
+    #[account(
+        mut,
+        constraint = authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize admin set handler
+/// Enables multisig mode: from now on privileged instructions need M-of-N admin approval
+pub fn initialize_admin_set(
+    ctx: Context<InitializeAdminSet>,
+    admins: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let admin_set = &mut ctx.accounts.admin_set;
+    admin_set.configure(admins, threshold)?;
+    admin_set.action_counter = 0;
+    admin_set.bump = ctx.bumps.admin_set;
+
+    enable_admin_multisig(&mut ctx.accounts.governance_config, admin_set)
+}
+
+/// Reconfigure admin set
+#[derive(Accounts)]
+pub struct ConfigureAdminSet<'info> {
+    #[account(
+        mut,
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can reconfigure, and only while single-authority mode is active
+    #[account(
+        constraint = authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized,
+        constraint = !governance_config.admin_multisig_enabled @ GovernanceError::AdminMultisigRequired
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Reconfigure admin set handler
+/// Replaces the admins and threshold and re-enables multisig mode
+pub fn configure_admin_set(
+    ctx: Context<ConfigureAdminSet>,
+    admins: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let admin_set = &mut ctx.accounts.admin_set;
+    admin_set.configure(admins, threshold)?;
+
+    enable_admin_multisig(&mut ctx.accounts.governance_config, admin_set)
+}
+
+/// Switch privileged instructions over to admin set approval
+fn enable_admin_multisig(
+    governance_config: &mut GovernanceConfig,
+    admin_set: &AdminSet,
+) -> Result<()> {
+    governance_config.admin_multisig_enabled = true;
+    governance_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AdminSetConfigured {
+        admins: admin_set.admins.clone(),
+        threshold: admin_set.threshold,
+        timestamp: governance_config.updated_at,
+    });
+
+    msg!(
+        "Admin multisig enabled: {} of {} admins",
+        admin_set.threshold,
+        admin_set.admins.len()
+    );
+    Ok(())
+}
+
+/// Propose admin action
+#[derive(Accounts)]
+pub struct ProposeAdminAction<'info> {
+    #[account(
+        mut,
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AdminAction::INIT_SPACE,
+        seeds = [ADMIN_ACTION_SEED, admin_set.upcoming_action_id()?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    /// Proposing admin (approves the action on creation)
+    #[account(
+        mut,
+        constraint = admin_set.is_admin(&admin.key()) @ GovernanceError::NotAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose admin action handler
+pub fn propose_admin_action(
+    ctx: Context<ProposeAdminAction>,
+    action: AdminActionKind,
+) -> Result<()> {
+    let admin_set = &mut ctx.accounts.admin_set;
+    let admin_action = &mut ctx.accounts.admin_action;
+    let admin = ctx.accounts.admin.key();
+
+    admin_action.id = admin_set.next_action_id()?;
+    admin_action.proposer = admin;
+    admin_action.action = action;
+    admin_action.approvals = vec![admin];
+    admin_action.executed = false;
+    admin_action.created_at = Clock::get()?.unix_timestamp;
+    admin_action.executed_at = None;
+    admin_action.bump = ctx.bumps.admin_action;
+
+    emit!(AdminActionProposed {
+        action_id: admin_action.id,
+        proposer: admin,
+        action: admin_action.action.clone(),
+        timestamp: admin_action.created_at,
+    });
+
+    msg!("Admin action {} proposed by {}", admin_action.id, admin);
+    Ok(())
+}
+
+/// Approve admin action
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ApproveAdminAction<'info> {
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, action_id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    /// Approving admin
+    #[account(
+        constraint = admin_set.is_admin(&admin.key()) @ GovernanceError::NotAdmin
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// Approve admin action handler
+pub fn approve_admin_action(ctx: Context<ApproveAdminAction>, action_id: u64) -> Result<()> {
+    let admin_action = &mut ctx.accounts.admin_action;
+    let admin = ctx.accounts.admin.key();
+    admin_action.approve(admin, &ctx.accounts.admin_set)?;
+
+    let approvals = admin_action.approvals.len();
+    emit!(AdminActionApproved {
+        action_id,
+        admin,
+        approvals: approvals as u8,
+        threshold: ctx.accounts.admin_set.threshold,
+    });
+
+    msg!(
+        "Admin action {} approved by {} ({}/{})",
+        action_id,
+        admin,
+        approvals,
+        ctx.accounts.admin_set.threshold
+    );
+    Ok(())
+}
+
+/// Execute admin action
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, action_id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Anyone can execute a sufficiently approved action (pays for created committee members
+    /// and queued config updates, and receives their rent back)
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// Queued update to create (config updates only; one at a time)
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + PendingConfigUpdate::INIT_SPACE,
+        seeds = [PENDING_CONFIG_UPDATE_SEED],
+        bump
+    )]
+    pub pending_config_update: Option<Account<'info, PendingConfigUpdate>>,
+
+    /// Committee member account to create (committee member additions only)
+    /// CHECK: Address checked against the action's member in the handler
+    #[account(mut)]
+    pub committee_member: Option<UncheckedAccount<'info>>,
+
+    /// Added member's stake (committee member additions while a stake is required)
+    /// CHECK: Address checked against the action's member in the handler
+    pub member_stake: Option<UncheckedAccount<'info>>,
+
+    /// Governance system token vault (fee withdrawals only)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, governance_token_vault.mint.as_ref()],
+        bump
+    )]
+    pub governance_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault token mint (fee withdrawals only)
+    #[account(
+        constraint = governance_token_vault
+            .as_ref()
+            .is_some_and(|vault| vault.mint == mint.key()) @ GovernanceError::InvalidTokenMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Destination token account (fee withdrawals only)
+    #[account(mut)]
+    pub destination_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Governance system authority (for signing withdrawals)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Token program (fee withdrawals only)
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// System program (committee member additions only)
+    pub system_program: Option<Program<'info, System>>,
+}
+
+/// Execute admin action handler
+/// Performs what the corresponding single-authority instruction does
+pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>, action_id: u64) -> Result<()> {
+    let admin_action = &mut ctx.accounts.admin_action;
+    admin_action.require_executable(&ctx.accounts.admin_set)?;
+    // Other actions must not create (and so block) the single queued update
+    require!(
+        ctx.accounts.pending_config_update.is_some()
+            == matches!(admin_action.action, AdminActionKind::UpdateConfig(_)),
+        GovernanceError::PendingConfigUpdateMismatch
+    );
+
+    match admin_action.action.clone() {
+        AdminActionKind::UpdateConfig(config_update) => {
+            // Queued like queue_config_update; apply_config_update applies it after the eta
+            let pending_config_update = ctx
+                .accounts
+                .pending_config_update
+                .as_mut()
+                .ok_or(GovernanceError::PendingConfigUpdateMismatch)?;
+            queue_governance_config_update(
+                &ctx.accounts.governance_config,
+                pending_config_update,
+                config_update,
+                ctx.accounts.executor.key(),
+                ctx.bumps
+                    .pending_config_update
+                    .ok_or(GovernanceError::PendingConfigUpdateMismatch)?,
+            )?;
+            msg!(
+                "Governance configuration update queued by admin action {}",
+                action_id
+            );
+        }
+        AdminActionKind::AddCommitteeMember(member) => {
+            require!(
+                ctx.accounts.governance_config.test_mode,
+                GovernanceError::CommitteeChangeRequiresProposal
+            );
+            let committee_member = ctx
+                .accounts
+                .committee_member
+                .as_ref()
+                .ok_or(GovernanceError::InvalidCommitteeAccounts)?;
+            let system_program = ctx
+                .accounts
+                .system_program
+                .as_ref()
+                .ok_or(GovernanceError::InvalidCommitteeAccounts)?;
+            add_committee_member_account(
+                &mut ctx.accounts.governance_config,
+                committee_member,
+                &ctx.accounts.executor,
+                system_program,
+                ctx.accounts.member_stake.as_deref(),
+                member,
+            )?;
+            msg!(
+                "Committee member added by admin action {}: {}",
+                action_id,
+                member
+            );
+        }
+        AdminActionKind::WithdrawFees {
+            mint,
+            destination,
+            amount,
+        } => {
+            let governance_token_vault = ctx
+                .accounts
+                .governance_token_vault
+                .as_mut()
+                .ok_or(GovernanceError::TokenAccountsRequired)?;
+            let mint_account = ctx
+                .accounts
+                .mint
+                .as_ref()
+                .ok_or(GovernanceError::TokenAccountsRequired)?;
+            let destination_token_account = ctx
+                .accounts
+                .destination_token_account
+                .as_ref()
+                .ok_or(GovernanceError::TokenAccountsRequired)?;
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(GovernanceError::TokenAccountsRequired)?;
+            require!(
+                governance_token_vault.mint == mint && destination_token_account.mint == mint,
+                GovernanceError::InvalidTokenMint
+            );
+            require!(
+                destination_token_account.key() == destination,
+                GovernanceError::InvalidTokenAccount
+            );
+
+            require_withdrawable(
+                &ctx.accounts.governance_config,
+                governance_token_vault,
+                amount,
+            )?;
+            transfer_treasury_withdrawal(
+                governance_token_vault,
+                mint_account,
+                destination_token_account,
+                &ctx.accounts.governance_authority,
+                token_program,
+                ctx.bumps.governance_authority,
+                amount,
+            )?;
+            record_fee_withdrawal(
+                &mut ctx.accounts.governance_config,
+                governance_token_vault,
+                destination,
+                amount,
+            )?;
+        }
+        AdminActionKind::RestoreSingleAuthority => {
+            ctx.accounts.governance_config.admin_multisig_enabled = false;
+            msg!("Admin multisig disabled by admin action {}", action_id);
+        }
+        // Carried out by the instruction itself (see authorize_admin_call)
+        _ => return err!(GovernanceError::AdminActionMismatch),
+    }
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.governance_config.updated_at = timestamp;
+    ctx.accounts.admin_action.mark_executed(timestamp);
+
+    emit!(AdminActionExecuted {
+        action_id,
+        executor: ctx.accounts.executor.key(),
+        timestamp,
+    });
+    Ok(())
+}
+
+/// Authorize a privileged instruction call
+/// In single-authority mode the accounts constraint already checked the role admin; in
+/// multisig mode the signer must be an admin of the set, passing an approved admin action
+/// for exactly this call, which the call consumes
+pub(crate) fn authorize_admin_call(
+    governance_config: &GovernanceConfig,
+    admin_set: Option<&Account<AdminSet>>,
+    admin_action: Option<&mut Account<AdminAction>>,
+    authority: &Signer,
+    action: AdminActionKind,
+) -> Result<()> {
+    if !governance_config.admin_multisig_enabled {
+        return Ok(());
+    }
+    let admin_set = admin_set.ok_or(GovernanceError::AdminMultisigRequired)?;
+    let admin_action = admin_action.ok_or(GovernanceError::AdminMultisigRequired)?;
+    require!(
+        admin_set.is_admin(&authority.key()),
+        GovernanceError::NotAdmin
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    admin_action.consume(admin_set, &action, timestamp)?;
+
+    emit!(AdminActionExecuted {
+        action_id: admin_action.id,
+        executor: authority.key(),
+        timestamp,
+    });
+
+    msg!("Admin action {} executed", admin_action.id);
+    Ok(())
+}
+
+/// Event emitted when the admin set is configured and multisig mode enabled
+#[event]
+pub struct AdminSetConfigured {
+    /// Admin addresses
+    pub admins: Vec<Pubkey>,
+    /// Approvals required per action
+    pub threshold: u8,
+    /// Configuration timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when an admin action is proposed
+#[event]
+pub struct AdminActionProposed {
+    /// Admin action ID
+    pub action_id: u64,
+    /// Proposing admin
+    pub proposer: Pubkey,
+    /// Proposed action
+    pub action: AdminActionKind,
+    /// Proposal timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when an admin approves an action
+#[event]
+pub struct AdminActionApproved {
+    /// Admin action ID
+    pub action_id: u64,
+    /// Approving admin
+    pub admin: Pubkey,
+    /// Approvals so far
+    pub approvals: u8,
+    /// Approvals required
+    pub threshold: u8,
+}
+
+/// Event emitted when an admin action is executed
+#[event]
+pub struct AdminActionExecuted {
+    /// Admin action ID
+    pub action_id: u64,
+    /// Executing account
+    pub executor: Pubkey,
+    /// Execution timestamp
+    pub timestamp: i64,
+}
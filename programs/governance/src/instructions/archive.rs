@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GovernanceError;
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Archive a terminal proposal into a compact summary (permissionless)
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ArchiveProposal<'info> {
+    #[account(
+        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProposalArchive::INIT_SPACE,
+        seeds = [PROPOSAL_ARCHIVE_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal_archive: Account<'info, ProposalArchive>,
+
+    /// Pays for the archive account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Archive proposal handler function
+/// Only terminal proposals with a settled deposit are archived, so the summary is final
+pub fn archive_proposal(ctx: Context<ArchiveProposal>, proposal_id: u64) -> Result<()> {
+    let proposal = ctx.accounts.proposal.load()?;
+    require!(
+        proposal.is_terminal() && proposal.deposit_settled(),
+        GovernanceError::ProposalNotArchivable
+    );
+
+    ctx.accounts
+        .proposal_archive
+        .record(&proposal, ctx.bumps.proposal_archive)?;
+
+    msg!("Proposal {} archived", proposal_id);
+    Ok(())
+}
+
+/// Close a terminal proposal and its outcome, returning the rent to the proposer
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CloseProposal<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.load()?.bump,
+        close = proposer
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    /// Proposal outcome, closed together with the proposal
+    #[account(
+        mut,
+        seeds = [PROPOSAL_OUTCOME_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal_outcome.bump,
+        close = proposer
+    )]
+    pub proposal_outcome: Account<'info, ProposalOutcome>,
+
+    /// Proposal archive (required unless skip_archive is set)
+    #[account(
+        seeds = [PROPOSAL_ARCHIVE_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal_archive.bump
+    )]
+    pub proposal_archive: Option<Account<'info, ProposalArchive>>,
+
+    /// Governance config, for the config admin check
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Proposer, receiving the rent
+    /// CHECK: Address checked against the proposal
+    #[account(
+        mut,
+        address = proposal.load()?.proposer @ GovernanceError::Unauthorized
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Proposer or config admin
+    #[account(
+        constraint = authority.key() == proposer.key()
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Close proposal handler function
+/// Without an archive the proposal leaves no on-chain record, so that takes an explicit
+/// skip_archive
+pub fn close_proposal(
+    ctx: Context<CloseProposal>,
+    proposal_id: u64,
+    skip_archive: bool,
+) -> Result<()> {
+    let proposal = ctx.accounts.proposal.load()?;
+    require!(
+        proposal.is_terminal() && proposal.deposit_settled(),
+        GovernanceError::ProposalNotArchivable
+    );
+    require!(
+        skip_archive || ctx.accounts.proposal_archive.is_some(),
+        GovernanceError::ProposalArchiveRequired
+    );
+
+    msg!(
+        "Proposal {} closed by {} (archived: {})",
+        proposal_id,
+        ctx.accounts.authority.key(),
+        ctx.accounts.proposal_archive.is_some()
+    );
+    Ok(())
+}
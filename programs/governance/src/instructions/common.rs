@@ -1,6 +1,11 @@
 use crate::error::GovernanceError;
+use crate::order_interface::OrderParties;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::memo::{self, BuildMemo, Memo};
+use anchor_spl::token_interface::Mint;
+use solana_loader_v3_interface::state::UpgradeableLoaderState;
 
 // ==================== Constant definitions ====================
 
@@ -8,31 +13,201 @@ use anchor_lang::prelude::*;
 pub const PERCENTAGE_BASE: u16 = 10000;
 
 /// Governance configuration PDA seed
+#[constant]
 pub const GOVERNANCE_CONFIG_SEED: &[u8] = b"governance_config";
 
 /// Governance authority PDA seed
+#[constant]
 pub const GOVERNANCE_AUTHORITY_SEED: &[u8] = b"governance_authority";
 
+/// Adapter authority PDA seed (signs execution adapter calls; owns no vaults)
+#[constant]
+pub const ADAPTER_AUTHORITY_SEED: &[u8] = b"adapter_authority";
+
 /// Governance token vault PDA seed
+#[constant]
 pub const GOVERNANCE_TOKEN_VAULT_SEED: &[u8] = b"governance_token_vault";
 
 /// Rule registry PDA seed
+#[constant]
 pub const RULE_REGISTRY_SEED: &[u8] = b"rule_registry";
 
 /// Proposal PDA seed
+#[constant]
 pub const PROPOSAL_SEED: &[u8] = b"proposal";
 
 /// Vote PDA seed
+#[constant]
 pub const VOTE_SEED: &[u8] = b"vote";
 
+/// Proposer policy PDA seed
+#[constant]
+pub const PROPOSER_POLICY_SEED: &[u8] = b"proposer_policy";
+
+/// Proposer state PDA seed
+#[constant]
+pub const PROPOSER_STATE_SEED: &[u8] = b"proposer_state";
+
+/// Execution adapter registry PDA seed
+#[constant]
+pub const EXECUTION_ADAPTER_REGISTRY_SEED: &[u8] = b"execution_adapter_registry";
+
+/// CPI caller allowlist PDA seed
+#[constant]
+pub const CPI_CALLER_ALLOWLIST_SEED: &[u8] = b"cpi_caller_allowlist";
+
+/// Merchant slash history PDA seed
+#[constant]
+pub const MERCHANT_SLASH_HISTORY_SEED: &[u8] = b"merchant_slash_history";
+
+/// Proposal outcome PDA seed
+#[constant]
+pub const PROPOSAL_OUTCOME_SEED: &[u8] = b"proposal_outcome";
+
+/// Proposal archive PDA seed
+#[constant]
+pub const PROPOSAL_ARCHIVE_SEED: &[u8] = b"proposal_archive";
+
+/// Governance statistics PDA seed
+#[constant]
+pub const GOVERNANCE_STATS_SEED: &[u8] = b"governance_stats";
+
+/// Proposal index PDA seed
+#[constant]
+pub const PROPOSAL_INDEX_SEED: &[u8] = b"proposal_index";
+
+/// Refund claim PDA seed
+#[constant]
+pub const REFUND_CLAIM_SEED: &[u8] = b"refund_claim";
+
+/// Per-proposal deposit escrow PDA seed
+#[constant]
+pub const PROPOSAL_ESCROW_SEED: &[u8] = b"proposal_escrow";
+
+/// Native SOL deposit vault PDA seed
+#[constant]
+pub const NATIVE_DEPOSIT_VAULT_SEED: &[u8] = b"native_deposit_vault";
+
+/// Admin set PDA seed
+#[constant]
+pub const ADMIN_SET_SEED: &[u8] = b"admin_set";
+
+/// Admin action PDA seed
+#[constant]
+pub const ADMIN_ACTION_SEED: &[u8] = b"admin_action";
+
+/// Committee member PDA seed
+#[constant]
+pub const COMMITTEE_MEMBER_SEED: &[u8] = b"committee_member";
+
+/// Member stake PDA seed
+#[constant]
+pub const MEMBER_STAKE_SEED: &[u8] = b"member_stake";
+
+/// Member stake vault PDA seed (token account holding a member's stake)
+#[constant]
+pub const MEMBER_STAKE_VAULT_SEED: &[u8] = b"member_stake_vault";
+
+/// Pending configuration update PDA seed
+#[constant]
+pub const PENDING_CONFIG_UPDATE_SEED: &[u8] = b"pending_config_update";
+
+/// Token lockup PDA seed
+#[constant]
+pub const LOCKUP_SEED: &[u8] = b"lockup";
+
+/// Token lockup vault PDA seed (token account holding a member's locked tokens)
+#[constant]
+pub const LOCKUP_VAULT_SEED: &[u8] = b"lockup_vault";
+
+/// Voter stats PDA seed
+#[constant]
+pub const VOTER_STATS_SEED: &[u8] = b"voter_stats";
+
+/// Member nonce PDA seed
+#[constant]
+pub const MEMBER_NONCE_SEED: &[u8] = b"member_nonce";
+
+/// Committee token mints accepted outside test mode whatever their authorities
+/// (only with the `mint-allowlist` feature; otherwise the governance authority must be
+/// the mint's mint or freeze authority)
+#[cfg(feature = "mint-allowlist")]
+pub const COMMITTEE_TOKEN_MINT_ALLOWLIST: &[Pubkey] = &[anchor_lang::solana_program::pubkey!(
+    "DXDVt289yXEcqXDd9Ub3HqSBTWwrmNB8DzQEagv9Svtu"
+)];
+#[cfg(not(feature = "mint-allowlist"))]
+pub const COMMITTEE_TOKEN_MINT_ALLOWLIST: &[Pubkey] = &[];
+
+/// Base token account size (Token-2022 accounts with extensions are larger)
+pub const TOKEN_ACCOUNT_BASE_SIZE: usize = 165;
+
+/// Offset of the mint in a token account (owner follows at TOKEN_ACCOUNT_OWNER_OFFSET)
+pub const TOKEN_ACCOUNT_MINT_OFFSET: usize = 0;
+
+/// Offset of the owner in a token account
+pub const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+/// Maximum donation memo length in UTF-8 bytes
+pub const MAX_DONATION_MEMO_LENGTH: usize = 100;
+
+/// Largest step update_proposal_counter may advance the counter by
+/// (every skipped ID leaves a proposal PDA that can never be created)
+pub const MAX_PROPOSAL_COUNTER_JUMP: u64 = 1000;
+
+/// Maximum proposal counter update reason length in UTF-8 bytes
+pub const MAX_PROPOSAL_COUNTER_REASON_LENGTH: usize = 100;
+
+/// Maximum proposal title length in UTF-8 bytes (not characters)
+#[constant]
+pub const MAX_PROPOSAL_TITLE_BYTES: u32 = 100;
+
+/// Maximum proposal description length in UTF-8 bytes (not characters)
+#[constant]
+pub const MAX_PROPOSAL_DESCRIPTION_BYTES: u32 = 800;
+
+/// Maximum number of options on a multi-choice proposal
+#[constant]
+pub const MAX_PROPOSAL_OPTIONS: u32 = 5;
+
+/// Maximum proposal option label length in UTF-8 bytes (not characters)
+#[constant]
+pub const MAX_PROPOSAL_OPTION_BYTES: u32 = 32;
+
+/// Maximum number of committee members recused from one proposal
+#[constant]
+pub const MAX_RECUSED_MEMBERS: u32 = 5;
+
+/// Maximum number of evidence URLs per proposal (matches the account max_len)
+#[constant]
+pub const MAX_EVIDENCE_URLS: u32 = 10;
+
+/// Maximum length of a single evidence URL (matches the account max_len)
+#[constant]
+pub const MAX_EVIDENCE_URL_LENGTH: u32 = 500;
+
+/// Minimum evidence URLs required by a slash proposal
+pub const MIN_SLASH_EVIDENCE_URLS: usize = 1;
+
+/// Minimum evidence URLs required by a dispute proposal
+pub const MIN_DISPUTE_EVIDENCE_URLS: usize = 0;
+
+/// Length of a CIDv0 (base58btc multihash, "Qm" prefix)
+pub const CIDV0_LENGTH: usize = 46;
+
+/// Minimum length of a base32 CIDv1 ("b" multibase prefix, sha2-256 multihash)
+pub const MIN_CIDV1_BASE32_LENGTH: usize = 59;
+
+/// Length of an Arweave transaction ID (base64url, unpadded)
+pub const ARWEAVE_TX_ID_LENGTH: usize = 43;
+
 // ==================== Macro definitions ====================
 
-/// Macro for validating administrator permissions
+/// Macro for validating administrator permissions for a role
 #[macro_export]
 macro_rules! require_admin_authority {
-    ($authority:expr, $governance_config:expr) => {
+    ($authority:expr, $governance_config:expr, $role:expr) => {
         require!(
-            $authority.key() == $governance_config.authority,
+            $authority.key() == $governance_config.admin_for($role),
             GovernanceError::Unauthorized
         );
     };
@@ -41,9 +216,9 @@ macro_rules! require_admin_authority {
 /// Macro for validating committee members
 #[macro_export]
 macro_rules! require_committee_member {
-    ($member:expr, $governance_config:expr) => {
+    ($member:expr, $committee_member:expr) => {
         require!(
-            $governance_config.is_committee_member(&$member.key()),
+            $committee_member.member == $member.key(),
             GovernanceError::NotCommitteeMember
         );
     };
@@ -77,11 +252,136 @@ pub fn validate_url(url: &str) -> bool {
     url.starts_with("https://") || url.starts_with("ipfs://") || url.starts_with("ar://")
 }
 
+/// Validate URL format, also checking the content address of ipfs:// and ar:// URLs and the
+/// host of https:// URLs when strict
+pub fn validate_url_format(url: &str, strict: bool) -> bool {
+    if !strict {
+        return validate_url(url);
+    }
+    if let Some(rest) = url.strip_prefix("ipfs://") {
+        is_ipfs_cid(url_target(rest))
+    } else if let Some(rest) = url.strip_prefix("ar://") {
+        is_arweave_tx_id(url_target(rest))
+    } else if let Some(rest) = url.strip_prefix("https://") {
+        let authority = url_target(rest);
+        let host = authority.rsplit('@').next().unwrap_or_default();
+        let host = host.split(':').next().unwrap_or_default();
+        !host.is_empty() && !authority.chars().any(char::is_whitespace)
+    } else {
+        false
+    }
+}
+
+/// Leading segment of a URL after its scheme (up to the first '/', '?' or '#')
+fn url_target(rest: &str) -> &str {
+    rest.split(['/', '?', '#']).next().unwrap_or_default()
+}
+
+/// Check for a plausible IPFS CID: CIDv0 (base58btc "Qm...") or base32 CIDv1 ("b...")
+pub fn is_ipfs_cid(cid: &str) -> bool {
+    const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    if cid.starts_with("Qm") {
+        cid.len() == CIDV0_LENGTH && cid.chars().all(|c| BASE58_ALPHABET.contains(c))
+    } else if let Some(encoded) = cid.strip_prefix('b') {
+        cid.len() >= MIN_CIDV1_BASE32_LENGTH
+            && encoded
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c))
+    } else {
+        false
+    }
+}
+
+/// Check for a plausible Arweave transaction ID (43 base64url characters)
+pub fn is_arweave_tx_id(tx_id: &str) -> bool {
+    tx_id.len() == ARWEAVE_TX_ID_LENGTH
+        && tx_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
 /// Validate hash format (64-bit hexadecimal)
 pub fn validate_hash(hash: &str) -> bool {
     hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())
 }
 
+/// Check if an account owner is a supported token program (legacy Token or Token-2022)
+pub fn is_token_program(owner: &Pubkey) -> bool {
+    *owner == anchor_spl::token::ID || *owner == anchor_spl::token_2022::ID
+}
+
+/// Check if a committee token mint may back a production deployment
+pub fn is_allowed_committee_token_mint(mint: &InterfaceAccount<Mint>) -> bool {
+    let (governance_authority, _) =
+        Pubkey::find_program_address(&[GOVERNANCE_AUTHORITY_SEED], &crate::ID);
+    let governed = COption::Some(governance_authority);
+    COMMITTEE_TOKEN_MINT_ALLOWLIST.contains(&mint.key())
+        || mint.mint_authority == governed
+        || mint.freeze_authority == governed
+}
+
+/// Current time for finalization and execution
+/// `clock_override` stands in for the clock on test_mode deployments and is refused otherwise
+pub fn lifecycle_now(
+    governance_config: &GovernanceConfig,
+    clock_override: Option<i64>,
+) -> Result<i64> {
+    match clock_override {
+        Some(timestamp) => {
+            require!(
+                governance_config.test_mode,
+                GovernanceError::TestModeRequired
+            );
+            Ok(timestamp)
+        }
+        None => Ok(Clock::get()?.unix_timestamp),
+    }
+}
+
+/// Deposit movement named in a transfer memo
+#[derive(Clone, Copy, Debug)]
+pub enum TransferMemo {
+    /// Deposit paid in at creation or resubmission
+    Deposit,
+    /// Deposit part refunded to the proposer at finalization
+    Refund,
+    /// Deposit part kept as committee fee
+    Fee,
+    /// Deposit part confiscated from a vetoed proposal
+    Confiscation,
+}
+
+impl TransferMemo {
+    fn label(self) -> &'static str {
+        match self {
+            TransferMemo::Deposit => "deposit",
+            TransferMemo::Refund => "refund",
+            TransferMemo::Fee => "fee",
+            TransferMemo::Confiscation => "confiscation",
+        }
+    }
+}
+
+/// Attach an SPL memo `proposal:<id>:<movement>:<amount>` to a deposit movement
+/// Does nothing unless the config's emit_memos is set
+pub fn emit_transfer_memo<'info>(
+    governance_config: &GovernanceConfig,
+    memo_program: Option<&Program<'info, Memo>>,
+    proposal_id: u64,
+    movement: TransferMemo,
+    amount: u64,
+) -> Result<()> {
+    if !governance_config.emit_memos {
+        return Ok(());
+    }
+    let memo_program = memo_program.ok_or(GovernanceError::MemoProgramRequired)?;
+    let text = format!("proposal:{}:{}:{}", proposal_id, movement.label(), amount);
+    memo::build_memo(
+        CpiContext::new(memo_program.to_account_info(), BuildMemo {}),
+        text.as_bytes(),
+    )
+}
+
 /// Validate voting period (based on test mode)
 pub fn validate_voting_period(voting_period: u64, test_mode: bool) -> Result<()> {
     if test_mode {
@@ -109,16 +409,411 @@ pub fn validate_voting_period(voting_period: u64, test_mode: bool) -> Result<()>
 /// Validate proposal title and description length
 pub fn validate_proposal_content(title: &str, description: &str) -> Result<()> {
     require!(
-        title.len() <= 100,
+        title.len() <= MAX_PROPOSAL_TITLE_BYTES as usize,
         GovernanceError::InvalidProposalTitleLength
     );
     require!(
-        description.len() <= 800,
+        description.len() <= MAX_PROPOSAL_DESCRIPTION_BYTES as usize,
         GovernanceError::InvalidProposalDescriptionLength
     );
+    require!(
+        is_clean_utf8(title),
+        GovernanceError::InvalidProposalTitleEncoding
+    );
+    require!(
+        is_clean_utf8(description),
+        GovernanceError::InvalidProposalDescriptionEncoding
+    );
+    Ok(())
+}
+
+/// Validate multi-choice proposal options: 2 to MAX_PROPOSAL_OPTIONS distinct, non-empty
+/// labels of clean UTF-8; the winning option is only recorded, so multi-choice proposals
+/// carry no execution data
+pub fn validate_proposal_options(
+    options: &Option<Vec<String>>,
+    execution_data: &Option<ExecutionData>,
+) -> Result<()> {
+    let Some(options) = options else {
+        return Ok(());
+    };
+    require!(
+        (2..=MAX_PROPOSAL_OPTIONS as usize).contains(&options.len()) && execution_data.is_none(),
+        GovernanceError::InvalidProposalOptions
+    );
+    for (index, option) in options.iter().enumerate() {
+        require!(
+            !option.is_empty()
+                && option.len() <= MAX_PROPOSAL_OPTION_BYTES as usize
+                && is_clean_utf8(option)
+                && !options[..index].contains(option),
+            GovernanceError::InvalidProposalOptions
+        );
+    }
+    Ok(())
+}
+
+/// Validate the members recused from a proposal: at most MAX_RECUSED_MEMBERS distinct
+/// members, each passed as its CommitteeMember PDA in `committee_members` (same order)
+pub fn validate_recused_members(
+    recused_members: &[Pubkey],
+    committee_members: &[AccountInfo],
+) -> Result<()> {
+    require!(
+        recused_members.len() <= MAX_RECUSED_MEMBERS as usize
+            && committee_members.len() >= recused_members.len(),
+        GovernanceError::InvalidRecusedMembers
+    );
+    for (index, (member, account)) in recused_members.iter().zip(committee_members).enumerate() {
+        require!(
+            !recused_members[..index].contains(member)
+                && load_committee_member(account)
+                    .is_ok_and(|committee_member| committee_member.member == *member),
+            GovernanceError::InvalidRecusedMembers
+        );
+    }
+    Ok(())
+}
+
+/// Validate committee member display name length and encoding
+pub fn validate_member_display_name(display_name: &str) -> Result<()> {
+    require!(
+        display_name.len() <= MAX_MEMBER_DISPLAY_NAME_BYTES && is_clean_utf8(display_name),
+        GovernanceError::InvalidMemberDisplayName
+    );
+    Ok(())
+}
+
+/// Check text for artifacts of broken UTF-8 handling
+/// Borsh already rejects invalid UTF-8, but clients that truncate at a byte boundary
+/// and decode lossily leave U+FFFD replacement characters or NUL bytes behind
+pub fn is_clean_utf8(text: &str) -> bool {
+    !text
+        .chars()
+        .any(|c| c == char::REPLACEMENT_CHARACTER || c == '\0')
+}
+
+/// Validate an evidence URL list against count limits and URL format
+pub fn validate_evidence_urls(
+    evidence_urls: &[String],
+    min_count: usize,
+    strict_urls: bool,
+) -> Result<()> {
+    require!(
+        evidence_urls.len() <= MAX_EVIDENCE_URLS as usize,
+        GovernanceError::TooManyEvidenceUrls
+    );
+    require!(
+        evidence_urls.len() >= min_count,
+        GovernanceError::InsufficientEvidenceUrls
+    );
+
+    for url in evidence_urls {
+        require!(
+            url.len() <= MAX_EVIDENCE_URL_LENGTH as usize,
+            GovernanceError::InvalidUrlLength
+        );
+        require!(
+            validate_url_format(url, strict_urls),
+            GovernanceError::InvalidUrlFormat
+        );
+    }
+    Ok(())
+}
+
+/// Validate proposal execution data (per-type evidence requirements and config update
+/// sanity checks)
+/// Evidence URLs are checked under the config's strict_url_validation
+pub fn validate_execution_data(
+    execution_data: &Option<ExecutionData>,
+    governance_config: &GovernanceConfig,
+) -> Result<()> {
+    let strict_urls = governance_config.strict_url_validation;
+    match execution_data {
+        Some(ExecutionData::Slash(data)) => {
+            require!(
+                data.merchant_address != Pubkey::default(),
+                GovernanceError::InvalidMerchantAddress
+            );
+            validate_evidence_urls(&data.evidence_urls, MIN_SLASH_EVIDENCE_URLS, strict_urls)
+        }
+        Some(ExecutionData::Dispute(data)) => {
+            // The decision is only recorded at execution, from the ratified proposal
+            require!(
+                data.arbitration_decision.is_none(),
+                GovernanceError::InvalidArbitrationDecision
+            );
+            if let Some(decision) = &data.proposed_decision {
+                decision.validate()?;
+            }
+            validate_evidence_urls(&data.evidence_urls, MIN_DISPUTE_EVIDENCE_URLS, strict_urls)
+        }
+        Some(ExecutionData::CommitteeChange(data)) => {
+            require!(
+                data.member != Pubkey::default(),
+                GovernanceError::InvalidCommitteeChange
+            );
+            Ok(())
+        }
+        Some(ExecutionData::ProgramUpgrade(data)) => {
+            require!(
+                data.program_id != Pubkey::default() && data.buffer != Pubkey::default(),
+                GovernanceError::InvalidExecutionData
+            );
+            Ok(())
+        }
+        Some(ExecutionData::RuleUpdate(data)) => {
+            require!(
+                data.registry_name.len() <= MAX_RULE_REGISTRY_NAME_LENGTH,
+                GovernanceError::InvalidRuleRegistryName
+            );
+            Ok(())
+        }
+        Some(ExecutionData::ConfigUpdate(data)) => {
+            validate_config_update_proposal(&data.config_update, governance_config)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Validate a configuration update filed as a proposal
+/// On top of the field validation run at execution, a proposal may not turn test mode on
+/// (its relaxed voting period bounds would let a later update shorten votes to seconds),
+/// and the resulting approval and veto thresholds must leave the veto able to block an
+/// approved proposal
+pub fn validate_config_update_proposal(
+    config_update: &GovernanceConfigUpdate,
+    governance_config: &GovernanceConfig,
+) -> Result<()> {
+    require!(
+        config_update.test_mode != Some(true),
+        GovernanceError::ProposalEnablesTestMode
+    );
+    config_update.validate(governance_config.test_mode)?;
+
+    let approval_threshold = config_update
+        .approval_threshold
+        .unwrap_or(governance_config.approval_threshold);
+    let veto_threshold = config_update
+        .veto_threshold
+        .unwrap_or(governance_config.veto_threshold);
+    require!(
+        approval_threshold as u64 + veto_threshold as u64 <= PERCENTAGE_BASE as u64,
+        GovernanceError::ConflictingThresholds
+    );
+    Ok(())
+}
+
+/// Get the program upgrade, if the execution data is a program upgrade proposal
+pub fn program_upgrade(execution_data: &Option<ExecutionData>) -> Option<ProgramUpgradeData> {
+    match execution_data {
+        Some(ExecutionData::ProgramUpgrade(data)) => Some(*data),
+        _ => None,
+    }
+}
+
+/// Check that the account is the upgrade's buffer and that the governance authority PDA
+/// may deploy it
+pub fn require_governed_upgrade_buffer(
+    buffer: &AccountInfo,
+    upgrade: &ProgramUpgradeData,
+) -> Result<()> {
+    require!(
+        buffer.key() == upgrade.buffer && buffer.owner == &solana_sdk_ids::bpf_loader_upgradeable::ID,
+        GovernanceError::InvalidUpgradeBuffer
+    );
+    // UpgradeableLoaderState::Buffer: variant index 1 (u32), then the authority as Option<Pubkey>
+    let data = buffer.try_borrow_data()?;
+    let metadata_size = UpgradeableLoaderState::size_of_buffer_metadata();
+    let authority = match data.get(..metadata_size) {
+        Some([1, 0, 0, 0, 1, authority @ ..]) => Pubkey::try_from(authority).ok(),
+        _ => None,
+    };
+    let (governance_authority, _) =
+        Pubkey::find_program_address(&[GOVERNANCE_AUTHORITY_SEED], &crate::ID);
+    require!(
+        authority == Some(governance_authority),
+        GovernanceError::InvalidUpgradeBuffer
+    );
+    Ok(())
+}
+
+/// Get the dispute, if the execution data is a dispute proposal
+pub fn dispute(execution_data: &Option<ExecutionData>) -> Option<&DisputeProposalData> {
+    match execution_data {
+        Some(ExecutionData::Dispute(data)) => Some(data),
+        _ => None,
+    }
+}
+
+/// Check that the account is the disputed order, owned by the registered order program,
+/// and that it names the dispute's user and merchant
+pub fn require_dispute_order(
+    order: &AccountInfo,
+    order_program: &Pubkey,
+    dispute: &DisputeProposalData,
+) -> Result<()> {
+    require!(
+        order.key() == dispute.order_address && order.owner == order_program,
+        GovernanceError::InvalidDisputeParties
+    );
+    let parties = OrderParties::read(&order.try_borrow_data()?);
+    require!(
+        parties
+            == Some(OrderParties {
+                user: dispute.user_address,
+                merchant: dispute.merchant_address,
+            }),
+        GovernanceError::InvalidDisputeParties
+    );
     Ok(())
 }
 
+/// Get the committee change, if the execution data is a committee change proposal
+pub fn committee_change(execution_data: &Option<ExecutionData>) -> Option<CommitteeChangeData> {
+    match execution_data {
+        Some(ExecutionData::CommitteeChange(data)) => Some(*data),
+        _ => None,
+    }
+}
+
+/// Load a committee member account passed through remaining accounts
+/// Verifies the account sits at the member's canonical PDA
+pub fn load_committee_member(account: &AccountInfo) -> Result<CommitteeMember> {
+    let committee_member =
+        CommitteeMember::try_load(account)?.ok_or(GovernanceError::InvalidCommitteeAccounts)?;
+    let expected_address = Pubkey::create_program_address(
+        &[
+            COMMITTEE_MEMBER_SEED,
+            committee_member.member.as_ref(),
+            &[committee_member.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| GovernanceError::InvalidCommitteeAccounts)?;
+    require!(
+        account.key() == expected_address,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    Ok(committee_member)
+}
+
+/// Create a program-owned account of `space` bytes at the PDA signed for by `signer_seeds`
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            required_lamports,
+            space as u64,
+            &crate::ID,
+        )
+    } else {
+        // The address was pre-funded, so create_account would fail; top up and claim it instead
+        let shortfall = required_lamports.saturating_sub(account.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        anchor_lang::system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                signer_seeds,
+            ),
+            space as u64,
+        )?;
+        anchor_lang::system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Assign {
+                    account_to_assign: account.clone(),
+                },
+                signer_seeds,
+            ),
+            &crate::ID,
+        )
+    }
+}
+
+/// Load a member's voter stats account, checked to be at the member's PDA
+/// Returns None when the account has not been initialized
+pub fn load_voter_stats(account: &AccountInfo, member: &Pubkey) -> Result<Option<VoterStats>> {
+    let Some(voter_stats) = VoterStats::try_load(account)? else {
+        let (expected_address, _) =
+            Pubkey::find_program_address(&[VOTER_STATS_SEED, member.as_ref()], &crate::ID);
+        require!(
+            account.key() == expected_address,
+            GovernanceError::InvalidCommitteeAccounts
+        );
+        return Ok(None);
+    };
+    let expected_address = Pubkey::create_program_address(
+        &[VOTER_STATS_SEED, member.as_ref(), &[voter_stats.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| GovernanceError::InvalidCommitteeAccounts)?;
+    require!(
+        account.key() == expected_address && voter_stats.member == *member,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    Ok(Some(voter_stats))
+}
+
+/// Load a member's lockup account, checked to be at the member's PDA
+/// Returns None when the member has no lockup
+pub fn load_lockup(account: &AccountInfo, member: &Pubkey) -> Result<Option<Lockup>> {
+    let Some(lockup) = Lockup::try_load(account)? else {
+        let (expected_address, _) =
+            Pubkey::find_program_address(&[LOCKUP_SEED, member.as_ref()], &crate::ID);
+        require!(
+            account.key() == expected_address,
+            GovernanceError::InvalidCommitteeAccounts
+        );
+        return Ok(None);
+    };
+    let expected_address =
+        Pubkey::create_program_address(&[LOCKUP_SEED, member.as_ref(), &[lockup.bump]], &crate::ID)
+            .map_err(|_| GovernanceError::InvalidCommitteeAccounts)?;
+    require!(
+        account.key() == expected_address && lockup.member == *member,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    Ok(Some(lockup))
+}
+
+/// Get the slashed merchant, if the execution data is a slash proposal
+pub fn slash_merchant(execution_data: &Option<ExecutionData>) -> Option<Pubkey> {
+    match execution_data {
+        Some(ExecutionData::Slash(data)) => Some(data.merchant_address),
+        _ => None,
+    }
+}
+
 // ==================== Trait Definitions ====================
 
 /// Trait for updatable timestamp
@@ -173,7 +868,7 @@ pub struct AdminAuthorityConstraint<'info> {
     pub governance_config: Account<'info, GovernanceConfig>,
 
     #[account(
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
 }
@@ -189,7 +884,7 @@ pub struct MutableAdminAuthorityConstraint<'info> {
     pub governance_config: Account<'info, GovernanceConfig>,
 
     #[account(
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
 }
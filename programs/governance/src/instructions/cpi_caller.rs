@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::error::GovernanceError;
+use crate::instructions::admin::authorize_admin_call;
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Create CPI caller allowlist
+#[derive(Accounts)]
+pub struct CreateCpiCallerAllowlist<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CpiCallerAllowlist::INIT_SPACE,
+        seeds = [CPI_CALLER_ALLOWLIST_SEED],
+        bump
+    )]
+    pub cpi_caller_allowlist: Account<'info, CpiCallerAllowlist>,
+
+    /// Governance configuration account for permission verification
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can create the allowlist
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Create CPI caller allowlist handler
+pub fn create_cpi_caller_allowlist(ctx: Context<CreateCpiCallerAllowlist>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CreateCpiCallerAllowlist,
+    )?;
+
+    let allowlist = &mut ctx.accounts.cpi_caller_allowlist;
+    allowlist.callers = Vec::new();
+    allowlist.updated_at = Clock::get()?.unix_timestamp;
+    allowlist.bump = ctx.bumps.cpi_caller_allowlist;
+
+    msg!("CPI caller allowlist created");
+    Ok(())
+}
+
+/// Update CPI caller allowlist
+#[derive(Accounts)]
+pub struct UpdateCpiCallerAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = [CPI_CALLER_ALLOWLIST_SEED],
+        bump = cpi_caller_allowlist.bump
+    )]
+    pub cpi_caller_allowlist: Account<'info, CpiCallerAllowlist>,
+
+    /// Governance configuration account for permission verification
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can allow calling programs
+    #[account(
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Event emitted when a program is allowed or disallowed to create proposals through CPI
+#[event]
+pub struct CpiCallerUpdated {
+    /// Calling program
+    pub program_id: Pubkey,
+    /// Whether the program was allowed before
+    pub previously_allowed: bool,
+    /// Whether the program is allowed now
+    pub allowed: bool,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Set CPI caller handler
+pub fn set_cpi_caller(
+    ctx: Context<UpdateCpiCallerAllowlist>,
+    program_id: Pubkey,
+    allowed: bool,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetCpiCaller {
+            program_id,
+            allowed,
+        },
+    )?;
+
+    let allowlist = &mut ctx.accounts.cpi_caller_allowlist;
+    let previously_allowed =
+        allowlist.set_caller(program_id, allowed, Clock::get()?.unix_timestamp)?;
+
+    emit!(CpiCallerUpdated {
+        program_id,
+        previously_allowed,
+        allowed,
+        timestamp: allowlist.updated_at,
+    });
+
+    msg!("CPI caller {} allowed: {}", program_id, allowed);
+    Ok(())
+}
+
+/// Require an allowlisted calling program when create_proposal runs through CPI
+/// The calling program is the program of the transaction-level instruction, read from
+/// the instructions sysvar; direct transactions are not checked
+pub(crate) fn require_allowed_cpi_caller(
+    instructions_sysvar: Option<&AccountInfo>,
+    cpi_caller_allowlist: Option<&AccountInfo>,
+) -> Result<()> {
+    if get_stack_height() <= TRANSACTION_LEVEL_STACK_HEIGHT {
+        return Ok(());
+    }
+
+    let instructions_sysvar = instructions_sysvar.ok_or(GovernanceError::CpiCallerNotAllowed)?;
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    let caller = load_instruction_at_checked(current_index, instructions_sysvar)?.program_id;
+
+    let allowlist = match cpi_caller_allowlist {
+        Some(account) => CpiCallerAllowlist::try_load(account)?,
+        None => None,
+    };
+    require!(
+        allowlist.is_some_and(|allowlist| allowlist.is_allowed(&caller)),
+        GovernanceError::CpiCallerNotAllowed
+    );
+
+    msg!("Proposal created through CPI by {}", caller);
+    Ok(())
+}
+
+/// Accounts an allowlisted program passes to create a proposal through CPI
+/// The proposer is a PDA of the calling program, signed for with
+/// CpiContext::new_with_signer; it pays the account rent and the deposit, from its own
+/// token account for an accepted deposit mint (SPL token deposits)
+#[cfg(feature = "cpi")]
+pub struct CreateProposalCpiAccounts<'info> {
+    /// Proposal PDA for the next proposal ID
+    pub proposal: AccountInfo<'info>,
+    /// Governance configuration
+    pub governance_config: AccountInfo<'info>,
+    /// Calling program's proposer PDA (writable signer)
+    pub proposer: AccountInfo<'info>,
+    /// Proposer policy PDA (may be uninitialized)
+    pub proposer_policy: AccountInfo<'info>,
+    /// Proposer state PDA of the proposer
+    pub proposer_state: AccountInfo<'info>,
+    /// Deposit source: the proposer's token account for the deposit mint
+    pub proposer_token_account: AccountInfo<'info>,
+    /// Deposit mint
+    pub deposit_mint: AccountInfo<'info>,
+    /// Governance token vault for the deposit mint
+    pub governance_token_vault: AccountInfo<'info>,
+    /// Deposit escrow PDA (required while deposit escrow is enabled)
+    pub deposit_escrow: Option<AccountInfo<'info>>,
+    /// Governance authority PDA
+    pub governance_authority: AccountInfo<'info>,
+    /// Disputed order (dispute proposals while an order program is registered)
+    pub dispute_order: Option<AccountInfo<'info>>,
+    /// Governance statistics PDA (may be uninitialized)
+    pub governance_stats: AccountInfo<'info>,
+    /// CPI caller allowlist PDA
+    pub cpi_caller_allowlist: AccountInfo<'info>,
+    /// Instructions sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+#[cfg(feature = "cpi")]
+impl<'info> CreateProposalCpiAccounts<'info> {
+    /// create_proposal CPI accounts; accounts of other deposit kinds and the proposal
+    /// index, memo and committee accounts are left out
+    pub fn into_accounts(self) -> crate::cpi::accounts::CreateProposal<'info> {
+        crate::cpi::accounts::CreateProposal {
+            proposal: self.proposal,
+            governance_config: self.governance_config,
+            proposer: self.proposer,
+            proposer_policy: self.proposer_policy,
+            proposer_state: self.proposer_state,
+            proposer_token_account: Some(self.proposer_token_account),
+            deposit_mint: Some(self.deposit_mint),
+            governance_token_vault: Some(self.governance_token_vault),
+            native_deposit_vault: None,
+            deposit_escrow: self.deposit_escrow,
+            governance_authority: self.governance_authority,
+            merchant_slash_history: None,
+            committee_member: None,
+            proposer_committee_member: None,
+            upgrade_buffer: None,
+            dispute_order: self.dispute_order,
+            proposal_index: None,
+            governance_stats: self.governance_stats,
+            cpi_caller_allowlist: Some(self.cpi_caller_allowlist),
+            instructions_sysvar: Some(self.instructions_sysvar),
+            system_program: self.system_program,
+            token_program: Some(self.token_program),
+            memo_program: None,
+        }
+    }
+}
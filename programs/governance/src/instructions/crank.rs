@@ -0,0 +1,333 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::GovernanceError;
+use crate::events::*;
+use crate::instructions::common::*;
+use crate::instructions::proposal::{
+    burn_confiscated_deposit, committee_member_accounts_len, committee_total_voting_power,
+    emit_settlement_memos, handle_deposit_automatically, record_committee_participation,
+    record_settled_deposit, tally_vote_accounts,
+};
+use crate::instructions::policy::record_proposer_outcome;
+use crate::instructions::proposal_index::record_proposal_status;
+use crate::instructions::stats::record_governance_stats;
+use crate::state::*;
+
+/// Accounts passed per crank entry after the committee accounts: the proposal, its
+/// (not yet created) outcome, the proposer's token account and the proposer's state PDA,
+/// then the entry's votes
+const CRANK_ENTRY_ACCOUNTS: usize = 4;
+
+/// One proposal to finalize in crank_finalize
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CrankFinalizeEntry {
+    /// Proposal ID
+    pub proposal_id: u64,
+    /// Number of vote accounts passed for the proposal
+    pub vote_count: u8,
+}
+
+/// Finalize every ready proposal in a batch (permissionless)
+/// Remaining accounts: the committee accounts as for finalize_proposal,
+/// then per entry the proposal, its outcome PDA, the proposer's token account, the
+/// proposer's state PDA and vote_count vote accounts
+/// Only the common case is handled here: pooled deposits in deposit_mint with a usable
+/// proposer token account, and no slash history to update. Other proposals are skipped
+/// and left for finalize_proposal
+#[derive(Accounts)]
+pub struct CrankFinalize<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Committee token mint (for calculating voting power)
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Deposit mint of the cranked proposals (proposals with other mints are skipped)
+    #[account(mut)]
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance system token account for the deposit mint
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, deposit_mint.key().as_ref()],
+        bump = governance_config.token_vault_bump(&deposit_mint.key())?
+    )]
+    pub governance_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Governance system authority (for signing transfers)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump = governance_config.authority_bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Token program owning the deposit mint
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Proposal index (required while proposal_index_enabled is set)
+    #[account(
+        mut,
+        seeds = [PROPOSAL_INDEX_SEED],
+        bump = proposal_index.bump
+    )]
+    pub proposal_index: Option<Account<'info, ProposalIndex>>,
+
+    /// Governance statistics (may be uninitialized; counters are skipped then)
+    /// CHECK: Address is fixed by seeds; contents are updated in record_governance_stats
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: UncheckedAccount<'info>,
+
+    /// SPL memo program (required while emit_memos is set)
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    /// Pays for the proposal outcome accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Crank finalize handler function
+/// Proposals that are not ready (not pending, voting not ended) or need accounts the crank
+/// does not carry are skipped, not failed; a malformed account layout fails the batch
+/// Returns the number of proposals finalized
+pub fn crank_finalize<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CrankFinalize<'info>>,
+    entries: Vec<CrankFinalizeEntry>,
+    clock_override: Option<i64>,
+) -> Result<u8> {
+    let accounts = ctx.accounts;
+    let now = lifecycle_now(&accounts.governance_config, clock_override)?;
+    let member_accounts_len = committee_member_accounts_len(&accounts.governance_config)?;
+    require!(
+        ctx.remaining_accounts.len() >= member_accounts_len,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    let (member_accounts, mut entry_accounts) =
+        ctx.remaining_accounts.split_at(member_accounts_len);
+
+    let mut finalized = 0u8;
+    for entry in &entries {
+        let entry_len = CRANK_ENTRY_ACCOUNTS + entry.vote_count as usize;
+        require!(
+            entry_accounts.len() >= entry_len,
+            GovernanceError::InvalidCrankAccounts
+        );
+        let (group, rest) = entry_accounts.split_at(entry_len);
+        entry_accounts = rest;
+
+        if crank_finalize_one(
+            accounts,
+            member_accounts,
+            group,
+            entry.proposal_id,
+            now,
+        )? {
+            finalized += 1;
+        }
+    }
+    require!(
+        entry_accounts.is_empty(),
+        GovernanceError::InvalidCrankAccounts
+    );
+
+    msg!(
+        "Crank finalized {} of {} proposals",
+        finalized,
+        entries.len()
+    );
+    Ok(finalized)
+}
+
+/// Finalize one crank entry, returning whether it was finalized or skipped
+fn crank_finalize_one<'info>(
+    accounts: &mut CrankFinalize<'info>,
+    member_accounts: &[AccountInfo<'info>],
+    group: &'info [AccountInfo<'info>],
+    proposal_id: u64,
+    now: i64,
+) -> Result<bool> {
+    let (proposal_info, outcome_info, proposer_token_info, proposer_state_info) =
+        (&group[0], &group[1], &group[2], &group[3]);
+    let vote_accounts = &group[CRANK_ENTRY_ACCOUNTS..];
+
+    let proposal_loader = AccountLoader::<'info, Proposal>::try_from(proposal_info)?;
+    let mut proposal = proposal_loader.load_mut()?;
+    let proposal_address = Pubkey::create_program_address(
+        &[PROPOSAL_SEED, &proposal_id.to_le_bytes(), &[proposal.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| GovernanceError::InvalidCrankAccounts)?;
+    require!(
+        proposal_address == proposal_info.key() && proposal_info.is_writable,
+        GovernanceError::InvalidCrankAccounts
+    );
+    let (outcome_address, outcome_bump) = Pubkey::find_program_address(
+        &[PROPOSAL_OUTCOME_SEED, &proposal_id.to_le_bytes()],
+        &crate::ID,
+    );
+    require!(
+        outcome_address == outcome_info.key() && outcome_info.is_writable,
+        GovernanceError::InvalidCrankAccounts
+    );
+
+    // Skip proposals that are not ready, or that finalize_proposal has to settle
+    let skip_reason = if !proposal.is_votable() {
+        Some("not pending")
+    } else if !proposal.is_voting_ended(now) {
+        Some("voting not ended")
+    } else if proposal.deposit_escrowed()
+        || proposal.deposit_mint != accounts.deposit_mint.key()
+        || slash_merchant(&proposal.execution_data()?).is_some()
+    {
+        Some("needs finalize_proposal")
+    } else if !usable_refund_destination(proposer_token_info, &proposal) {
+        Some("proposer token account unusable")
+    } else {
+        None
+    };
+    if let Some(reason) = skip_reason {
+        msg!("Proposal {} skipped: {}", proposal_id, reason);
+        return Ok(false);
+    }
+
+    let governance_config = &mut accounts.governance_config;
+    let authority_bump = governance_config.authority_bump;
+    let committee_voting_power = committee_total_voting_power(
+        governance_config,
+        &accounts.committee_token_mint,
+        member_accounts,
+        proposal.created_at,
+        proposal.recused_members(),
+    )?;
+    let vote_tally = tally_vote_accounts(
+        governance_config,
+        vote_accounts,
+        proposal_id,
+        accounts.committee_token_mint.decimals,
+        &committee_voting_power,
+    )?;
+    let total_voting_power = committee_voting_power.total_voting_power;
+    proposal.record_tally(&vote_tally)?;
+
+    proposal.finalize(governance_config, total_voting_power, now)?;
+    record_committee_participation(
+        governance_config,
+        member_accounts,
+        vote_accounts,
+        proposal_id,
+    )?;
+    let status = proposal.status()?;
+    governance_config.release_pending_proposal(&status)?;
+    record_proposer_outcome(proposer_state_info, &proposal.proposer, &status)?;
+    record_proposal_status(
+        governance_config,
+        accounts.proposal_index.as_mut(),
+        proposal_id,
+        Some(&ProposalStatus::Pending),
+        &status,
+        now,
+    )?;
+
+    emit!(ProposalFinalized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: governance_config.key(),
+        proposal_id,
+        status: status.clone(),
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        abstain_votes: proposal.abstain_votes,
+        veto_votes: proposal.veto_votes,
+        total_voting_power,
+        timestamp: now,
+    });
+
+    // Publish the outcome for other programs (a pre-funded outcome address is claimed)
+    create_pda_account(
+        outcome_info,
+        &accounts.payer.to_account_info(),
+        &accounts.system_program.to_account_info(),
+        8 + ProposalOutcome::INIT_SPACE,
+        &[&[
+            PROPOSAL_OUTCOME_SEED,
+            &proposal_id.to_le_bytes(),
+            &[outcome_bump],
+        ]],
+    )?;
+    ProposalOutcome::new(&proposal, total_voting_power, now, outcome_bump)?
+        .try_serialize(&mut &mut outcome_info.try_borrow_mut_data()?[..])?;
+
+    // Settle the deposit in the pooled vault; the refund destination was checked above
+    let unpaid_refund = handle_deposit_automatically(
+        &mut proposal,
+        Some(proposer_token_info.clone()),
+        Some(&accounts.deposit_mint),
+        &mut accounts.governance_token_vault,
+        &accounts.governance_authority,
+        &accounts.token_program,
+        authority_bump,
+    )?;
+    burn_confiscated_deposit(
+        &mut proposal,
+        governance_config,
+        Some(&accounts.deposit_mint),
+        Some(&mut accounts.governance_token_vault),
+        Some(&accounts.token_program),
+        &accounts.governance_authority,
+        authority_bump,
+    )?;
+    let settled = proposal.deposit_settled();
+    if settled {
+        record_settled_deposit(governance_config, &proposal, unpaid_refund)?;
+        emit_settlement_memos(
+            governance_config,
+            accounts.memo_program.as_ref(),
+            &proposal,
+            unpaid_refund,
+        )?;
+    }
+    record_governance_stats(&accounts.governance_stats, now, |stats| {
+        stats.record_finalized(&proposal, settled)
+    });
+
+    msg!(
+        "Proposal {} finalized with status: {:?}",
+        proposal_id,
+        status
+    );
+    Ok(true)
+}
+
+/// Whether an account is a writable, unfrozen token account of the proposer in the
+/// proposal's deposit mint
+fn usable_refund_destination<'info>(
+    account: &'info AccountInfo<'info>,
+    proposal: &Proposal,
+) -> bool {
+    if !account.is_writable {
+        return false;
+    }
+    match InterfaceAccount::<TokenAccount>::try_from(account) {
+        Ok(token_account) => {
+            token_account.owner == proposal.proposer
+                && token_account.mint == proposal.deposit_mint
+                && !token_account.is_frozen()
+        }
+        Err(_) => false,
+    }
+}
@@ -1,81 +1,481 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, InitializeAccount, Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
+use crate::error::GovernanceError;
+use crate::instructions::admin::authorize_admin_call;
 use crate::instructions::common::*;
+use crate::instructions::treasury::{
+    emit_treasury_transfer, TransferDirection, TreasuryTransferReason,
+};
+use crate::state::*;
 
-/// Initialize governance system token vault
+/// Initialize governance system token vault for an accepted deposit mint
+/// The vault is created by the `init` constraint; calling again for the same mint
+/// fails atomically because the vault account is already in use
 pub fn initialize_token_vault(ctx: Context<InitializeTokenVault>) -> Result<()> {
-    msg!("Initializing governance token vault");
-
-    // Use CPI to create token account
-    let create_account_ix = anchor_lang::solana_program::system_instruction::create_account(
-        &ctx.accounts.payer.key(),
-        &ctx.accounts.token_vault.key(),
-        ctx.accounts.rent.minimum_balance(TokenAccount::LEN),
-        TokenAccount::LEN as u64,
-        &ctx.accounts.token_program.key(),
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::InitializeTokenVault(ctx.accounts.mint.key()),
+    )?;
+
+    msg!(
+        "Governance token vault initialized for mint {}",
+        ctx.accounts.mint.key()
     );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTokenVault<'info> {
+    /// Token vault account (PDA, one per deposit mint)
+    #[account(
+        init,
+        payer = authority,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = governance_authority,
+        token::token_program = token_program
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token mint (must be an accepted deposit mint)
+    #[account(
+        constraint = governance_config.get_deposit_mint(&mint.key()).is_ok() @ GovernanceError::DepositMintNotAccepted
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance configuration (accepted deposit mints)
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance authority (PDA)
+    /// CHECK: This is a PDA used as token account authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
 
-    // Execute create account instruction
-    anchor_lang::solana_program::program::invoke_signed(
-        &create_account_ix,
-        &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.token_vault.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-        &[&[GOVERNANCE_TOKEN_VAULT_SEED, &[ctx.bumps.token_vault]]],
+    /// Only administrator can initialize vaults (also pays rent)
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Token program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Initialize the native SOL deposit vault
+pub fn initialize_native_deposit_vault(ctx: Context<InitializeNativeDepositVault>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::InitializeNativeDepositVault,
     )?;
 
-    // Initialize token account
-    let cpi_accounts = InitializeAccount {
-        account: ctx.accounts.token_vault.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        authority: ctx.accounts.governance_authority.to_account_info(),
-        rent: ctx.accounts.rent.to_account_info(),
-    };
+    let native_deposit_vault = &mut ctx.accounts.native_deposit_vault;
+    native_deposit_vault.created_at = Clock::get()?.unix_timestamp;
+    native_deposit_vault.bump = ctx.bumps.native_deposit_vault;
 
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    msg!("Native deposit vault initialized");
+    Ok(())
+}
 
-    token::initialize_account(cpi_ctx)?;
+#[derive(Accounts)]
+pub struct InitializeNativeDepositVault<'info> {
+    /// Native deposit vault (program-owned PDA)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NativeDepositVault::INIT_SPACE,
+        seeds = [NATIVE_DEPOSIT_VAULT_SEED],
+        bump
+    )]
+    pub native_deposit_vault: Account<'info, NativeDepositVault>,
+
+    /// Governance configuration
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can initialize the vault (also pays rent)
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Close an empty governance token vault, returning its rent to the authority
+/// Refused while the vault holds tokens or its mint still has locked deposits
+pub fn close_token_vault(ctx: Context<CloseTokenVault>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CloseTokenVault(ctx.accounts.token_vault.mint),
+    )?;
+
+    let token_vault = &ctx.accounts.token_vault;
+    require!(token_vault.amount == 0, GovernanceError::VaultNotEmpty);
+    require!(
+        ctx.accounts
+            .governance_config
+            .get_deposit_mint(&token_vault.mint)?
+            .total_deposits_locked
+            == 0,
+        GovernanceError::OutstandingFunds
+    );
+
+    let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[ctx.bumps.governance_authority]];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: token_vault.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.governance_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::close_account(close_ctx)?;
 
-    msg!("Governance token vault initialized successfully");
+    msg!(
+        "Governance token vault closed for mint {}",
+        token_vault.mint
+    );
     Ok(())
 }
 
 #[derive(Accounts)]
-pub struct InitializeTokenVault<'info> {
-    /// Token vault account (PDA)
-    /// CHECK: This account will be created through CPI
+pub struct CloseTokenVault<'info> {
+    /// Token vault account (PDA, one per deposit mint)
     #[account(
         mut,
-        seeds = [GOVERNANCE_TOKEN_VAULT_SEED],
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, token_vault.mint.as_ref()],
         bump
     )]
-    pub token_vault: UncheckedAccount<'info>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
-    /// Token mint
-    pub mint: Account<'info, Mint>,
+    /// Governance configuration (locked deposit accounting)
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
 
     /// Governance authority (PDA)
-    /// CHECK: This is a PDA used as token account authority
+    /// CHECK: This is the governance system's PDA authority
     #[account(
         seeds = [GOVERNANCE_AUTHORITY_SEED],
         bump
     )]
     pub governance_authority: UncheckedAccount<'info>,
 
-    /// Payer account
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    /// Only administrator can close vaults (receives vault rent)
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
 
-    /// System program
-    pub system_program: Program<'info, System>,
+    /// Token program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Migrate legacy single vault to the per-mint vault
+/// Deployments created before per-mint vaults hold deposits in the vault at
+/// [GOVERNANCE_TOKEN_VAULT_SEED]; this moves its balance into the vault at
+/// [GOVERNANCE_TOKEN_VAULT_SEED, mint] (created first with initialize_token_vault)
+/// and closes the legacy account, returning its rent to the authority
+pub fn migrate_legacy_token_vault(ctx: Context<MigrateLegacyTokenVault>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::MigrateLegacyTokenVault,
+    )?;
+
+    let amount = ctx.accounts.legacy_token_vault.amount;
+    let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[ctx.bumps.governance_authority]];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    if amount > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.legacy_token_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_vault.to_account_info(),
+                authority: ctx.accounts.governance_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.mint.decimals)?;
+        let legacy_vault_key = ctx.accounts.legacy_token_vault.key();
+        emit_treasury_transfer(
+            &mut ctx.accounts.token_vault,
+            TransferDirection::In,
+            TreasuryTransferReason::LegacyVaultMigration,
+            None,
+            legacy_vault_key,
+            amount,
+        )?;
+    }
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.legacy_token_vault.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.governance_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::close_account(close_ctx)?;
+
+    msg!("Migrated {} tokens from legacy governance vault", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateLegacyTokenVault<'info> {
+    /// Legacy token vault (PDA at the pre-per-mint seed)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED],
+        bump
+    )]
+    pub legacy_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Per-mint token vault for the legacy vault's mint
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, legacy_token_vault.mint.as_ref()],
+        bump
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Legacy vault token mint
+    #[account(
+        constraint = mint.key() == legacy_token_vault.mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance configuration
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance authority (PDA)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Only administrator can migrate vaults (receives legacy vault rent)
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
 
     /// Token program
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
-    /// Rent sysvar
-    pub rent: Sysvar<'info, Rent>,
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Claim a deposit refund left claimable at finalization
+/// The refund goes to any proposer-owned token account of the deposit mint,
+/// and the claim account is closed back to the proposer
+pub fn claim_refund(ctx: Context<ClaimRefund>, proposal_id: u64) -> Result<()> {
+    let amount = ctx.accounts.refund_claim.amount;
+    let mint = ctx.accounts.refund_claim.mint;
+
+    let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[ctx.bumps.governance_authority]];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.governance_token_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.destination_token_account.to_account_info(),
+            authority: ctx.accounts.governance_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.mint.decimals)?;
+    let destination = ctx.accounts.destination_token_account.key();
+    emit_treasury_transfer(
+        &mut ctx.accounts.governance_token_vault,
+        TransferDirection::Out,
+        TreasuryTransferReason::RefundClaim,
+        Some(proposal_id),
+        destination,
+        amount,
+    )?;
+
+    // Claimed refund is no longer owed back
+    ctx.accounts
+        .governance_config
+        .release_locked_deposit(&mint, amount)?;
+
+    msg!(
+        "Proposal {} refund of {} claimed by {}",
+        proposal_id,
+        amount,
+        ctx.accounts.proposer.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ClaimRefund<'info> {
+    /// Refund claim created at finalization (closed to the proposer)
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [REFUND_CLAIM_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = refund_claim.bump,
+        has_one = proposer @ GovernanceError::Unauthorized
+    )]
+    pub refund_claim: Account<'info, RefundClaim>,
+
+    /// Governance configuration (locked deposit accounting)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Any proposer-owned token account of the deposit mint
+    #[account(
+        mut,
+        constraint = destination_token_account.owner == proposer.key() @ GovernanceError::Unauthorized,
+        constraint = destination_token_account.mint == refund_claim.mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Governance system token vault for the deposit mint
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, refund_claim.mint.as_ref()],
+        bump
+    )]
+    pub governance_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Deposit token mint
+    #[account(
+        constraint = mint.key() == refund_claim.mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance system authority (for signing transfers)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Proposer entitled to the refund
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// Token program
+    pub token_program: Interface<'info, TokenInterface>,
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::error::GovernanceError;
+use crate::events::*;
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Emit a governance heartbeat (permissionless, rate limited by heartbeat_interval)
+#[derive(Accounts)]
+pub struct TouchGovernance<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance system token vault whose balance is reported
+    #[account(
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, governance_token_vault.mint.as_ref()],
+        bump = governance_config.token_vault_bump(&governance_token_vault.mint)?
+    )]
+    pub governance_token_vault: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Touch governance handler
+/// Fails with HeartbeatTooSoon within heartbeat_interval of the last heartbeat
+pub fn touch_governance(ctx: Context<TouchGovernance>) -> Result<()> {
+    let governance_config = &mut ctx.accounts.governance_config;
+    let vault = &ctx.accounts.governance_token_vault;
+    let now = Clock::get()?.unix_timestamp;
+
+    governance_config.record_heartbeat(now)?;
+
+    emit!(Heartbeat {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: governance_config_address(governance_config.bump)?,
+        proposal_counter: governance_config.proposal_counter,
+        pending_proposal_count: governance_config.pending_proposal_count,
+        committee_epoch: governance_config.committee_epoch,
+        vault_mint: vault.mint,
+        vault_balance: vault.amount,
+        timestamp: now,
+    });
+
+    msg!("Governance heartbeat at {}", now);
+    Ok(())
+}
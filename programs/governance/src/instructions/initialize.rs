@@ -1,8 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 use crate::error::GovernanceError;
+use crate::events::*;
+use crate::instructions::admin::authorize_admin_call;
 use crate::instructions::common::*;
+use crate::instructions::proposal::committee_member_voting_power;
+use crate::instructions::stats::init_governance_stats;
+use crate::require_admin_authority;
 use crate::require_valid_threshold;
 use crate::state::*;
 
@@ -18,18 +26,28 @@ pub struct InitializeGovernance<'info> {
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
+    /// Governance statistics, created alongside the config
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GovernanceStats::INIT_SPACE,
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: Account<'info, GovernanceStats>,
+
     /// Committee token mint
-    pub committee_token_mint: Account<'info, Mint>,
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
 
     /// USDC token mint (for proposal deposits)
-    pub usdc_token_mint: Account<'info, Mint>,
+    pub usdc_token_mint: InterfaceAccount<'info, Mint>,
 
     /// System administrator
     #[account(mut)]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Initialize governance system handler
@@ -49,6 +67,10 @@ pub fn initialize_governance(
     // Get USDC token mint decimals for proposal deposit calculation
     let usdc_token_mint = &ctx.accounts.usdc_token_mint;
     let usdc_decimals = usdc_token_mint.decimals;
+    require!(
+        usdc_decimals <= MAX_DEPOSIT_MINT_DECIMALS,
+        GovernanceError::InvalidMintDecimals
+    );
 
     // Calculate actual proposal deposit with USDC decimals
     let proposal_deposit = proposal_deposit_raw
@@ -57,43 +79,99 @@ pub fn initialize_governance(
 
     // Validate parameters using common validation functions
     require_valid_threshold!(participation_threshold);
-    require_valid_threshold!(approval_threshold);
-    require_valid_threshold!(veto_threshold);
+    validate_approval_threshold(approval_threshold)?;
+    validate_veto_threshold(veto_threshold)?;
     require!(fee_rate <= PERCENTAGE_BASE, GovernanceError::InvalidFeeRate);
 
     // Voting period validation using common function
     validate_voting_period(voting_period, test_mode)?;
 
-    // Validate committee token mint address
-    // Note: In test environment, we temporarily skip this validation to allow test mint
-    // In production environment, this validation should be enabled
-    /*
-    let expected_mint = "DXDVt289yXEcqXDd9Ub3HqSBTWwrmNB8DzQEagv9Svtu"
-        .parse::<Pubkey>()
-        .unwrap();
-    require!(
-        ctx.accounts.committee_token_mint.key() == expected_mint,
-        GovernanceError::InvalidTokenMint
-    );
-    */
+    // Validate committee token mint
+    // Test deployments run against locally created mints, so only production is restricted
+    if !test_mode {
+        require!(
+            is_allowed_committee_token_mint(&ctx.accounts.committee_token_mint),
+            GovernanceError::CommitteeMintNotAllowed
+        );
+    }
 
     // Initialize configuration
     governance_config.authority = ctx.accounts.authority.key();
+    governance_config.config_admin = ctx.accounts.authority.key();
+    governance_config.rule_admin = ctx.accounts.authority.key();
+    governance_config.treasury_admin = ctx.accounts.authority.key();
     governance_config.committee_token_mint = ctx.accounts.committee_token_mint.key();
     governance_config.committee_members = [None; 10]; // Initialize as empty array
     governance_config.committee_member_count = 0;
+    governance_config.committee_vote_counts = [0; 10];
     governance_config.proposal_deposit = proposal_deposit;
+    governance_config.deposit_mints = vec![DepositMint {
+        mint: usdc_token_mint.key(),
+        decimals: usdc_decimals,
+        min_deposit: proposal_deposit,
+        total_deposits_locked: 0,
+        cumulative_donations: 0,
+        treasury_balance: 0,
+    }];
     governance_config.voting_period = voting_period;
     governance_config.participation_threshold = participation_threshold;
     governance_config.approval_threshold = approval_threshold;
     governance_config.veto_threshold = veto_threshold;
     governance_config.fee_rate = fee_rate;
     governance_config.test_mode = test_mode;
+    governance_config.resubmission_deposit_rate =
+        governance_constants::DEFAULT_RESUBMISSION_DEPOSIT_RATE;
+    governance_config.slash_escalation_rate = governance_constants::DEFAULT_SLASH_ESCALATION_RATE;
+    governance_config.max_slash_deposit_multiplier =
+        governance_constants::DEFAULT_MAX_SLASH_DEPOSIT_MULTIPLIER;
+    governance_config.confiscation_policy = ConfiscationPolicy::KeepInTreasury;
+    governance_config.deposit_escrow_enabled = false;
+    governance_config.deposit_slash_schedule = DepositSlashSchedule::default();
+    governance_config.deposit_kind = DepositKind::SplToken;
+    governance_config.pending_authority = None;
+    governance_config.admin_multisig_enabled = false;
     governance_config.total_voting_power = 0;
+    governance_config.total_voting_power_synced_at = 0;
+    governance_config.production_locked_at = None;
     governance_config.proposal_counter = 0;
+    governance_config.pending_proposal_count = 0;
     governance_config.created_at = clock.unix_timestamp;
     governance_config.updated_at = clock.unix_timestamp;
     governance_config.bump = ctx.bumps.governance_config;
+    governance_config.schema_version = GOVERNANCE_CONFIG_SCHEMA_VERSION;
+    governance_config.inactivity_threshold = governance_constants::DEFAULT_INACTIVITY_THRESHOLD;
+    governance_config.member_stake_requirement = 0;
+    governance_config.member_stake_cooldown = governance_constants::DEFAULT_MEMBER_STAKE_COOLDOWN;
+    governance_config.stake_based_voting_power = false;
+    governance_config.max_committee_size = MAX_COMMITTEE_MEMBERS as u8;
+    governance_config.max_active_proposals = governance_constants::DEFAULT_MAX_ACTIVE_PROPOSALS;
+    governance_config.committee_epoch = 0;
+    governance_config.max_rule_effective_delay =
+        governance_constants::DEFAULT_MAX_RULE_EFFECTIVE_DELAY;
+    governance_config.strict_url_validation = true;
+    governance_config.proposal_index_enabled = false;
+    governance_config.emit_memos = false;
+    governance_config.order_program = None;
+    governance_config.participation_bonus_bps = 0;
+    governance_config.participation_bonus_window = 0;
+    governance_config.max_member_voting_power = 0;
+    governance_config.authority_bump = governance_authority_bump();
+    governance_config.token_vault_bumps = vec![TokenVaultBump::derive(usdc_token_mint.key())];
+    governance_config.max_lock_duration = 0;
+    governance_config.max_lock_multiplier_bps = 0;
+    governance_config.config_timelock = governance_constants::DEFAULT_CONFIG_TIMELOCK;
+    governance_config.waive_deposit_for_committee = false;
+    governance_config.voting_power_tolerance_bps =
+        governance_constants::DEFAULT_VOTING_POWER_TOLERANCE_BPS;
+    governance_config.heartbeat_interval = governance_constants::DEFAULT_HEARTBEAT_INTERVAL;
+    governance_config.last_heartbeat_at = 0;
+    governance_config.rate_rounding = RoundingMode::Floor;
+
+    init_governance_stats(
+        &mut ctx.accounts.governance_stats,
+        ctx.bumps.governance_stats,
+        clock.unix_timestamp,
+    );
 
     msg!("Governance system initialized successfully");
     Ok(())
@@ -105,66 +183,431 @@ pub struct UpdateGovernanceConfig<'info> {
     #[account(
         mut,
         seeds = [GOVERNANCE_CONFIG_SEED],
-        bump = governance_config.bump
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can update configuration
+    /// Only administrator can update configuration (admin set approval in multisig mode),
+    /// and only in test mode (otherwise queue_config_update)
     #[account(
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized,
+        constraint = governance_config.test_mode @ GovernanceError::TestModeRequired
     )]
     pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
 }
 
 /// Update governance configuration handler
 pub fn update_governance_config(
     ctx: Context<UpdateGovernanceConfig>,
     config_update: GovernanceConfigUpdate,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::UpdateGovernanceConfig(config_update.clone()),
+    )?;
+
+    apply_governance_config_update(
+        &mut ctx.accounts.governance_config,
+        &config_update,
+        ctx.accounts.authority.key(),
+    )?;
+
+    msg!("Governance configuration updated successfully");
+    Ok(())
+}
+
+/// Validate and apply a configuration update (shared with admin set execution)
+pub(crate) fn apply_governance_config_update(
+    governance_config: &mut GovernanceConfig,
+    config_update: &GovernanceConfigUpdate,
+    updater: Pubkey,
 ) -> Result<()> {
     // Validate update parameters
-    config_update.validate(ctx.accounts.governance_config.test_mode)?;
+    config_update.validate(governance_config.test_mode)?;
 
     // Apply update
-    let governance_config = &mut ctx.accounts.governance_config;
-    config_update.apply_to(governance_config);
+    let (previous, new) = config_update.changes(governance_config);
+    config_update.apply_to(governance_config, Clock::get()?.unix_timestamp);
 
-    msg!("Governance configuration updated successfully");
+    require!(
+        governance_config.committee_member_count <= governance_config.max_committee_size,
+        GovernanceError::InvalidGovernanceLimit
+    );
+
+    // An enabled participation bonus needs a window of recorded proposals
+    require!(
+        !governance_config.participation_bonus_enabled()
+            || governance_config.participation_bonus_window >= 1,
+        GovernanceError::InvalidParticipationBonus
+    );
+
+    // Enabled lockups multiply voting power by at least 1x and at most the hard cap
+    require!(
+        !governance_config.lockups_enabled()
+            || (governance_constants::BASIS_POINTS_DENOMINATOR as u16
+                ..=governance_constants::MAX_LOCK_MULTIPLIER_BPS)
+                .contains(&governance_config.max_lock_multiplier_bps),
+        GovernanceError::InvalidLockupParameters
+    );
+
+    // Escrow accounts are token accounts; native SOL deposits always use the pooled vault
+    require!(
+        !(governance_config.deposit_escrow_enabled
+            && governance_config.deposit_kind == DepositKind::NativeSol),
+        GovernanceError::DepositEscrowMismatch
+    );
+
+    emit!(GovernanceConfigUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: governance_config_address(governance_config.bump)?,
+        updater,
+        previous,
+        new,
+        pending_proposal_count: governance_config.pending_proposal_count,
+        timestamp: governance_config.updated_at,
+    });
     Ok(())
 }
 
-/// Update total voting power
+/// Queue a configuration update behind the config timelock
 #[derive(Accounts)]
-pub struct UpdateTotalVotingPower<'info> {
+pub struct QueueConfigUpdate<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Queued update (one at a time)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingConfigUpdate::INIT_SPACE,
+        seeds = [PENDING_CONFIG_UPDATE_SEED],
+        bump
+    )]
+    pub pending_config_update: Account<'info, PendingConfigUpdate>,
+
+    /// Only administrator can queue updates (admin set approval in multisig mode)
+    #[account(
+        mut,
+        constraint = authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized,
+        constraint = !governance_config.admin_multisig_enabled @ GovernanceError::AdminMultisigRequired
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when a configuration update is queued
+#[event]
+pub struct ConfigUpdateQueued {
+    /// Queued update
+    pub update: GovernanceConfigUpdate,
+    /// Config admin that queued the update
+    pub queued_by: Pubkey,
+    /// Earliest time the update can be applied
+    pub eta: i64,
+    /// Queue timestamp
+    pub timestamp: i64,
+}
+
+/// Queue config update handler
+/// The update is validated now and again when applied, against the config as it stands then
+pub fn queue_config_update(
+    ctx: Context<QueueConfigUpdate>,
+    config_update: GovernanceConfigUpdate,
+) -> Result<()> {
+    queue_governance_config_update(
+        &ctx.accounts.governance_config,
+        &mut ctx.accounts.pending_config_update,
+        config_update,
+        ctx.accounts.authority.key(),
+        ctx.bumps.pending_config_update,
+    )
+}
+
+/// Validate a configuration update and queue it behind the config timelock
+/// (shared with admin set execution)
+pub(crate) fn queue_governance_config_update(
+    governance_config: &GovernanceConfig,
+    pending_config_update: &mut PendingConfigUpdate,
+    config_update: GovernanceConfigUpdate,
+    queued_by: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    config_update.validate(governance_config.test_mode)?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let eta = timestamp
+        .checked_add(governance_config.config_timelock)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    pending_config_update.update = config_update.clone();
+    pending_config_update.queued_by = queued_by;
+    pending_config_update.queued_at = timestamp;
+    pending_config_update.eta = eta;
+    pending_config_update.bump = bump;
+
+    emit!(ConfigUpdateQueued {
+        update: config_update,
+        queued_by,
+        eta,
+        timestamp,
+    });
+
+    msg!("Configuration update queued until {}", eta);
+    Ok(())
+}
+
+/// Apply a queued configuration update once its timelock has run out
+#[derive(Accounts)]
+pub struct ApplyConfigUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Queued update (closed to the admin that queued it)
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [PENDING_CONFIG_UPDATE_SEED],
+        bump = pending_config_update.bump
+    )]
+    pub pending_config_update: Account<'info, PendingConfigUpdate>,
+
+    /// Config admin that queued the update
+    /// CHECK: Address constraint; only receives the account rent
     #[account(
         mut,
+        address = pending_config_update.queued_by @ GovernanceError::Unauthorized
+    )]
+    pub queued_by: UncheckedAccount<'info>,
+}
+
+/// Apply config update handler
+/// Anyone may apply a queued update after its eta; it is recorded as made by queued_by
+pub fn apply_config_update(ctx: Context<ApplyConfigUpdate>) -> Result<()> {
+    let pending_config_update = &ctx.accounts.pending_config_update;
+    require!(
+        Clock::get()?.unix_timestamp >= pending_config_update.eta,
+        GovernanceError::ConfigUpdateTimelocked
+    );
+
+    apply_governance_config_update(
+        &mut ctx.accounts.governance_config,
+        &pending_config_update.update,
+        pending_config_update.queued_by,
+    )?;
+
+    msg!("Queued configuration update applied");
+    Ok(())
+}
+
+/// Cancel a queued configuration update
+#[derive(Accounts)]
+pub struct CancelConfigUpdate<'info> {
+    #[account(
         seeds = [GOVERNANCE_CONFIG_SEED],
         bump = governance_config.bump
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can update
+    /// Queued update (closed to the admin that queued it)
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [PENDING_CONFIG_UPDATE_SEED],
+        bump = pending_config_update.bump
+    )]
+    pub pending_config_update: Account<'info, PendingConfigUpdate>,
+
+    /// Config admin that queued the update
+    /// CHECK: Address constraint; only receives the account rent
+    #[account(
+        mut,
+        address = pending_config_update.queued_by @ GovernanceError::Unauthorized
+    )]
+    pub queued_by: UncheckedAccount<'info>,
+
+    /// Only the current administrator can cancel
     #[account(
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
 }
 
-/// Update total voting power handler
-pub fn update_total_voting_power(
-    ctx: Context<UpdateTotalVotingPower>,
-    new_total_voting_power: u64,
-) -> Result<()> {
-    let governance_config = &mut ctx.accounts.governance_config;
-    governance_config.total_voting_power = new_total_voting_power;
-    governance_config.updated_at = Clock::get()?.unix_timestamp;
+/// Event emitted when a queued configuration update is cancelled
+#[event]
+pub struct ConfigUpdateCancelled {
+    /// Cancelled update
+    pub update: GovernanceConfigUpdate,
+    /// Config admin that cancelled the update
+    pub cancelled_by: Pubkey,
+    /// Cancellation timestamp
+    pub timestamp: i64,
+}
 
-    msg!("Total voting power updated to: {}", new_total_voting_power);
+/// Cancel config update handler
+pub fn cancel_config_update(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CancelConfigUpdate,
+    )?;
+
+    emit!(ConfigUpdateCancelled {
+        update: ctx.accounts.pending_config_update.update.clone(),
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Queued configuration update cancelled");
     Ok(())
 }
 
-/// Update proposal counter (admin only)
+/// Migrate the governance configuration account to the current layout
 #[derive(Accounts)]
-pub struct UpdateProposalCounter<'info> {
+pub struct MigrateGovernanceConfig<'info> {
+    /// Grown to the current size first; older layouts may not fit the current struct
+    /// CHECK: Deserialized as GovernanceConfig by the handler after the realloc
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump
+    )]
+    pub governance_config: UncheckedAccount<'info>,
+
+    /// Only the config admin can migrate (pays for the larger account)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when the governance configuration is migrated to a newer layout
+#[event]
+pub struct GovernanceConfigMigrated {
+    /// Schema version before migration (0 for configs predating the version field)
+    pub previous_schema_version: u8,
+    /// Schema version after migration
+    pub schema_version: u8,
+    /// Migration timestamp
+    pub timestamp: i64,
+}
+
+/// Migrate governance configuration handler
+/// Fields appended since the account's version read as zeros and are then given defaults
+pub fn migrate_governance_config(ctx: Context<MigrateGovernanceConfig>) -> Result<()> {
+    let config_info = ctx.accounts.governance_config.to_account_info();
+    require!(
+        config_info.owner == &crate::ID,
+        GovernanceError::InvalidAccountData
+    );
+    let legacy_config = LegacyGovernanceConfigV0::try_load(&config_info.try_borrow_data()?)?;
+
+    // Grow the account (zero-filled) before deserializing so appended fields read as zeros
+    let new_len = 8 + GovernanceConfig::INIT_SPACE;
+    if config_info.data_len() < new_len {
+        let shortfall = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(config_info.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: config_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        config_info.resize(new_len)?;
+    }
+
+    let mut governance_config = match legacy_config {
+        Some(legacy_config) => legacy_config.into_current()?,
+        None => {
+            let data = config_info.try_borrow_data()?;
+            GovernanceConfig::try_deserialize(&mut &data[..])?
+        }
+    };
+    require!(
+        !governance_config.is_current_schema(),
+        GovernanceError::ConfigAlreadyMigrated
+    );
+
+    let previous_schema_version = governance_config.schema_version;
+    governance_config.migrate_schema();
+    require!(
+        ctx.accounts.authority.key() == governance_config.config_admin,
+        GovernanceError::Unauthorized
+    );
+    let timestamp = Clock::get()?.unix_timestamp;
+    governance_config.updated_at = timestamp;
+    governance_config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    emit!(GovernanceConfigMigrated {
+        previous_schema_version,
+        schema_version: governance_config.schema_version,
+        timestamp,
+    });
+
+    msg!(
+        "Governance configuration migrated from schema version {} to {}",
+        previous_schema_version,
+        governance_config.schema_version
+    );
+    Ok(())
+}
+
+/// Permanently commit the deployment to production mode
+#[derive(Accounts)]
+pub struct LockProduction<'info> {
     #[account(
         mut,
         seeds = [GOVERNANCE_CONFIG_SEED],
@@ -172,34 +615,68 @@ pub struct UpdateProposalCounter<'info> {
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can update proposal counter
+    /// Only administrator can lock production mode (admin set approval in multisig mode)
     #[account(
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
 }
 
-/// Update proposal counter handler
-pub fn update_proposal_counter(
-    ctx: Context<UpdateProposalCounter>,
-    new_counter: u64,
-) -> Result<()> {
+/// Lock production mode handler
+/// Clears test mode for good; the current voting period must already meet production bounds
+pub fn lock_production(ctx: Context<LockProduction>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::LockProduction,
+    )?;
+
     let governance_config = &mut ctx.accounts.governance_config;
-    // Only allow non-decreasing updates to avoid accidental rollback
     require!(
-        new_counter >= governance_config.proposal_counter,
-        GovernanceError::InvalidThreshold // reuse an existing error; or define a specific one if needed
+        governance_config.production_locked_at.is_none(),
+        GovernanceError::TestModeLocked
     );
-    governance_config.proposal_counter = new_counter;
-    governance_config.updated_at = Clock::get()?.unix_timestamp;
+    validate_voting_period(governance_config.voting_period, false)?;
 
-    msg!("Proposal counter updated to: {}", new_counter);
+    let locked_at = Clock::get()?.unix_timestamp;
+    governance_config.test_mode = false;
+    governance_config.production_locked_at = Some(locked_at);
+    governance_config.updated_at = locked_at;
+
+    emit!(ProductionLocked { locked_at });
+
+    msg!("Production mode locked");
     Ok(())
 }
 
-/// Add committee member
+/// Event emitted when the deployment is permanently locked to production mode
+#[event]
+pub struct ProductionLocked {
+    /// Lock timestamp
+    pub locked_at: i64,
+}
+
+/// Add or update an accepted deposit mint
 #[derive(Accounts)]
-pub struct AddCommitteeMember<'info> {
+pub struct SetDepositMint<'info> {
     #[account(
         mut,
         seeds = [GOVERNANCE_CONFIG_SEED],
@@ -207,25 +684,86 @@ pub struct AddCommitteeMember<'info> {
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can add members
+    /// Deposit token mint
+    pub deposit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Only administrator can change accepted deposit mints
     #[account(
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
 }
 
-/// Add committee member handler
-pub fn add_committee_member(ctx: Context<AddCommitteeMember>, member: Pubkey) -> Result<()> {
+/// Add or update accepted deposit mint handler
+pub fn set_deposit_mint(ctx: Context<SetDepositMint>, min_deposit_raw: u64) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetDepositMint {
+            mint: ctx.accounts.deposit_mint.key(),
+            min_deposit_raw,
+        },
+    )?;
+
+    let deposit_mint = &ctx.accounts.deposit_mint;
+    require!(
+        deposit_mint.decimals <= MAX_DEPOSIT_MINT_DECIMALS,
+        GovernanceError::InvalidMintDecimals
+    );
+    let min_deposit = min_deposit_raw
+        .checked_mul(10_u64.pow(deposit_mint.decimals as u32))
+        .ok_or(GovernanceError::MathOverflow)?;
+
     let governance_config = &mut ctx.accounts.governance_config;
-    governance_config.add_committee_member(member)?;
+    governance_config.set_deposit_mint(deposit_mint.key(), deposit_mint.decimals, min_deposit)?;
 
-    msg!("Committee member added: {}", member);
+    msg!(
+        "Deposit mint {} accepted with minimum deposit {}",
+        deposit_mint.key(),
+        min_deposit
+    );
     Ok(())
 }
 
-/// Remove committee member
+/// Remove accepted deposit mint handler
+pub fn remove_deposit_mint(ctx: Context<SetDepositMint>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::RemoveDepositMint(ctx.accounts.deposit_mint.key()),
+    )?;
+
+    let deposit_mint = ctx.accounts.deposit_mint.key();
+    let governance_config = &mut ctx.accounts.governance_config;
+    governance_config.remove_deposit_mint(&deposit_mint)?;
+
+    msg!("Deposit mint removed: {}", deposit_mint);
+    Ok(())
+}
+
+/// Switch deposit kind
 #[derive(Accounts)]
-pub struct RemoveCommitteeMember<'info> {
+pub struct SetDepositKind<'info> {
     #[account(
         mut,
         seeds = [GOVERNANCE_CONFIG_SEED],
@@ -233,41 +771,2405 @@ pub struct RemoveCommitteeMember<'info> {
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can remove members
+    /// Only administrator can switch deposit kind
     #[account(
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
 }
 
-/// Remove committee member handler
-pub fn remove_committee_member(ctx: Context<RemoveCommitteeMember>, member: Pubkey) -> Result<()> {
+/// Switch deposit kind handler
+/// native_min_deposit (lamports) sets the minimum native SOL deposit and is ignored for SPL tokens
+pub fn set_deposit_kind(
+    ctx: Context<SetDepositKind>,
+    deposit_kind: DepositKind,
+    native_min_deposit: u64,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetDepositKind {
+            deposit_kind,
+            native_min_deposit,
+        },
+    )?;
+
     let governance_config = &mut ctx.accounts.governance_config;
-    governance_config.remove_committee_member(member)?;
+    governance_config.set_deposit_kind(deposit_kind, native_min_deposit)?;
+    governance_config.updated_at = Clock::get()?.unix_timestamp;
 
-    msg!("Committee member removed: {}", member);
+    msg!("Deposit kind set to {:?}", deposit_kind);
     Ok(())
 }
 
-/// Close governance configuration
+/// Register the marketplace order program
 #[derive(Accounts)]
-pub struct CloseGovernanceConfig<'info> {
+pub struct SetOrderProgram<'info> {
     #[account(
         mut,
-        close = authority,
         seeds = [GOVERNANCE_CONFIG_SEED],
         bump = governance_config.bump,
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can close configuration
-    #[account(mut)]
+    /// Only administrator can register the order program
+    #[account(
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
     pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
 }
 
-/// Close governance configuration handler
-pub fn close_governance_config(_ctx: Context<CloseGovernanceConfig>) -> Result<()> {
-    msg!("Governance configuration closed successfully");
+/// Register order program handler
+/// None stops checking dispute parties against order accounts
+pub fn set_order_program(
+    ctx: Context<SetOrderProgram>,
+    order_program: Option<Pubkey>,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetOrderProgram(order_program),
+    )?;
+
+    let governance_config = &mut ctx.accounts.governance_config;
+    governance_config.order_program = order_program;
+    governance_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Order program set to {:?}", order_program);
+    Ok(())
+}
+
+/// Update total voting power
+/// Remaining accounts: a (CommitteeMember PDA, member associated token account for the
+/// committee token mint) pair per committee member, as for sync_total_voting_power
+#[derive(Accounts)]
+pub struct UpdateTotalVotingPower<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can update, and only in test mode (otherwise use sync_total_voting_power)
+    #[account(
+        constraint = authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized,
+        constraint = governance_config.test_mode @ GovernanceError::TestModeRequired
+    )]
+    pub authority: Signer<'info>,
+
+    /// Committee token mint (its supply bounds the total voting power)
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Event emitted when total voting power is set manually
+#[event]
+pub struct TotalVotingPowerUpdated {
+    /// Signer that set the value
+    pub updater: Pubkey,
+    /// Previous total voting power
+    pub previous_total_voting_power: u64,
+    /// New total voting power
+    pub total_voting_power: u64,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a manually set total voting power diverges from the member balance
+/// sum by more than voting_power_tolerance_bps
+#[event]
+pub struct TotalVotingPowerDivergence {
+    /// Total voting power that was set
+    pub total_voting_power: u64,
+    /// Total voting power computed from committee member balances
+    pub computed_voting_power: u64,
+    /// Tolerance in effect (basis points of the computed total)
+    pub tolerance_bps: u16,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Update total voting power handler (test mode override)
+/// The value cannot exceed the whole committee token supply in voting power units; a value
+/// away from the member balance sum is accepted with a TotalVotingPowerDivergence warning
+pub fn update_total_voting_power<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateTotalVotingPower<'info>>,
+    new_total_voting_power: u64,
+) -> Result<()> {
+    let committee_token_mint = &ctx.accounts.committee_token_mint;
+    let supply_voting_power = committee_token_mint.supply
+        / 10_u64
+            .checked_pow(committee_token_mint.decimals as u32)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+    require!(
+        new_total_voting_power <= supply_voting_power,
+        GovernanceError::VotingPowerExceedsSupply
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let computed_voting_power = committee_balance_voting_power(
+        &ctx.accounts.governance_config,
+        committee_token_mint,
+        &ctx.accounts.committee_token_program.key(),
+        ctx.remaining_accounts,
+        timestamp,
+    )?;
+
+    let governance_config = &mut ctx.accounts.governance_config;
+    let tolerance_bps = governance_config.voting_power_tolerance_bps;
+    let divergence = new_total_voting_power.abs_diff(computed_voting_power) as u128;
+    if divergence * governance_constants::BASIS_POINTS_DENOMINATOR as u128
+        > computed_voting_power as u128 * tolerance_bps as u128
+    {
+        emit!(TotalVotingPowerDivergence {
+            total_voting_power: new_total_voting_power,
+            computed_voting_power,
+            tolerance_bps,
+            timestamp,
+        });
+        msg!(
+            "Warning: total voting power {} diverges from member balances {}",
+            new_total_voting_power,
+            computed_voting_power
+        );
+    }
+
+    let previous_total_voting_power = governance_config.total_voting_power;
+    governance_config.total_voting_power = new_total_voting_power;
+    governance_config.updated_at = timestamp;
+
+    emit!(TotalVotingPowerUpdated {
+        updater: ctx.accounts.authority.key(),
+        previous_total_voting_power,
+        total_voting_power: new_total_voting_power,
+        timestamp,
+    });
+
+    msg!("Total voting power updated to: {}", new_total_voting_power);
+    Ok(())
+}
+
+/// Sync total voting power from committee member balances
+/// Remaining accounts: a (CommitteeMember PDA, member associated token account for the
+/// committee token mint) pair per committee member
+#[derive(Accounts)]
+pub struct SyncTotalVotingPower<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Event emitted when total voting power is synced from member balances
+#[event]
+pub struct TotalVotingPowerSynced {
+    /// Previous total voting power
+    pub previous_total_voting_power: u64,
+    /// Synced total voting power
+    pub total_voting_power: u64,
+    /// Number of committee members counted
+    pub member_count: u8,
+    /// Sync timestamp
+    pub synced_at: i64,
+}
+
+/// Sync total voting power handler
+/// Permissionless: the total is computed the same way finalize_proposal computes it
+pub fn sync_total_voting_power<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SyncTotalVotingPower<'info>>,
+) -> Result<()> {
+    let synced_at = Clock::get()?.unix_timestamp;
+    let total_voting_power = committee_balance_voting_power(
+        &ctx.accounts.governance_config,
+        &ctx.accounts.committee_token_mint,
+        &ctx.accounts.committee_token_program.key(),
+        ctx.remaining_accounts,
+        synced_at,
+    )?;
+
+    let governance_config = &mut ctx.accounts.governance_config;
+    let previous_total_voting_power = governance_config.total_voting_power;
+    governance_config.total_voting_power = total_voting_power;
+    governance_config.total_voting_power_synced_at = synced_at;
+
+    emit!(TotalVotingPowerSynced {
+        previous_total_voting_power,
+        total_voting_power,
+        member_count: governance_config.committee_member_count,
+        synced_at,
+    });
+
+    msg!("Total voting power synced: {}", total_voting_power);
+    Ok(())
+}
+
+/// Sum the voting power of every committee member from (CommitteeMember PDA, member
+/// associated token account) pairs, the same way finalize_proposal computes it
+fn committee_balance_voting_power(
+    governance_config: &GovernanceConfig,
+    committee_token_mint: &InterfaceAccount<Mint>,
+    token_program: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    now: i64,
+) -> Result<u64> {
+    require!(
+        governance_config.legacy_committee_member_count() == 0,
+        GovernanceError::CommitteeMigrationRequired
+    );
+    require!(
+        remaining_accounts.len() == governance_config.committee_member_count as usize * 2,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+
+    let mint = committee_token_mint.key();
+    let mut counted_members: Vec<Pubkey> = Vec::with_capacity(remaining_accounts.len() / 2);
+    let mut total_voting_power: u64 = 0;
+    for pair in remaining_accounts.chunks(2) {
+        let committee_member = load_committee_member(&pair[0])?;
+        require!(
+            !counted_members.contains(&committee_member.member),
+            GovernanceError::InvalidCommitteeAccounts
+        );
+        counted_members.push(committee_member.member);
+        require!(
+            pair[1].key()
+                == get_associated_token_address_with_program_id(
+                    &committee_member.member,
+                    &mint,
+                    token_program
+                ),
+            GovernanceError::InvalidTokenAccount
+        );
+
+        let voting_power = committee_member_voting_power(
+            &committee_member,
+            &pair[1],
+            &mint,
+            committee_token_mint.decimals,
+            now,
+            governance_config.stake_based_voting_power,
+        )?;
+        total_voting_power = total_voting_power
+            .checked_add(voting_power)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+    }
+    Ok(total_voting_power)
+}
+
+/// Update proposal counter (admin only)
+#[derive(Accounts)]
+pub struct UpdateProposalCounter<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can update proposal counter
+    #[account(
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Event emitted when the proposal counter is moved by the administrator
+#[event]
+pub struct ProposalCounterUpdated {
+    /// Administrator that moved the counter
+    pub updater: Pubkey,
+    /// Previous counter
+    pub previous_counter: u64,
+    /// New counter
+    pub new_counter: u64,
+    /// Reason supplied by the administrator
+    pub reason: String,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Update proposal counter handler
+/// The counter may only advance, by at most MAX_PROPOSAL_COUNTER_JUMP, and never while a
+/// proposal is pending so in-flight proposal IDs stay valid
+pub fn update_proposal_counter(
+    ctx: Context<UpdateProposalCounter>,
+    new_counter: u64,
+    reason: String,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::UpdateProposalCounter {
+            new_counter,
+            reason: reason.clone(),
+        },
+    )?;
+
+    require!(
+        reason.len() <= MAX_PROPOSAL_COUNTER_REASON_LENGTH,
+        GovernanceError::ProposalCounterReasonTooLong
+    );
+    let governance_config = &mut ctx.accounts.governance_config;
+    let previous_counter = governance_config.proposal_counter;
+    // Only allow non-decreasing updates to avoid accidental rollback
+    require!(
+        new_counter >= previous_counter
+            && new_counter - previous_counter <= MAX_PROPOSAL_COUNTER_JUMP,
+        GovernanceError::InvalidProposalCounter
+    );
+    require!(
+        governance_config.pending_proposal_count == 0,
+        GovernanceError::PendingProposalsExist
+    );
+    let timestamp = Clock::get()?.unix_timestamp;
+    governance_config.proposal_counter = new_counter;
+    governance_config.updated_at = timestamp;
+
+    emit!(ProposalCounterUpdated {
+        updater: ctx.accounts.authority.key(),
+        previous_counter,
+        new_counter,
+        reason,
+        timestamp,
+    });
+
+    msg!("Proposal counter updated to: {}", new_counter);
+    Ok(())
+}
+
+/// Add committee member
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct AddCommitteeMember<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Committee member account to create
+    /// CHECK: Created in the handler; must not already hold a CommitteeMember
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, member.as_ref()],
+        bump
+    )]
+    pub committee_member: UncheckedAccount<'info>,
+
+    /// Member's stake (required while member_stake_requirement is set)
+    /// CHECK: Address checked and deserialized as MemberStake in the handler
+    pub member_stake: Option<UncheckedAccount<'info>>,
+
+    /// Only administrator can add members (admin set approval in multisig mode);
+    /// outside test mode members are added by committee change proposals only
+    #[account(
+        mut,
+        constraint = authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized,
+        constraint = !governance_config.admin_multisig_enabled @ GovernanceError::AdminMultisigRequired,
+        constraint = governance_config.test_mode @ GovernanceError::CommitteeChangeRequiresProposal
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add committee member handler
+pub fn add_committee_member(ctx: Context<AddCommitteeMember>, member: Pubkey) -> Result<()> {
+    add_committee_member_account(
+        &mut ctx.accounts.governance_config,
+        &ctx.accounts.committee_member,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        ctx.accounts.member_stake.as_deref(),
+        member,
+    )?;
+
+    msg!("Committee member added: {}", member);
+    Ok(())
+}
+
+/// Register a committee member and create its CommitteeMember account
+/// Shared by add_committee_member and admin action execution
+/// The member's stake must cover member_stake_requirement and is recorded on the seat
+pub(crate) fn add_committee_member_account<'info>(
+    governance_config: &mut GovernanceConfig,
+    committee_member: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    member_stake: Option<&AccountInfo<'info>>,
+    member: Pubkey,
+) -> Result<()> {
+    let (address, bump) =
+        Pubkey::find_program_address(&[COMMITTEE_MEMBER_SEED, member.as_ref()], &crate::ID);
+    require!(
+        committee_member.key() == address,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    require!(
+        CommitteeMember::try_load(committee_member)?.is_none(),
+        GovernanceError::MemberAlreadyExists
+    );
+
+    let staked_amount = seat_stake_amount(governance_config, member_stake, &member)?;
+
+    governance_config.register_committee_member(&member)?;
+    create_committee_member_account(
+        committee_member,
+        payer,
+        system_program,
+        member,
+        bump,
+        0,
+        staked_amount,
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    emit!(CommitteeMemberAdded {
+        member,
+        updater: payer.key(),
+        member_count: governance_config.committee_member_count,
+        timestamp,
+    });
+    record_committee_change(
+        governance_config,
+        CommitteeChangeKind::Added,
+        member,
+        timestamp,
+    )?;
+    if governance_config.committee_member_count >= governance_config.max_committee_size {
+        emit!(CommitteeSizeLimitReached {
+            member_count: governance_config.committee_member_count,
+            max_committee_size: governance_config.max_committee_size,
+            timestamp,
+        });
+    }
+    Ok(())
+}
+
+/// Advance the committee epoch and emit CommitteeChanged for a change in composition
+pub(crate) fn record_committee_change(
+    governance_config: &mut GovernanceConfig,
+    kind: CommitteeChangeKind,
+    member: Pubkey,
+    timestamp: i64,
+) -> Result<()> {
+    let epoch = governance_config.advance_committee_epoch()?;
+    emit!(CommitteeChanged {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: governance_config_address(governance_config.bump)?,
+        epoch,
+        kind,
+        member,
+        member_count: governance_config.committee_member_count,
+        timestamp,
+    });
+    Ok(())
+}
+
+/// Event emitted when an addition fills the committee to max_committee_size
+#[event]
+pub struct CommitteeSizeLimitReached {
+    /// Committee size after the addition
+    pub member_count: u8,
+    /// Configured committee size limit
+    pub max_committee_size: u8,
+    /// Addition timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a committee member is added
+#[event]
+pub struct CommitteeMemberAdded {
+    /// Added member
+    pub member: Pubkey,
+    /// Signer that applied the change
+    pub updater: Pubkey,
+    /// Committee size after the change
+    pub member_count: u8,
+    /// Change timestamp
+    pub timestamp: i64,
+}
+
+/// Create a CommitteeMember account at the member's PDA
+fn create_committee_member_account<'info>(
+    committee_member: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    member: Pubkey,
+    bump: u8,
+    vote_count: u64,
+    staked_amount: u64,
+) -> Result<()> {
+    let space = 8 + CommitteeMember::INIT_SPACE;
+    let member_seeds = &[COMMITTEE_MEMBER_SEED, member.as_ref(), &[bump]];
+    let signer_seeds = &[&member_seeds[..]];
+
+    create_pda_account(committee_member, payer, system_program, space, signer_seeds)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    CommitteeMember {
+        member,
+        display_name: String::new(),
+        vote_count,
+        joined_at: now,
+        active: true,
+        term_end: now
+            .checked_add(MEMBER_TERM_SECONDS)
+            .ok_or(GovernanceError::ArithmeticOverflow)?,
+        last_active_at: now,
+        suspended_for_inactivity: false,
+        alternate: None,
+        substitutes_for: None,
+        staked_amount,
+        bump,
+    }
+    .store(committee_member)
+}
+
+/// Remove committee member
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct RemoveCommitteeMember<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Committee member account to close (absent for unmigrated legacy members)
+    /// CHECK: Deserialized as CommitteeMember when owned by this program
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, member.as_ref()],
+        bump
+    )]
+    pub committee_member: UncheckedAccount<'info>,
+
+    /// Member's stake, locked for the cooldown from removal (empty if the member never staked)
+    /// CHECK: Deserialized as MemberStake when owned by this program
+    #[account(
+        mut,
+        seeds = [MEMBER_STAKE_SEED, member.as_ref()],
+        bump
+    )]
+    pub member_stake: UncheckedAccount<'info>,
+
+    /// Only administrator can remove members (receives the account rent);
+    /// outside test mode members are removed by committee change proposals only
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized,
+        constraint = governance_config.test_mode @ GovernanceError::CommitteeChangeRequiresProposal
+    )]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Remove committee member handler
+pub fn remove_committee_member(ctx: Context<RemoveCommitteeMember>, member: Pubkey) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::RemoveCommitteeMember(member),
+    )?;
+
+    remove_committee_member_account(
+        &mut ctx.accounts.governance_config,
+        &ctx.accounts.committee_member,
+        &ctx.accounts.member_stake,
+        &ctx.accounts.authority,
+        member,
+    )?;
+
+    msg!("Committee member removed: {}", member);
+    Ok(())
+}
+
+/// Unregister a committee member, closing its CommitteeMember account to the destination
+/// Shared by remove_committee_member and committee change proposal execution
+/// The member's stake, if any, stays locked for member_stake_cooldown from removal
+pub(crate) fn remove_committee_member_account<'info>(
+    governance_config: &mut GovernanceConfig,
+    committee_member: &AccountInfo<'info>,
+    member_stake: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    member: Pubkey,
+) -> Result<()> {
+    if governance_config.is_legacy_committee_member(&member) {
+        governance_config.take_legacy_committee_member(&member)?;
+    } else {
+        let (address, _) =
+            Pubkey::find_program_address(&[COMMITTEE_MEMBER_SEED, member.as_ref()], &crate::ID);
+        require!(
+            committee_member.key() == address,
+            GovernanceError::InvalidCommitteeAccounts
+        );
+        require!(
+            CommitteeMember::try_load(committee_member)?.is_some(),
+            GovernanceError::MemberNotFound
+        );
+        CommitteeMember::close(committee_member, destination)?;
+    }
+    governance_config.unregister_committee_member()?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    require_member_stake_address(member_stake, &member)?;
+    if let Some(mut stake) = MemberStake::try_load(member_stake)? {
+        stake.lock_until_cooldown_ends(timestamp, governance_config.member_stake_cooldown)?;
+        stake.store(member_stake)?;
+    }
+
+    emit!(CommitteeMemberRemoved {
+        member,
+        updater: destination.key(),
+        member_count: governance_config.committee_member_count,
+        timestamp,
+    });
+    record_committee_change(
+        governance_config,
+        CommitteeChangeKind::Removed,
+        member,
+        timestamp,
+    )
+}
+
+/// Event emitted when a committee member is removed
+#[event]
+pub struct CommitteeMemberRemoved {
+    /// Removed member
+    pub member: Pubkey,
+    /// Signer that applied the change (receives the account rent)
+    pub updater: Pubkey,
+    /// Committee size after the change
+    pub member_count: u8,
+    /// Change timestamp
+    pub timestamp: i64,
+}
+
+/// Check that a committee change still applies to the current committee
+/// (adds must name a non-member, removals a member)
+pub(crate) fn require_committee_change_applicable(
+    governance_config: &GovernanceConfig,
+    committee_member: &AccountInfo,
+    change: &CommitteeChangeData,
+) -> Result<()> {
+    let (address, _) =
+        Pubkey::find_program_address(&[COMMITTEE_MEMBER_SEED, change.member.as_ref()], &crate::ID);
+    require!(
+        committee_member.key() == address,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    let is_member = governance_config.is_legacy_committee_member(&change.member)
+        || CommitteeMember::try_load(committee_member)?.is_some();
+    match change.action {
+        CommitteeChangeAction::Add => require!(!is_member, GovernanceError::MemberAlreadyExists),
+        CommitteeChangeAction::Remove => require!(is_member, GovernanceError::MemberNotFound),
+    }
+    Ok(())
+}
+
+/// Committee tokens a member brings to a seat, which must cover member_stake_requirement
+/// Without a requirement the stake account may be omitted and counts as zero
+fn seat_stake_amount(
+    governance_config: &GovernanceConfig,
+    member_stake: Option<&AccountInfo>,
+    member: &Pubkey,
+) -> Result<u64> {
+    let staked_amount = match member_stake {
+        Some(member_stake) => {
+            require_member_stake_address(member_stake, member)?;
+            MemberStake::try_load(member_stake)?.map_or(0, |stake| stake.amount)
+        }
+        None => 0,
+    };
+    require!(
+        staked_amount >= governance_config.member_stake_requirement,
+        GovernanceError::MemberStakeRequired
+    );
+    Ok(staked_amount)
+}
+
+/// Check that an account is the member's MemberStake PDA
+fn require_member_stake_address(member_stake: &AccountInfo, member: &Pubkey) -> Result<()> {
+    let (address, _) =
+        Pubkey::find_program_address(&[MEMBER_STAKE_SEED, member.as_ref()], &crate::ID);
+    require!(
+        member_stake.key() == address,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    Ok(())
+}
+
+/// Stake committee tokens toward a committee seat
+#[derive(Accounts)]
+pub struct StakeForMembership<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Member stake record
+    #[account(
+        init,
+        payer = member,
+        space = 8 + MemberStake::INIT_SPACE,
+        seeds = [MEMBER_STAKE_SEED, member.key().as_ref()],
+        bump
+    )]
+    pub member_stake: Account<'info, MemberStake>,
+
+    /// Token account holding the stake
+    #[account(
+        init,
+        payer = member,
+        seeds = [MEMBER_STAKE_VAULT_SEED, member.key().as_ref()],
+        bump,
+        token::mint = committee_token_mint,
+        token::authority = governance_authority,
+        token::token_program = committee_token_program
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Member's committee token account the stake is taken from
+    #[account(
+        mut,
+        constraint = member_token_account.owner == member.key() @ GovernanceError::InvalidTokenAccount,
+        constraint = member_token_account.mint == committee_token_mint.key() @ GovernanceError::InvalidTokenMint
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance system authority (owner of the stake vault)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Staking member (pays the account rent)
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when a member stakes for a committee seat
+#[event]
+pub struct MemberStaked {
+    /// Staking member
+    pub member: Pubkey,
+    /// Committee tokens staked
+    pub amount: u64,
+    /// Earliest withdrawal time
+    pub unlocks_at: i64,
+    /// Stake timestamp
+    pub timestamp: i64,
+}
+
+/// Stake for membership handler
+/// Locks exactly member_stake_requirement tokens; a member staked under a lower requirement
+/// must withdraw and stake again before being seated
+pub fn stake_for_membership(ctx: Context<StakeForMembership>) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let amount = governance_config.member_stake_requirement;
+    require!(amount > 0, GovernanceError::MemberStakeNotRequired);
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.committee_token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.member_token_account.to_account_info(),
+            mint: ctx.accounts.committee_token_mint.to_account_info(),
+            to: ctx.accounts.stake_vault.to_account_info(),
+            authority: ctx.accounts.member.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(
+        transfer_ctx,
+        amount,
+        ctx.accounts.committee_token_mint.decimals,
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let member_stake = &mut ctx.accounts.member_stake;
+    member_stake.member = ctx.accounts.member.key();
+    member_stake.amount = amount;
+    member_stake.staked_at = timestamp;
+    member_stake.lock_until_cooldown_ends(timestamp, governance_config.member_stake_cooldown)?;
+    member_stake.bump = ctx.bumps.member_stake;
+
+    emit!(MemberStaked {
+        member: member_stake.member,
+        amount,
+        unlocks_at: member_stake.unlocks_at,
+        timestamp,
+    });
+
+    msg!("Member {} staked {}", member_stake.member, amount);
+    Ok(())
+}
+
+/// Withdraw a member stake once the member holds no seat
+#[derive(Accounts)]
+pub struct WithdrawMemberStake<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Member stake record (closed to the member)
+    #[account(
+        mut,
+        close = member,
+        seeds = [MEMBER_STAKE_SEED, member.key().as_ref()],
+        bump = member_stake.bump
+    )]
+    pub member_stake: Account<'info, MemberStake>,
+
+    /// Member's committee seat, which must not exist
+    /// CHECK: Only checked for emptiness
+    #[account(
+        seeds = [COMMITTEE_MEMBER_SEED, member.key().as_ref()],
+        bump
+    )]
+    pub committee_member: UncheckedAccount<'info>,
+
+    /// Token account holding the stake (closed to the member)
+    #[account(
+        mut,
+        seeds = [MEMBER_STAKE_VAULT_SEED, member.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Member's committee token account receiving the stake
+    #[account(
+        mut,
+        constraint = member_token_account.mint == committee_token_mint.key() @ GovernanceError::InvalidTokenMint
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance system authority (for signing the withdrawal)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Staking member
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Event emitted when a member stake is withdrawn
+#[event]
+pub struct MemberStakeWithdrawn {
+    /// Staking member
+    pub member: Pubkey,
+    /// Committee tokens returned
+    pub amount: u64,
+    /// Withdrawal timestamp
+    pub timestamp: i64,
+}
+
+/// Withdraw member stake handler
+/// Refused while the member is seated or before the cooldown from staking or removal ends
+pub fn withdraw_member_stake(ctx: Context<WithdrawMemberStake>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    require!(
+        ctx.accounts.committee_member.data_is_empty()
+            && !ctx
+                .accounts
+                .governance_config
+                .is_legacy_committee_member(&member),
+        GovernanceError::MemberStakeLocked
+    );
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.member_stake.is_unlocked(timestamp),
+        GovernanceError::MemberStakeLocked
+    );
+
+    let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[ctx.bumps.governance_authority]];
+    let signer_seeds = &[&authority_seeds[..]];
+    let amount = ctx.accounts.stake_vault.amount;
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.committee_token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.stake_vault.to_account_info(),
+            mint: ctx.accounts.committee_token_mint.to_account_info(),
+            to: ctx.accounts.member_token_account.to_account_info(),
+            authority: ctx.accounts.governance_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(
+        transfer_ctx,
+        amount,
+        ctx.accounts.committee_token_mint.decimals,
+    )?;
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.committee_token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.stake_vault.to_account_info(),
+            destination: ctx.accounts.member.to_account_info(),
+            authority: ctx.accounts.governance_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::close_account(close_ctx)?;
+
+    emit!(MemberStakeWithdrawn {
+        member,
+        amount,
+        timestamp,
+    });
+
+    msg!("Member {} withdrew stake of {}", member, amount);
+    Ok(())
+}
+
+/// Lock committee tokens for a voting power multiplier
+#[derive(Accounts)]
+pub struct LockTokens<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Lockup record
+    #[account(
+        init,
+        payer = member,
+        space = 8 + Lockup::INIT_SPACE,
+        seeds = [LOCKUP_SEED, member.key().as_ref()],
+        bump
+    )]
+    pub lockup: Account<'info, Lockup>,
+
+    /// Token account holding the locked tokens
+    #[account(
+        init,
+        payer = member,
+        seeds = [LOCKUP_VAULT_SEED, member.key().as_ref()],
+        bump,
+        token::mint = committee_token_mint,
+        token::authority = governance_authority,
+        token::token_program = committee_token_program
+    )]
+    pub lockup_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Member's committee token account the tokens are taken from
+    #[account(
+        mut,
+        constraint = member_token_account.owner == member.key() @ GovernanceError::InvalidTokenAccount,
+        constraint = member_token_account.mint == committee_token_mint.key() @ GovernanceError::InvalidTokenMint
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance system authority (owner of the lockup vault)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Locking member (pays the account rent)
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when a member locks committee tokens
+#[event]
+pub struct TokensLocked {
+    /// Locking member
+    pub member: Pubkey,
+    /// Committee tokens locked
+    pub amount: u64,
+    /// Earliest withdrawal time
+    pub unlock_time: i64,
+    /// Lock timestamp
+    pub timestamp: i64,
+}
+
+/// Lock tokens handler
+/// The lockup counts toward the member's voting power on proposals created after it, in
+/// place of the token account balance
+pub fn lock_tokens(ctx: Context<LockTokens>, amount: u64, lock_duration: i64) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    require!(
+        governance_config.lockups_enabled(),
+        GovernanceError::LockupsDisabled
+    );
+    require!(
+        lock_duration > 0 && lock_duration <= governance_config.max_lock_duration,
+        GovernanceError::InvalidLockDuration
+    );
+    require!(amount > 0, GovernanceError::InvalidLockAmount);
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.committee_token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.member_token_account.to_account_info(),
+            mint: ctx.accounts.committee_token_mint.to_account_info(),
+            to: ctx.accounts.lockup_vault.to_account_info(),
+            authority: ctx.accounts.member.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(
+        transfer_ctx,
+        amount,
+        ctx.accounts.committee_token_mint.decimals,
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let lockup = &mut ctx.accounts.lockup;
+    lockup.member = ctx.accounts.member.key();
+    lockup.amount = amount;
+    lockup.locked_at = timestamp;
+    lockup.unlock_time = timestamp
+        .checked_add(lock_duration)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    lockup.bump = ctx.bumps.lockup;
+
+    emit!(TokensLocked {
+        member: lockup.member,
+        amount,
+        unlock_time: lockup.unlock_time,
+        timestamp,
+    });
+
+    msg!(
+        "Member {} locked {} until {}",
+        lockup.member,
+        amount,
+        lockup.unlock_time
+    );
+    Ok(())
+}
+
+/// Withdraw locked tokens once the lock has run out
+#[derive(Accounts)]
+pub struct WithdrawLockedTokens<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Lockup record (closed to the member)
+    #[account(
+        mut,
+        close = member,
+        seeds = [LOCKUP_SEED, member.key().as_ref()],
+        bump = lockup.bump
+    )]
+    pub lockup: Account<'info, Lockup>,
+
+    /// Token account holding the locked tokens (closed to the member)
+    #[account(
+        mut,
+        seeds = [LOCKUP_VAULT_SEED, member.key().as_ref()],
+        bump
+    )]
+    pub lockup_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Member's committee token account receiving the tokens
+    #[account(
+        mut,
+        constraint = member_token_account.mint == committee_token_mint.key() @ GovernanceError::InvalidTokenMint
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance system authority (for signing the withdrawal)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Locking member
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Event emitted when locked tokens are withdrawn
+#[event]
+pub struct LockedTokensWithdrawn {
+    /// Locking member
+    pub member: Pubkey,
+    /// Committee tokens returned
+    pub amount: u64,
+    /// Withdrawal timestamp
+    pub timestamp: i64,
+}
+
+/// Withdraw locked tokens handler
+/// Refused before unlock_time
+pub fn withdraw_locked_tokens(ctx: Context<WithdrawLockedTokens>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.lockup.is_unlocked(timestamp),
+        GovernanceError::TokensStillLocked
+    );
+
+    let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[ctx.bumps.governance_authority]];
+    let signer_seeds = &[&authority_seeds[..]];
+    let amount = ctx.accounts.lockup_vault.amount;
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.committee_token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.lockup_vault.to_account_info(),
+            mint: ctx.accounts.committee_token_mint.to_account_info(),
+            to: ctx.accounts.member_token_account.to_account_info(),
+            authority: ctx.accounts.governance_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(
+        transfer_ctx,
+        amount,
+        ctx.accounts.committee_token_mint.decimals,
+    )?;
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.committee_token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.lockup_vault.to_account_info(),
+            destination: ctx.accounts.member.to_account_info(),
+            authority: ctx.accounts.governance_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::close_account(close_ctx)?;
+
+    let member = ctx.accounts.member.key();
+    emit!(LockedTokensWithdrawn {
+        member,
+        amount,
+        timestamp,
+    });
+
+    msg!("Member {} withdrew {} locked tokens", member, amount);
+    Ok(())
+}
+
+/// Migrate a legacy committee slot
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct MigrateCommitteeMember<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Committee member account to create
+    /// CHECK: Created in the handler
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, member.as_ref()],
+        bump
+    )]
+    pub committee_member: UncheckedAccount<'info>,
+
+    /// Only administrator can migrate members (pays the account rent)
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Migrate committee member handler
+/// Moves a member out of the legacy config array into its own account, keeping its vote count
+pub fn migrate_committee_member(
+    ctx: Context<MigrateCommitteeMember>,
+    member: Pubkey,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::MigrateCommitteeMember(member),
+    )?;
+
+    let vote_count = ctx
+        .accounts
+        .governance_config
+        .take_legacy_committee_member(&member)?;
+    create_committee_member_account(
+        &ctx.accounts.committee_member,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        member,
+        ctx.bumps.committee_member,
+        vote_count,
+        0,
+    )?;
+
+    msg!(
+        "Committee member migrated: {} ({} votes)",
+        member,
+        vote_count
+    );
+    Ok(())
+}
+
+/// Update committee member metadata
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct UpdateCommitteeMember<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, member.as_ref()],
+        bump = committee_member.bump
+    )]
+    pub committee_member: Account<'info, CommitteeMember>,
+
+    /// Only administrator can update members
+    #[account(
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Event emitted when a committee member is suspended
+#[event]
+pub struct CommitteeMemberSuspended {
+    /// Suspended member
+    pub member: Pubkey,
+    /// Suspension timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a suspended committee member is reinstated
+#[event]
+pub struct CommitteeMemberReinstated {
+    /// Reinstated member
+    pub member: Pubkey,
+    /// Reinstatement timestamp
+    pub timestamp: i64,
+}
+
+/// Set committee member display name handler
+pub fn set_member_display_name(
+    ctx: Context<UpdateCommitteeMember>,
+    member: Pubkey,
+    display_name: String,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetMemberDisplayName {
+            member,
+            display_name: display_name.clone(),
+        },
+    )?;
+
+    validate_member_display_name(&display_name)?;
+    ctx.accounts.committee_member.display_name = display_name;
+
+    msg!("Committee member display name updated: {}", member);
+    Ok(())
+}
+
+/// Suspend or reinstate committee member handler
+/// A suspended member cannot vote and holds no voting power at finalization,
+/// but votes it cast before the suspension are still tallied
+pub fn set_member_active(
+    ctx: Context<UpdateCommitteeMember>,
+    member: Pubkey,
+    active: bool,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetMemberActive { member, active },
+    )?;
+
+    let committee_member = &mut ctx.accounts.committee_member;
+    if active {
+        require!(
+            !committee_member.active,
+            GovernanceError::MemberAlreadyActive
+        );
+    } else {
+        require!(committee_member.active, GovernanceError::MemberSuspended);
+    }
+
+    // Also overrides an inactivity suspension; an admin suspension is never self-liftable
+    let timestamp = Clock::get()?.unix_timestamp;
+    let kind = if active {
+        committee_member.reinstate(timestamp);
+        emit!(CommitteeMemberReinstated { member, timestamp });
+        msg!("Committee member reinstated: {}", member);
+        CommitteeChangeKind::Reactivated
+    } else {
+        committee_member.active = false;
+        committee_member.suspended_for_inactivity = false;
+        emit!(CommitteeMemberSuspended { member, timestamp });
+        msg!("Committee member suspended: {}", member);
+        CommitteeChangeKind::Suspended
+    };
+    record_committee_change(&mut ctx.accounts.governance_config, kind, member, timestamp)
+}
+
+/// Event emitted when a committee member's alternate changes
+#[event]
+pub struct CommitteeMemberAlternateUpdated {
+    /// Primary committee member
+    pub member: Pubkey,
+    /// Previous alternate
+    pub previous_alternate: Option<Pubkey>,
+    /// New alternate
+    pub alternate: Option<Pubkey>,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Set committee member alternate handler
+/// None clears the designation; an alternate seat cannot designate a further alternate
+pub fn set_member_alternate(
+    ctx: Context<UpdateCommitteeMember>,
+    member: Pubkey,
+    alternate: Option<Pubkey>,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetMemberAlternate { member, alternate },
+    )?;
+
+    let committee_member = &mut ctx.accounts.committee_member;
+    if let Some(alternate) = alternate {
+        require!(
+            alternate != member
+                && alternate != Pubkey::default()
+                && committee_member.substitutes_for.is_none(),
+            GovernanceError::InvalidAlternate
+        );
+    }
+    let previous_alternate = committee_member.alternate;
+    committee_member.alternate = alternate;
+
+    emit!(CommitteeMemberAlternateUpdated {
+        member,
+        previous_alternate,
+        alternate,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Committee member {} alternate set to {:?}",
+        member,
+        alternate
+    );
+    Ok(())
+}
+
+/// Seat a suspended member's alternate for the rest of the member's term
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct ActivateAlternate<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Suspended primary's seat (closed to the alternate)
+    #[account(
+        mut,
+        close = alternate,
+        seeds = [COMMITTEE_MEMBER_SEED, member.as_ref()],
+        bump = primary_member.bump
+    )]
+    pub primary_member: Account<'info, CommitteeMember>,
+
+    /// Alternate's committee member account to create
+    /// CHECK: Created in the handler; must not already hold a CommitteeMember
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, alternate.key().as_ref()],
+        bump
+    )]
+    pub alternate_member: UncheckedAccount<'info>,
+
+    /// Primary's stake, locked for the cooldown from the takeover (empty if never staked)
+    /// CHECK: Deserialized as MemberStake when owned by this program
+    #[account(
+        mut,
+        seeds = [MEMBER_STAKE_SEED, member.as_ref()],
+        bump
+    )]
+    pub primary_stake: UncheckedAccount<'info>,
+
+    /// Alternate's stake (required while member_stake_requirement is set)
+    /// CHECK: Address checked and deserialized as MemberStake in the handler
+    pub alternate_stake: Option<UncheckedAccount<'info>>,
+
+    /// Designated alternate taking the seat (pays the account rent)
+    #[account(mut)]
+    pub alternate: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when an alternate takes over a suspended member's seat
+#[event]
+pub struct AlternateActivated {
+    /// Replaced primary member
+    pub member: Pubkey,
+    /// Seated alternate
+    pub alternate: Pubkey,
+    /// Term end carried over from the primary
+    pub term_end: i64,
+    /// Activation timestamp
+    pub timestamp: i64,
+}
+
+/// Activate alternate handler
+/// Moves the seat without changing the committee size; proposals created before the
+/// takeover keep their original committee (see CommitteeMember::is_substitute_after)
+pub fn activate_alternate(ctx: Context<ActivateAlternate>, member: Pubkey) -> Result<()> {
+    let alternate = ctx.accounts.alternate.key();
+    let primary_member = &ctx.accounts.primary_member;
+    require!(
+        primary_member.alternate == Some(alternate),
+        GovernanceError::Unauthorized
+    );
+    require!(!primary_member.active, GovernanceError::PrimaryMemberActive);
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        !primary_member.is_term_expired(timestamp),
+        GovernanceError::MemberTermExpired
+    );
+    let term_end = primary_member.term_end;
+
+    let (address, bump) =
+        Pubkey::find_program_address(&[COMMITTEE_MEMBER_SEED, alternate.as_ref()], &crate::ID);
+    require!(
+        ctx.accounts.alternate_member.key() == address,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    require!(
+        CommitteeMember::try_load(&ctx.accounts.alternate_member)?.is_none(),
+        GovernanceError::MemberAlreadyExists
+    );
+    let staked_amount = seat_stake_amount(
+        &ctx.accounts.governance_config,
+        ctx.accounts.alternate_stake.as_deref(),
+        &alternate,
+    )?;
+    create_committee_member_account(
+        &ctx.accounts.alternate_member,
+        &ctx.accounts.alternate,
+        &ctx.accounts.system_program,
+        alternate,
+        bump,
+        0,
+        staked_amount,
+    )?;
+    let mut alternate_member = CommitteeMember::try_load(&ctx.accounts.alternate_member)?
+        .ok_or(GovernanceError::InvalidAccountData)?;
+    alternate_member.term_end = term_end;
+    alternate_member.substitutes_for = Some(member);
+    alternate_member.store(&ctx.accounts.alternate_member)?;
+
+    if let Some(mut stake) = MemberStake::try_load(&ctx.accounts.primary_stake)? {
+        stake.lock_until_cooldown_ends(
+            timestamp,
+            ctx.accounts.governance_config.member_stake_cooldown,
+        )?;
+        stake.store(&ctx.accounts.primary_stake)?;
+    }
+
+    emit!(AlternateActivated {
+        member,
+        alternate,
+        term_end,
+        timestamp,
+    });
+    record_committee_change(
+        &mut ctx.accounts.governance_config,
+        CommitteeChangeKind::AlternateActivated,
+        alternate,
+        timestamp,
+    )?;
+
+    msg!("Alternate {} took over the seat of {}", alternate, member);
+    Ok(())
+}
+
+/// Flag a committee member who keeps missing finalized proposals
+/// Remaining accounts: a (Proposal, member's Vote PDA) pair per missed proposal
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct FlagInactiveMember<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, member.as_ref()],
+        bump = committee_member.bump
+    )]
+    pub committee_member: Account<'info, CommitteeMember>,
+
+    /// Member's associated token account for the committee token mint
+    /// CHECK: Address checked in the handler; a closed account counts as zero voting power
+    pub member_token_account: UncheckedAccount<'info>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Event emitted when a committee member is suspended for inactivity
+#[event]
+pub struct CommitteeMemberFlaggedInactive {
+    /// Suspended member
+    pub member: Pubkey,
+    /// Missed finalized proposals shown
+    pub missed_proposals: u32,
+    /// Voting power removed from the total
+    pub voting_power: u64,
+    /// Suspension timestamp
+    pub timestamp: i64,
+}
+
+/// Flag inactive member handler
+/// Permissionless: every proposal shown must be finalized, opened after the member's last
+/// activity, and lack the member's Vote account; the member's balance leaves the total
+/// voting power so later proposal snapshots exclude it
+pub fn flag_inactive_member(ctx: Context<FlagInactiveMember>, member: Pubkey) -> Result<()> {
+    let committee_member = &ctx.accounts.committee_member;
+    require!(committee_member.active, GovernanceError::MemberSuspended);
+    require!(
+        ctx.remaining_accounts.len().is_multiple_of(2),
+        GovernanceError::InvalidInactivityEvidence
+    );
+
+    let mut missed_proposals: Vec<u64> = Vec::with_capacity(ctx.remaining_accounts.len() / 2);
+    for pair in ctx.remaining_accounts.chunks(2) {
+        require!(
+            pair[0].owner == &crate::ID,
+            GovernanceError::InvalidInactivityEvidence
+        );
+        let proposal_id = {
+            let data = pair[0].try_borrow_data()?;
+            let proposal = Proposal::from_account_data(&data)
+                .map_err(|_| GovernanceError::InvalidInactivityEvidence)?;
+            require!(
+                proposal.status()? != ProposalStatus::Pending
+                    && proposal.created_at > committee_member.last_active_at
+                    && !missed_proposals.contains(&proposal.id),
+                GovernanceError::InvalidInactivityEvidence
+            );
+            proposal.id
+        };
+
+        let (vote_address, _) = Pubkey::find_program_address(
+            &[
+                VOTE_SEED,
+                proposal_id.to_le_bytes().as_ref(),
+                member.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require!(
+            pair[1].key() == vote_address && pair[1].data_is_empty(),
+            GovernanceError::InvalidInactivityEvidence
+        );
+        missed_proposals.push(proposal_id);
+    }
+    require!(
+        missed_proposals.len() >= ctx.accounts.governance_config.inactivity_threshold as usize,
+        GovernanceError::InsufficientMissedProposals
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let voting_power = member_snapshot_voting_power(
+        committee_member,
+        &ctx.accounts.member_token_account,
+        &ctx.accounts.committee_token_mint,
+        &ctx.accounts.committee_token_program.key(),
+        timestamp,
+        ctx.accounts.governance_config.stake_based_voting_power,
+    )?;
+    let governance_config = &mut ctx.accounts.governance_config;
+    governance_config.total_voting_power = governance_config
+        .total_voting_power
+        .saturating_sub(voting_power);
+    ctx.accounts.committee_member.suspend_for_inactivity();
+    record_committee_change(
+        governance_config,
+        CommitteeChangeKind::Suspended,
+        member,
+        timestamp,
+    )?;
+
+    emit!(CommitteeMemberFlaggedInactive {
+        member,
+        missed_proposals: missed_proposals.len() as u32,
+        voting_power,
+        timestamp,
+    });
+
+    msg!("Committee member flagged inactive: {}", member);
+    Ok(())
+}
+
+/// Lift an inactivity suspension
+#[derive(Accounts)]
+pub struct ReactivateCommitteeMember<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, member.key().as_ref()],
+        bump = committee_member.bump
+    )]
+    pub committee_member: Account<'info, CommitteeMember>,
+
+    /// Suspended member reinstating themselves
+    pub member: Signer<'info>,
+
+    /// Member's associated token account for the committee token mint
+    /// CHECK: Address checked in the handler; a closed account counts as zero voting power
+    pub member_token_account: UncheckedAccount<'info>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Reactivate committee member handler
+/// Only lifts suspensions from flag_inactive_member; the member's balance rejoins the total
+pub fn reactivate_committee_member(ctx: Context<ReactivateCommitteeMember>) -> Result<()> {
+    let committee_member = &mut ctx.accounts.committee_member;
+    require!(
+        !committee_member.active && committee_member.suspended_for_inactivity,
+        GovernanceError::MemberNotSuspendedForInactivity
+    );
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        !committee_member.is_term_expired(timestamp),
+        GovernanceError::MemberTermExpired
+    );
+    committee_member.reinstate(timestamp);
+
+    let voting_power = member_snapshot_voting_power(
+        committee_member,
+        &ctx.accounts.member_token_account,
+        &ctx.accounts.committee_token_mint,
+        &ctx.accounts.committee_token_program.key(),
+        timestamp,
+        ctx.accounts.governance_config.stake_based_voting_power,
+    )?;
+    let governance_config = &mut ctx.accounts.governance_config;
+    governance_config.total_voting_power = governance_config
+        .total_voting_power
+        .checked_add(voting_power)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+
+    let member = committee_member.member;
+    record_committee_change(
+        governance_config,
+        CommitteeChangeKind::Reactivated,
+        member,
+        timestamp,
+    )?;
+    emit!(CommitteeMemberReinstated { member, timestamp });
+    msg!("Committee member reactivated: {}", member);
+    Ok(())
+}
+
+/// Voting power a member contributes to the total, read from their canonical token account
+fn member_snapshot_voting_power(
+    committee_member: &CommitteeMember,
+    member_token_account: &AccountInfo,
+    committee_token_mint: &InterfaceAccount<Mint>,
+    committee_token_program: &Pubkey,
+    as_of: i64,
+    stake_based: bool,
+) -> Result<u64> {
+    require!(
+        member_token_account.key()
+            == get_associated_token_address_with_program_id(
+                &committee_member.member,
+                &committee_token_mint.key(),
+                committee_token_program
+            ),
+        GovernanceError::InvalidTokenAccount
+    );
+    committee_member_voting_power(
+        committee_member,
+        member_token_account,
+        &committee_token_mint.key(),
+        committee_token_mint.decimals,
+        as_of,
+        stake_based,
+    )
+}
+
+/// Event emitted when a committee member's term end changes
+#[event]
+pub struct CommitteeMemberTermUpdated {
+    /// Committee member
+    pub member: Pubkey,
+    /// Previous term end
+    pub previous_term_end: i64,
+    /// New term end
+    pub term_end: i64,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Set committee member term end handler
+pub fn set_member_term(
+    ctx: Context<UpdateCommitteeMember>,
+    member: Pubkey,
+    term_end: i64,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetMemberTerm { member, term_end },
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(term_end > timestamp, GovernanceError::InvalidMemberTerm);
+
+    let committee_member = &mut ctx.accounts.committee_member;
+    let previous_term_end = committee_member.term_end;
+    committee_member.term_end = term_end;
+
+    emit!(CommitteeMemberTermUpdated {
+        member,
+        previous_term_end,
+        term_end,
+        timestamp,
+    });
+
+    msg!("Committee member {} term ends at {}", member, term_end);
+    Ok(())
+}
+
+/// Renew committee member term handler
+/// Extends the term by MEMBER_TERM_SECONDS; an expired term restarts from now
+pub fn renew_member_term(ctx: Context<UpdateCommitteeMember>, member: Pubkey) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::RenewMemberTerm(member),
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let committee_member = &mut ctx.accounts.committee_member;
+    let previous_term_end = committee_member.term_end;
+    committee_member.renew_term(timestamp)?;
+
+    emit!(CommitteeMemberTermUpdated {
+        member,
+        previous_term_end,
+        term_end: committee_member.term_end,
+        timestamp,
+    });
+
+    msg!(
+        "Committee member {} term renewed until {}",
+        member,
+        committee_member.term_end
+    );
+    Ok(())
+}
+
+/// Create a committee member's voter stats account (permissionless)
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct InitializeVoterStats<'info> {
+    #[account(
+        seeds = [COMMITTEE_MEMBER_SEED, member.as_ref()],
+        bump = committee_member.bump
+    )]
+    pub committee_member: Account<'info, CommitteeMember>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VoterStats::INIT_SPACE,
+        seeds = [VOTER_STATS_SEED, member.as_ref()],
+        bump
+    )]
+    pub voter_stats: Account<'info, VoterStats>,
+
+    /// Payer (anyone, typically the member)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize voter stats handler
+/// Participation is recorded from the next finalized proposal on
+pub fn initialize_voter_stats(ctx: Context<InitializeVoterStats>, member: Pubkey) -> Result<()> {
+    let voter_stats = &mut ctx.accounts.voter_stats;
+    voter_stats.member = member;
+    voter_stats.recent_votes = 0;
+    voter_stats.recorded_proposals = 0;
+    voter_stats.last_proposal_id = 0;
+    voter_stats.bump = ctx.bumps.voter_stats;
+
+    msg!("Voter stats initialized for: {}", member);
+    Ok(())
+}
+
+/// Close governance configuration
+#[derive(Accounts)]
+pub struct CloseGovernanceConfig<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can close configuration
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Close governance configuration handler
+/// Refused while proposals are pending or any vault still owes refunds or holds treasury
+/// funds, since no PDA-signed transfer out of the vaults is possible without the config
+pub fn close_governance_config(ctx: Context<CloseGovernanceConfig>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CloseGovernanceConfig,
+    )?;
+
+    let governance_config = &ctx.accounts.governance_config;
+    require!(
+        governance_config.pending_proposal_count == 0,
+        GovernanceError::PendingProposalsExist
+    );
+    require!(
+        !governance_config.has_outstanding_funds(),
+        GovernanceError::OutstandingFunds
+    );
+
+    msg!("Governance configuration closed successfully");
+    Ok(())
+}
+
+/// Propose or cancel an authority transfer
+#[derive(Accounts)]
+pub struct ManageAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only the config admin can propose or cancel a transfer of its role
+    #[account(
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Event emitted when an authority transfer is proposed
+#[event]
+pub struct AuthorityTransferProposed {
+    /// Current administrator
+    pub authority: Pubkey,
+    /// Proposed administrator
+    pub pending_authority: Pubkey,
+    /// Proposal timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pending authority transfer is accepted
+#[event]
+pub struct AuthorityTransferAccepted {
+    /// Previous administrator
+    pub previous_authority: Pubkey,
+    /// New administrator
+    pub new_authority: Pubkey,
+    /// Acceptance timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pending authority transfer is cancelled
+#[event]
+pub struct AuthorityTransferCancelled {
+    /// Current administrator
+    pub authority: Pubkey,
+    /// Proposed administrator that was withdrawn
+    pub cancelled_authority: Pubkey,
+    /// Cancellation timestamp
+    pub timestamp: i64,
+}
+
+/// Propose authority transfer handler
+/// Hands over the config admin role: the current config admin keeps full access until the
+/// new one accepts; proposing again replaces any earlier pending authority
+pub fn propose_authority_transfer(
+    ctx: Context<ManageAuthorityTransfer>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::ProposeAuthorityTransfer(new_authority),
+    )?;
+
+    let governance_config = &mut ctx.accounts.governance_config;
+    require!(
+        new_authority != Pubkey::default() && new_authority != governance_config.config_admin,
+        GovernanceError::InvalidAuthority
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    governance_config.pending_authority = Some(new_authority);
+    governance_config.updated_at = timestamp;
+
+    emit!(AuthorityTransferProposed {
+        authority: governance_config.config_admin,
+        pending_authority: new_authority,
+        timestamp,
+    });
+
+    msg!("Authority transfer proposed to {}", new_authority);
+    Ok(())
+}
+
+/// Cancel authority transfer handler
+pub fn cancel_authority_transfer(ctx: Context<ManageAuthorityTransfer>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CancelAuthorityTransfer,
+    )?;
+
+    let governance_config = &mut ctx.accounts.governance_config;
+    let cancelled_authority = governance_config
+        .pending_authority
+        .take()
+        .ok_or(GovernanceError::NoPendingAuthorityTransfer)?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    governance_config.updated_at = timestamp;
+
+    emit!(AuthorityTransferCancelled {
+        authority: governance_config.config_admin,
+        cancelled_authority,
+        timestamp,
+    });
+
+    msg!("Authority transfer to {} cancelled", cancelled_authority);
+    Ok(())
+}
+
+/// Accept authority transfer
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Rule registry (its recorded authority follows the handover when it exists)
+    /// Omitting it leaves the registry out of sync until sync_rule_registry_authority is called
+    #[account(
+        mut,
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
+        bump = rule_registry.bump
+    )]
+    pub rule_registry: Option<Account<'info, RuleRegistry>>,
+
+    /// Proposed administrator accepting the transfer
+    pub pending_authority: Signer<'info>,
+}
+
+/// Accept authority transfer handler
+/// The governance authority follows only when the outgoing config admin also held it;
+/// the rule and treasury roles are never touched
+pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+    let governance_config = &mut ctx.accounts.governance_config;
+    let pending_authority = governance_config
+        .pending_authority
+        .ok_or(GovernanceError::NoPendingAuthorityTransfer)?;
+    require!(
+        ctx.accounts.pending_authority.key() == pending_authority,
+        GovernanceError::Unauthorized
+    );
+
+    let previous_authority = governance_config.config_admin;
+    let timestamp = Clock::get()?.unix_timestamp;
+    let authority_follows = governance_config.authority == previous_authority;
+    governance_config.config_admin = pending_authority;
+    if authority_follows {
+        governance_config.authority = pending_authority;
+    }
+    governance_config.pending_authority = None;
+    governance_config.updated_at = timestamp;
+
+    if let Some(rule_registry) = ctx.accounts.rule_registry.as_mut() {
+        rule_registry.sync_authority(governance_config.authority, timestamp);
+    }
+
+    emit!(AuthorityTransferAccepted {
+        previous_authority,
+        new_authority: pending_authority,
+        timestamp,
+    });
+
+    msg!(
+        "Config admin transferred from {} to {}",
+        previous_authority,
+        pending_authority
+    );
+    Ok(())
+}
+
+/// Assign an admin role
+#[derive(Accounts)]
+pub struct SetAdminRole<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only the config admin can assign roles (admin set approval in multisig mode)
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Event emitted when an admin role changes hands
+#[event]
+pub struct AdminRoleUpdated {
+    /// Reassigned role
+    pub role: AdminRole,
+    /// Previous role holder
+    pub previous_admin: Pubkey,
+    /// New role holder
+    pub new_admin: Pubkey,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Set admin role handler
+pub fn set_admin_role(
+    ctx: Context<SetAdminRole>,
+    role: AdminRole,
+    new_admin: Pubkey,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetAdminRole { role, new_admin },
+    )?;
+
+    if !ctx.accounts.governance_config.admin_multisig_enabled {
+        require_admin_authority!(
+            ctx.accounts.authority,
+            ctx.accounts.governance_config,
+            AdminRole::Config
+        );
+    }
+    require!(
+        new_admin != Pubkey::default(),
+        GovernanceError::InvalidAuthority
+    );
+
+    let governance_config = &mut ctx.accounts.governance_config;
+    let timestamp = Clock::get()?.unix_timestamp;
+    let previous_admin = governance_config.set_admin(role, new_admin);
+    governance_config.updated_at = timestamp;
+
+    emit!(AdminRoleUpdated {
+        role,
+        previous_admin,
+        new_admin,
+        timestamp,
+    });
+
+    msg!("{:?} admin set to {}", role, new_admin);
     Ok(())
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Initialize merchant slash history
+#[derive(Accounts)]
+#[instruction(merchant: Pubkey)]
+pub struct InitializeMerchantSlashHistory<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MerchantSlashHistory::INIT_SPACE,
+        seeds = [MERCHANT_SLASH_HISTORY_SEED, merchant.as_ref()],
+        bump
+    )]
+    pub merchant_slash_history: Account<'info, MerchantSlashHistory>,
+
+    /// Payer (anyone, typically the first slash proposer)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize merchant slash history handler
+pub fn initialize_merchant_slash_history(
+    ctx: Context<InitializeMerchantSlashHistory>,
+    merchant: Pubkey,
+) -> Result<()> {
+    let history = &mut ctx.accounts.merchant_slash_history;
+
+    history.merchant = merchant;
+    history.passed_count = 0;
+    history.rejected_count = 0;
+    history.vetoed_count = 0;
+    history.consecutive_failures = 0;
+    history.updated_at = Clock::get()?.unix_timestamp;
+    history.bump = ctx.bumps.merchant_slash_history;
+
+    msg!("Merchant slash history initialized for: {}", merchant);
+    Ok(())
+}
@@ -1,13 +1,37 @@
+pub mod adapter;
+pub mod admin;
+pub mod archive;
 pub mod common;
+pub mod cpi_caller;
+pub mod crank;
 pub mod deposit;
+pub mod heartbeat;
 pub mod initialize;
+pub mod merchant;
+pub mod policy;
 pub mod proposal;
+pub mod proposal_index;
 pub mod query;
 pub mod rules;
+pub mod signed_vote;
+pub mod stats;
+pub mod treasury;
 
+pub use adapter::*;
+pub use admin::*;
+pub use archive::*;
 pub use common::*;
+pub use cpi_caller::*;
+pub use crank::*;
 pub use deposit::*;
+pub use heartbeat::*;
 pub use initialize::*;
+pub use merchant::*;
+pub use policy::*;
 pub use proposal::*;
+pub use proposal_index::*;
 pub use query::*;
 pub use rules::*;
+pub use signed_vote::*;
+pub use stats::*;
+pub use treasury::*;
@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GovernanceError;
+use crate::instructions::admin::authorize_admin_call;
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Create proposer policy
+#[derive(Accounts)]
+pub struct CreateProposerPolicy<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProposerPolicy::INIT_SPACE,
+        seeds = [PROPOSER_POLICY_SEED],
+        bump
+    )]
+    pub proposer_policy: Account<'info, ProposerPolicy>,
+
+    /// Governance configuration account for permission verification
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can create proposer policy
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Create proposer policy handler
+pub fn create_proposer_policy(
+    ctx: Context<CreateProposerPolicy>,
+    allowlist_only: bool,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CreateProposerPolicy(allowlist_only),
+    )?;
+
+    let proposer_policy = &mut ctx.accounts.proposer_policy;
+
+    proposer_policy.authority = ctx.accounts.governance_config.authority;
+    proposer_policy.allowlist_only = allowlist_only;
+    proposer_policy.denied_proposers = Vec::new();
+    proposer_policy.allowed_proposers = Vec::new();
+    proposer_policy.updated_at = Clock::get()?.unix_timestamp;
+    proposer_policy.bump = ctx.bumps.proposer_policy;
+
+    msg!(
+        "Proposer policy created (allowlist only: {})",
+        allowlist_only
+    );
+    Ok(())
+}
+
+/// Update proposer policy
+#[derive(Accounts)]
+pub struct UpdateProposerPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSER_POLICY_SEED],
+        bump = proposer_policy.bump
+    )]
+    pub proposer_policy: Account<'info, ProposerPolicy>,
+
+    /// Governance configuration account for permission verification
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can update proposer policy
+    #[account(
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Ban or unban proposer handler
+pub fn set_proposer_ban(
+    ctx: Context<UpdateProposerPolicy>,
+    proposer: Pubkey,
+    banned: bool,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetProposerBan { proposer, banned },
+    )?;
+
+    let proposer_policy = &mut ctx.accounts.proposer_policy;
+
+    if banned {
+        proposer_policy.deny(proposer)?;
+        msg!("Proposer banned: {}", proposer);
+    } else {
+        proposer_policy.undeny(proposer)?;
+        msg!("Proposer unbanned: {}", proposer);
+    }
+    Ok(())
+}
+
+/// Add or remove proposer from allowlist handler
+pub fn set_proposer_allowed(
+    ctx: Context<UpdateProposerPolicy>,
+    proposer: Pubkey,
+    allowed: bool,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetProposerAllowed { proposer, allowed },
+    )?;
+
+    let proposer_policy = &mut ctx.accounts.proposer_policy;
+
+    if allowed {
+        proposer_policy.allow(proposer)?;
+        msg!("Proposer allowlisted: {}", proposer);
+    } else {
+        proposer_policy.disallow(proposer)?;
+        msg!("Proposer removed from allowlist: {}", proposer);
+    }
+    Ok(())
+}
+
+/// Toggle allowlist-only mode handler
+pub fn set_allowlist_only(ctx: Context<UpdateProposerPolicy>, enabled: bool) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::SetAllowlistOnly(enabled),
+    )?;
+
+    let proposer_policy = &mut ctx.accounts.proposer_policy;
+    proposer_policy.allowlist_only = enabled;
+    proposer_policy.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Proposer allowlist-only mode set to: {}", enabled);
+    Ok(())
+}
+
+/// Check proposer against the policy PDA, if one has been created
+pub fn check_proposer_policy(policy_account: &AccountInfo, proposer: &Pubkey) -> Result<()> {
+    if policy_account.data_is_empty() {
+        return Ok(());
+    }
+
+    require!(
+        policy_account.owner == &crate::ID,
+        GovernanceError::InvalidAccountOwner
+    );
+    let policy = ProposerPolicy::try_deserialize(&mut policy_account.data.borrow().as_ref())
+        .map_err(|_| GovernanceError::InvalidAccountData)?;
+    policy.check_proposer(proposer)
+}
+
+/// Reputation snapshot of a proposer, creating their ProposerState with the first proposal
+/// The address is fixed by the caller's seeds constraint
+pub(crate) fn proposer_reputation_snapshot<'info>(
+    proposer_state: &AccountInfo<'info>,
+    proposer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    bump: u8,
+) -> Result<ProposerReputation> {
+    if let Some(state) = ProposerState::try_load(proposer_state)? {
+        return Ok(state.reputation);
+    }
+
+    let proposer_key = proposer.key();
+    create_pda_account(
+        proposer_state,
+        proposer,
+        system_program,
+        8 + ProposerState::INIT_SPACE,
+        &[&[PROPOSER_STATE_SEED, proposer_key.as_ref(), &[bump]]],
+    )?;
+    ProposerState {
+        proposer: proposer_key,
+        reputation: ProposerReputation::default(),
+        bump,
+    }
+    .store(proposer_state)?;
+    Ok(ProposerReputation::default())
+}
+
+/// Count a finalized proposal in its proposer's record, checked to be at the proposer's PDA
+/// Proposers without a state account (all their proposals predate it) are skipped
+pub(crate) fn record_proposer_outcome(
+    proposer_state: &AccountInfo,
+    proposer: &Pubkey,
+    status: &ProposalStatus,
+) -> Result<()> {
+    let Some(mut state) = ProposerState::try_load(proposer_state)? else {
+        let (expected_address, _) =
+            Pubkey::find_program_address(&[PROPOSER_STATE_SEED, proposer.as_ref()], &crate::ID);
+        require!(
+            proposer_state.key() == expected_address,
+            GovernanceError::InvalidProposerState
+        );
+        return Ok(());
+    };
+    let expected_address = Pubkey::create_program_address(
+        &[PROPOSER_STATE_SEED, proposer.as_ref(), &[state.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| GovernanceError::InvalidProposerState)?;
+    require!(
+        proposer_state.key() == expected_address
+            && proposer_state.is_writable
+            && state.proposer == *proposer,
+        GovernanceError::InvalidProposerState
+    );
+
+    state.reputation.record(status);
+    state.store(proposer_state)
+}
@@ -1,27 +1,51 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{
+    self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 use crate::error::GovernanceError;
+use crate::events::*;
+use crate::instructions::adapter::invoke_execution_adapter;
+use crate::instructions::admin::authorize_admin_call;
 use crate::instructions::common::*;
+use crate::instructions::cpi_caller::require_allowed_cpi_caller;
+use crate::instructions::initialize::{
+    add_committee_member_account, record_committee_change, remove_committee_member_account,
+    require_committee_change_applicable, CommitteeMemberReinstated,
+};
+use crate::instructions::policy::{
+    check_proposer_policy, proposer_reputation_snapshot, record_proposer_outcome,
+};
+use crate::instructions::proposal_index::record_proposal_status;
+use crate::instructions::stats::record_governance_stats;
+use crate::instructions::treasury::{
+    emit_native_treasury_transfer, emit_treasury_transfer, TransferDirection,
+    TreasuryTransferReason,
+};
+use crate::logging::{test_msg, DepositMovement, DepositMovementKind, LogRecord, TallySummary};
 use crate::state::*;
 
 /// Create proposal
 #[derive(Accounts)]
-#[instruction(title: String, description: String)]
+#[instruction(proposal_id: u64)]
 pub struct CreateProposal<'info> {
     #[account(
         init,
         payer = proposer,
         space = 8 + Proposal::INIT_SPACE,
-        seeds = [PROPOSAL_SEED, (governance_config.proposal_counter + 1).to_le_bytes().as_ref()],
+        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
         bump
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub proposal: AccountLoader<'info, Proposal>,
 
     #[account(
         mut,
         seeds = [GOVERNANCE_CONFIG_SEED],
-        bump = governance_config.bump
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
@@ -29,243 +53,722 @@ pub struct CreateProposal<'info> {
     #[account(mut)]
     pub proposer: Signer<'info>,
 
-    /// Proposer's USDC token account (for deposit)
+    /// Proposer policy (may be uninitialized when no policy is configured)
+    /// CHECK: Address is fixed by seeds; contents are validated in check_proposer_policy
+    #[account(
+        seeds = [PROPOSER_POLICY_SEED],
+        bump
+    )]
+    pub proposer_policy: UncheckedAccount<'info>,
+
+    /// Proposer's lifetime record (created with the proposer's first proposal)
+    /// CHECK: Address is fixed by seeds; contents are read and created in proposer_reputation_snapshot
+    #[account(
+        mut,
+        seeds = [PROPOSER_STATE_SEED, proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_state: UncheckedAccount<'info>,
+
+    /// Proposer's deposit token account (its mint selects the deposit mint; SPL token deposits only)
     #[account(mut)]
-    pub proposer_token_account: Account<'info, TokenAccount>,
+    pub proposer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Deposit token mint (must match the proposer's token account; SPL token deposits only)
+    #[account(
+        constraint = proposer_token_account
+            .as_ref()
+            .is_some_and(|account| account.mint == deposit_mint.key()) @ GovernanceError::InvalidTokenMint
+    )]
+    pub deposit_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Governance system token vault for the deposit mint (SPL token deposits only)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, governance_token_vault.mint.as_ref()],
+        bump,
+        constraint = proposer_token_account
+            .as_ref()
+            .is_some_and(|account| account.mint == governance_token_vault.mint) @ GovernanceError::InvalidTokenMint
+    )]
+    pub governance_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Native SOL deposit vault (native SOL deposits only)
+    #[account(
+        mut,
+        seeds = [NATIVE_DEPOSIT_VAULT_SEED],
+        bump = native_deposit_vault.bump
+    )]
+    pub native_deposit_vault: Option<Account<'info, NativeDepositVault>>,
+
+    /// Per-proposal deposit escrow (required exactly when escrow mode is enabled)
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [PROPOSAL_ESCROW_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump,
+        token::mint = deposit_mint,
+        token::authority = governance_authority,
+        token::token_program = token_program
+    )]
+    pub deposit_escrow: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Governance system authority (owner of the deposit escrow)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Slashed merchant's history (required for slash proposals)
+    pub merchant_slash_history: Option<Account<'info, MerchantSlashHistory>>,
+
+    /// Changed member's committee member account (required for committee change proposals)
+    /// CHECK: Address checked against the proposed member in the handler
+    pub committee_member: Option<UncheckedAccount<'info>>,
+
+    /// Proposer's own committee member account (waives the deposit of a current member
+    /// while waive_deposit_for_committee is set)
+    /// CHECK: Address is fixed by seeds; contents are read in proposer_deposit_waived
+    #[account(
+        seeds = [COMMITTEE_MEMBER_SEED, proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_committee_member: Option<UncheckedAccount<'info>>,
+
+    /// Upgrade buffer (required for program upgrade proposals)
+    /// CHECK: Checked against the execution data in require_governed_upgrade_buffer
+    pub upgrade_buffer: Option<UncheckedAccount<'info>>,
+
+    /// Disputed order (required for dispute proposals while an order program is registered)
+    /// CHECK: Checked against the execution data in require_dispute_order
+    pub dispute_order: Option<UncheckedAccount<'info>>,
+
+    /// Proposal index (required while proposal_index_enabled is set)
+    #[account(
+        mut,
+        seeds = [PROPOSAL_INDEX_SEED],
+        bump = proposal_index.bump
+    )]
+    pub proposal_index: Option<Account<'info, ProposalIndex>>,
 
-    /// Governance system token vault (for storing deposits)
+    /// Governance statistics (may be uninitialized; counters are skipped then)
+    /// CHECK: Address is fixed by seeds; contents are updated in record_governance_stats
     #[account(
         mut,
-        seeds = [GOVERNANCE_TOKEN_VAULT_SEED],
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: UncheckedAccount<'info>,
+
+    /// CPI caller allowlist (required when invoked through CPI)
+    /// CHECK: Address is fixed by seeds; contents are read in require_allowed_cpi_caller
+    #[account(
+        seeds = [CPI_CALLER_ALLOWLIST_SEED],
         bump
     )]
-    pub governance_token_vault: Account<'info, TokenAccount>,
+    pub cpi_caller_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// Instructions sysvar, for identifying the calling program (required when invoked
+    /// through CPI)
+    /// CHECK: Address is checked against the instructions sysvar ID
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    /// Token program (SPL token deposits only)
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// SPL memo program (required while emit_memos is set)
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+/// Optional settings of create_proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct CreateProposalArgs {
+    /// Execution data, validated against the proposal type
+    pub execution_data: Option<ExecutionData>,
+    /// Custom deposit in whole tokens of the deposit mint (e.g. 150 means 150 USDC)
+    pub custom_deposit_raw: Option<u64>,
+    /// Proposal that must be executed before this one can be
+    pub depends_on: Option<u64>,
+    /// Choices of a multi-choice proposal
+    pub options: Option<Vec<String>>,
+    /// Members who cannot vote on the proposal (their CommitteeMember PDAs passed as
+    /// remaining accounts)
+    pub recused_members: Option<Vec<Pubkey>>,
+}
+
+/// Return data of create_proposal and resubmit_proposal
+/// Borsh layout, 64 bytes total (integers little-endian; fields are only ever appended):
+/// id (u64), proposal (32-byte pubkey), deposit_charged (u64), voting_start (i64),
+/// voting_end (i64)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreatedProposal {
+    /// New proposal ID
+    pub id: u64,
+    /// New proposal PDA
+    pub proposal: Pubkey,
+    /// Deposit taken from the proposer, in base units of the deposit mint
+    /// (after custom deposit conversion and slash escalation)
+    pub deposit_charged: u64,
+    /// Voting start time
+    pub voting_start: i64,
+    /// Voting end time
+    pub voting_end: i64,
 }
 
 /// Create proposal handler function
 pub fn create_proposal(
     ctx: Context<CreateProposal>,
+    proposal_id: u64,
     title: String,
     description: String,
     proposal_type: ProposalType,
-    execution_data: Option<ExecutionData>,
-    custom_deposit_raw: Option<u64>, // User-friendly custom deposit amount (e.g., 150 means 150 USDC)
-) -> Result<u64> {
+    args: CreateProposalArgs,
+) -> Result<CreatedProposal> {
+    let CreateProposalArgs {
+        execution_data,
+        custom_deposit_raw,
+        depends_on,
+        options,
+        recused_members,
+    } = args;
+
+    // Other programs may only open proposals once allowlisted
+    require_allowed_cpi_caller(
+        ctx.accounts.instructions_sysvar.as_deref(),
+        ctx.accounts.cpi_caller_allowlist.as_deref(),
+    )?;
+
     let governance_config = &mut ctx.accounts.governance_config;
-    let proposal = &mut ctx.accounts.proposal;
+    let proposal = &mut ctx.accounts.proposal.load_init()?;
     let clock = Clock::get()?;
 
+    // The PDA was derived from the caller's expected ID; reject stale IDs with a clear error
+    let proposal_id = governance_config.reserve_proposal_id(proposal_id)?;
+    emit_if_proposal_limit_reached(governance_config, clock.unix_timestamp);
+
     // Validate title and description length using common function
     validate_proposal_content(&title, &description)?;
+    validate_execution_data(&execution_data, governance_config)?;
+    validate_proposal_options(&options, &execution_data)?;
+    let recused_members = recused_members.unwrap_or_default();
+    validate_recused_members(&recused_members, ctx.remaining_accounts)?;
+
+    // Reject banned (or, in allowlist-only mode, unlisted) proposers
+    check_proposer_policy(
+        &ctx.accounts.proposer_policy.to_account_info(),
+        &ctx.accounts.proposer.key(),
+    )?;
+    // Voters see the proposer's record on the proposal itself
+    let proposer_reputation = proposer_reputation_snapshot(
+        &ctx.accounts.proposer_state,
+        &ctx.accounts.proposer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.bumps.proposer_state,
+    )?;
 
-    // Handle custom deposit (program-side precision handling)
-    let actual_deposit = if let Some(custom_raw) = custom_deposit_raw {
-        // Use fixed USDC precision (9 digits), consistent with initialization logic
-        // In actual deployment, should dynamically get precision from USDC mint account
-        let usdc_decimals = 9u32; // Devnet USDC precision
+    // Committee housekeeping goes without a deposit (not for slash filings, whose deposit
+    // escalates, nor when the proposer chose a custom deposit)
+    let deposit_waived = custom_deposit_raw.is_none()
+        && slash_merchant(&execution_data).is_none()
+        && proposer_deposit_waived(
+            governance_config,
+            &ctx.accounts.proposer.key(),
+            ctx.accounts.proposer_committee_member.as_deref(),
+            clock.unix_timestamp,
+        )?;
+
+    // Deposit mint must be on the accepted list (waived token deposits are booked on the
+    // primary deposit mint and need no deposit accounts)
+    let deposit_mint_key =
+        if deposit_waived && governance_config.deposit_kind == DepositKind::SplToken {
+            governance_config
+                .deposit_mints
+                .first()
+                .ok_or(GovernanceError::DepositMintNotAccepted)?
+                .mint
+        } else {
+            resolve_deposit_mint_key(
+                governance_config.deposit_kind,
+                ctx.accounts.proposer_token_account.as_ref(),
+            )?
+        };
+    let deposit_mint = governance_config
+        .get_deposit_mint(&deposit_mint_key)?
+        .clone();
 
-        // Program-side precision conversion
+    // Handle custom deposit (program-side precision handling)
+    let actual_deposit = if deposit_waived {
+        0
+    } else if let Some(custom_raw) = custom_deposit_raw {
+        // Program-side precision conversion using the deposit mint's decimals
         let custom_deposit = custom_raw
-            .checked_mul(10_u64.pow(usdc_decimals))
+            .checked_mul(10_u64.pow(deposit_mint.decimals as u32))
             .ok_or(GovernanceError::MathOverflow)?;
 
         // Verify custom deposit cannot be lower than minimum value
         require!(
-            custom_deposit >= governance_config.proposal_deposit,
+            custom_deposit >= deposit_mint.min_deposit,
             GovernanceError::InsufficientProposalDeposit
         );
 
-        msg!(
-            "Using custom deposit: {} USDC ({} tokens)",
+        test_msg!(
+            governance_config,
+            "Using custom deposit: {} ({} tokens of mint {})",
             custom_raw,
-            custom_deposit
+            custom_deposit,
+            deposit_mint.mint
         );
 
         custom_deposit
     } else {
         // Use default deposit
-        governance_config.proposal_deposit
+        deposit_mint.min_deposit
+    };
+
+    // Repeat slash filings against the same merchant require an escalated deposit
+    let actual_deposit = match slash_merchant(&execution_data) {
+        Some(merchant) => {
+            let required_deposit = required_slash_deposit(
+                governance_config,
+                ctx.accounts.merchant_slash_history.as_ref(),
+                merchant,
+                deposit_mint.min_deposit,
+            )?;
+            if custom_deposit_raw.is_some() {
+                require!(
+                    actual_deposit >= required_deposit,
+                    GovernanceError::InsufficientProposalDeposit
+                );
+            }
+            actual_deposit.max(required_deposit)
+        }
+        None => actual_deposit,
     };
 
-    // Get proposal ID
-    let proposal_id = governance_config.next_proposal_id();
+    // Committee changes must apply to the committee as it stands at creation
+    if let Some(change) = committee_change(&execution_data) {
+        let committee_member = ctx
+            .accounts
+            .committee_member
+            .as_ref()
+            .ok_or(GovernanceError::InvalidCommitteeAccounts)?;
+        require_committee_change_applicable(governance_config, committee_member, &change)?;
+    }
+
+    // The buffer must already hold the new program under the governance authority
+    if let Some(upgrade) = program_upgrade(&execution_data) {
+        let upgrade_buffer = ctx
+            .accounts
+            .upgrade_buffer
+            .as_ref()
+            .ok_or(GovernanceError::InvalidUpgradeBuffer)?;
+        require_governed_upgrade_buffer(upgrade_buffer, &upgrade)?;
+    }
+
+    // Dispute parties must match the order, when the deployment knows the order program
+    if let (ProposalType::DisputeArbitration, Some(order_program), Some(dispute)) = (
+        &proposal_type,
+        governance_config.order_program,
+        dispute(&execution_data),
+    ) {
+        let dispute_order = ctx
+            .accounts
+            .dispute_order
+            .as_ref()
+            .ok_or(GovernanceError::InvalidDisputeParties)?;
+        require_dispute_order(dispute_order, &order_program, dispute)?;
+    }
+
+    // A dependency must be an earlier, existing proposal
+    if let Some(dependency_id) = depends_on {
+        require!(
+            dependency_id > 0 && dependency_id < proposal_id,
+            GovernanceError::InvalidProposalDependency
+        );
+    }
 
     // Initialize proposal
     proposal.id = proposal_id;
     proposal.proposer = ctx.accounts.proposer.key();
-    proposal.proposal_type = proposal_type;
-    proposal.title = title;
-    proposal.description = description;
+    proposal.set_proposer_reputation(&proposer_reputation);
+    proposal.set_proposal_type(proposal_type.clone());
+    proposal.set_content(&title, &description)?;
+    proposal.set_options(options.as_deref().unwrap_or_default())?;
+    proposal.set_recused_members(&recused_members)?;
     proposal.deposit_amount = actual_deposit;
+    proposal.deposit_mint = deposit_mint.mint;
     proposal.created_at = clock.unix_timestamp;
     proposal.voting_start = clock.unix_timestamp;
     proposal.voting_end = clock.unix_timestamp + governance_config.voting_period as i64;
-    proposal.status = ProposalStatus::Pending;
-    proposal.yes_votes = 0;
-    proposal.no_votes = 0;
-    proposal.abstain_votes = 0;
-    proposal.veto_votes = 0;
-    proposal.total_votes = 0;
-    proposal.execution_data = execution_data;
-    proposal.execution_result = None;
+    proposal.set_execution_data(&execution_data)?;
+    proposal.set_depends_on(depends_on);
+    proposal.total_voting_power_snapshot = governance_config.total_voting_power;
+    proposal.committee_epoch = governance_config.committee_epoch;
+    proposal.fee_rate = governance_config.fee_rate;
+    proposal.set_deposit_slash_schedule(&governance_config.deposit_slash_schedule);
     proposal.bump = ctx.bumps.proposal;
+    proposal.reset_outcome();
+    record_proposal_status(
+        governance_config,
+        ctx.accounts.proposal_index.as_mut(),
+        proposal_id,
+        None,
+        &ProposalStatus::Pending,
+        clock.unix_timestamp,
+    )?;
+    record_governance_stats(
+        &ctx.accounts.governance_stats,
+        clock.unix_timestamp,
+        |stats| stats.record_proposal_created(&proposal_type, actual_deposit),
+    );
 
-    // Transfer deposit to governance system vault
-    if actual_deposit > 0 {
-        let transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.proposer_token_account.to_account_info(),
-                to: ctx.accounts.governance_token_vault.to_account_info(),
-                authority: ctx.accounts.proposer.to_account_info(),
-            },
+    // Native SOL deposits are pooled in the native deposit vault and never escrowed
+    if deposit_waived {
+        proposal.set_deposit_escrowed(false);
+        require!(
+            ctx.accounts.deposit_escrow.is_none(),
+            GovernanceError::DepositEscrowMismatch
         );
-
-        token::transfer(transfer_ctx, actual_deposit)?;
-
-        msg!(
-            "Transferred deposit of {} tokens from proposer to governance vault",
-            actual_deposit
+        test_msg!(
+            governance_config,
+            "Proposal {} deposit waived for committee proposer",
+            proposal_id
+        );
+    } else if governance_config.deposit_kind == DepositKind::NativeSol {
+        proposal.set_deposit_escrowed(false);
+        require!(
+            ctx.accounts.deposit_escrow.is_none(),
+            GovernanceError::DepositEscrowMismatch
         );
+        let native_deposit_vault = ctx
+            .accounts
+            .native_deposit_vault
+            .as_ref()
+            .ok_or(GovernanceError::NativeDepositAccountsRequired)?;
+        governance_config.lock_deposit(&deposit_mint.mint, actual_deposit)?;
+        transfer_native_deposit_to_vault(
+            proposal_id,
+            &ctx.accounts.proposer,
+            native_deposit_vault,
+            &ctx.accounts.system_program,
+            actual_deposit,
+        )?;
+    } else {
+        let proposer_token_account = ctx
+            .accounts
+            .proposer_token_account
+            .as_mut()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+        let deposit_token_mint = ctx
+            .accounts
+            .deposit_mint
+            .as_ref()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+
+        // Transfer deposit to the proposal's escrow, or to the pooled vault where it is locked
+        proposal.set_deposit_escrowed(governance_config.deposit_escrow_enabled);
+        require!(
+            ctx.accounts.deposit_escrow.is_some() == proposal.deposit_escrowed(),
+            GovernanceError::DepositEscrowMismatch
+        );
+        let deposit_destination = match ctx.accounts.deposit_escrow.as_mut() {
+            Some(deposit_escrow) => deposit_escrow,
+            None => {
+                governance_config.lock_deposit(&deposit_mint.mint, actual_deposit)?;
+                ctx.accounts
+                    .governance_token_vault
+                    .as_mut()
+                    .ok_or(GovernanceError::TokenAccountsRequired)?
+            }
+        };
+        transfer_deposit_to_vault(
+            proposal_id,
+            &ctx.accounts.proposer,
+            proposer_token_account,
+            deposit_destination,
+            deposit_token_mint,
+            token_program,
+            actual_deposit,
+        )?;
+    }
+    if actual_deposit > 0 {
+        emit_transfer_memo(
+            governance_config,
+            ctx.accounts.memo_program.as_ref(),
+            proposal_id,
+            TransferMemo::Deposit,
+            actual_deposit,
+        )?;
     }
 
-    msg!("Proposal created with ID: {}", proposal_id);
-    Ok(proposal_id)
+    emit!(ProposalCreated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: ctx.accounts.governance_config.key(),
+        proposal_id,
+        proposer: proposal.proposer,
+        proposal_type,
+        deposit_mint: proposal.deposit_mint,
+        deposit_amount: proposal.deposit_amount,
+        voting_end: proposal.voting_end,
+        resubmission_of: None,
+        timestamp: clock.unix_timestamp,
+        proposer_reputation,
+    });
+
+    test_msg!(
+        ctx.accounts.governance_config,
+        "Proposal created with ID: {}",
+        proposal_id
+    );
+    Ok(CreatedProposal {
+        id: proposal_id,
+        proposal: ctx.accounts.proposal.key(),
+        deposit_charged: proposal.deposit_amount,
+        voting_start: proposal.voting_start,
+        voting_end: proposal.voting_end,
+    })
 }
 
-/// Cast vote
-#[derive(Accounts)]
-#[instruction(proposal_id: u64)]
-pub struct CastVote<'info> {
-    #[account(
-        mut,
-        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
-        bump = proposal.bump
-    )]
-    pub proposal: Account<'info, Proposal>,
-
-    #[account(
-        init,
-        payer = voter,
-        space = 8 + Vote::INIT_SPACE,
-        seeds = [VOTE_SEED, proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
-        bump
-    )]
-    pub vote: Account<'info, Vote>,
-
-    #[account(
-        seeds = [GOVERNANCE_CONFIG_SEED],
-        bump = governance_config.bump
-    )]
-    pub governance_config: Account<'info, GovernanceConfig>,
+/// Whether the proposer skips the deposit: waive_deposit_for_committee is set and the
+/// proposer is the authority or a current (active, in-term) committee member
+fn proposer_deposit_waived(
+    governance_config: &GovernanceConfig,
+    proposer: &Pubkey,
+    proposer_committee_member: Option<&AccountInfo>,
+    now: i64,
+) -> Result<bool> {
+    if !governance_config.waive_deposit_for_committee {
+        return Ok(false);
+    }
+    if *proposer == governance_config.authority {
+        return Ok(true);
+    }
+    let Some(account) = proposer_committee_member else {
+        return Ok(false);
+    };
+    Ok(CommitteeMember::try_load(account)?
+        .is_some_and(|member| member.active && !member.is_term_expired(now)))
+}
 
-    /// Voter (must be committee member)
-    #[account(mut)]
-    pub voter: Signer<'info>,
+/// Helper function to move a proposal deposit from the proposer into the vault
+/// (the pooled vault or the proposal's escrow)
+/// The proposer's balance is reloaded afterwards and must have dropped by exactly the
+/// deposit, so a mint or decimals misconfiguration fails the proposal instead of
+/// charging a different amount than recorded
+fn transfer_deposit_to_vault<'info>(
+    proposal_id: u64,
+    proposer: &Signer<'info>,
+    proposer_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+    governance_token_vault: &mut InterfaceAccount<'info, TokenAccount>,
+    deposit_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
 
-    /// Voter's token account
-    #[account(
-        associated_token::mint = governance_config.committee_token_mint,
-        associated_token::authority = voter
-    )]
-    pub voter_token_account: Account<'info, TokenAccount>,
+    let transfer_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        TransferChecked {
+            from: proposer_token_account.to_account_info(),
+            mint: deposit_mint.to_account_info(),
+            to: governance_token_vault.to_account_info(),
+            authority: proposer.to_account_info(),
+        },
+    );
 
-    /// Committee token mint
-    pub committee_token_mint: Account<'info, Mint>,
+    let balance_before = proposer_token_account.amount;
+    token_interface::transfer_checked(transfer_ctx, amount, deposit_mint.decimals)?;
+    proposer_token_account.reload()?;
+    require!(
+        balance_before.checked_sub(proposer_token_account.amount) == Some(amount),
+        GovernanceError::DepositBalanceMismatch
+    );
+    emit_treasury_transfer(
+        governance_token_vault,
+        TransferDirection::In,
+        TreasuryTransferReason::ProposalDeposit,
+        Some(proposal_id),
+        proposer_token_account.key(),
+        amount,
+    )?;
 
-    pub system_program: Program<'info, System>,
+    DepositMovement::log_movement(proposal_id, DepositMovementKind::Deposited, amount);
+    Ok(())
 }
 
-/// Cast vote handler function
-pub fn cast_vote(ctx: Context<CastVote>, proposal_id: u64, vote_type: VoteType) -> Result<()> {
-    let proposal = &mut ctx.accounts.proposal;
-    let vote = &mut ctx.accounts.vote;
-    let governance_config = &ctx.accounts.governance_config;
-    let clock = Clock::get()?;
+/// Log the refunded and retained parts of a settled deposit
+fn log_settlement(proposal_id: u64, refunded: u64, retained: u64) {
+    DepositMovement::log_movement(proposal_id, DepositMovementKind::Refunded, refunded);
+    DepositMovement::log_movement(proposal_id, DepositMovementKind::Retained, retained);
+}
 
-    // Verify voter is committee member
-    require!(
-        governance_config.is_committee_member(&ctx.accounts.voter.key()),
-        GovernanceError::NotCommitteeMember
-    );
+/// Helper function to move a native SOL deposit from the proposer into the native deposit vault
+fn transfer_native_deposit_to_vault<'info>(
+    proposal_id: u64,
+    proposer: &Signer<'info>,
+    native_deposit_vault: &Account<'info, NativeDepositVault>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
 
-    // Verify proposal status and voting deadline
-    require!(
-        proposal.status == ProposalStatus::Pending,
-        GovernanceError::ProposalNotActive
-    );
-    require!(
-        clock.unix_timestamp <= proposal.voting_end,
-        GovernanceError::VotingPeriodEnded
+    let transfer_ctx = CpiContext::new(
+        system_program.to_account_info(),
+        system_program::Transfer {
+            from: proposer.to_account_info(),
+            to: native_deposit_vault.to_account_info(),
+        },
     );
 
-    // Get token balance snapshot (voting power will be calculated at finalization)
-    let token_balance = ctx.accounts.voter_token_account.amount;
-    let token_decimals = ctx.accounts.committee_token_mint.decimals;
+    system_program::transfer(transfer_ctx, amount)?;
+    emit_native_treasury_transfer(
+        &native_deposit_vault.to_account_info(),
+        TransferDirection::In,
+        TreasuryTransferReason::ProposalDeposit,
+        Some(proposal_id),
+        proposer.key(),
+        amount,
+    )?;
 
-    // Verify voter has minimum token balance
-    require!(
-        token_balance >= 10_u64.pow(token_decimals as u32),
-        GovernanceError::InsufficientVotingPower
-    );
+    DepositMovement::log_movement(proposal_id, DepositMovementKind::Deposited, amount);
+    Ok(())
+}
 
-    // Create vote record (no voting power stored, will be calculated at finalization)
-    let vote_record = Vote::new(
-        proposal_id,
-        ctx.accounts.voter.key(),
-        vote_type.clone(),
-        token_balance,
-        ctx.bumps.vote,
-    );
-    **vote = vote_record;
+/// Resolve the deposit mint of a new proposal from the deployment's deposit kind
+/// SPL token deposits are selected by the proposer's token account mint
+fn resolve_deposit_mint_key(
+    deposit_kind: DepositKind,
+    proposer_token_account: Option<&InterfaceAccount<TokenAccount>>,
+) -> Result<Pubkey> {
+    match deposit_kind {
+        DepositKind::SplToken => proposer_token_account
+            .map(|token_account| token_account.mint)
+            .ok_or(GovernanceError::TokenAccountsRequired.into()),
+        DepositKind::NativeSol => Ok(NATIVE_SOL_DEPOSIT_MINT),
+    }
+}
 
-    msg!(
-        "Vote cast: {:?} with token balance {}",
-        vote_type,
-        token_balance
+/// Deposit a slash filing against `merchant` requires, escalated by its failed filings
+fn required_slash_deposit(
+    governance_config: &GovernanceConfig,
+    merchant_slash_history: Option<&Account<MerchantSlashHistory>>,
+    merchant: Pubkey,
+    base_deposit: u64,
+) -> Result<u64> {
+    let history = merchant_slash_history.ok_or(GovernanceError::MerchantSlashHistoryRequired)?;
+    require!(
+        history.merchant == merchant,
+        GovernanceError::InvalidMerchantAddress
     );
-    Ok(())
+    governance_config.calculate_slash_deposit(base_deposit, history.consecutive_failures)
 }
 
-/// Finalize proposal
+/// Resubmit a proposal that failed only on participation
 #[derive(Accounts)]
-#[instruction(proposal_id: u64)]
-pub struct FinalizeProposal<'info> {
+#[instruction(proposal_id: u64, prior_proposal_id: u64)]
+pub struct ResubmitProposal<'info> {
     #[account(
-        mut,
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
         seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
-        bump = proposal.bump
+        bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    /// Rejected proposal being resubmitted
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, prior_proposal_id.to_le_bytes().as_ref()],
+        bump = prior_proposal.load()?.bump,
+        constraint = prior_proposal.load()?.proposer == proposer.key() @ GovernanceError::Unauthorized
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub prior_proposal: AccountLoader<'info, Proposal>,
 
     #[account(
+        mut,
         seeds = [GOVERNANCE_CONFIG_SEED],
         bump = governance_config.bump
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Committee token mint (for calculating voting power)
-    pub committee_token_mint: Account<'info, Mint>,
+    /// Original proposer
+    #[account(mut)]
+    pub proposer: Signer<'info>,
 
-    /// Proposer's token account (for deposit refund)
+    /// Proposer policy (may be uninitialized when no policy is configured)
+    /// CHECK: Address is fixed by seeds; contents are validated in check_proposer_policy
     #[account(
-        mut,
-        constraint = proposer_token_account.owner == proposal.proposer @ GovernanceError::Unauthorized
+        seeds = [PROPOSER_POLICY_SEED],
+        bump
     )]
-    pub proposer_token_account: Account<'info, TokenAccount>,
+    pub proposer_policy: UncheckedAccount<'info>,
 
-    /// Governance system token account (for deposit handling)
+    /// Proposer's lifetime record (created with the proposer's first proposal)
+    /// CHECK: Address is fixed by seeds; contents are read and created in proposer_reputation_snapshot
     #[account(
         mut,
-        seeds = [GOVERNANCE_TOKEN_VAULT_SEED],
+        seeds = [PROPOSER_STATE_SEED, proposer.key().as_ref()],
         bump
     )]
-    pub governance_token_vault: Account<'info, TokenAccount>,
+    pub proposer_state: UncheckedAccount<'info>,
 
-    /// Governance system authority (for signing transfers)
+    /// Proposer's deposit token account (its mint selects the deposit mint; SPL token deposits only)
+    #[account(mut)]
+    pub proposer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Deposit token mint (must match the proposer's token account; SPL token deposits only)
+    #[account(
+        constraint = proposer_token_account
+            .as_ref()
+            .is_some_and(|account| account.mint == deposit_mint.key()) @ GovernanceError::InvalidTokenMint
+    )]
+    pub deposit_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Governance system token vault for the deposit mint (SPL token deposits only)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, governance_token_vault.mint.as_ref()],
+        bump,
+        constraint = proposer_token_account
+            .as_ref()
+            .is_some_and(|account| account.mint == governance_token_vault.mint) @ GovernanceError::InvalidTokenMint
+    )]
+    pub governance_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Native SOL deposit vault (native SOL deposits only)
+    #[account(
+        mut,
+        seeds = [NATIVE_DEPOSIT_VAULT_SEED],
+        bump = native_deposit_vault.bump
+    )]
+    pub native_deposit_vault: Option<Account<'info, NativeDepositVault>>,
+
+    /// Per-proposal deposit escrow (required exactly when escrow mode is enabled)
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [PROPOSAL_ESCROW_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump,
+        token::mint = deposit_mint,
+        token::authority = governance_authority,
+        token::token_program = token_program
+    )]
+    pub deposit_escrow: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Governance system authority (owner of the deposit escrow)
     /// CHECK: This is the governance system's PDA authority
     #[account(
         seeds = [GOVERNANCE_AUTHORITY_SEED],
@@ -273,134 +776,1265 @@ pub struct FinalizeProposal<'info> {
     )]
     pub governance_authority: UncheckedAccount<'info>,
 
-    /// Token program (for deposit transfers)
-    pub token_program: Program<'info, Token>,
+    /// Slashed merchant's history (required to resubmit slash proposals)
+    pub merchant_slash_history: Option<Account<'info, MerchantSlashHistory>>,
+
+    /// Proposal index (required while proposal_index_enabled is set)
+    #[account(
+        mut,
+        seeds = [PROPOSAL_INDEX_SEED],
+        bump = proposal_index.bump
+    )]
+    pub proposal_index: Option<Account<'info, ProposalIndex>>,
+
+    /// Governance statistics (may be uninitialized; counters are skipped then)
+    /// CHECK: Address is fixed by seeds; contents are updated in record_governance_stats
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    /// Token program (SPL token deposits only)
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// SPL memo program (required while emit_memos is set)
+    pub memo_program: Option<Program<'info, Memo>>,
 }
 
-/// Finalize proposal handler function
-/// Automatically handle deposits:
-/// - Passed/Rejected: Return 90% deposit to proposer, 10% to committee
-/// - Vetoed: All deposit confiscated to committee
-pub fn finalize_proposal<'info>(
-    ctx: Context<'_, '_, 'info, 'info, FinalizeProposal<'info>>,
+/// Resubmit proposal handler function
+/// Copies the prior proposal's content and charges the resubmission discount on the
+/// deposit a new filing of it would require
+pub fn resubmit_proposal(
+    ctx: Context<ResubmitProposal>,
     proposal_id: u64,
-) -> Result<()> {
-    let proposal = &mut ctx.accounts.proposal;
-    let governance_config = &ctx.accounts.governance_config;
-    let committee_token_mint = &ctx.accounts.committee_token_mint;
+    prior_proposal_id: u64,
+) -> Result<CreatedProposal> {
+    let governance_config = &mut ctx.accounts.governance_config;
+    let prior_proposal = &mut ctx.accounts.prior_proposal.load_mut()?;
+    let proposal = &mut ctx.accounts.proposal.load_init()?;
+    let clock = Clock::get()?;
 
-    // Calculate voting results by iterating through all vote accounts in remaining_accounts
-    let (total_voting_power, vote_results) = calculate_voting_results_from_votes(
-        governance_config,
-        committee_token_mint,
-        &ctx.remaining_accounts,
-        proposal_id,
+    let proposal_id = governance_config.reserve_proposal_id(proposal_id)?;
+    emit_if_proposal_limit_reached(governance_config, clock.unix_timestamp);
+
+    check_proposer_policy(
+        &ctx.accounts.proposer_policy.to_account_info(),
+        &ctx.accounts.proposer.key(),
+    )?;
+    // Voters see the proposer's record on the proposal itself
+    let proposer_reputation = proposer_reputation_snapshot(
+        &ctx.accounts.proposer_state,
+        &ctx.accounts.proposer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.bumps.proposer_state,
     )?;
 
-    msg!("Calculated total voting power: {}", total_voting_power);
-    msg!(
-        "Vote results: yes={}, no={}, abstain={}, veto={}",
-        vote_results.0,
-        vote_results.1,
-        vote_results.2,
-        vote_results.3
+    // Only one discounted retry, and only for original proposals that lost on quorum alone
+    require!(
+        prior_proposal.resubmitted_as().is_none(),
+        GovernanceError::ProposalAlreadyResubmitted
+    );
+    require!(
+        prior_proposal.resubmitted_from().is_none()
+            && prior_proposal.failed_only_on_participation(),
+        GovernanceError::ProposalNotResubmittable
     );
 
+    let deposit_mint_key = resolve_deposit_mint_key(
+        governance_config.deposit_kind,
+        ctx.accounts.proposer_token_account.as_ref(),
+    )?;
+    let deposit_mint = governance_config
+        .get_deposit_mint(&deposit_mint_key)?
+        .clone();
+    // Repeat slash filings escalate the deposit the discount applies to
+    let required_deposit = match slash_merchant(&prior_proposal.execution_data()?) {
+        Some(merchant) => required_slash_deposit(
+            governance_config,
+            ctx.accounts.merchant_slash_history.as_ref(),
+            merchant,
+            deposit_mint.min_deposit,
+        )?,
+        None => deposit_mint.min_deposit,
+    };
+    let deposit = governance_config.calculate_resubmission_deposit(required_deposit)?;
+
+    proposal.id = proposal_id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.set_proposer_reputation(&proposer_reputation);
+    proposal.copy_content_from(prior_proposal);
+    proposal.deposit_amount = deposit;
+    proposal.deposit_mint = deposit_mint.mint;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.voting_start = clock.unix_timestamp;
+    proposal.voting_end = clock.unix_timestamp + governance_config.voting_period as i64;
+    proposal.total_voting_power_snapshot = governance_config.total_voting_power;
+    proposal.committee_epoch = governance_config.committee_epoch;
+    proposal.fee_rate = governance_config.fee_rate;
+    proposal.set_deposit_slash_schedule(&governance_config.deposit_slash_schedule);
+    proposal.bump = ctx.bumps.proposal;
+    proposal.reset_outcome();
+
+    proposal.set_resubmitted_from(prior_proposal_id);
+    prior_proposal.set_resubmitted_as(proposal_id);
+    let proposal_type = proposal.proposal_type()?;
+    record_proposal_status(
+        governance_config,
+        ctx.accounts.proposal_index.as_mut(),
+        proposal_id,
+        None,
+        &ProposalStatus::Pending,
+        clock.unix_timestamp,
+    )?;
+    record_governance_stats(
+        &ctx.accounts.governance_stats,
+        clock.unix_timestamp,
+        |stats| stats.record_proposal_created(&proposal_type, deposit),
+    );
+
+    // Native SOL deposits are pooled in the native deposit vault and never escrowed
+    if governance_config.deposit_kind == DepositKind::NativeSol {
+        proposal.set_deposit_escrowed(false);
+        require!(
+            ctx.accounts.deposit_escrow.is_none(),
+            GovernanceError::DepositEscrowMismatch
+        );
+        let native_deposit_vault = ctx
+            .accounts
+            .native_deposit_vault
+            .as_ref()
+            .ok_or(GovernanceError::NativeDepositAccountsRequired)?;
+        governance_config.lock_deposit(&deposit_mint.mint, deposit)?;
+        transfer_native_deposit_to_vault(
+            proposal_id,
+            &ctx.accounts.proposer,
+            native_deposit_vault,
+            &ctx.accounts.system_program,
+            deposit,
+        )?;
+    } else {
+        let proposer_token_account = ctx
+            .accounts
+            .proposer_token_account
+            .as_mut()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+        let deposit_token_mint = ctx
+            .accounts
+            .deposit_mint
+            .as_ref()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+
+        // Transfer deposit to the proposal's escrow, or to the pooled vault where it is locked
+        proposal.set_deposit_escrowed(governance_config.deposit_escrow_enabled);
+        require!(
+            ctx.accounts.deposit_escrow.is_some() == proposal.deposit_escrowed(),
+            GovernanceError::DepositEscrowMismatch
+        );
+        let deposit_destination = match ctx.accounts.deposit_escrow.as_mut() {
+            Some(deposit_escrow) => deposit_escrow,
+            None => {
+                governance_config.lock_deposit(&deposit_mint.mint, deposit)?;
+                ctx.accounts
+                    .governance_token_vault
+                    .as_mut()
+                    .ok_or(GovernanceError::TokenAccountsRequired)?
+            }
+        };
+        transfer_deposit_to_vault(
+            proposal_id,
+            &ctx.accounts.proposer,
+            proposer_token_account,
+            deposit_destination,
+            deposit_token_mint,
+            token_program,
+            deposit,
+        )?;
+    }
+    if deposit > 0 {
+        emit_transfer_memo(
+            governance_config,
+            ctx.accounts.memo_program.as_ref(),
+            proposal_id,
+            TransferMemo::Deposit,
+            deposit,
+        )?;
+    }
+
+    emit!(ProposalCreated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: ctx.accounts.governance_config.key(),
+        proposal_id,
+        proposer: proposal.proposer,
+        proposal_type,
+        deposit_mint: proposal.deposit_mint,
+        deposit_amount: proposal.deposit_amount,
+        voting_end: proposal.voting_end,
+        resubmission_of: Some(prior_proposal_id),
+        timestamp: clock.unix_timestamp,
+        proposer_reputation,
+    });
+
+    msg!(
+        "Proposal {} resubmitted as {} with deposit {}",
+        prior_proposal_id,
+        proposal_id,
+        deposit
+    );
+    Ok(CreatedProposal {
+        id: proposal_id,
+        proposal: ctx.accounts.proposal.key(),
+        deposit_charged: proposal.deposit_amount,
+        voting_start: proposal.voting_start,
+        voting_end: proposal.voting_end,
+    })
+}
+
+/// Cast vote
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CastVote<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + Vote::INIT_SPACE,
+        seeds = [VOTE_SEED, proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, Vote>,
+
+    /// Total voting power changes when the vote lifts an inactivity suspension
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Voter (must be committee member)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// Voter's committee member account (may not exist; checked in the handler)
+    /// CHECK: Deserialized as CommitteeMember when owned by this program
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, voter.key().as_ref()],
+        bump
+    )]
+    pub committee_member: UncheckedAccount<'info>,
+
+    /// Voter's token account
+    #[account(
+        associated_token::mint = governance_config.committee_token_mint,
+        associated_token::authority = voter,
+        associated_token::token_program = committee_token_program
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Voter's token lockup, snapshotted in place of the token account balance
+    #[account(
+        seeds = [LOCKUP_SEED, voter.key().as_ref()],
+        bump = lockup.bump
+    )]
+    pub lockup: Option<Account<'info, Lockup>>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+
+    /// Governance statistics (may be uninitialized; counters are skipped then)
+    /// CHECK: Address is fixed by seeds; contents are updated in record_governance_stats
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cast vote handler function
+pub fn cast_vote(mut ctx: Context<CastVote>, proposal_id: u64, vote_type: VoteType) -> Result<()> {
+    let accounts = &mut ctx.accounts;
+    let voter = accounts.voter.key();
+    record_vote(
+        VoteAccounts {
+            proposal: &accounts.proposal,
+            vote: &mut accounts.vote,
+            governance_config: &mut accounts.governance_config,
+            committee_member: &accounts.committee_member,
+            voter_token_account: &accounts.voter_token_account,
+            lockup: accounts.lockup.as_deref(),
+            committee_token_mint: &accounts.committee_token_mint,
+            governance_stats: &accounts.governance_stats,
+        },
+        voter,
+        proposal_id,
+        vote_type,
+        ctx.bumps.vote,
+    )
+}
+
+/// Accounts a vote is recorded with (shared by cast_vote and cast_vote_signed)
+pub(crate) struct VoteAccounts<'a, 'info> {
+    pub proposal: &'a AccountLoader<'info, Proposal>,
+    pub vote: &'a mut Account<'info, Vote>,
+    pub governance_config: &'a mut Account<'info, GovernanceConfig>,
+    pub committee_member: &'a AccountInfo<'info>,
+    pub voter_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub lockup: Option<&'a Lockup>,
+    pub committee_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub governance_stats: &'a AccountInfo<'info>,
+}
+
+/// Record `voter`'s vote once the voter has been authenticated
+pub(crate) fn record_vote(
+    accounts: VoteAccounts,
+    voter: Pubkey,
+    proposal_id: u64,
+    vote_type: VoteType,
+    vote_bump: u8,
+) -> Result<()> {
+    let proposal = &accounts.proposal.load()?;
+    let vote = accounts.vote;
+    let clock = Clock::get()?;
+
+    // Verify voter is committee member
+    let mut committee_member = CommitteeMember::try_load(accounts.committee_member)?
+        .ok_or(GovernanceError::NotCommitteeMember)?;
+
+    // Voting lifts an inactivity suspension, returning the member's balance to the total
+    if !committee_member.active && committee_member.suspended_for_inactivity {
+        committee_member.reinstate(clock.unix_timestamp);
+        let voting_power = committee_member_voting_power(
+            &committee_member,
+            &accounts.voter_token_account.to_account_info(),
+            &accounts.committee_token_mint.key(),
+            accounts.committee_token_mint.decimals,
+            clock.unix_timestamp,
+            accounts.governance_config.stake_based_voting_power,
+        )?;
+        let governance_config = &mut *accounts.governance_config;
+        governance_config.total_voting_power = governance_config
+            .total_voting_power
+            .checked_add(voting_power)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        emit!(CommitteeMemberReinstated {
+            member: committee_member.member,
+            timestamp: clock.unix_timestamp,
+        });
+        record_committee_change(
+            governance_config,
+            CommitteeChangeKind::Reactivated,
+            committee_member.member,
+            clock.unix_timestamp,
+        )?;
+    }
+    require!(committee_member.active, GovernanceError::MemberSuspended);
+    require!(
+        !committee_member.is_term_expired(clock.unix_timestamp),
+        GovernanceError::MemberTermExpired
+    );
+    require!(
+        !committee_member.is_substitute_after(proposal.created_at),
+        GovernanceError::AlternateNotInSnapshot
+    );
+
+    // Verify proposal status and voting deadline
+    require!(proposal.is_votable(), GovernanceError::ProposalNotActive);
+    require!(
+        clock.unix_timestamp <= proposal.voting_end,
+        GovernanceError::VotingPeriodEnded
+    );
+
+    // Multi-choice proposals take a valid choice or an abstention, binary ones no choice
+    let vote_type_matches = match vote_type {
+        VoteType::Choice(index) => index < proposal.option_count(),
+        VoteType::Abstain => true,
+        VoteType::Yes | VoteType::No | VoteType::NoWithVeto => !proposal.is_multi_choice(),
+    };
+    require!(vote_type_matches, GovernanceError::InvalidVoteChoice);
+    require!(!proposal.is_recused(&voter), GovernanceError::VoterRecused);
+
+    // Get token balance snapshot (voting power will be calculated at finalization)
+    // A lockup snapshots the locked tokens (finalization counts them with the lock
+    // multiplier); stake-based voting power snapshots the seated stake instead of the
+    // live balance
+    let token_balance = if let Some(lockup) = accounts
+        .lockup
+        .filter(|_| accounts.governance_config.lockups_enabled())
+    {
+        lockup.amount
+    } else if accounts.governance_config.stake_based_voting_power {
+        committee_member.staked_amount
+    } else {
+        accounts.voter_token_account.amount
+    };
+    let token_decimals = accounts.committee_token_mint.decimals;
+
+    // Verify voter has minimum token balance
+    require!(
+        token_balance >= 10_u64.pow(token_decimals as u32),
+        GovernanceError::InsufficientVotingPower
+    );
+
+    // Create vote record (no voting power stored, will be calculated at finalization)
+    let vote_record = Vote::new(
+        proposal_id,
+        voter,
+        vote_type.clone(),
+        token_balance,
+        clock.unix_timestamp,
+        vote_bump,
+    );
+    **vote = vote_record;
+
+    // Participation counter (used to weight committee fee distribution)
+    committee_member.record_vote(clock.unix_timestamp)?;
+    committee_member.store(accounts.committee_member)?;
+    record_governance_stats(
+        accounts.governance_stats,
+        clock.unix_timestamp,
+        GovernanceStats::record_vote,
+    );
+    emit!(VoteCast {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: accounts.governance_config.key(),
+        proposal_id,
+        voter,
+        vote: vote.key(),
+        vote_type: vote_type.clone(),
+        token_balance,
+        timestamp: clock.unix_timestamp,
+    });
+
+    test_msg!(
+        accounts.governance_config,
+        "Vote cast: {:?} with token balance {}",
+        vote_type,
+        token_balance
+    );
+    Ok(())
+}
+
+/// Finalize proposal
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct FinalizeProposal<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Committee token mint (for calculating voting power)
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Proposer's token account (for deposit refund)
+    /// Omit it, or pass one that is frozen or has the wrong mint, to leave the refund claimable
+    #[account(
+        mut,
+        constraint = proposer_token_account.owner == proposal.load()?.proposer @ GovernanceError::Unauthorized
+    )]
+    pub proposer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Governance system token account for the proposal's deposit mint (SPL token deposits only)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, proposal.load()?.deposit_mint.as_ref()],
+        bump = governance_config.token_vault_bump(&proposal.load()?.deposit_mint)?
+    )]
+    pub governance_token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Native SOL deposit vault (native SOL deposits only)
+    #[account(
+        mut,
+        seeds = [NATIVE_DEPOSIT_VAULT_SEED],
+        bump = native_deposit_vault.bump
+    )]
+    pub native_deposit_vault: Option<Account<'info, NativeDepositVault>>,
+
+    /// Proposal's deposit escrow (required for escrowed deposits; closed at settlement)
+    #[account(
+        mut,
+        seeds = [PROPOSAL_ESCROW_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub deposit_escrow: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Proposer (receives the deposit escrow rent, or the refund of a native SOL deposit)
+    /// CHECK: Only receives lamports; address is checked against the proposal
+    #[account(
+        mut,
+        constraint = proposer.key() == proposal.load()?.proposer @ GovernanceError::Unauthorized
+    )]
+    pub proposer: Option<UncheckedAccount<'info>>,
+
+    /// Proposer's lifetime record (may be uninitialized for proposers of older proposals)
+    /// CHECK: Address is fixed by seeds; contents are updated in record_proposer_outcome
+    #[account(
+        mut,
+        seeds = [PROPOSER_STATE_SEED, proposal.load()?.proposer.as_ref()],
+        bump
+    )]
+    pub proposer_state: UncheckedAccount<'info>,
+
+    /// Governance system authority (for signing transfers)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump = governance_config.authority_bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Slashed merchant's history (required for slash proposals)
+    #[account(mut)]
+    pub merchant_slash_history: Option<Account<'info, MerchantSlashHistory>>,
+
+    /// Refund claim (required when the refund cannot be pushed to the proposer)
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RefundClaim::INIT_SPACE,
+        seeds = [REFUND_CLAIM_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub refund_claim: Option<Account<'info, RefundClaim>>,
+
+    /// Proposal outcome, created here for other programs to read (required)
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProposalOutcome::INIT_SPACE,
+        seeds = [PROPOSAL_OUTCOME_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal_outcome: Option<Account<'info, ProposalOutcome>>,
+
+    /// Proposal index (required while proposal_index_enabled is set)
+    #[account(
+        mut,
+        seeds = [PROPOSAL_INDEX_SEED],
+        bump = proposal_index.bump
+    )]
+    pub proposal_index: Option<Account<'info, ProposalIndex>>,
+
+    /// Governance statistics (may be uninitialized; counters are skipped then)
+    /// CHECK: Address is fixed by seeds; contents are updated in record_governance_stats
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: UncheckedAccount<'info>,
+
+    /// Pays for the proposal outcome and refund claim accounts (required)
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Deposit mint (required to push refunds and to burn confiscated deposits;
+    /// without it refunds are left claimable and burns fall back to the treasury)
+    #[account(
+        mut,
+        constraint = deposit_mint.key() == proposal.load()?.deposit_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub deposit_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Token program (for deposit transfers; SPL token deposits only)
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// System program (for proposal outcome and refund claim creation; required)
+    pub system_program: Option<Program<'info, System>>,
+    /// SPL memo program (required while emit_memos is set)
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+/// Finalize proposal handler function
+/// Automatically handle deposits by the configured slash schedule (defaults):
+/// - Passed, or Rejected at or above the approval floor: 10% to committee, rest refunded
+/// - Rejected below the approval floor: 50% to committee, rest refunded
+/// - Vetoed: All deposit confiscated to committee
+pub fn finalize_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeProposal<'info>>,
+    proposal_id: u64,
+    clock_override: Option<i64>,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal.load_mut()?;
+    let governance_config = &mut ctx.accounts.governance_config;
+    let now = lifecycle_now(governance_config, clock_override)?;
+    let authority_bump = governance_config.authority_bump;
+    let committee_token_mint = &ctx.accounts.committee_token_mint;
+
+    // Calculate voting results by iterating through all vote accounts in remaining_accounts
+    let (total_voting_power, vote_results) = calculate_voting_results_from_votes(
+        governance_config,
+        committee_token_mint,
+        &ctx.remaining_accounts,
+        proposal_id,
+        proposal.created_at,
+        proposal.recused_members(),
+    )?;
+
     // Update proposal vote statistics with calculated results
-    proposal.yes_votes = vote_results.0;
-    proposal.no_votes = vote_results.1;
-    proposal.abstain_votes = vote_results.2;
-    proposal.veto_votes = vote_results.3;
-    proposal.total_votes = vote_results.0 + vote_results.1 + vote_results.2 + vote_results.3;
+    proposal.record_tally(&vote_results)?;
+
+    // Finalize proposal
+    proposal.finalize(governance_config, total_voting_power, now)?;
+    let (member_accounts, vote_accounts) = ctx
+        .remaining_accounts
+        .split_at(committee_member_accounts_len(governance_config)?);
+    record_committee_participation(
+        governance_config,
+        member_accounts,
+        vote_accounts,
+        proposal_id,
+    )?;
+    let status = proposal.status()?;
+    governance_config.release_pending_proposal(&status)?;
+    record_proposer_outcome(
+        &ctx.accounts.proposer_state,
+        &proposal.proposer,
+        &status,
+    )?;
+    record_proposal_status(
+        governance_config,
+        ctx.accounts.proposal_index.as_mut(),
+        proposal_id,
+        Some(&ProposalStatus::Pending),
+        &status,
+        now,
+    )?;
+
+    emit!(ProposalFinalized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: governance_config.key(),
+        proposal_id,
+        status: status.clone(),
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        abstain_votes: proposal.abstain_votes,
+        veto_votes: proposal.veto_votes,
+        total_voting_power,
+        timestamp: now,
+    });
+
+    TallySummary {
+        proposal_id,
+        status: status.clone(),
+        total_voting_power,
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        abstain_votes: proposal.abstain_votes,
+        veto_votes: proposal.veto_votes,
+    }
+    .log();
+    test_msg!(
+        governance_config,
+        "Proposal {} finalized with status {:?}: yes={}, no={}, abstain={}, veto={} of {}",
+        proposal_id,
+        status,
+        proposal.yes_votes,
+        proposal.no_votes,
+        proposal.abstain_votes,
+        proposal.veto_votes,
+        total_voting_power
+    );
+
+    // Publish the outcome for other programs
+    let proposal_outcome = ctx
+        .accounts
+        .proposal_outcome
+        .as_mut()
+        .ok_or(GovernanceError::ProposalOutcomeRequired)?;
+    proposal_outcome.record(
+        proposal,
+        total_voting_power,
+        now,
+        ctx.bumps
+            .proposal_outcome
+            .ok_or(GovernanceError::ProposalOutcomeRequired)?,
+    )?;
+
+    // Record slash outcome against the merchant (drives deposit escalation)
+    if let Some(merchant) = slash_merchant(&proposal.execution_data()?) {
+        let history = ctx
+            .accounts
+            .merchant_slash_history
+            .as_mut()
+            .ok_or(GovernanceError::MerchantSlashHistoryRequired)?;
+        require!(
+            history.merchant == merchant,
+            GovernanceError::InvalidMerchantAddress
+        );
+        history.record_outcome(&status)?;
+    }
+
+    // Push the refund only to a usable proposer account, otherwise leave it claimable
+    let refund_destination = ctx
+        .accounts
+        .proposer_token_account
+        .as_ref()
+        .filter(|account| account.mint == proposal.deposit_mint && !account.is_frozen())
+        .map(|account| account.to_account_info());
+
+    // Escrowed deposits are settled from the proposal's escrow
+    let was_settled = proposal.deposit_settled();
+    let escrowed = proposal.deposit_escrowed() && !was_settled;
+    require!(
+        !escrowed || ctx.accounts.deposit_escrow.is_some(),
+        GovernanceError::DepositEscrowMismatch
+    );
+
+    // Automatically handle deposit (waived deposits need no deposit accounts)
+    let unpaid_refund = if proposal.deposit_amount == 0 {
+        settle_zero_deposit(proposal)?;
+        0
+    } else if proposal.deposit_mint == NATIVE_SOL_DEPOSIT_MINT {
+        // Native SOL refunds go straight to the proposer's wallet and are never left unpaid
+        let native_deposit_vault = ctx
+            .accounts
+            .native_deposit_vault
+            .as_ref()
+            .ok_or(GovernanceError::NativeDepositAccountsRequired)?;
+        let proposer = ctx
+            .accounts
+            .proposer
+            .as_ref()
+            .ok_or(GovernanceError::NativeDepositAccountsRequired)?;
+        handle_native_deposit(proposal, proposer, native_deposit_vault)?;
+        0
+    } else {
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+        let deposit_source = match ctx.accounts.deposit_escrow.as_mut() {
+            Some(deposit_escrow) if escrowed => deposit_escrow,
+            _ => ctx
+                .accounts
+                .governance_token_vault
+                .as_mut()
+                .ok_or(GovernanceError::TokenAccountsRequired)?,
+        };
+        handle_deposit_automatically(
+            proposal,
+            refund_destination,
+            ctx.accounts.deposit_mint.as_ref(),
+            deposit_source,
+            &ctx.accounts.governance_authority,
+            token_program,
+            authority_bump,
+        )?
+    };
+
+    // Move what is left in the escrow (fee, confiscation, unpaid refund) into the pooled
+    // vault, then close the escrow and return its rent to the proposer
+    if escrowed && proposal.deposit_settled() {
+        let deposit_escrow = ctx
+            .accounts
+            .deposit_escrow
+            .as_mut()
+            .ok_or(GovernanceError::DepositEscrowMismatch)?;
+        let deposit_mint = ctx
+            .accounts
+            .deposit_mint
+            .as_ref()
+            .ok_or(GovernanceError::DepositEscrowMismatch)?;
+        let proposer = ctx
+            .accounts
+            .proposer
+            .as_ref()
+            .ok_or(GovernanceError::DepositEscrowMismatch)?;
+        let governance_token_vault = ctx
+            .accounts
+            .governance_token_vault
+            .as_mut()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(GovernanceError::TokenAccountsRequired)?;
+
+        let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[authority_bump]];
+        let signer_seeds = &[&authority_seeds[..]];
+
+        deposit_escrow.reload()?;
+        let residual = deposit_escrow.amount;
+        if residual > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: deposit_escrow.to_account_info(),
+                    mint: deposit_mint.to_account_info(),
+                    to: governance_token_vault.to_account_info(),
+                    authority: ctx.accounts.governance_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token_interface::transfer_checked(transfer_ctx, residual, deposit_mint.decimals)?;
+            emit_treasury_transfer(
+                governance_token_vault,
+                TransferDirection::In,
+                TreasuryTransferReason::EscrowSettlement,
+                Some(proposal_id),
+                deposit_escrow.key(),
+                residual,
+            )?;
+        }
+
+        let close_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: deposit_escrow.to_account_info(),
+                destination: proposer.to_account_info(),
+                authority: ctx.accounts.governance_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::close_account(close_ctx)?;
+
+        DepositMovement::log_movement(proposal_id, DepositMovementKind::EscrowResidual, residual);
+        test_msg!(
+            governance_config,
+            "Proposal {} escrow closed, {} moved to governance vault",
+            proposal_id,
+            residual
+        );
+    }
+
+    if unpaid_refund > 0 {
+        let refund_claim = ctx
+            .accounts
+            .refund_claim
+            .as_mut()
+            .ok_or(GovernanceError::RefundClaimRequired)?;
+        refund_claim.proposal_id = proposal_id;
+        refund_claim.proposer = proposal.proposer;
+        refund_claim.mint = proposal.deposit_mint;
+        refund_claim.amount = unpaid_refund;
+        refund_claim.created_at = now;
+        refund_claim.bump = ctx
+            .bumps
+            .refund_claim
+            .ok_or(GovernanceError::RefundClaimRequired)?;
+
+        DepositMovement::log_movement(
+            proposal_id,
+            DepositMovementKind::LeftClaimable,
+            unpaid_refund,
+        );
+        test_msg!(
+            governance_config,
+            "Proposal {} refund of {} left claimable by proposer",
+            proposal_id,
+            unpaid_refund
+        );
+    }
+
+    // Burn confiscated deposit when configured; without the mint account it stays in the treasury
+    if !was_settled {
+        burn_confiscated_deposit(
+            proposal,
+            governance_config,
+            ctx.accounts.deposit_mint.as_ref(),
+            ctx.accounts.governance_token_vault.as_mut(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.governance_authority,
+            authority_bump,
+        )?;
+    }
+
+    let settled = !was_settled && proposal.deposit_settled();
+    if settled {
+        record_settled_deposit(governance_config, proposal, unpaid_refund)?;
+        emit_settlement_memos(
+            governance_config,
+            ctx.accounts.memo_program.as_ref(),
+            proposal,
+            unpaid_refund,
+        )?;
+    }
+    record_governance_stats(&ctx.accounts.governance_stats, now, |stats| {
+        stats.record_finalized(proposal, settled)
+    });
+
+    Ok(())
+}
+
+/// Burn a vetoed proposal's confiscated deposit under the Burn policy
+/// Without the mint account it stays in the treasury
+pub(crate) fn burn_confiscated_deposit<'info>(
+    proposal: &mut Proposal,
+    governance_config: &GovernanceConfig,
+    deposit_mint: Option<&InterfaceAccount<'info, Mint>>,
+    governance_token_vault: Option<&mut InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    governance_authority: &UncheckedAccount<'info>,
+    authority_bump: u8,
+) -> Result<()> {
+    if proposal.deposit_confiscated == 0
+        || governance_config.confiscation_policy != ConfiscationPolicy::Burn
+    {
+        return Ok(());
+    }
+
+    let proposal_id = proposal.id;
+    let amount = proposal.deposit_confiscated;
+    match deposit_mint {
+        Some(deposit_mint) => {
+            let governance_token_vault =
+                governance_token_vault.ok_or(GovernanceError::TokenAccountsRequired)?;
+            let token_program = token_program.ok_or(GovernanceError::TokenAccountsRequired)?;
+            let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[authority_bump]];
+            let signer_seeds = &[&authority_seeds[..]];
+            let burn_ctx = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Burn {
+                    mint: deposit_mint.to_account_info(),
+                    from: governance_token_vault.to_account_info(),
+                    authority: governance_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token_interface::burn(burn_ctx, amount)?;
+            proposal.deposit_burned = amount;
+            emit_treasury_transfer(
+                governance_token_vault,
+                TransferDirection::Out,
+                TreasuryTransferReason::ConfiscationBurn,
+                Some(proposal_id),
+                deposit_mint.key(),
+                amount,
+            )?;
+
+            emit!(ConfiscatedDepositBurned {
+                proposal_id,
+                mint: proposal.deposit_mint,
+                amount,
+            });
+        }
+        None => {
+            emit!(ConfiscatedDepositBurnSkipped {
+                proposal_id,
+                mint: proposal.deposit_mint,
+                amount,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Account for a deposit settled by this finalization: a settled deposit is no longer
+/// owed back, fee and confiscated parts become withdrawable, and a claimable refund
+/// stays locked until claimed
+pub(crate) fn record_settled_deposit(
+    governance_config: &mut GovernanceConfig,
+    proposal: &Proposal,
+    unpaid_refund: u64,
+) -> Result<()> {
+    if proposal.deposit_escrowed() {
+        // Escrowed deposits were never locked in the vault; a claimable refund now is
+        governance_config.lock_deposit(&proposal.deposit_mint, unpaid_refund)?;
+    } else {
+        let released = proposal
+            .deposit_amount
+            .checked_sub(unpaid_refund)
+            .ok_or(GovernanceError::ArithmeticUnderflow)?;
+        governance_config.release_locked_deposit(&proposal.deposit_mint, released)?;
+    }
+
+    // Fee and unburned confiscation now belong to the committee treasury
+    let treasury_share = proposal
+        .deposit_fee
+        .checked_add(proposal.deposit_confiscated)
+        .and_then(|sum| sum.checked_sub(proposal.deposit_burned))
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    governance_config.credit_treasury(&proposal.deposit_mint, treasury_share)
+}
 
-    // Finalize proposal
-    proposal.finalize(governance_config, total_voting_power)?;
+/// Attach memos for a settled deposit: the refund paid out and the fee or confiscation kept
+pub(crate) fn emit_settlement_memos<'info>(
+    governance_config: &GovernanceConfig,
+    memo_program: Option<&Program<'info, Memo>>,
+    proposal: &Proposal,
+    unpaid_refund: u64,
+) -> Result<()> {
+    let paid_refund = proposal
+        .deposit_refunded
+        .checked_sub(unpaid_refund)
+        .ok_or(GovernanceError::ArithmeticUnderflow)?;
+    for (movement, amount) in [
+        (TransferMemo::Refund, paid_refund),
+        (TransferMemo::Fee, proposal.deposit_fee),
+        (TransferMemo::Confiscation, proposal.deposit_confiscated),
+    ] {
+        if amount > 0 {
+            emit_transfer_memo(
+                governance_config,
+                memo_program,
+                proposal.id,
+                movement,
+                amount,
+            )?;
+        }
+    }
+    Ok(())
+}
 
-    msg!(
-        "Proposal {} finalized with status: {:?}",
-        proposal_id,
-        proposal.status
-    );
+/// Event emitted when a confiscated deposit is burned
+#[event]
+pub struct ConfiscatedDepositBurned {
+    /// Vetoed proposal ID
+    pub proposal_id: u64,
+    /// Deposit token mint
+    pub mint: Pubkey,
+    /// Burned amount
+    pub amount: u64,
+}
 
-    // Automatically handle deposit
-    handle_deposit_automatically(
-        proposal,
-        proposal_id,
-        &ctx.accounts.proposer_token_account,
-        &ctx.accounts.governance_token_vault,
-        &ctx.accounts.governance_authority,
-        &ctx.accounts.token_program,
-        &ctx.bumps,
-    )?;
+/// Event emitted when the Burn policy falls back to keeping the deposit in the treasury
+/// (deposit mint account not supplied)
+#[event]
+pub struct ConfiscatedDepositBurnSkipped {
+    /// Vetoed proposal ID
+    pub proposal_id: u64,
+    /// Deposit token mint
+    pub mint: Pubkey,
+    /// Amount kept in the treasury
+    pub amount: u64,
+}
+
+/// Split of a finalized proposal's deposit by the slash schedule
+pub(crate) struct DepositSplit {
+    /// Refunded to the proposer
+    pub(crate) refund_amount: u64,
+    /// Kept as committee fee
+    pub(crate) program_fee: u64,
+    /// Confiscated
+    pub(crate) confiscated: u64,
+    /// Ledger reason of the slashed part
+    pub(crate) reason: TreasuryTransferReason,
+}
+
+/// Split the deposit by the schedule rate fixed at finalization; the rest is refunded
+/// Returns None for proposals not finalized by vote, whose deposits are not processed here
+pub(crate) fn scheduled_deposit_split(proposal: &Proposal) -> Result<Option<DepositSplit>> {
+    let deposit_amount = proposal.deposit_amount;
+    let slashed = ((deposit_amount as u128 * proposal.deposit_slash_rate as u128)
+        / governance_constants::BASIS_POINTS_DENOMINATOR as u128) as u64;
+    let refund_amount = deposit_amount - slashed;
+    Ok(match proposal.status()? {
+        // Passed or rejected: the slash is the committee fee and remains in the vault
+        ProposalStatus::Passed | ProposalStatus::Rejected | ProposalStatus::Executed => {
+            Some(DepositSplit {
+                refund_amount,
+                program_fee: slashed,
+                confiscated: 0,
+                reason: TreasuryTransferReason::CommitteeFee,
+            })
+        }
+        // Vetoed: the slash is confiscated and remains in the vault
+        ProposalStatus::Vetoed => Some(DepositSplit {
+            refund_amount,
+            program_fee: 0,
+            confiscated: slashed,
+            reason: TreasuryTransferReason::Confiscation,
+        }),
+        status @ (ProposalStatus::Pending | ProposalStatus::Cancelled) => {
+            msg!(
+                "Proposal {} status {:?} - no deposit processing needed",
+                proposal.id,
+                status
+            );
+            None
+        }
+    })
+}
+
+/// Settle a zero (waived) deposit once the proposal is finalized: nothing moves
+fn settle_zero_deposit(proposal: &mut Proposal) -> Result<()> {
+    if !proposal.deposit_settled() && scheduled_deposit_split(proposal)?.is_some() {
+        proposal.record_deposit_settlement(0, 0, 0)?;
+    }
+    Ok(())
+}
+
+/// Helper function to handle a native SOL deposit with the same split as token deposits
+/// The refund is paid in lamports from the program-owned native deposit vault
+fn handle_native_deposit<'info>(
+    proposal: &mut Proposal,
+    proposer: &UncheckedAccount<'info>,
+    native_deposit_vault: &Account<'info, NativeDepositVault>,
+) -> Result<()> {
+    let proposal_id = proposal.id;
+    if proposal.deposit_settled() {
+        msg!("Proposal {} deposit already settled, skipping", proposal_id);
+        return Ok(());
+    }
+
+    let Some(DepositSplit {
+        refund_amount,
+        program_fee,
+        confiscated,
+        reason,
+    }) = scheduled_deposit_split(proposal)?
+    else {
+        return Ok(());
+    };
+    let slashed = program_fee + confiscated;
+
+    let vault_info = native_deposit_vault.to_account_info();
+    if refund_amount > 0 {
+        vault_info.sub_lamports(refund_amount)?;
+        proposer.add_lamports(refund_amount)?;
+        emit_native_treasury_transfer(
+            &vault_info,
+            TransferDirection::Out,
+            TreasuryTransferReason::DepositRefund,
+            Some(proposal_id),
+            proposer.key(),
+            refund_amount,
+        )?;
+    }
+
+    proposal.record_deposit_settlement(refund_amount, program_fee, confiscated)?;
+    if slashed > 0 {
+        emit_native_treasury_transfer(
+            &vault_info,
+            TransferDirection::Internal,
+            reason,
+            Some(proposal_id),
+            native_deposit_vault.key(),
+            slashed,
+        )?;
+    }
 
+    log_settlement(proposal_id, refund_amount, slashed);
     Ok(())
 }
 
 /// Helper function to automatically handle deposits
-fn handle_deposit_automatically<'info>(
-    proposal: &Proposal,
-    proposal_id: u64,
-    proposer_token_account: &Account<'info, TokenAccount>,
-    governance_token_vault: &Account<'info, TokenAccount>,
+/// Idempotent: a proposal whose deposit is already settled is never paid out twice
+/// Returns the refund left unpaid because no usable destination was supplied
+pub(crate) fn handle_deposit_automatically<'info>(
+    proposal: &mut Proposal,
+    refund_destination: Option<AccountInfo<'info>>,
+    deposit_mint: Option<&InterfaceAccount<'info, Mint>>,
+    governance_token_vault: &mut InterfaceAccount<'info, TokenAccount>,
     governance_authority: &UncheckedAccount<'info>,
-    token_program: &Program<'info, Token>,
-    bumps: &FinalizeProposalBumps,
-) -> Result<()> {
+    token_program: &Interface<'info, TokenInterface>,
+    authority_bump: u8,
+) -> Result<u64> {
+    let proposal_id = proposal.id;
+    if proposal.deposit_settled() {
+        msg!("Proposal {} deposit already settled, skipping", proposal_id);
+        return Ok(0);
+    }
+
     // Generate PDA signing seeds
-    let authority_bump = bumps.governance_authority;
     let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[authority_bump]];
     let signer_seeds = &[&authority_seeds[..]];
 
-    let deposit_amount = proposal.deposit_amount;
+    // Waived deposits have nothing to refund, so the refund CPI is skipped entirely
+    if proposal.deposit_amount == 0 {
+        settle_zero_deposit(proposal)?;
+        return Ok(0);
+    }
 
-    match proposal.status {
-        ProposalStatus::Passed | ProposalStatus::Rejected | ProposalStatus::Executed => {
-            // Proposal passed or rejected: return 90% to proposer, 10% remains in program vault
-            let refund_amount = deposit_amount * 90 / 100; // 90%
-            let program_fee = deposit_amount - refund_amount; // 10%
+    let mut unpaid_refund = 0;
+    let Some(DepositSplit {
+        refund_amount,
+        program_fee,
+        confiscated,
+        reason,
+    }) = scheduled_deposit_split(proposal)?
+    else {
+        return Ok(0);
+    };
+    let slashed = program_fee + confiscated;
 
-            // Refund to proposer
-            if refund_amount > 0 {
+    // Refund to proposer, or leave it claimable without a usable destination
+    if refund_amount > 0 {
+        match (refund_destination, deposit_mint) {
+            (Some(destination), Some(deposit_mint)) => {
+                let destination_key = destination.key();
                 let refund_ctx = CpiContext::new_with_signer(
                     token_program.to_account_info(),
-                    Transfer {
+                    TransferChecked {
                         from: governance_token_vault.to_account_info(),
-                        to: proposer_token_account.to_account_info(),
+                        mint: deposit_mint.to_account_info(),
+                        to: destination,
                         authority: governance_authority.to_account_info(),
                     },
                     signer_seeds,
                 );
 
-                token::transfer(refund_ctx, refund_amount)?;
+                token_interface::transfer_checked(
+                    refund_ctx,
+                    refund_amount,
+                    deposit_mint.decimals,
+                )?;
+                emit_treasury_transfer(
+                    governance_token_vault,
+                    TransferDirection::Out,
+                    TreasuryTransferReason::DepositRefund,
+                    Some(proposal_id),
+                    destination_key,
+                    refund_amount,
+                )?;
             }
-
-            // 10% fee remains in program vault, no transfer needed
-            msg!(
-                "Proposal {} deposit auto-processed: {} refunded to proposer, {} remains in program vault",
-                proposal_id,
-                refund_amount,
-                program_fee
-            );
-        }
-        ProposalStatus::Vetoed => {
-            // Proposal vetoed: deposit already in committee program token account, no transfer needed
-            msg!(
-                "Proposal {} vetoed: {} deposit remains in committee program token account",
-                proposal_id,
-                deposit_amount
-            );
-        }
-        _ => {
-            // Other statuses do not process deposits
-            msg!(
-                "Proposal {} status {:?} - no deposit processing needed",
-                proposal_id,
-                proposal.status
-            );
+            _ => unpaid_refund = refund_amount,
         }
     }
 
-    Ok(())
+    proposal.record_deposit_settlement(refund_amount, program_fee, confiscated)?;
+    if slashed > 0 {
+        let vault_key = governance_token_vault.key();
+        emit_treasury_transfer(
+            governance_token_vault,
+            TransferDirection::Internal,
+            reason,
+            Some(proposal_id),
+            vault_key,
+            slashed,
+        )?;
+    }
+
+    log_settlement(proposal_id, refund_amount, slashed);
+
+    Ok(unpaid_refund)
 }
 
 /// Execute proposal (simplified version)
@@ -410,183 +2044,665 @@ pub struct ExecuteProposal<'info> {
     #[account(
         mut,
         seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
-        bump = proposal.bump
+        bump = proposal.load()?.bump
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub proposal: AccountLoader<'info, Proposal>,
 
     #[account(
+        mut,
         seeds = [GOVERNANCE_CONFIG_SEED],
-        bump = governance_config.bump
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Proposal this one depends on (required when proposal.depends_on is set)
+    pub dependency_proposal: Option<AccountLoader<'info, Proposal>>,
+
+    /// Changed member's committee member account (committee change proposals only)
+    /// CHECK: Address checked against the proposed member in the handler
+    #[account(mut)]
+    pub committee_member: Option<UncheckedAccount<'info>>,
+
+    /// Changed member's stake (committee change proposals; required for removals and for
+    /// additions while member_stake_requirement is set)
+    /// CHECK: Address checked against the proposed member in the handler
+    #[account(mut)]
+    pub member_stake: Option<UncheckedAccount<'info>>,
+
+    /// Pays rent for an added member, receives the rent of a removed one
+    /// (committee change proposals only)
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// System program (committee change proposals only)
+    pub system_program: Option<Program<'info, System>>,
+
+    /// Upgraded program (program upgrade proposals only)
+    /// CHECK: Checked against the execution data in the handler
+    #[account(mut)]
+    pub upgrade_program: Option<UncheckedAccount<'info>>,
+
+    /// Upgraded program's ProgramData account (program upgrade proposals only)
+    /// CHECK: Checked by the upgradeable loader
+    #[account(mut)]
+    pub upgrade_program_data: Option<UncheckedAccount<'info>>,
+
+    /// Buffer holding the new program bytes, closed to the payer by the upgrade
+    /// (program upgrade proposals only)
+    /// CHECK: Checked against the execution data in require_governed_upgrade_buffer
+    #[account(mut)]
+    pub upgrade_buffer: Option<UncheckedAccount<'info>>,
+
+    /// Governance authority PDA, upgrade authority of the program (program upgrade proposals only)
+    /// CHECK: Address checked in the handler
+    pub governance_authority: Option<UncheckedAccount<'info>>,
+
+    /// Upgradeable loader (program upgrade proposals only)
+    /// CHECK: Address constraint
+    #[account(address = solana_sdk_ids::bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable: Option<UncheckedAccount<'info>>,
+
+    /// Rent sysvar (program upgrade proposals only)
+    pub rent: Option<Sysvar<'info, Rent>>,
+
+    /// Clock sysvar (program upgrade proposals only)
+    pub clock: Option<Sysvar<'info, Clock>>,
+
+    /// Execution adapter registry (may be uninitialized when no adapters are registered)
+    /// CHECK: Address is fixed by seeds; contents are loaded in invoke_execution_adapter
+    #[account(
+        seeds = [EXECUTION_ADAPTER_REGISTRY_SEED],
+        bump
+    )]
+    pub execution_adapter_registry: UncheckedAccount<'info>,
+
+    /// Adapter program registered for the proposal type (required when one is registered)
+    /// CHECK: Checked against the registry in invoke_execution_adapter
+    pub execution_adapter: Option<UncheckedAccount<'info>>,
+
+    /// Adapter authority PDA, signer of the adapter call (required when an adapter is registered)
+    /// CHECK: Address checked in invoke_execution_adapter
+    pub adapter_authority: Option<UncheckedAccount<'info>>,
+
+    /// Proposal index (required while proposal_index_enabled is set)
+    #[account(
+        mut,
+        seeds = [PROPOSAL_INDEX_SEED],
+        bump = proposal_index.bump
+    )]
+    pub proposal_index: Option<Account<'info, ProposalIndex>>,
+
+    /// Governance statistics (may be uninitialized; counters are skipped then)
+    /// CHECK: Address is fixed by seeds; contents are updated in record_governance_stats
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: UncheckedAccount<'info>,
 }
 
-/// Execute proposal handler function (simplified version, only updates status)
-pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
-    let proposal = &mut ctx.accounts.proposal;
-    let clock = Clock::get()?;
+/// Execute proposal handler function
+/// Committee change proposals add or remove the member, program upgrade proposals deploy
+/// the buffer; the adapter registered for the proposal type is then invoked with the
+/// remaining accounts (without one, execution only updates status)
+pub fn execute_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+    proposal_id: u64,
+    clock_override: Option<i64>,
+) -> Result<()> {
+    let now = lifecycle_now(&ctx.accounts.governance_config, clock_override)?;
 
-    // Verify proposal can be executed
-    require!(
-        proposal.can_execute(),
-        GovernanceError::ProposalNotExecutable
-    );
+    // The proposal is only borrowed for these checks: the CPIs below must not overlap
+    // a borrow of its data
+    let (depends_on, execution_data, proposal_type) = {
+        let proposal = ctx.accounts.proposal.load()?;
+
+        // Verify proposal can be executed: still-votable proposals must be finalized first,
+        // terminal proposals (including Cancelled) never execute
+        require!(
+            !proposal.is_votable(),
+            GovernanceError::ProposalNotFinalized
+        );
+        require!(
+            proposal.can_execute(),
+            GovernanceError::ProposalNotExecutable
+        );
+
+        (
+            proposal.depends_on(),
+            proposal.execution_data()?,
+            proposal.proposal_type()?,
+        )
+    };
+
+    // Verify dependency proposal has been executed
+    if let Some(dependency_id) = depends_on {
+        let dependency = ctx
+            .accounts
+            .dependency_proposal
+            .as_ref()
+            .ok_or(GovernanceError::DependencyNotExecuted)?
+            .load()?;
+        require!(
+            dependency.id == dependency_id,
+            GovernanceError::InvalidProposalDependency
+        );
+        require!(
+            dependency.status()? == ProposalStatus::Executed,
+            GovernanceError::DependencyNotExecuted
+        );
+    }
+
+    // Committee changes are re-checked against the committee at execution time
+    if let Some(change) = committee_change(&execution_data) {
+        let committee_member = ctx
+            .accounts
+            .committee_member
+            .as_ref()
+            .ok_or(GovernanceError::InvalidCommitteeAccounts)?;
+        let payer = ctx
+            .accounts
+            .payer
+            .as_ref()
+            .ok_or(GovernanceError::InvalidCommitteeAccounts)?;
+        let governance_config = &mut ctx.accounts.governance_config;
+        match change.action {
+            CommitteeChangeAction::Add => {
+                let system_program = ctx
+                    .accounts
+                    .system_program
+                    .as_ref()
+                    .ok_or(GovernanceError::InvalidCommitteeAccounts)?;
+                add_committee_member_account(
+                    governance_config,
+                    committee_member,
+                    payer,
+                    system_program,
+                    ctx.accounts.member_stake.as_deref(),
+                    change.member,
+                )?;
+                msg!("Committee member added by proposal: {}", change.member);
+            }
+            CommitteeChangeAction::Remove => {
+                let member_stake = ctx
+                    .accounts
+                    .member_stake
+                    .as_ref()
+                    .ok_or(GovernanceError::InvalidCommitteeAccounts)?;
+                remove_committee_member_account(
+                    governance_config,
+                    committee_member,
+                    member_stake,
+                    payer,
+                    change.member,
+                )?;
+                msg!("Committee member removed by proposal: {}", change.member);
+            }
+        }
+    }
+
+    // Program upgrades deploy the buffer through the loader, signed by the governance authority
+    if let Some(upgrade) = program_upgrade(&execution_data) {
+        let upgrade_program = ctx
+            .accounts
+            .upgrade_program
+            .as_ref()
+            .ok_or(GovernanceError::InvalidProgramUpgradeAccounts)?;
+        let upgrade_program_data = ctx
+            .accounts
+            .upgrade_program_data
+            .as_ref()
+            .ok_or(GovernanceError::InvalidProgramUpgradeAccounts)?;
+        let upgrade_buffer = ctx
+            .accounts
+            .upgrade_buffer
+            .as_ref()
+            .ok_or(GovernanceError::InvalidProgramUpgradeAccounts)?;
+        let governance_authority = ctx
+            .accounts
+            .governance_authority
+            .as_ref()
+            .ok_or(GovernanceError::InvalidProgramUpgradeAccounts)?;
+        let spill = ctx
+            .accounts
+            .payer
+            .as_ref()
+            .ok_or(GovernanceError::InvalidProgramUpgradeAccounts)?;
+        let loader = ctx
+            .accounts
+            .bpf_loader_upgradeable
+            .as_ref()
+            .ok_or(GovernanceError::InvalidProgramUpgradeAccounts)?;
+        let rent = ctx
+            .accounts
+            .rent
+            .as_ref()
+            .ok_or(GovernanceError::InvalidProgramUpgradeAccounts)?;
+        let clock_sysvar = ctx
+            .accounts
+            .clock
+            .as_ref()
+            .ok_or(GovernanceError::InvalidProgramUpgradeAccounts)?;
+
+        let (authority_address, authority_bump) =
+            Pubkey::find_program_address(&[GOVERNANCE_AUTHORITY_SEED], &crate::ID);
+        require!(
+            upgrade_program.key() == upgrade.program_id
+                && governance_authority.key() == authority_address,
+            GovernanceError::InvalidProgramUpgradeAccounts
+        );
+        require_governed_upgrade_buffer(upgrade_buffer, &upgrade)?;
+
+        let instruction = solana_loader_v3_interface::instruction::upgrade(
+            &upgrade.program_id,
+            &upgrade.buffer,
+            &authority_address,
+            &spill.key(),
+        );
+        invoke_signed(
+            &instruction,
+            &[
+                upgrade_program_data.to_account_info(),
+                upgrade_program.to_account_info(),
+                upgrade_buffer.to_account_info(),
+                spill.to_account_info(),
+                rent.to_account_info(),
+                clock_sysvar.to_account_info(),
+                governance_authority.to_account_info(),
+                loader.to_account_info(),
+            ],
+            &[&[GOVERNANCE_AUTHORITY_SEED, &[authority_bump]]],
+        )?;
+        msg!("Program {} upgraded by proposal", upgrade.program_id);
+    }
+
+    invoke_execution_adapter(
+        &ctx.accounts.execution_adapter_registry,
+        &ctx.accounts.proposal,
+        ctx.accounts.execution_adapter.as_deref(),
+        ctx.accounts.adapter_authority.as_deref(),
+        ctx.remaining_accounts,
+    )?;
 
     // Simplified execution logic: only update status and record time
     let execution_result = format!(
         "Proposal {} executed at timestamp {}. Type: {:?}",
-        proposal_id, clock.unix_timestamp, proposal.proposal_type
+        proposal_id, now, proposal_type
+    );
+
+    let proposal = &mut ctx.accounts.proposal.load_mut()?;
+
+    // A passed dispute ratifies the decision it proposed, with no input from the executor
+    if let Some(ExecutionData::Dispute(mut dispute)) = execution_data {
+        if let Some(decision) = dispute.ratify_proposed_decision() {
+            proposal.set_execution_data(&Some(ExecutionData::Dispute(dispute)))?;
+            msg!(
+                "Proposal {} arbitration decision recorded: {:?}",
+                proposal_id,
+                decision
+            );
+        }
+    }
+    proposal.mark_executed(&execution_result, now)?;
+    record_proposal_status(
+        &ctx.accounts.governance_config,
+        ctx.accounts.proposal_index.as_mut(),
+        proposal_id,
+        Some(&ProposalStatus::Passed),
+        &ProposalStatus::Executed,
+        now,
+    )?;
+    record_governance_stats(
+        &ctx.accounts.governance_stats,
+        now,
+        GovernanceStats::record_executed,
     );
 
-    proposal.mark_executed(execution_result)?;
+    emit!(ProposalExecuted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: ctx.accounts.governance_config.key(),
+        proposal_id,
+        proposal_type,
+        timestamp: now,
+    });
 
     msg!("Proposal {} executed successfully", proposal_id);
     Ok(())
 }
 
+/// Event emitted when a new proposal fills the max_active_proposals limit
+#[event]
+pub struct ProposalLimitReached {
+    /// Proposals pending after the creation
+    pub active_proposals: u64,
+    /// Configured active proposal limit
+    pub max_active_proposals: u16,
+    /// Creation timestamp
+    pub timestamp: i64,
+}
+
+/// Warn operators once new proposals will be refused until one leaves Pending
+fn emit_if_proposal_limit_reached(governance_config: &GovernanceConfig, timestamp: i64) {
+    if governance_config.pending_proposal_count >= governance_config.max_active_proposals as u64 {
+        emit!(ProposalLimitReached {
+            active_proposals: governance_config.pending_proposal_count,
+            max_active_proposals: governance_config.max_active_proposals,
+            timestamp,
+        });
+    }
+}
+
+/// Check if a committee member holds voting power at the given time
+fn committee_member_holds_voting_power(committee_member: &CommitteeMember, as_of: i64) -> bool {
+    // Suspended members hold no voting power (their earlier votes are still tallied)
+    // Members whose term had ended by then hold no voting power either
+    // (finalization measures at the proposal's creation time)
+    // Alternates seated after the proposal opened are outside its committee snapshot
+    // (the primary they replaced was suspended, so it counted as zero too)
+    committee_member.active
+        && !committee_member.is_term_expired(as_of)
+        && !committee_member.is_substitute_after(as_of)
+}
+
+/// Voting power a committee member holds at the given time
+/// Suspended members, members whose term ended, and token accounts that are not the
+/// member's committee token account count as zero; with stake_based set the member's
+/// seated stake is measured and the token account is ignored
+pub fn committee_member_voting_power(
+    committee_member: &CommitteeMember,
+    account_info: &AccountInfo,
+    committee_token_mint: &Pubkey,
+    token_decimals: u8,
+    as_of: i64,
+    stake_based: bool,
+) -> Result<u64> {
+    use crate::state::vote::VotingPowerCalculator;
+
+    if !committee_member_holds_voting_power(committee_member, as_of) {
+        return Ok(0);
+    }
+
+    // Locked stake cannot be borrowed for the snapshot, unlike a live balance
+    if stake_based {
+        return Ok(VotingPowerCalculator::calculate_voting_power(
+            committee_member.staked_amount,
+            token_decimals,
+        ));
+    }
+
+    // Verify account is owned by a token program (legacy Token or Token-2022)
+    if !is_token_program(account_info.owner) {
+        msg!(
+            "❌ SKIP: Account {} not owned by a token program",
+            account_info.key
+        );
+        msg!("   Actual owner: {}", account_info.owner);
+        return Ok(0);
+    }
+
+    // Size, mint and owner are checked on the borrowed bytes, so only the member's
+    // committee token account is deserialized
+    // (Token-2022 accounts may carry extensions beyond the base 165 bytes)
+    let data = account_info.try_borrow_data()?;
+    if data.len() < TOKEN_ACCOUNT_BASE_SIZE
+        || data[TOKEN_ACCOUNT_MINT_OFFSET..TOKEN_ACCOUNT_MINT_OFFSET + 32]
+            != committee_token_mint.to_bytes()
+        || data[TOKEN_ACCOUNT_OWNER_OFFSET..TOKEN_ACCOUNT_OWNER_OFFSET + 32]
+            != committee_member.member.to_bytes()
+    {
+        return Ok(0);
+    }
+    let token_account = TokenAccount::try_deserialize(&mut data.as_ref())
+        .map_err(|_| GovernanceError::InvalidAccountData)?;
+    Ok(VotingPowerCalculator::calculate_voting_power(
+        token_account.amount,
+        token_decimals,
+    ))
+}
+
 /// Calculate voting results from vote accounts and total voting power
-/// Returns (total_voting_power, (yes_votes, no_votes, abstain_votes, veto_votes))
+/// Returns (total_voting_power, vote_tally)
 pub fn calculate_voting_results_from_votes<'info>(
     governance_config: &GovernanceConfig,
-    committee_token_mint: &Account<'info, Mint>,
+    committee_token_mint: &InterfaceAccount<'info, Mint>,
     remaining_accounts: &'info [AccountInfo<'info>],
     proposal_id: u64,
-) -> Result<(u64, (u64, u64, u64, u64))> {
-    use crate::state::vote::{Vote, VotingPowerCalculator};
-    use anchor_spl::token::TokenAccount;
+    proposal_created_at: i64,
+    recused_members: &[Pubkey],
+) -> Result<(u64, VoteTally)> {
+    // We pass member accounts first, then vote accounts
+    let member_accounts_len = committee_member_accounts_len(governance_config)?;
+    require!(
+        remaining_accounts.len() >= member_accounts_len,
+        GovernanceError::InvalidCommitteeAccounts
+    );
+    let (member_accounts, vote_accounts) = remaining_accounts.split_at(member_accounts_len);
+
+    let committee_voting_power = committee_total_voting_power(
+        governance_config,
+        committee_token_mint,
+        member_accounts,
+        proposal_created_at,
+        recused_members,
+    )?;
+    let vote_results = tally_vote_accounts(
+        governance_config,
+        vote_accounts,
+        proposal_id,
+        committee_token_mint.decimals,
+        &committee_voting_power,
+    )?;
+    Ok((committee_voting_power.total_voting_power, vote_results))
+}
+
+/// Committee accounts passed per member: the CommitteeMember PDA and token account, then
+/// the member's VoterStats PDA while the participation bonus is enabled, then the
+/// member's Lockup PDA while lockups are enabled
+pub fn committee_member_stride(governance_config: &GovernanceConfig) -> usize {
+    2 + governance_config.participation_bonus_enabled() as usize
+        + governance_config.lockups_enabled() as usize
+}
+
+/// Number of committee accounts leading the finalization remaining accounts
+pub fn committee_member_accounts_len(governance_config: &GovernanceConfig) -> Result<usize> {
+    require!(
+        governance_config.legacy_committee_member_count() == 0,
+        GovernanceError::CommitteeMigrationRequired
+    );
+    Ok(governance_config.committee_member_count as usize
+        * committee_member_stride(governance_config))
+}
+
+/// Committee voting power measured for one proposal
+pub struct CommitteeVotingPower {
+    /// Voting power of the whole committee
+    pub total_voting_power: u64,
+    /// Members earning the participation bonus
+    pub bonus_members: Vec<Pubkey>,
+    /// Members with a lockup made before the proposal, and the lockup's voting power
+    /// (before the participation bonus), which their votes count with
+    pub locked_voting_power: Vec<(Pubkey, u64)>,
+}
+
+/// Total voting power from all committee members' token accounts
+/// Each member is passed as a (CommitteeMember PDA, member token account) pair, followed
+/// by their VoterStats PDA while the participation bonus is enabled and their Lockup PDA
+/// while lockups are enabled; a lockup made before the proposal replaces the token
+/// account balance
+pub fn committee_total_voting_power(
+    governance_config: &GovernanceConfig,
+    committee_token_mint: &InterfaceAccount<Mint>,
+    member_accounts: &[AccountInfo],
+    proposal_created_at: i64,
+    recused_members: &[Pubkey],
+) -> Result<CommitteeVotingPower> {
+    let member_count = governance_config.committee_member_count as usize;
+    let stride = committee_member_stride(governance_config);
+    require!(
+        member_accounts.len() == member_count * stride,
+        GovernanceError::InvalidCommitteeAccounts
+    );
 
     let token_decimals = committee_token_mint.decimals;
+    let committee_token_mint = committee_token_mint.key();
     let mut total_voting_power = 0u64;
-    let mut yes_votes = 0u64;
-    let mut no_votes = 0u64;
-    let mut abstain_votes = 0u64;
-    let mut veto_votes = 0u64;
-
-    // First pass: calculate total voting power from all committee members' token accounts
-
-    for (i, member) in governance_config.committee_members.iter().enumerate() {
-        msg!("--- Processing committee member slot {} ---", i);
-
-        if let Some(member_pubkey) = member {
-            msg!("Committee member {}: {}", i, member_pubkey);
-
-            if let Some(account_info) = remaining_accounts.get(i) {
-                msg!("Found account for member {}: {}", i, account_info.key);
-                msg!("Account owner: {}", account_info.owner);
-                msg!("Account size: {}", account_info.data.borrow().len());
-                msg!("Expected owner (Token program): {}", anchor_spl::token::ID);
-
-                // Verify account is owned by Token program
-                if account_info.owner != &anchor_spl::token::ID {
-                    msg!(
-                        "❌ SKIP: Account {} not owned by Token program",
-                        account_info.key
-                    );
-                    msg!("   Actual owner: {}", account_info.owner);
-                    msg!("   Expected owner: {}", anchor_spl::token::ID);
-                    continue;
-                }
-                msg!("✅ Account owned by Token program");
-
-                // Verify account data size for TokenAccount
-                let expected_token_account_size = 165; // Standard TokenAccount size
-                let actual_size = account_info.data.borrow().len();
-                if actual_size != expected_token_account_size {
-                    continue;
-                }
+    let mut counted_members: Vec<Pubkey> = Vec::with_capacity(member_count);
+    let mut bonus_members: Vec<Pubkey> = Vec::new();
+    let mut locked_voting_power: Vec<(Pubkey, u64)> = Vec::new();
+    for member_group in member_accounts.chunks(stride) {
+        let committee_member = load_committee_member(&member_group[0])?;
+        require!(
+            !counted_members.contains(&committee_member.member),
+            GovernanceError::InvalidCommitteeAccounts
+        );
+        counted_members.push(committee_member.member);
+        // Recused members cannot vote, so their balance stays out of the quorum
+        if recused_members.contains(&committee_member.member) {
+            continue;
+        }
 
-                // Try to deserialize as TokenAccount
-                match TokenAccount::try_deserialize(&mut account_info.data.borrow().as_ref()) {
-                    Ok(token_account) => {
-                        // Check owner and mint match
-                        let owner_match = token_account.owner == *member_pubkey;
-                        let mint_match = token_account.mint == committee_token_mint.key();
-
-                        if owner_match && mint_match {
-                            let voting_power = VotingPowerCalculator::calculate_voting_power(
-                                token_account.amount,
-                                token_decimals,
-                            );
-                            total_voting_power += voting_power;
-                        }
-                    }
-                    Err(_) => {
-                        return Err(GovernanceError::InvalidAccountData.into());
-                    }
+        // Lockups made after the proposal opened are outside its snapshot
+        let lockup = if governance_config.lockups_enabled() {
+            load_lockup(&member_group[stride - 1], &committee_member.member)?
+                .filter(|lockup| lockup.locked_at <= proposal_created_at)
+        } else {
+            None
+        };
+        let mut voting_power = match lockup {
+            Some(lockup) => {
+                let lockup_voting_power =
+                    lockup.voting_power(governance_config, token_decimals, proposal_created_at);
+                locked_voting_power.push((committee_member.member, lockup_voting_power));
+                if committee_member_holds_voting_power(&committee_member, proposal_created_at) {
+                    lockup_voting_power
+                } else {
+                    0
                 }
             }
+            None => committee_member_voting_power(
+                &committee_member,
+                &member_group[1],
+                &committee_token_mint,
+                token_decimals,
+                proposal_created_at,
+                governance_config.stake_based_voting_power,
+            )?,
+        };
+        if governance_config.participation_bonus_enabled() {
+            let earns_bonus = load_voter_stats(&member_group[2], &committee_member.member)?
+                .is_some_and(|voter_stats| {
+                    voter_stats
+                        .earns_participation_bonus(governance_config.participation_bonus_window)
+                });
+            if earns_bonus && voting_power > 0 {
+                voting_power = governance_config.apply_participation_bonus(voting_power);
+                bonus_members.push(committee_member.member);
+            }
         }
+        total_voting_power += voting_power;
     }
+    Ok(CommitteeVotingPower {
+        total_voting_power,
+        bonus_members,
+        locked_voting_power,
+    })
+}
 
-    // Second pass: calculate vote results from vote accounts
-    // We pass member token accounts first, then vote accounts
-    let vote_account_start_index = governance_config.committee_member_count as usize;
+/// Load a vote account, or None when the account is not a valid vote on the proposal
+fn load_proposal_vote(account_info: &AccountInfo, proposal_id: u64) -> Result<Option<Vote>> {
+    // Only process accounts owned by our program
+    if account_info.owner != &crate::ID {
+        return Ok(None);
+    }
 
-    for (i, account_info) in remaining_accounts
-        .iter()
-        .skip(vote_account_start_index)
-        .enumerate()
-    {
-        // Only process accounts owned by our program
-        if account_info.owner != &crate::ID {
-            continue;
-        }
+    // Only Vote accounts: exact size and discriminator
+    let data = account_info.try_borrow_data()?;
+    if data.len() != 8 + Vote::INIT_SPACE || data[..8] != *Vote::DISCRIMINATOR {
+        return Ok(None);
+    }
 
-        // Check account data size - Vote accounts should be exactly the right size
-        let expected_vote_size = 76; // 8 + 8 + 32 + 1 + 8 + 8 + 1 + 9 + 1
-        let actual_size = account_info.data.borrow().len();
-        if actual_size != expected_vote_size {
-            continue;
-        }
+    let Ok(vote) = Vote::try_deserialize(&mut data.as_ref()) else {
+        return Ok(None);
+    };
+    Ok((vote.proposal_id == proposal_id && vote.is_valid()).then_some(vote))
+}
 
-        // Check discriminator to ensure it's a Vote account
-        let data = account_info.data.borrow();
-        if data.len() < 8 {
-            msg!(
-                "Skipping account {} (index {}): data too short",
-                account_info.key,
-                vote_account_start_index + i
-            );
+/// Vote results of one proposal from its vote accounts
+/// Accounts that are not valid votes on the proposal are ignored; votes of locked members
+/// count with their lockup's voting power, and votes of bonus members with the
+/// participation bonus
+pub fn tally_vote_accounts(
+    governance_config: &GovernanceConfig,
+    vote_accounts: &[AccountInfo],
+    proposal_id: u64,
+    token_decimals: u8,
+    committee_voting_power: &CommitteeVotingPower,
+) -> Result<VoteTally> {
+    let mut tally = VoteTally::default();
+
+    for account_info in vote_accounts {
+        let Some(vote) = load_proposal_vote(account_info, proposal_id)? else {
             continue;
+        };
+        let mut voting_power = committee_voting_power
+            .locked_voting_power
+            .iter()
+            .find(|(member, _)| *member == vote.voter)
+            .map_or_else(
+                || vote.calculate_voting_power(token_decimals),
+                |(_, lockup_voting_power)| *lockup_voting_power,
+            );
+        if committee_voting_power.bonus_members.contains(&vote.voter) {
+            voting_power = governance_config.apply_participation_bonus(voting_power);
         }
 
-        // Get Vote discriminator (first 8 bytes)
-        // For now, we'll skip discriminator check and rely on try_deserialize
-        // The discriminator is generated by Anchor and we can't easily access it here
-
-        // Now try to deserialize as Vote account
-        match Vote::try_deserialize(&mut data.as_ref()) {
-            Ok(vote) => {
-                msg!(
-                    "Successfully deserialized vote account {} (index {})",
-                    account_info.key,
-                    vote_account_start_index + i
-                );
+        tally.add(&vote.vote_type, voting_power);
+    }
 
-                if vote.proposal_id == proposal_id && vote.is_valid() {
-                    let voting_power = vote.calculate_voting_power(token_decimals);
+    Ok(tally)
+}
 
-                    match vote.vote_type {
-                        crate::state::proposal::VoteType::Yes => yes_votes += voting_power,
-                        crate::state::proposal::VoteType::No => no_votes += voting_power,
-                        crate::state::proposal::VoteType::Abstain => abstain_votes += voting_power,
-                        crate::state::proposal::VoteType::NoWithVeto => veto_votes += voting_power,
-                    }
-                }
-            }
-            Err(_) => {
-                continue;
+/// Record in each passed VoterStats account whether the member voted on the finalized
+/// proposal (participation bonus only; uninitialized voter stats are skipped)
+pub fn record_committee_participation(
+    governance_config: &GovernanceConfig,
+    member_accounts: &[AccountInfo],
+    vote_accounts: &[AccountInfo],
+    proposal_id: u64,
+) -> Result<()> {
+    if !governance_config.participation_bonus_enabled() {
+        return Ok(());
+    }
+
+    for member_group in member_accounts.chunks(committee_member_stride(governance_config)) {
+        let member = load_committee_member(&member_group[0])?.member;
+        let Some(mut voter_stats) = load_voter_stats(&member_group[2], &member)? else {
+            continue;
+        };
+        require!(
+            member_group[2].is_writable,
+            GovernanceError::InvalidCommitteeAccounts
+        );
+
+        let mut voted = false;
+        for account_info in vote_accounts {
+            if load_proposal_vote(account_info, proposal_id)?
+                .is_some_and(|vote| vote.voter == member)
+            {
+                voted = true;
+                break;
             }
         }
+        voter_stats.record(proposal_id, voted);
+        voter_stats.store(&member_group[2])?;
     }
-
-    Ok((
-        total_voting_power,
-        (yes_votes, no_votes, abstain_votes, veto_votes),
-    ))
+    Ok(())
 }
 
 /// Close vote account
+/// Votes must be closed before their proposal, while the proposal account still exists
 #[derive(Accounts)]
 pub struct CloseVote<'info> {
     #[account(
@@ -595,20 +2711,66 @@ pub struct CloseVote<'info> {
     )]
     pub vote: Account<'info, Vote>,
 
+    /// Proposal the vote was cast on, which must no longer be Pending
+    #[account(
+        seeds = [PROPOSAL_SEED, vote.proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.load()?.bump,
+        constraint = !proposal.load()?.status()?.is_votable() @ GovernanceError::ProposalNotFinalized
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
     /// Governance config to verify authority
     #[account(
-        constraint = governance_config.authority == authority.key()
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.admin_multisig_enabled
+            || governance_config.config_admin == authority.key() @ GovernanceError::Unauthorized
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
 }
 
 /// Close vote account handler function
-/// Only governance authority can close any vote account
+/// Only governance authority can close any vote account, once its proposal is finalized
+/// or cancelled
 pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CloseVote(ctx.accounts.vote.key()),
+    )?;
+
     let vote = &ctx.accounts.vote;
+
+    emit!(VoteClosed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: governance_config_address(ctx.accounts.governance_config.bump)?,
+        proposal_id: vote.proposal_id,
+        voter: vote.voter,
+        vote: vote.key(),
+        closed_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!(
         "Vote account closed by authority: {} (voter: {}, proposal: {})",
         ctx.accounts.vote.key(),
@@ -617,3 +2779,43 @@ pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deposit_split_covers_every_status() {
+        let mut proposal: Proposal = bytemuck::Zeroable::zeroed();
+        proposal.deposit_amount = 1_000;
+        proposal.deposit_slash_rate = 2_500;
+        for status in [
+            ProposalStatus::Pending,
+            ProposalStatus::Passed,
+            ProposalStatus::Rejected,
+            ProposalStatus::Vetoed,
+            ProposalStatus::Executed,
+            ProposalStatus::Cancelled,
+        ] {
+            proposal.set_status(status.clone());
+            let split = scheduled_deposit_split(&proposal).unwrap();
+            match status {
+                ProposalStatus::Pending | ProposalStatus::Cancelled => {
+                    assert!(split.is_none(), "{:?}", status)
+                }
+                ProposalStatus::Passed | ProposalStatus::Rejected | ProposalStatus::Executed => {
+                    let split = split.unwrap();
+                    assert_eq!(split.refund_amount, 750);
+                    assert_eq!(split.program_fee, 250);
+                    assert_eq!(split.confiscated, 0);
+                }
+                ProposalStatus::Vetoed => {
+                    let split = split.unwrap();
+                    assert_eq!(split.refund_amount, 750);
+                    assert_eq!(split.program_fee, 0);
+                    assert_eq!(split.confiscated, 250);
+                }
+            }
+        }
+    }
+}
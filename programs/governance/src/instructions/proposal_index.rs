@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GovernanceError;
+use crate::instructions::admin::authorize_admin_call;
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Create the proposal index and start recording proposal status changes in it
+#[derive(Accounts)]
+pub struct InitializeProposalIndex<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProposalIndex::INIT_SPACE,
+        seeds = [PROPOSAL_INDEX_SEED],
+        bump
+    )]
+    pub proposal_index: Account<'info, ProposalIndex>,
+
+    /// Governance configuration account (proposal_index_enabled is set here)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can enable the index
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Initialize proposal index handler
+/// From here on create, resubmit, finalize and execute require the index account
+pub fn initialize_proposal_index(ctx: Context<InitializeProposalIndex>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::InitializeProposalIndex,
+    )?;
+
+    let proposal_index = &mut ctx.accounts.proposal_index;
+    proposal_index.pending = Vec::new();
+    proposal_index.passed = Vec::new();
+    proposal_index.closed = Vec::new();
+    proposal_index.updated_at = Clock::get()?.unix_timestamp;
+    proposal_index.bump = ctx.bumps.proposal_index;
+
+    ctx.accounts.governance_config.proposal_index_enabled = true;
+
+    msg!("Proposal index initialized");
+    Ok(())
+}
+
+/// Close the proposal index and stop recording proposal status changes
+#[derive(Accounts)]
+pub struct CloseProposalIndex<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PROPOSAL_INDEX_SEED],
+        bump = proposal_index.bump
+    )]
+    pub proposal_index: Account<'info, ProposalIndex>,
+
+    /// Governance configuration account (proposal_index_enabled is cleared here)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can disable the index (receives the rent)
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Close proposal index handler
+/// A later initialize_proposal_index starts from an empty index
+pub fn close_proposal_index(ctx: Context<CloseProposalIndex>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::CloseProposalIndex,
+    )?;
+
+    ctx.accounts.governance_config.proposal_index_enabled = false;
+
+    msg!("Proposal index closed");
+    Ok(())
+}
+
+/// Read a page of the proposal index
+#[derive(Accounts)]
+pub struct GetProposalIndexPage<'info> {
+    #[account(
+        seeds = [PROPOSAL_INDEX_SEED],
+        bump = proposal_index.bump
+    )]
+    pub proposal_index: Account<'info, ProposalIndex>,
+}
+
+/// Return data of get_proposal_index_page
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProposalIdPage {
+    /// IDs in the whole list
+    pub total: u32,
+    /// Position of the first returned ID in the list
+    pub offset: u32,
+    /// Proposal IDs, in the order they entered the list
+    pub ids: Vec<u64>,
+}
+
+/// Get proposal index page handler
+/// Returns up to `limit` IDs (at most MAX_PROPOSAL_INDEX_PAGE) starting at `offset`
+pub fn get_proposal_index_page(
+    ctx: Context<GetProposalIndexPage>,
+    list: ProposalIndexList,
+    offset: u32,
+    limit: u32,
+) -> Result<ProposalIdPage> {
+    let ids = ctx.accounts.proposal_index.list(list);
+    let start = (offset as usize).min(ids.len());
+    let end = start
+        .saturating_add(limit.min(MAX_PROPOSAL_INDEX_PAGE) as usize)
+        .min(ids.len());
+
+    Ok(ProposalIdPage {
+        total: ids.len() as u32,
+        offset,
+        ids: ids[start..end].to_vec(),
+    })
+}
+
+/// Record a proposal status change in the index while it is enabled
+/// `previous` is None for newly created proposals
+pub fn record_proposal_status(
+    governance_config: &GovernanceConfig,
+    proposal_index: Option<&mut Account<ProposalIndex>>,
+    proposal_id: u64,
+    previous: Option<&ProposalStatus>,
+    status: &ProposalStatus,
+    now: i64,
+) -> Result<()> {
+    if !governance_config.proposal_index_enabled {
+        return Ok(());
+    }
+
+    proposal_index
+        .ok_or(GovernanceError::ProposalIndexRequired)?
+        .record(proposal_id, previous, status, now)
+}
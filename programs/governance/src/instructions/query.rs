@@ -1,14 +1,38 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::error::GovernanceError;
+use crate::instructions::common::*;
+use crate::instructions::proposal::calculate_voting_results_from_votes;
+use crate::state::*;
 
 /// Query voting power and statistics for a proposal
 #[derive(Accounts)]
+#[instruction(proposal_id: u64)]
 pub struct QueryVotingPower<'info> {
-    /// Clock sysvar for timestamp
-    pub clock: Sysvar<'info, Clock>,
+    /// Queried proposal (read-only; the tally is recomputed from the vote accounts)
+    #[account(
+        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Committee token mint (voting power is measured in its balances)
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
 }
 
-/// Event emitted when voting power is queried
+/// Event emitted when voting power is queried (also the instruction's return data)
 #[event]
+#[derive(Clone, Debug)]
 pub struct VotingPowerQueried {
     /// Proposal ID that was queried
     pub proposal_id: u64,
@@ -32,70 +56,622 @@ pub struct VotingPowerQueried {
     pub veto_rate: u16,
     /// Query timestamp
     pub timestamp: i64,
+    /// Status finalize_proposal would set from this tally
+    pub projected_status: ProposalStatus,
 }
 
 /// Query voting power and statistics for a proposal
 /// This is a read-only instruction that uses the same logic as finalize_proposal
 /// to ensure consistency in voting power calculations.
+/// CPI callers read the result with get_return_data.
 ///
 /// Expected remaining_accounts order:
-/// 1. Committee member token accounts (first N accounts, where N = committee_member_count)
+/// 1. (CommitteeMember PDA, member token account) pairs (first 2N accounts,
+///    where N = committee_member_count), each followed by the member's VoterStats PDA
+///    while the participation bonus is enabled (3N accounts)
 /// 2. Vote accounts for this proposal (remaining accounts)
 pub fn query_voting_power<'info>(
     ctx: Context<'_, '_, 'info, 'info, QueryVotingPower<'info>>,
     proposal_id: u64,
-) -> Result<()> {
-    msg!("=== Query Voting Power Start ===");
-    msg!("Query voting power for proposal {}", proposal_id);
-    msg!("Remaining accounts count: {}", ctx.remaining_accounts.len());
-
-    // Simplified implementation: return fixed voting statistics data
-    // This data matches the actual voting results for proposal 193
-    let total_voting_power = 100u64;
-    let yes_votes = 55u64;
-    let no_votes = 0u64;
-    let abstain_votes = 0u64;
-    let veto_votes = 20u64;
-    let total_votes = yes_votes + no_votes + abstain_votes + veto_votes;
-
-    // Calculate percentages (in basis points)
-    let participation_rate = if total_voting_power > 0 {
-        ((total_votes * 10000) / total_voting_power) as u16
-    } else {
-        0u16
-    };
+) -> Result<VotingPowerQueried> {
+    let governance_config = &ctx.accounts.governance_config;
+    let (total_voting_power, vote_stats) =
+        tally_votes(ctx.accounts, ctx.remaining_accounts, proposal_id)?;
 
-    let approval_rate = if total_votes > 0 {
-        ((yes_votes * 10000) / total_votes) as u16
-    } else {
-        0u16
+    let query = VotingPowerQueried {
+        proposal_id,
+        total_voting_power,
+        yes_votes: vote_stats.yes_votes,
+        no_votes: vote_stats.no_votes,
+        abstain_votes: vote_stats.abstain_votes,
+        veto_votes: vote_stats.veto_votes,
+        total_votes: vote_stats.total_votes,
+        participation_rate: vote_stats
+            .calculate_participation_rate(total_voting_power, governance_config.rate_rounding),
+        approval_rate: vote_stats.calculate_approval_rate(governance_config.rate_rounding),
+        veto_rate: vote_stats.calculate_veto_rate(governance_config.rate_rounding),
+        timestamp: Clock::get()?.unix_timestamp,
+        projected_status: governance_config
+            .determine_proposal_status_with_vote_stats(&vote_stats, total_voting_power),
     };
 
-    let veto_rate = if total_votes > 0 {
-        ((veto_votes * 10000) / total_votes) as u16
-    } else {
-        0u16
-    };
+    emit!(query.clone());
+    Ok(query)
+}
+
+/// Tally a proposal's votes exactly like finalize_proposal
+/// Returns (total_voting_power, vote_stats)
+fn tally_votes<'info>(
+    accounts: &QueryVotingPower<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    proposal_id: u64,
+) -> Result<(u64, VoteStats)> {
+    let proposal = accounts.proposal.load()?;
+    let (total_voting_power, vote_tally) = calculate_voting_results_from_votes(
+        &accounts.governance_config,
+        &accounts.committee_token_mint,
+        remaining_accounts,
+        proposal_id,
+        proposal.created_at,
+        proposal.recused_members(),
+    )?;
+    Ok((total_voting_power, vote_tally.vote_stats()))
+}
+
+/// Event emitted by preview_finalize (also the instruction's return data)
+/// Margins are rate minus threshold in basis points: participation needs a margin of at
+/// least 0, approval more than 0, and a veto margin of 0 or more vetoes the proposal
+#[event]
+#[derive(Clone, Debug)]
+pub struct FinalizePreviewed {
+    /// Proposal ID that was previewed
+    pub proposal_id: u64,
+    /// Status finalize_proposal would set if run now
+    pub status: ProposalStatus,
+    /// Whether the voting period has ended (finalize_proposal can actually run)
+    pub voting_ended: bool,
+    /// Total voting power (dynamically calculated)
+    pub total_voting_power: u64,
+    /// Participation rate (basis points)
+    pub participation_rate: u16,
+    /// Approval rate (basis points)
+    pub approval_rate: u16,
+    /// Veto rate (basis points)
+    pub veto_rate: u16,
+    /// Participation rate minus the participation threshold
+    pub participation_margin: i32,
+    /// Approval rate minus the approval threshold
+    pub approval_margin: i32,
+    /// Veto rate minus the veto threshold
+    pub veto_margin: i32,
+    /// Preview timestamp
+    pub timestamp: i64,
+}
+
+/// Preview finalize handler
+/// Reports the status the proposal would get if finalized now, before voting_end too
+/// Read-only: never writes the proposal or moves deposits
+/// Takes the same remaining_accounts as finalize_proposal
+pub fn preview_finalize<'info>(
+    ctx: Context<'_, '_, 'info, 'info, QueryVotingPower<'info>>,
+    proposal_id: u64,
+) -> Result<FinalizePreviewed> {
+    let proposal = &ctx.accounts.proposal.load()?;
+    require!(proposal.is_votable(), GovernanceError::ProposalNotActive);
 
-    // Emit query result event
-    emit!(VotingPowerQueried {
+    let governance_config = &ctx.accounts.governance_config;
+    let (total_voting_power, vote_stats) =
+        tally_votes(ctx.accounts, ctx.remaining_accounts, proposal_id)?;
+    let participation_rate = vote_stats
+        .calculate_participation_rate(total_voting_power, governance_config.rate_rounding);
+    let approval_rate = vote_stats.calculate_approval_rate(governance_config.rate_rounding);
+    let veto_rate = vote_stats.calculate_veto_rate(governance_config.rate_rounding);
+    let margin = |rate: u16, threshold: u16| i32::from(rate) - i32::from(threshold);
+
+    let preview = FinalizePreviewed {
         proposal_id,
+        status: governance_config
+            .determine_proposal_status_with_vote_stats(&vote_stats, total_voting_power),
+        voting_ended: proposal.is_voting_ended(Clock::get()?.unix_timestamp),
         total_voting_power,
-        yes_votes,
-        no_votes,
-        abstain_votes,
-        veto_votes,
-        total_votes,
         participation_rate,
         approval_rate,
         veto_rate,
+        participation_margin: margin(
+            participation_rate,
+            governance_config.participation_threshold,
+        ),
+        approval_margin: margin(approval_rate, governance_config.approval_threshold),
+        veto_margin: margin(veto_rate, governance_config.veto_threshold),
         timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    emit!(preview.clone());
+    Ok(preview)
+}
+
+/// Get the ID (and therefore PDA seed) the next created proposal must use
+#[derive(Accounts)]
+pub struct GetNextProposalId<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+}
+
+/// Event emitted with the next proposal ID
+#[event]
+pub struct NextProposalIdQueried {
+    /// ID the next create_proposal call must pass
+    pub next_proposal_id: u64,
+    /// Number of proposals still pending
+    pub pending_proposal_count: u64,
+}
+
+/// Get next proposal ID handler
+/// Clients derive the proposal PDA from this ID and pass it to create_proposal
+pub fn get_next_proposal_id(ctx: Context<GetNextProposalId>) -> Result<u64> {
+    let governance_config = &ctx.accounts.governance_config;
+    let next_proposal_id = governance_config.proposal_counter + 1;
+
+    emit!(NextProposalIdQueried {
+        next_proposal_id,
+        pending_proposal_count: governance_config.pending_proposal_count,
     });
 
-    msg!(
-        "Query voting power event emitted for proposal {}",
-        proposal_id
+    Ok(next_proposal_id)
+}
+
+/// Read a summary of the governance state
+#[derive(Accounts)]
+pub struct GetGovernanceState<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Rule registry, included in the summary when passed
+    #[account(
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
+        bump = rule_registry.bump
+    )]
+    pub rule_registry: Option<Account<'info, RuleRegistry>>,
+}
+
+/// Return data of get_governance_state
+/// Borsh encoded in field order; fields are only ever appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GovernanceStateSummary {
+    /// GovernanceConfig layout version
+    pub schema_version: u8,
+    /// Whether the config still needs migrate_governance_config
+    pub migration_required: bool,
+    /// Last assigned proposal ID
+    pub proposal_counter: u64,
+    /// Proposals still in Pending status
+    pub pending_proposal_count: u64,
+    /// Most proposals that may be Pending at once
+    pub max_active_proposals: u16,
+    /// Committee member count
+    pub committee_member_count: u8,
+    /// Most committee members the deployment seats
+    pub max_committee_size: u8,
+    /// Committee composition counter
+    pub committee_epoch: u64,
+    /// Total committee voting power, as of total_voting_power_synced_at
+    pub total_voting_power: u64,
+    /// Participation threshold (basis points)
+    pub participation_threshold: u16,
+    /// Approval threshold (basis points)
+    pub approval_threshold: u16,
+    /// Veto threshold (basis points)
+    pub veto_threshold: u16,
+    /// Committee fee rate (basis points)
+    pub fee_rate: u16,
+    /// Voting period in seconds
+    pub voting_period: u64,
+    /// Refundable deposits held across all deposit mints (base units of each mint)
+    pub total_deposits_locked: u64,
+    /// Test mode flag
+    pub test_mode: bool,
+    /// Whether privileged instructions go through the admin set
+    pub admin_multisig_enabled: bool,
+    /// Whether new proposals are refused (active proposal limit reached)
+    pub proposals_paused: bool,
+    /// Rule registry version (None when no registry was passed)
+    pub rule_registry_version: Option<u32>,
+    /// Rule documents in the registry (None when no registry was passed)
+    pub rule_document_count: Option<u32>,
+}
+
+/// Get governance state handler
+/// Read-only; CPI callers read the summary with get_return_data
+pub fn get_governance_state(ctx: Context<GetGovernanceState>) -> Result<GovernanceStateSummary> {
+    let governance_config = &ctx.accounts.governance_config;
+    let total_deposits_locked = governance_config
+        .deposit_mints
+        .iter()
+        .try_fold(0u64, |total, mint| {
+            total.checked_add(mint.total_deposits_locked)
+        })
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    let rule_registry = ctx.accounts.rule_registry.as_ref();
+
+    Ok(GovernanceStateSummary {
+        schema_version: governance_config.schema_version,
+        migration_required: !governance_config.is_current_schema(),
+        proposal_counter: governance_config.proposal_counter,
+        pending_proposal_count: governance_config.pending_proposal_count,
+        max_active_proposals: governance_config.max_active_proposals,
+        committee_member_count: governance_config.committee_member_count,
+        max_committee_size: governance_config.max_committee_size,
+        committee_epoch: governance_config.committee_epoch,
+        total_voting_power: governance_config.total_voting_power,
+        participation_threshold: governance_config.participation_threshold,
+        approval_threshold: governance_config.approval_threshold,
+        veto_threshold: governance_config.veto_threshold,
+        fee_rate: governance_config.fee_rate,
+        voting_period: governance_config.voting_period,
+        total_deposits_locked,
+        test_mode: governance_config.test_mode,
+        admin_multisig_enabled: governance_config.admin_multisig_enabled,
+        proposals_paused: governance_config.pending_proposal_count
+            >= governance_config.max_active_proposals as u64,
+        rule_registry_version: rule_registry.map(|registry| registry.version),
+        rule_document_count: rule_registry.map(|registry| registry.rule_documents.len() as u32),
+    })
+}
+
+/// Read one proposal
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct GetProposal<'info> {
+    #[account(
+        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+}
+
+/// Return data of get_proposal_summary (at most 55 bytes)
+/// Borsh encoded in field order; fields are only ever appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProposalSummary {
+    /// Proposal ID
+    pub id: u64,
+    /// Current status
+    pub status: ProposalStatus,
+    /// Proposal type
+    pub proposal_type: ProposalType,
+    /// Arbitration decision (dispute proposals with a decision only)
+    pub arbitration_decision: Option<ArbitrationDecision>,
+    /// Execution time (None until executed)
+    pub executed_at: Option<i64>,
+    /// Yes votes
+    pub yes: u64,
+    /// No votes
+    pub no: u64,
+    /// Veto votes
+    pub veto: u64,
+    /// Committee voting power the proposal was finalized against (0 until finalized)
+    pub total_power: u64,
+    /// Winning option of a passed multi-choice proposal
+    pub winning_option: Option<u8>,
+}
+
+/// Get proposal summary handler
+/// Read-only; CPI callers read the summary with get_return_data
+pub fn get_proposal_summary(
+    ctx: Context<GetProposal>,
+    proposal_id: u64,
+) -> Result<ProposalSummary> {
+    let proposal = &ctx.accounts.proposal.load()?;
+    let arbitration_decision = match proposal.execution_data()? {
+        Some(ExecutionData::Dispute(data)) => data.arbitration_decision,
+        _ => None,
+    };
+
+    Ok(ProposalSummary {
+        id: proposal_id,
+        status: proposal.status()?,
+        proposal_type: proposal.proposal_type()?,
+        arbitration_decision,
+        executed_at: proposal.executed_at(),
+        yes: proposal.yes_votes,
+        no: proposal.no_votes,
+        veto: proposal.veto_votes,
+        total_power: proposal.finalized_voting_power,
+        winning_option: proposal.winning_option(),
+    })
+}
+
+/// Has passed handler
+/// True once the proposal is Passed or Executed
+pub fn has_passed(ctx: Context<GetProposal>, proposal_id: u64) -> Result<bool> {
+    let passed = matches!(
+        ctx.accounts.proposal.load()?.status()?,
+        ProposalStatus::Passed | ProposalStatus::Executed
+    );
+
+    msg!("Proposal {} passed: {}", proposal_id, passed);
+    Ok(passed)
+}
+
+/// Read one vote
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, voter: Pubkey)]
+pub struct GetVote<'info> {
+    /// Voter's vote on the proposal (may not exist; checked in the handler)
+    /// CHECK: Address is fixed by seeds; deserialized as Vote when owned by this program
+    #[account(
+        seeds = [VOTE_SEED, proposal_id.to_le_bytes().as_ref(), voter.as_ref()],
+        bump
+    )]
+    pub vote: UncheckedAccount<'info>,
+}
+
+/// Return data of get_vote
+/// Borsh encoded in field order; fields are only ever appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VoteReceipt {
+    /// Vote type
+    pub vote_type: VoteType,
+    /// Voter token balance snapshot
+    pub token_balance_snapshot: u64,
+    /// Vote time
+    pub timestamp: i64,
+    /// Whether the vote was revoked (revoked votes are not tallied)
+    pub is_revoked: bool,
+}
+
+/// Get vote handler
+/// Read-only; fails with VoteNotFound when the voter has not voted on the proposal
+pub fn get_vote(ctx: Context<GetVote>, proposal_id: u64, voter: Pubkey) -> Result<VoteReceipt> {
+    let vote_info = &ctx.accounts.vote;
+    require!(
+        vote_info.owner == &crate::ID && !vote_info.data_is_empty(),
+        GovernanceError::VoteNotFound
     );
+    let vote = Vote::try_deserialize(&mut vote_info.data.borrow().as_ref())
+        .map_err(|_| GovernanceError::InvalidAccountData)?;
+
+    msg!("Vote of {} on proposal {} read", voter, proposal_id);
+    Ok(VoteReceipt {
+        vote_type: vote.vote_type,
+        token_balance_snapshot: vote.token_balance_snapshot,
+        timestamp: vote.timestamp,
+        is_revoked: vote.is_revoked,
+    })
+}
+
+/// Read the queued configuration update
+#[derive(Accounts)]
+pub struct GetPendingConfigUpdate<'info> {
+    /// Queued update (may not exist; checked in the handler)
+    /// CHECK: Address is fixed by seeds; deserialized as PendingConfigUpdate when owned by this program
+    #[account(
+        seeds = [PENDING_CONFIG_UPDATE_SEED],
+        bump
+    )]
+    pub pending_config_update: UncheckedAccount<'info>,
+}
+
+/// Return data of get_pending_config_update
+/// Borsh encoded in field order; fields are only ever appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PendingConfigUpdateSummary {
+    /// Queued update
+    pub update: GovernanceConfigUpdate,
+    /// Config admin that queued the update
+    pub queued_by: Pubkey,
+    /// Queue timestamp
+    pub queued_at: i64,
+    /// Earliest time the update can be applied
+    pub eta: i64,
+    /// Whether the eta has passed
+    pub ready: bool,
+}
+
+/// Get pending config update handler
+/// Read-only; None when no update is queued
+pub fn get_pending_config_update(
+    ctx: Context<GetPendingConfigUpdate>,
+) -> Result<Option<PendingConfigUpdateSummary>> {
+    let pending_info = &ctx.accounts.pending_config_update;
+    if pending_info.owner != &crate::ID || pending_info.data_is_empty() {
+        return Ok(None);
+    }
+    let pending = PendingConfigUpdate::try_deserialize(&mut pending_info.data.borrow().as_ref())
+        .map_err(|_| GovernanceError::InvalidAccountData)?;
+
+    Ok(Some(PendingConfigUpdateSummary {
+        ready: Clock::get()?.unix_timestamp >= pending.eta,
+        update: pending.update,
+        queued_by: pending.queued_by,
+        queued_at: pending.queued_at,
+        eta: pending.eta,
+    }))
+}
+
+/// Read the program constants (the config account only anchors the call to a deployment)
+#[derive(Accounts)]
+pub struct GetProgramConstants<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+}
+
+/// Return data of get_program_constants, for clients that cannot read the IDL
+/// Account sizes include the 8-byte discriminator and change with the account layouts
+/// Borsh encoded in field order; fields are only ever appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProgramConstants {
+    /// GovernanceConfig layout version
+    pub governance_config_schema_version: u8,
+    /// Event layout version
+    pub event_schema_version: u8,
+    /// GovernanceConfig account size
+    pub governance_config_account_size: u32,
+    /// Proposal account size
+    pub proposal_account_size: u32,
+    /// Vote account size
+    pub vote_account_size: u32,
+    /// ProposalOutcome account size
+    pub proposal_outcome_account_size: u32,
+    /// CommitteeMember account size
+    pub committee_member_account_size: u32,
+    /// Maximum proposal title length in UTF-8 bytes
+    pub max_proposal_title_bytes: u32,
+    /// Maximum proposal description length in UTF-8 bytes
+    pub max_proposal_description_bytes: u32,
+    /// Maximum committee size
+    pub max_committee_members: u32,
+    /// Maximum number of documents in the rule registry
+    pub max_rule_documents: u32,
+}
+
+/// Get program constants handler
+pub fn get_program_constants(_ctx: Context<GetProgramConstants>) -> Result<ProgramConstants> {
+    Ok(ProgramConstants {
+        governance_config_schema_version: GOVERNANCE_CONFIG_SCHEMA_VERSION,
+        event_schema_version: crate::events::EVENT_SCHEMA_VERSION,
+        governance_config_account_size: (8 + GovernanceConfig::INIT_SPACE) as u32,
+        proposal_account_size: (8 + Proposal::INIT_SPACE) as u32,
+        vote_account_size: (8 + Vote::INIT_SPACE) as u32,
+        proposal_outcome_account_size: (8 + ProposalOutcome::INIT_SPACE) as u32,
+        committee_member_account_size: (8 + CommitteeMember::INIT_SPACE) as u32,
+        max_proposal_title_bytes: MAX_PROPOSAL_TITLE_BYTES,
+        max_proposal_description_bytes: MAX_PROPOSAL_DESCRIPTION_BYTES,
+        max_committee_members: MAX_COMMITTEE_MEMBERS,
+        max_rule_documents: crate::constants::MAX_RULE_DOCUMENTS,
+    })
+}
+
+/// Dry-run validation of proposal execution data
+#[derive(Accounts)]
+pub struct ValidateExecutionData<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Rule registry a rule update targets, checked against the update when passed
+    #[account(
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
+        bump = rule_registry.bump
+    )]
+    pub rule_registry: Option<Account<'info, RuleRegistry>>,
+
+    /// Execution adapter registry (may not exist yet)
+    /// CHECK: Address is fixed by seeds; deserialized only when owned by this program
+    #[account(
+        seeds = [EXECUTION_ADAPTER_REGISTRY_SEED],
+        bump
+    )]
+    pub execution_adapter_registry: UncheckedAccount<'info>,
+}
+
+/// Return data of validate_execution_data
+/// Checks that do not apply to the payload (or need an account that was not passed) are None
+/// Borsh encoded in field order; fields are only ever appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ExecutionDataValidation {
+    /// Whether create_proposal would accept the payload and every applicable check passed
+    pub valid: bool,
+    /// create_proposal's execution data validation
+    pub payload_valid: bool,
+    /// Whether the payload variant matches the proposal type (None without execution data)
+    pub matches_proposal_type: Option<bool>,
+    /// Rule update: targeted document exists, or the registry has room for an added one
+    pub rule_registry_valid: Option<bool>,
+    /// Config update: update fields pass the validation update_governance_config applies
+    pub config_update_valid: Option<bool>,
+    /// Adapter program invoked when a proposal of this type executes (None: no adapter)
+    pub execution_adapter: Option<Pubkey>,
+}
+
+/// Validate execution data handler
+/// Read-only; runs create_proposal's payload validation plus the execution checks that do
+/// not depend on the proposal's final state, and reports each result instead of failing
+pub fn check_execution_data(
+    ctx: Context<ValidateExecutionData>,
+    proposal_type: ProposalType,
+    execution_data: Option<ExecutionData>,
+) -> Result<ExecutionDataValidation> {
+    let governance_config = &ctx.accounts.governance_config;
+    let payload_valid = validate_execution_data(&execution_data, governance_config).is_ok();
+    let matches_proposal_type = execution_data
+        .as_ref()
+        .map(|data| execution_data_type(data) == proposal_type);
+
+    let rule_registry_valid = match (&execution_data, ctx.accounts.rule_registry.as_ref()) {
+        (Some(ExecutionData::RuleUpdate(update)), Some(registry)) => {
+            Some(rule_update_fits_registry(update, registry))
+        }
+        _ => None,
+    };
+    let config_update_valid = match &execution_data {
+        Some(ExecutionData::ConfigUpdate(data)) => Some(
+            data.config_update
+                .validate(governance_config.test_mode)
+                .is_ok(),
+        ),
+        _ => None,
+    };
+    let execution_adapter =
+        ExecutionAdapterRegistry::try_load(&ctx.accounts.execution_adapter_registry)?
+            .and_then(|registry| registry.adapter_for(&proposal_type));
+
+    let valid = payload_valid
+        && [
+            matches_proposal_type,
+            rule_registry_valid,
+            config_update_valid,
+        ]
+        .iter()
+        .all(|check| *check != Some(false));
+
+    msg!("Execution data for {:?} valid: {}", proposal_type, valid);
+    Ok(ExecutionDataValidation {
+        valid,
+        payload_valid,
+        matches_proposal_type,
+        rule_registry_valid,
+        config_update_valid,
+        execution_adapter,
+    })
+}
+
+/// Proposal type an execution data variant belongs to
+fn execution_data_type(execution_data: &ExecutionData) -> ProposalType {
+    match execution_data {
+        ExecutionData::Slash(_) => ProposalType::SlashMerchant,
+        ExecutionData::Dispute(_) => ProposalType::DisputeArbitration,
+        ExecutionData::RuleUpdate(_) => ProposalType::RuleUpdate,
+        ExecutionData::ConfigUpdate(_) => ProposalType::ConfigUpdate,
+        ExecutionData::CommitteeChange(_) => ProposalType::CommitteeChange,
+        ExecutionData::ProgramUpgrade(_) => ProposalType::ProgramUpgrade,
+    }
+}
 
-    Ok(())
+/// Whether a rule update targets this registry and an existing document (or, when adding,
+/// brings a document the registry has room for)
+fn rule_update_fits_registry(update: &RuleUpdateData, registry: &RuleRegistry) -> bool {
+    let document_exists = update
+        .document_id
+        .is_some_and(|document_id| registry.document(document_id).is_some());
+    registry.name == update.registry_name
+        && match update.operation {
+            RuleOperation::Add => {
+                update.document_data.is_some()
+                    && (registry.rule_documents.len() as u32) < crate::constants::MAX_RULE_DOCUMENTS
+            }
+            RuleOperation::Update => document_exists && update.document_data.is_some(),
+            RuleOperation::Remove => document_exists,
+        }
 }
@@ -1,17 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::MAX_RETURN_DATA;
 
 use crate::error::GovernanceError;
+use crate::events::*;
 use crate::instructions::common::*;
 use crate::state::*;
 
 /// Create rule registry
 #[derive(Accounts)]
+#[instruction(name: String)]
 pub struct CreateRuleRegistry<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + RuleRegistry::INIT_SPACE, // Initially empty
-        seeds = [RULE_REGISTRY_SEED],
+        space = RuleRegistry::account_size_for_name(&name)?, // Initially empty
+        seeds = [RULE_REGISTRY_SEED, name.as_bytes()],
         bump
     )]
     pub rule_registry: Account<'info, RuleRegistry>,
@@ -23,10 +26,10 @@ pub struct CreateRuleRegistry<'info> {
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can create rule registry
+    /// Only the rule admin can create the rule registry
     #[account(
         mut,
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = authority.key() == governance_config.rule_admin @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -34,7 +37,8 @@ pub struct CreateRuleRegistry<'info> {
 }
 
 /// Create rule registry handler
-pub fn create_rule_registry(ctx: Context<CreateRuleRegistry>) -> Result<()> {
+/// The empty name addresses the original registry at [RULE_REGISTRY_SEED]
+pub fn create_rule_registry(ctx: Context<CreateRuleRegistry>, name: String) -> Result<()> {
     let rule_registry = &mut ctx.accounts.rule_registry;
     let clock = Clock::get()?;
 
@@ -44,8 +48,13 @@ pub fn create_rule_registry(ctx: Context<CreateRuleRegistry>) -> Result<()> {
     rule_registry.version = 1;
     rule_registry.created_at = clock.unix_timestamp;
     rule_registry.bump = ctx.bumps.rule_registry;
+    rule_registry.next_document_id = 1;
+    rule_registry.name = name;
 
-    msg!("Rule registry created successfully");
+    msg!(
+        "Rule registry '{}' created successfully",
+        rule_registry.name
+    );
     Ok(())
 }
 
@@ -55,25 +64,31 @@ pub fn create_rule_registry(ctx: Context<CreateRuleRegistry>) -> Result<()> {
 pub struct AddRuleDocument<'info> {
     #[account(
         mut,
-        seeds = [RULE_REGISTRY_SEED],
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
         bump = rule_registry.bump,
-        realloc = 8 + RuleRegistry::INIT_SPACE,
+        realloc = rule_registry.account_size_after_add(&category, &title, &url, &hash)?,
         realloc::payer = authority,
         realloc::zero = false
     )]
     pub rule_registry: Account<'info, RuleRegistry>,
 
-    /// Governance configuration account for permission verification
+    /// Governance configuration account for permission verification and the scheduling horizon
     #[account(
         seeds = [GOVERNANCE_CONFIG_SEED],
-        bump = governance_config.bump
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired,
+        constraint = rule_registry.authority == governance_config.authority @ GovernanceError::RuleRegistryAuthorityMismatch
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can add rule documents
+    /// The rule admin, or the category's admin (pays for the grown registry)
     #[account(
         mut,
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = rule_registry.can_manage_category(
+            &category,
+            &authority.key(),
+            &governance_config.rule_admin
+        ) @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -87,27 +102,158 @@ pub fn add_rule_document(
     title: String,
     url: String,
     hash: String,
+    effective_from: Option<i64>,
+    allow_custom_category: bool,
 ) -> Result<()> {
+    let document = new_rule_document(
+        &ctx.accounts.governance_config,
+        RuleDocumentInput {
+            category,
+            title,
+            url,
+            hash,
+            effective_from,
+            allow_custom_category,
+        },
+    )?;
+
+    // Add document to registry
+    let category = document.category.clone();
+    let hash = document.hash.clone();
     let rule_registry = &mut ctx.accounts.rule_registry;
+    let document_id = rule_registry.add_document(document)?;
+
+    emit!(RuleDocumentAdded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: ctx.accounts.governance_config.key(),
+        rule_registry: rule_registry.key(),
+        document_id,
+        category,
+        hash,
+        registry_version: rule_registry.version,
+        timestamp: rule_registry.last_updated,
+    });
+
+    msg!(
+        "Rule document {} added successfully, version: {}",
+        document_id,
+        rule_registry.version
+    );
+    Ok(())
+}
+
+/// Build a rule document from its input after validating the fields and effective date
+fn new_rule_document(
+    governance_config: &GovernanceConfig,
+    input: RuleDocumentInput,
+) -> Result<RuleDocument> {
+    if let Some(effective_from) = input.effective_from {
+        RuleDocument::require_effective_within(
+            effective_from,
+            Clock::get()?.unix_timestamp,
+            governance_config.max_rule_effective_delay,
+        )?;
+    }
+
+    // Known categories are stored by their canonical string
+    let category = RuleCategory::validate(&input.category, input.allow_custom_category)?;
 
     // Create new rule document
-    let document = RuleDocument::new(category, title, url, hash)?;
+    let document = RuleDocument::new(
+        category,
+        input.title,
+        input.url,
+        input.hash,
+        input.effective_from,
+    )?;
 
     // Validate URL and hash format using common functions
     require!(
-        validate_url(&document.url),
+        validate_url_format(&document.url, governance_config.strict_url_validation),
         GovernanceError::InvalidUrlFormat
     );
     require!(
         validate_hash(&document.hash),
         GovernanceError::InvalidHashFormat
     );
+    Ok(document)
+}
 
-    // Add document to registry
-    rule_registry.add_document(document)?;
+/// Add several rule documents in one instruction
+#[derive(Accounts)]
+#[instruction(documents: Vec<RuleDocumentInput>)]
+pub struct AddRuleDocumentsBatch<'info> {
+    #[account(
+        mut,
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
+        bump = rule_registry.bump,
+        constraint = (1..=MAX_RULE_DOCUMENT_BATCH).contains(&documents.len())
+            @ GovernanceError::InvalidRuleDocumentBatch,
+        realloc = rule_registry.account_size_after_batch(&documents)?,
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub rule_registry: Account<'info, RuleRegistry>,
+
+    /// Governance configuration account for permission verification and the scheduling horizon
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired,
+        constraint = rule_registry.authority == governance_config.authority @ GovernanceError::RuleRegistryAuthorityMismatch
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// The rule admin, or the admin of every category in the batch (pays for the grown registry)
+    #[account(
+        mut,
+        constraint = documents.iter().all(|input| rule_registry.can_manage_category(
+            &input.category,
+            &authority.key(),
+            &governance_config.rule_admin
+        )) @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add rule documents batch handler
+/// Every document goes through the single-add checks; any failure rejects the whole batch
+pub fn add_rule_documents_batch(
+    ctx: Context<AddRuleDocumentsBatch>,
+    documents: Vec<RuleDocumentInput>,
+) -> Result<()> {
+    let count = documents.len();
+    let documents = documents
+        .into_iter()
+        .map(|input| new_rule_document(&ctx.accounts.governance_config, input))
+        .collect::<Result<Vec<_>>>()?;
+
+    let added: Vec<(String, String)> = documents
+        .iter()
+        .map(|document| (document.category.clone(), document.hash.clone()))
+        .collect();
+    let rule_registry = &mut ctx.accounts.rule_registry;
+    let first_document_id = rule_registry.add_documents(documents)?;
+
+    for (document_id, (category, hash)) in (first_document_id..).zip(added) {
+        emit!(RuleDocumentAdded {
+            schema_version: EVENT_SCHEMA_VERSION,
+            governance_config: ctx.accounts.governance_config.key(),
+            rule_registry: rule_registry.key(),
+            document_id,
+            category,
+            hash,
+            registry_version: rule_registry.version,
+            timestamp: rule_registry.last_updated,
+        });
+    }
 
     msg!(
-        "Rule document added successfully, version: {}",
+        "{} rule documents added from ID {}, version: {}",
+        count,
+        first_document_id,
         rule_registry.version
     );
     Ok(())
@@ -115,41 +261,69 @@ pub fn add_rule_document(
 
 /// Update rule document
 #[derive(Accounts)]
-#[instruction(document_index: u32)]
+#[instruction(document_id: u32, new_url: Option<String>, new_hash: Option<String>)]
 pub struct UpdateRuleDocument<'info> {
     #[account(
         mut,
-        seeds = [RULE_REGISTRY_SEED],
-        bump = rule_registry.bump
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
+        bump = rule_registry.bump,
+        realloc = rule_registry.account_size_after_update(
+            document_id,
+            new_url.as_deref(),
+            new_hash.as_deref()
+        )?,
+        realloc::payer = authority,
+        realloc::zero = false
     )]
     pub rule_registry: Account<'info, RuleRegistry>,
 
-    /// Governance configuration account for permission verification
+    /// Governance configuration account for permission verification and the scheduling horizon
     #[account(
         seeds = [GOVERNANCE_CONFIG_SEED],
-        bump = governance_config.bump
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired,
+        constraint = rule_registry.authority == governance_config.authority @ GovernanceError::RuleRegistryAuthorityMismatch
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can update rule documents
+    /// The rule admin, or the document category's admin (pays for or is refunded the resize)
     #[account(
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        mut,
+        constraint = rule_registry.can_manage_document(
+            document_id,
+            &authority.key(),
+            &governance_config.rule_admin
+        ) @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Update rule document handler
 pub fn update_rule_document(
     ctx: Context<UpdateRuleDocument>,
-    document_index: u32,
+    document_id: u32,
     new_url: Option<String>,
     new_hash: Option<String>,
+    new_effective_from: Option<i64>,
 ) -> Result<()> {
     let rule_registry = &mut ctx.accounts.rule_registry;
 
+    if let Some(effective_from) = new_effective_from {
+        RuleDocument::require_effective_within(
+            effective_from,
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.governance_config.max_rule_effective_delay,
+        )?;
+    }
+
     // Validate new URL and hash format using common functions
     if let Some(ref url) = new_url {
-        require!(validate_url(url), GovernanceError::InvalidUrlFormat);
+        require!(
+            validate_url_format(url, ctx.accounts.governance_config.strict_url_validation),
+            GovernanceError::InvalidUrlFormat
+        );
     }
 
     if let Some(ref hash) = new_hash {
@@ -157,10 +331,28 @@ pub fn update_rule_document(
     }
 
     // Update document
-    rule_registry.update_document(document_index as usize, new_url, new_hash)?;
+    rule_registry.update_document(document_id, new_url, new_hash, new_effective_from)?;
+
+    if let Some(document) = rule_registry
+        .rule_documents
+        .iter()
+        .find(|document| document.id == document_id)
+    {
+        emit!(RuleDocumentUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            governance_config: ctx.accounts.governance_config.key(),
+            rule_registry: rule_registry.key(),
+            document_id,
+            url: document.url.clone(),
+            hash: document.hash.clone(),
+            registry_version: rule_registry.version,
+            timestamp: rule_registry.last_updated,
+        });
+    }
 
     msg!(
-        "Rule document updated successfully, version: {}",
+        "Rule document {} updated successfully, version: {}",
+        document_id,
         rule_registry.version
     );
     Ok(())
@@ -168,13 +360,13 @@ pub fn update_rule_document(
 
 /// Remove rule document
 #[derive(Accounts)]
-#[instruction(document_index: u32)]
+#[instruction(document_id: u32)]
 pub struct RemoveRuleDocument<'info> {
     #[account(
         mut,
-        seeds = [RULE_REGISTRY_SEED],
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
         bump = rule_registry.bump,
-        realloc = 8 + RuleRegistry::INIT_SPACE,
+        realloc = rule_registry.account_size_after_remove(document_id)?,
         realloc::payer = authority,
         realloc::zero = false
     )]
@@ -183,14 +375,19 @@ pub struct RemoveRuleDocument<'info> {
     /// Governance configuration account for permission verification
     #[account(
         seeds = [GOVERNANCE_CONFIG_SEED],
-        bump = governance_config.bump
+        bump = governance_config.bump,
+        constraint = rule_registry.authority == governance_config.authority @ GovernanceError::RuleRegistryAuthorityMismatch
     )]
     pub governance_config: Account<'info, GovernanceConfig>,
 
-    /// Only administrator can remove rule documents
+    /// The rule admin, or the document category's admin (refunded the rent of the shrunk registry)
     #[account(
         mut,
-        constraint = authority.key() == governance_config.authority @ GovernanceError::Unauthorized
+        constraint = rule_registry.can_manage_document(
+            document_id,
+            &authority.key(),
+            &governance_config.rule_admin
+        ) @ GovernanceError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -198,30 +395,182 @@ pub struct RemoveRuleDocument<'info> {
 }
 
 /// Remove rule document handler
-pub fn remove_rule_document(ctx: Context<RemoveRuleDocument>, document_index: u32) -> Result<()> {
+pub fn remove_rule_document(ctx: Context<RemoveRuleDocument>, document_id: u32) -> Result<()> {
     let rule_registry = &mut ctx.accounts.rule_registry;
 
     // Remove document
-    rule_registry.remove_document(document_index as usize)?;
+    rule_registry.remove_document(document_id)?;
+
+    emit!(RuleDocumentRemoved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: ctx.accounts.governance_config.key(),
+        rule_registry: rule_registry.key(),
+        document_id,
+        registry_version: rule_registry.version,
+        timestamp: rule_registry.last_updated,
+    });
 
     msg!(
-        "Rule document removed successfully, version: {}",
+        "Rule document {} removed successfully, version: {}",
+        document_id,
         rule_registry.version
     );
     Ok(())
 }
 
+/// Set or remove the admin delegated to a rule category
+#[derive(Accounts)]
+#[instruction(category: String, admin: Option<Pubkey>)]
+pub struct SetCategoryAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
+        bump = rule_registry.bump,
+        realloc = rule_registry.account_size_after_category_admin(&category, admin)?,
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub rule_registry: Account<'info, RuleRegistry>,
+
+    /// Governance configuration account for permission verification
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = rule_registry.authority == governance_config.authority @ GovernanceError::RuleRegistryAuthorityMismatch
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only the rule admin can assign or remove category admins
+    #[account(
+        mut,
+        constraint = authority.key() == governance_config.rule_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set category admin handler
+pub fn set_category_admin(
+    ctx: Context<SetCategoryAdmin>,
+    category: String,
+    admin: Option<Pubkey>,
+) -> Result<()> {
+    let rule_registry = &mut ctx.accounts.rule_registry;
+    rule_registry.set_category_admin(&category, admin)?;
+
+    match admin {
+        Some(admin) => msg!("Category {} delegated to {}", category, admin),
+        None => msg!("Category {} admin removed", category),
+    }
+    Ok(())
+}
+
+/// Sync rule registry authority
+#[derive(Accounts)]
+pub struct SyncRuleRegistryAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
+        bump = rule_registry.bump
+    )]
+    pub rule_registry: Account<'info, RuleRegistry>,
+
+    /// Governance configuration account holding the current authority
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+}
+
+/// Sync rule registry authority handler
+/// Permissionless: repairs a registry left behind by a transfer accepted without it
+pub fn sync_rule_registry_authority(ctx: Context<SyncRuleRegistryAuthority>) -> Result<()> {
+    let authority = ctx.accounts.governance_config.authority;
+    let rule_registry = &mut ctx.accounts.rule_registry;
+    rule_registry.sync_authority(authority, Clock::get()?.unix_timestamp);
+
+    msg!("Rule registry authority synced to {}", authority);
+    Ok(())
+}
+
 /// Get rule documents information
 #[derive(Accounts)]
 pub struct GetRuleDocuments<'info> {
     #[account(
-        seeds = [RULE_REGISTRY_SEED],
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
         bump = rule_registry.bump
     )]
     pub rule_registry: Account<'info, RuleRegistry>,
 }
 
+/// Return data of get_rule_documents_page
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RuleDocumentPage {
+    /// Documents matching the filter across all pages
+    pub total: u32,
+    /// Position of the first returned document among the matching documents
+    pub offset: u32,
+    /// Requested page of matching documents
+    pub documents: Vec<RuleDocument>,
+}
+
+/// Get rule documents page handler
+/// Fails instead of truncating when the page does not fit in the return data limit
+pub fn get_rule_documents_page(
+    ctx: Context<GetRuleDocuments>,
+    offset: u32,
+    limit: u32,
+    category: Option<String>,
+    only_effective: bool,
+) -> Result<RuleDocumentPage> {
+    let (total, documents) = ctx.accounts.rule_registry.documents_page(
+        category.as_deref(),
+        only_effective,
+        Clock::get()?.unix_timestamp,
+        offset as usize,
+        limit as usize,
+    );
+    let page = RuleDocumentPage {
+        total,
+        offset,
+        documents,
+    };
+    let mut encoded = Vec::new();
+    page.serialize(&mut encoded)?;
+    require!(
+        encoded.len() <= MAX_RETURN_DATA,
+        GovernanceError::RuleDocumentPageTooLarge
+    );
+    Ok(page)
+}
+
+/// Get rule document history handler
+/// Returns the versions replaced by the document's recent updates, oldest first
+pub fn get_document_history(
+    ctx: Context<GetRuleDocuments>,
+    document_id: u32,
+) -> Result<Vec<RuleDocumentRevision>> {
+    let history = ctx
+        .accounts
+        .rule_registry
+        .document(document_id)
+        .ok_or(GovernanceError::RuleDocumentNotFound)?
+        .history
+        .clone();
+
+    let mut encoded = Vec::new();
+    history.serialize(&mut encoded)?;
+    require!(
+        encoded.len() <= MAX_RETURN_DATA,
+        GovernanceError::RuleDocumentHistoryTooLarge
+    );
+    Ok(history)
+}
+
 /// Get rule documents information handler
+/// Deprecated: the full list overflows the return data limit; use get_rule_documents_page
 pub fn get_rule_documents(ctx: Context<GetRuleDocuments>) -> Result<Vec<RuleDocument>> {
     let rule_registry = &ctx.accounts.rule_registry;
     Ok(rule_registry.rule_documents.clone())
@@ -231,7 +580,7 @@ pub fn get_rule_documents(ctx: Context<GetRuleDocuments>) -> Result<Vec<RuleDocu
 #[derive(Accounts)]
 pub struct VerifyRuleDocument<'info> {
     #[account(
-        seeds = [RULE_REGISTRY_SEED],
+        seeds = [RULE_REGISTRY_SEED, rule_registry.name.as_bytes()],
         bump = rule_registry.bump
     )]
     pub rule_registry: Account<'info, RuleRegistry>,
@@ -240,22 +589,28 @@ pub struct VerifyRuleDocument<'info> {
 /// Verify rule document hash handler
 pub fn verify_rule_document(
     ctx: Context<VerifyRuleDocument>,
-    document_index: u32,
+    document_id: u32,
     expected_hash: String,
 ) -> Result<bool> {
     let rule_registry = &ctx.accounts.rule_registry;
-    let is_valid = rule_registry.verify_document_hash(document_index as usize, &expected_hash);
+    let is_valid = rule_registry.verify_document_hash(document_id, &expected_hash);
 
     msg!("Document hash verification result: {}", is_valid);
     Ok(is_valid)
 }
 
-/// Find rule documents by category
+/// Find rule documents by category, optionally only those already in force
+/// Deprecated: the result is not paginated; use get_rule_documents_page with a category
 pub fn find_documents_by_category(
     ctx: Context<GetRuleDocuments>,
     category: String,
+    only_effective: bool,
 ) -> Result<Vec<RuleDocument>> {
     let rule_registry = &ctx.accounts.rule_registry;
-    let documents = rule_registry.find_documents_by_category(&category);
+    let documents = rule_registry.find_documents_by_category(
+        &category,
+        only_effective,
+        Clock::get()?.unix_timestamp,
+    );
     Ok(documents.into_iter().cloned().collect())
 }
@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::GovernanceError;
+use crate::instructions::common::*;
+use crate::instructions::proposal::{record_vote, VoteAccounts};
+use crate::state::*;
+
+/// Ed25519 program instruction header: signature count and padding, then one offsets entry
+const ED25519_HEADER_LEN: usize = 16;
+
+/// Vote signed off-chain by a committee member
+/// The voter signs this message's Borsh encoding
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SignedVoteMessage {
+    /// Governance config of the deployment the vote is for
+    pub governance_config: Pubkey,
+    /// Proposal voted on
+    pub proposal_id: u64,
+    /// Vote type
+    pub vote_type: VoteType,
+    /// Last slot the vote may be cast in
+    pub expiry_slot: u64,
+    /// Voter
+    pub voter: Pubkey,
+    /// Voter's current MemberNonce nonce; each relayed action uses it up
+    pub nonce: u64,
+}
+
+/// Cast a vote signed off-chain, submitted and paid for by any relayer
+/// The transaction must carry an ed25519 program instruction verifying the voter's
+/// signature over the message, right before this one
+#[derive(Accounts)]
+#[instruction(message: SignedVoteMessage)]
+pub struct CastVoteSigned<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, message.proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    /// Vote record; its existence makes a relayed message single use
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Vote::INIT_SPACE,
+        seeds = [VOTE_SEED, message.proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, Vote>,
+
+    /// Total voting power changes when the vote lifts an inactivity suspension
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+        constraint = governance_config.is_current_schema() @ GovernanceError::ConfigMigrationRequired,
+        constraint = governance_config.key() == message.governance_config @ GovernanceError::InvalidVoteSignature
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Voter (must be committee member; signs the message, not the transaction)
+    /// CHECK: Address constraint; the signature is checked against the ed25519 instruction
+    #[account(address = message.voter @ GovernanceError::InvalidVoteSignature)]
+    pub voter: UncheckedAccount<'info>,
+
+    /// Voter's committee member account (may not exist; checked in the handler)
+    /// CHECK: Deserialized as CommitteeMember when owned by this program
+    #[account(
+        mut,
+        seeds = [COMMITTEE_MEMBER_SEED, voter.key().as_ref()],
+        bump
+    )]
+    pub committee_member: UncheckedAccount<'info>,
+
+    /// Voter's signed message nonce (created by the voter's first relayed vote)
+    /// CHECK: Address is fixed by seeds; contents are read and created in consume_member_nonce
+    #[account(
+        mut,
+        seeds = [MEMBER_NONCE_SEED, voter.key().as_ref()],
+        bump
+    )]
+    pub member_nonce: UncheckedAccount<'info>,
+
+    /// Voter's token account
+    #[account(
+        associated_token::mint = governance_config.committee_token_mint,
+        associated_token::authority = voter,
+        associated_token::token_program = committee_token_program
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Voter's token lockup, snapshotted in place of the token account balance
+    #[account(
+        seeds = [LOCKUP_SEED, voter.key().as_ref()],
+        bump = lockup.bump
+    )]
+    pub lockup: Option<Account<'info, Lockup>>,
+
+    /// Committee token mint
+    #[account(
+        constraint = committee_token_mint.key() == governance_config.committee_token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub committee_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token program owning the committee token mint (legacy Token or Token-2022)
+    pub committee_token_program: Interface<'info, TokenInterface>,
+
+    /// Governance statistics (may be uninitialized; counters are skipped then)
+    /// CHECK: Address is fixed by seeds; contents are updated in record_governance_stats
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: UncheckedAccount<'info>,
+
+    /// Relayer submitting the vote (pays the fees and the vote and nonce account rent)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Instructions sysvar, for reading the ed25519 instruction
+    /// CHECK: Address constraint
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cast signed vote handler function
+/// Replays are refused by the member nonce, which also covers a vote revoked and closed
+/// after the message was relayed, and by the expiry slot
+pub fn cast_vote_signed(
+    mut ctx: Context<CastVoteSigned>,
+    message: SignedVoteMessage,
+) -> Result<()> {
+    require!(
+        Clock::get()?.slot <= message.expiry_slot,
+        GovernanceError::VoteMessageExpired
+    );
+    let mut signed_bytes = Vec::new();
+    message.serialize(&mut signed_bytes)?;
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &message.voter,
+        &signed_bytes,
+    )?;
+
+    consume_member_nonce(
+        &ctx.accounts.member_nonce,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        &message.voter,
+        message.nonce,
+        ctx.bumps.member_nonce,
+    )?;
+
+    let accounts = &mut ctx.accounts;
+    let payer = accounts.payer.key();
+    record_vote(
+        VoteAccounts {
+            proposal: &accounts.proposal,
+            vote: &mut accounts.vote,
+            governance_config: &mut accounts.governance_config,
+            committee_member: &accounts.committee_member,
+            voter_token_account: &accounts.voter_token_account,
+            lockup: accounts.lockup.as_deref(),
+            committee_token_mint: &accounts.committee_token_mint,
+            governance_stats: &accounts.governance_stats,
+        },
+        message.voter,
+        message.proposal_id,
+        message.vote_type,
+        ctx.bumps.vote,
+    )?;
+
+    msg!("Signed vote relayed by {}", payer);
+    Ok(())
+}
+
+/// Check a signed message's nonce against the member's current nonce and advance it
+/// The nonce account is created, at nonce 0, with the member's first relayed action
+fn consume_member_nonce<'info>(
+    member_nonce: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    member: &Pubkey,
+    nonce: u64,
+    bump: u8,
+) -> Result<()> {
+    let mut state = match MemberNonce::try_load(member_nonce)? {
+        Some(state) => state,
+        None => {
+            create_pda_account(
+                member_nonce,
+                payer,
+                system_program,
+                8 + MemberNonce::INIT_SPACE,
+                &[&[MEMBER_NONCE_SEED, member.as_ref(), &[bump]]],
+            )?;
+            MemberNonce {
+                member: *member,
+                nonce: 0,
+                bump,
+            }
+        }
+    };
+    require!(state.nonce == nonce, GovernanceError::InvalidVoteNonce);
+    state.nonce = state
+        .nonce
+        .checked_add(1)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    state.store(member_nonce)
+}
+
+/// Check that the instruction before the current one is an ed25519 program instruction
+/// verifying one signature by `signer` over `message`, with the signature, public key and
+/// message all stored in that instruction
+fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    require!(current_index > 0, GovernanceError::InvalidVoteSignature);
+    let instruction = load_instruction_at_checked(current_index - 1, instructions_sysvar)?;
+    require!(
+        instruction.program_id == ed25519_program::ID && instruction.accounts.is_empty(),
+        GovernanceError::InvalidVoteSignature
+    );
+
+    let data = &instruction.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN && data[0] == 1,
+        GovernanceError::InvalidVoteSignature
+    );
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]) as usize;
+    let (signature_index, public_key_index, message_index) =
+        (read_u16(4), read_u16(8), read_u16(14));
+    require!(
+        signature_index == u16::MAX as usize
+            && public_key_index == u16::MAX as usize
+            && message_index == u16::MAX as usize,
+        GovernanceError::InvalidVoteSignature
+    );
+
+    let public_key_offset = read_u16(6);
+    let (message_offset, message_size) = (read_u16(10), read_u16(12));
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(GovernanceError::InvalidVoteSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(GovernanceError::InvalidVoteSignature)?;
+    require!(
+        public_key == signer.as_ref() && signed_message == message,
+        GovernanceError::InvalidVoteSignature
+    );
+    Ok(())
+}
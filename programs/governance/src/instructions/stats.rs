@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GovernanceError;
+use crate::instructions::admin::authorize_admin_call;
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Create the governance statistics account for a deployment initialized without it
+#[derive(Accounts)]
+pub struct InitializeGovernanceStats<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GovernanceStats::INIT_SPACE,
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump
+    )]
+    pub governance_stats: Account<'info, GovernanceStats>,
+
+    /// Governance configuration account for permission verification
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Only administrator can create the statistics account
+    #[account(
+        mut,
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.config_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Initialize governance statistics handler
+/// Counting starts now; earlier activity is not included
+pub fn initialize_governance_stats(ctx: Context<InitializeGovernanceStats>) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::InitializeGovernanceStats,
+    )?;
+
+    init_governance_stats(
+        &mut ctx.accounts.governance_stats,
+        ctx.bumps.governance_stats,
+        Clock::get()?.unix_timestamp,
+    );
+
+    msg!("Governance statistics initialized");
+    Ok(())
+}
+
+/// Reset a newly created statistics account
+pub fn init_governance_stats(governance_stats: &mut GovernanceStats, bump: u8, now: i64) {
+    *governance_stats = GovernanceStats {
+        proposals_created: [0; PROPOSAL_TYPE_COUNT],
+        proposals_passed: 0,
+        proposals_rejected: 0,
+        proposals_vetoed: 0,
+        proposals_executed: 0,
+        total_deposits: 0,
+        total_fees: 0,
+        total_confiscated: 0,
+        total_refunds: 0,
+        votes_cast: 0,
+        updated_at: now,
+        bump,
+    };
+}
+
+/// Read the governance statistics
+#[derive(Accounts)]
+pub struct GetGovernanceStats<'info> {
+    #[account(
+        seeds = [GOVERNANCE_STATS_SEED],
+        bump = governance_stats.bump
+    )]
+    pub governance_stats: Account<'info, GovernanceStats>,
+}
+
+/// Get governance statistics handler
+pub fn get_governance_stats(ctx: Context<GetGovernanceStats>) -> Result<GovernanceStats> {
+    Ok((*ctx.accounts.governance_stats).clone())
+}
+
+/// Apply an update to the governance statistics, if the account has been initialized
+/// Never fails: a statistics account that cannot be loaded or written is skipped
+pub fn record_governance_stats(
+    governance_stats: &AccountInfo,
+    now: i64,
+    update: impl FnOnce(&mut GovernanceStats),
+) {
+    let mut stats = match GovernanceStats::try_load(governance_stats) {
+        Ok(Some(stats)) => stats,
+        Ok(None) => return,
+        Err(_) => {
+            msg!("Governance statistics not updated: account unreadable");
+            return;
+        }
+    };
+    update(&mut stats);
+    stats.updated_at = now;
+    if stats.store(governance_stats).is_err() {
+        msg!("Governance statistics not updated: account not writable");
+    }
+}
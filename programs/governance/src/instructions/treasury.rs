@@ -0,0 +1,727 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::error::GovernanceError;
+use crate::events::*;
+use crate::instructions::admin::authorize_admin_call;
+use crate::instructions::common::*;
+use crate::state::*;
+
+/// Direction of a treasury ledger entry relative to the vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// Tokens entered the vault
+    In,
+    /// Tokens left the vault (transferred or burned)
+    Out,
+    /// Tokens stayed in the vault and were attributed to the treasury
+    Internal,
+}
+
+/// Reason for a treasury ledger entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreasuryTransferReason {
+    ProposalDeposit,
+    DepositRefund,
+    RefundClaim,
+    CommitteeFee,
+    Confiscation,
+    ConfiscationBurn,
+    FeeWithdrawal,
+    FeeDistribution,
+    Donation,
+    ReconciliationSweep,
+    LegacyVaultMigration,
+    EscrowSettlement,
+}
+
+/// Emit a TreasuryTransfer entry, reloading the vault so the balance reflects any CPI
+pub fn emit_treasury_transfer(
+    governance_token_vault: &mut InterfaceAccount<TokenAccount>,
+    direction: TransferDirection,
+    reason: TreasuryTransferReason,
+    proposal_id: Option<u64>,
+    counterparty: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    governance_token_vault.reload()?;
+    emit!(TreasuryTransfer {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: derive_governance_config_address(),
+        mint: governance_token_vault.mint,
+        direction,
+        reason,
+        proposal_id,
+        counterparty,
+        amount,
+        vault_balance_after: governance_token_vault.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Emit a TreasuryTransfer entry for the native SOL deposit vault (balance in lamports)
+pub fn emit_native_treasury_transfer(
+    native_deposit_vault: &AccountInfo,
+    direction: TransferDirection,
+    reason: TreasuryTransferReason,
+    proposal_id: Option<u64>,
+    counterparty: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    emit!(TreasuryTransfer {
+        schema_version: EVENT_SCHEMA_VERSION,
+        governance_config: derive_governance_config_address(),
+        mint: NATIVE_SOL_DEPOSIT_MINT,
+        direction,
+        reason,
+        proposal_id,
+        counterparty,
+        amount,
+        vault_balance_after: native_deposit_vault.lamports(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Withdraw accumulated committee fees from a deposit vault
+#[derive(Accounts)]
+pub struct WithdrawCommitteeFees<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance system token vault for the withdrawn mint
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, governance_token_vault.mint.as_ref()],
+        bump
+    )]
+    pub governance_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Vault token mint
+    #[account(
+        constraint = mint.key() == governance_token_vault.mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Destination token account (same mint as the vault)
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == governance_token_vault.mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Governance system authority (for signing transfers)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Only the treasury admin can withdraw fees (admin set approval in multisig mode)
+    #[account(
+        constraint = authority.key() == governance_config.treasury_admin @ GovernanceError::Unauthorized,
+        constraint = !governance_config.admin_multisig_enabled @ GovernanceError::AdminMultisigRequired
+    )]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Event emitted when committee fees are withdrawn
+#[event]
+pub struct FeesWithdrawn {
+    /// Withdrawn token mint
+    pub mint: Pubkey,
+    /// Withdrawn amount
+    pub amount: u64,
+    /// Destination token account
+    pub destination: Pubkey,
+    /// Vault balance remaining after withdrawal
+    pub remaining_vault_balance: u64,
+    /// Refundable deposits still locked in the vault
+    pub total_deposits_locked: u64,
+    /// Withdrawal timestamp
+    pub timestamp: i64,
+}
+
+/// Withdraw committee fees handler
+/// Only treasury funds backed by the vault balance above refundable locked deposits can be withdrawn
+pub fn withdraw_committee_fees(ctx: Context<WithdrawCommitteeFees>, amount: u64) -> Result<()> {
+    require_withdrawable(
+        &ctx.accounts.governance_config,
+        &ctx.accounts.governance_token_vault,
+        amount,
+    )?;
+    transfer_treasury_withdrawal(
+        &mut ctx.accounts.governance_token_vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.governance_authority,
+        &ctx.accounts.token_program,
+        ctx.bumps.governance_authority,
+        amount,
+    )?;
+    record_fee_withdrawal(
+        &mut ctx.accounts.governance_config,
+        &ctx.accounts.governance_token_vault,
+        ctx.accounts.destination_token_account.key(),
+        amount,
+    )
+}
+
+/// Check that a withdrawal only takes treasury funds backed by the vault
+pub(crate) fn require_withdrawable(
+    governance_config: &GovernanceConfig,
+    governance_token_vault: &InterfaceAccount<TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    let withdrawable = governance_config
+        .withdrawable_amount(&governance_token_vault.mint, governance_token_vault.amount)?;
+    require!(
+        amount > 0 && amount <= withdrawable,
+        GovernanceError::InsufficientWithdrawableFees
+    );
+    Ok(())
+}
+
+/// Transfer a treasury withdrawal out of the vault, signed by the governance authority
+pub(crate) fn transfer_treasury_withdrawal<'info>(
+    governance_token_vault: &mut InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination_token_account: &InterfaceAccount<'info, TokenAccount>,
+    governance_authority: &UncheckedAccount<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[authority_bump]];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        TransferChecked {
+            from: governance_token_vault.to_account_info(),
+            mint: mint.to_account_info(),
+            to: destination_token_account.to_account_info(),
+            authority: governance_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, mint.decimals)?;
+
+    emit_treasury_transfer(
+        governance_token_vault,
+        TransferDirection::Out,
+        TreasuryTransferReason::FeeWithdrawal,
+        None,
+        destination_token_account.key(),
+        amount,
+    )
+}
+
+/// Debit a completed withdrawal from the treasury and emit FeesWithdrawn
+pub(crate) fn record_fee_withdrawal(
+    governance_config: &mut GovernanceConfig,
+    governance_token_vault: &InterfaceAccount<TokenAccount>,
+    destination: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let mint = governance_token_vault.mint;
+    governance_config.debit_treasury(&mint, amount)?;
+    let total_deposits_locked = governance_config
+        .get_deposit_mint(&mint)?
+        .total_deposits_locked;
+
+    emit!(FeesWithdrawn {
+        mint,
+        amount,
+        destination,
+        remaining_vault_balance: governance_token_vault.amount,
+        total_deposits_locked,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Withdrew {} committee fee tokens of mint {}", amount, mint);
+    Ok(())
+}
+
+/// Verify a deposit vault covers its locked deposits (permissionless)
+#[derive(Accounts)]
+pub struct VerifyVaultSolvency<'info> {
+    #[account(
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance system token vault to verify
+    #[account(
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, governance_token_vault.mint.as_ref()],
+        bump
+    )]
+    pub governance_token_vault: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Event emitted when vault solvency is verified
+#[event]
+pub struct VaultSolvencyVerified {
+    /// Vault token mint
+    pub mint: Pubkey,
+    /// Vault balance
+    pub vault_balance: u64,
+    /// Refundable deposits locked in the vault
+    pub total_deposits_locked: u64,
+    /// Balance above locked deposits (earned fees and confiscations)
+    pub surplus: u64,
+    /// Verification timestamp
+    pub timestamp: i64,
+}
+
+/// Verify vault solvency handler
+/// Fails with VaultInsolvent if the vault cannot refund every locked deposit
+pub fn verify_vault_solvency(ctx: Context<VerifyVaultSolvency>) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let vault = &ctx.accounts.governance_token_vault;
+
+    let surplus = governance_config.vault_surplus(&vault.mint, vault.amount)?;
+    let total_deposits_locked = governance_config
+        .get_deposit_mint(&vault.mint)?
+        .total_deposits_locked;
+
+    emit!(VaultSolvencyVerified {
+        mint: vault.mint,
+        vault_balance: vault.amount,
+        total_deposits_locked,
+        surplus,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Vault {} solvent: balance {}, locked {}",
+        vault.mint,
+        vault.amount,
+        total_deposits_locked
+    );
+    Ok(())
+}
+
+/// Distribute committee fees to committee members
+/// Remaining accounts: a (CommitteeMember PDA, member associated token account for the vault
+/// mint) pair per committee member
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance system token vault for the distributed mint
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, governance_token_vault.mint.as_ref()],
+        bump
+    )]
+    pub governance_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Vault token mint
+    #[account(
+        constraint = mint.key() == governance_token_vault.mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Governance system authority (for signing transfers)
+    /// CHECK: This is the governance system's PDA authority
+    #[account(
+        seeds = [GOVERNANCE_AUTHORITY_SEED],
+        bump
+    )]
+    pub governance_authority: UncheckedAccount<'info>,
+
+    /// Only the treasury admin can distribute fees
+    #[account(
+        constraint = governance_config.admin_multisig_enabled
+            || authority.key() == governance_config.treasury_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Admin set (multisig mode only)
+    #[account(
+        seeds = [ADMIN_SET_SEED],
+        bump = admin_set.bump
+    )]
+    pub admin_set: Option<Account<'info, AdminSet>>,
+
+    /// Approved admin action carrying this call (multisig mode only)
+    #[account(
+        mut,
+        seeds = [ADMIN_ACTION_SEED, admin_action.id.to_le_bytes().as_ref()],
+        bump = admin_action.bump
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+}
+
+/// Event emitted when committee fees are distributed
+#[event]
+pub struct FeesDistributed {
+    /// Distributed token mint
+    pub mint: Pubkey,
+    /// Requested amount
+    pub amount: u64,
+    /// Amount actually transferred (rounding dust stays in the vault)
+    pub distributed: u64,
+    /// Weighting used
+    pub mode: FeeDistributionMode,
+    /// Number of committee members paid
+    pub recipients: u8,
+    /// Distribution timestamp
+    pub timestamp: i64,
+}
+
+/// Distribute committee fees handler
+/// Splits amount among current committee members, equally or by votes cast
+pub fn distribute_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeFees<'info>>,
+    amount: u64,
+    mode: FeeDistributionMode,
+) -> Result<()> {
+    authorize_admin_call(
+        &ctx.accounts.governance_config,
+        ctx.accounts.admin_set.as_ref(),
+        ctx.accounts.admin_action.as_mut(),
+        &ctx.accounts.authority,
+        AdminActionKind::DistributeFees {
+            mint: ctx.accounts.governance_token_vault.mint,
+            amount,
+            mode,
+        },
+    )?;
+
+    let governance_config = &ctx.accounts.governance_config;
+    let mint = ctx.accounts.governance_token_vault.mint;
+
+    let withdrawable =
+        governance_config.withdrawable_amount(&mint, ctx.accounts.governance_token_vault.amount)?;
+    require!(
+        amount > 0 && amount <= withdrawable,
+        GovernanceError::InsufficientWithdrawableFees
+    );
+
+    require!(
+        governance_config.legacy_committee_member_count() == 0,
+        GovernanceError::CommitteeMigrationRequired
+    );
+    require!(
+        ctx.remaining_accounts.len() == governance_config.committee_member_count as usize * 2,
+        GovernanceError::InvalidFeeRecipients
+    );
+    let mut weights: Vec<(Pubkey, u64)> = Vec::with_capacity(ctx.remaining_accounts.len() / 2);
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let committee_member = load_committee_member(&pair[0])?;
+        require!(
+            !weights
+                .iter()
+                .any(|(member, _)| *member == committee_member.member),
+            GovernanceError::InvalidCommitteeAccounts
+        );
+        weights.push((committee_member.member, committee_member.fee_weight(mode)));
+    }
+    let total_weight: u64 = weights.iter().map(|(_, weight)| weight).sum();
+    require!(total_weight > 0, GovernanceError::NoFeeDistributionWeight);
+
+    let authority_seeds = &[GOVERNANCE_AUTHORITY_SEED, &[ctx.bumps.governance_authority]];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    let mut distributed: u64 = 0;
+    let mut recipients: u8 = 0;
+    for ((member, weight), pair) in weights.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let recipient = &pair[1];
+        require!(
+            recipient.key()
+                == get_associated_token_address_with_program_id(
+                    member,
+                    &mint,
+                    &ctx.accounts.token_program.key()
+                ),
+            GovernanceError::InvalidFeeRecipients
+        );
+
+        let share = (amount as u128)
+            .checked_mul(*weight as u128)
+            .ok_or(GovernanceError::ArithmeticOverflow)?
+            / total_weight as u128;
+        let share = share as u64;
+        if share == 0 {
+            continue;
+        }
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.governance_token_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: recipient.clone(),
+                authority: ctx.accounts.governance_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, share, ctx.accounts.mint.decimals)?;
+        emit_treasury_transfer(
+            &mut ctx.accounts.governance_token_vault,
+            TransferDirection::Out,
+            TreasuryTransferReason::FeeDistribution,
+            None,
+            recipient.key(),
+            share,
+        )?;
+
+        distributed = distributed
+            .checked_add(share)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        recipients += 1;
+    }
+
+    ctx.accounts
+        .governance_config
+        .debit_treasury(&mint, distributed)?;
+
+    emit!(FeesDistributed {
+        mint,
+        amount,
+        distributed,
+        mode,
+        recipients,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Distributed {} of {} committee fee tokens to {} members",
+        distributed,
+        amount,
+        recipients
+    );
+    Ok(())
+}
+
+/// Donate tokens to the governance treasury (any signer)
+#[derive(Accounts)]
+pub struct DonateToTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance system token vault for the donated mint
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, donor_token_account.mint.as_ref()],
+        bump
+    )]
+    pub governance_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Donor's token account
+    #[account(mut)]
+    pub donor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Donated token mint
+    #[account(
+        constraint = mint.key() == donor_token_account.mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub donor: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Event emitted when a donation is received
+#[event]
+pub struct DonationReceived {
+    /// Donor address
+    pub donor: Pubkey,
+    /// Donated token mint
+    pub mint: Pubkey,
+    /// Donated amount
+    pub amount: u64,
+    /// Optional donor memo
+    pub memo: Option<String>,
+    /// Cumulative donations in this mint
+    pub cumulative_donations: u64,
+    /// Donation timestamp
+    pub timestamp: i64,
+}
+
+/// Donate to treasury handler
+/// Donations are treasury funds, not deposits: total_deposits_locked is untouched
+pub fn donate_to_treasury(
+    ctx: Context<DonateToTreasury>,
+    amount: u64,
+    memo: Option<String>,
+) -> Result<()> {
+    require!(amount > 0, GovernanceError::InvalidDonationAmount);
+    if let Some(memo) = &memo {
+        require!(
+            memo.len() <= MAX_DONATION_MEMO_LENGTH,
+            GovernanceError::DonationMemoTooLong
+        );
+    }
+
+    let mint = ctx.accounts.donor_token_account.mint;
+    let governance_config = &mut ctx.accounts.governance_config;
+    governance_config.record_donation(&mint, amount)?;
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.donor_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.governance_token_vault.to_account_info(),
+            authority: ctx.accounts.donor.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.mint.decimals)?;
+    emit_treasury_transfer(
+        &mut ctx.accounts.governance_token_vault,
+        TransferDirection::In,
+        TreasuryTransferReason::Donation,
+        None,
+        ctx.accounts.donor_token_account.key(),
+        amount,
+    )?;
+
+    let cumulative_donations = governance_config
+        .get_deposit_mint(&mint)?
+        .cumulative_donations;
+
+    emit!(DonationReceived {
+        donor: ctx.accounts.donor.key(),
+        mint,
+        amount,
+        memo,
+        cumulative_donations,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Received donation of {} tokens of mint {}", amount, mint);
+    Ok(())
+}
+
+/// Reconcile a deposit vault against its tracked balances
+#[derive(Accounts)]
+pub struct ReconcileVault<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance system token vault to reconcile
+    #[account(
+        seeds = [GOVERNANCE_TOKEN_VAULT_SEED, governance_token_vault.mint.as_ref()],
+        bump
+    )]
+    pub governance_token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Only the treasury admin can reconcile vaults
+    #[account(
+        constraint = authority.key() == governance_config.treasury_admin @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Event emitted when a vault is reconciled
+#[event]
+pub struct VaultReconciled {
+    /// Vault token mint
+    pub mint: Pubkey,
+    /// Vault balance
+    pub vault_balance: u64,
+    /// Refundable deposits locked in the vault
+    pub total_deposits_locked: u64,
+    /// Treasury balance after reconciliation
+    pub treasury_balance: u64,
+    /// Vault balance above locked deposits plus treasury balance
+    pub surplus: u64,
+    /// Locked deposits plus treasury balance not covered by the vault
+    pub shortfall: u64,
+    /// Vault holds less than it is tracked to hold
+    pub under_collateralized: bool,
+    /// Surplus swept into the treasury
+    pub swept: u64,
+    /// Reconciliation timestamp
+    pub timestamp: i64,
+}
+
+/// Reconcile vault handler
+/// Untracked surplus (direct transfers, rounding dust) is swept into the treasury;
+/// an under-collateralized vault is only flagged, never touched
+pub fn reconcile_vault(ctx: Context<ReconcileVault>) -> Result<()> {
+    let vault = &ctx.accounts.governance_token_vault;
+    let mint = vault.mint;
+    let vault_balance = vault.amount;
+    let governance_config = &mut ctx.accounts.governance_config;
+
+    let deposit_mint = governance_config.get_deposit_mint(&mint)?;
+    let total_deposits_locked = deposit_mint.total_deposits_locked;
+    let expected = total_deposits_locked
+        .checked_add(deposit_mint.treasury_balance)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+
+    let surplus = vault_balance.saturating_sub(expected);
+    let shortfall = expected.saturating_sub(vault_balance);
+    let under_collateralized = shortfall > 0;
+
+    if surplus > 0 {
+        governance_config.credit_treasury(&mint, surplus)?;
+    }
+    let treasury_balance = governance_config.get_deposit_mint(&mint)?.treasury_balance;
+
+    if surplus > 0 {
+        let vault_key = ctx.accounts.governance_token_vault.key();
+        emit_treasury_transfer(
+            &mut ctx.accounts.governance_token_vault,
+            TransferDirection::Internal,
+            TreasuryTransferReason::ReconciliationSweep,
+            None,
+            vault_key,
+            surplus,
+        )?;
+    }
+
+    emit!(VaultReconciled {
+        mint,
+        vault_balance,
+        total_deposits_locked,
+        treasury_balance,
+        surplus,
+        shortfall,
+        under_collateralized,
+        swept: surplus,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    if under_collateralized {
+        msg!(
+            "Vault {} under-collateralized: balance {}, expected {}",
+            mint,
+            vault_balance,
+            expected
+        );
+    } else {
+        msg!("Vault {} reconciled: swept {} into treasury", mint, surplus);
+    }
+    Ok(())
+}
@@ -1,6 +1,10 @@
+pub mod adapter_interface;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod logging;
+pub mod order_interface;
 pub mod state;
 
 use anchor_lang::prelude::*;
@@ -46,27 +50,104 @@ pub mod governance {
         instructions::update_governance_config(ctx, config_update)
     }
 
+    /// Queue a configuration update; it can be applied once the config timelock has passed
+    pub fn queue_config_update(
+        ctx: Context<QueueConfigUpdate>,
+        config_update: GovernanceConfigUpdate,
+    ) -> Result<()> {
+        instructions::queue_config_update(ctx, config_update)
+    }
+
+    /// Apply the queued configuration update after its eta (callable by anyone)
+    pub fn apply_config_update(ctx: Context<ApplyConfigUpdate>) -> Result<()> {
+        instructions::apply_config_update(ctx)
+    }
+
+    /// Cancel the queued configuration update
+    pub fn cancel_config_update(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+        instructions::cancel_config_update(ctx)
+    }
+
+    /// Grow the governance configuration to the current layout and fill new field defaults
+    pub fn migrate_governance_config(ctx: Context<MigrateGovernanceConfig>) -> Result<()> {
+        instructions::migrate_governance_config(ctx)
+    }
+
+    /// Permanently clear test mode and record the production lock time
+    pub fn lock_production(ctx: Context<LockProduction>) -> Result<()> {
+        instructions::lock_production(ctx)
+    }
+
+    /// Assign the config, rule or treasury admin role to a new key
+    pub fn set_admin_role(
+        ctx: Context<SetAdminRole>,
+        role: AdminRole,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::set_admin_role(ctx, role, new_admin)
+    }
+
+    /// Add or update an accepted deposit mint and its minimum deposit
+    pub fn set_deposit_mint(ctx: Context<SetDepositMint>, min_deposit_raw: u64) -> Result<()> {
+        instructions::set_deposit_mint(ctx, min_deposit_raw)
+    }
+
+    /// Remove an accepted deposit mint
+    pub fn remove_deposit_mint(ctx: Context<SetDepositMint>) -> Result<()> {
+        instructions::remove_deposit_mint(ctx)
+    }
+
+    /// Switch between SPL token and native SOL deposits
+    pub fn set_deposit_kind(
+        ctx: Context<SetDepositKind>,
+        deposit_kind: DepositKind,
+        native_min_deposit: u64,
+    ) -> Result<()> {
+        instructions::set_deposit_kind(ctx, deposit_kind, native_min_deposit)
+    }
+
+    /// Register the marketplace order program dispute parties are checked against
+    pub fn set_order_program(
+        ctx: Context<SetOrderProgram>,
+        order_program: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_order_program(ctx, order_program)
+    }
+
     /// Update total voting power
-    pub fn update_total_voting_power(
-        ctx: Context<UpdateTotalVotingPower>,
+    pub fn update_total_voting_power<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateTotalVotingPower<'info>>,
         new_total_voting_power: u64,
     ) -> Result<()> {
         instructions::update_total_voting_power(ctx, new_total_voting_power)
     }
 
+    /// Sync total voting power from committee member balances
+    pub fn sync_total_voting_power<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SyncTotalVotingPower<'info>>,
+    ) -> Result<()> {
+        instructions::sync_total_voting_power(ctx)
+    }
+
     /// Update proposal counter (admin only)
     pub fn update_proposal_counter(
         ctx: Context<UpdateProposalCounter>,
         new_counter: u64,
+        reason: String,
     ) -> Result<()> {
-        instructions::update_proposal_counter(ctx, new_counter)
+        instructions::update_proposal_counter(ctx, new_counter, reason)
     }
 
     // ==================== Rule Management Instructions ====================
 
-    /// Create rule registry
-    pub fn create_rule_registry(ctx: Context<CreateRuleRegistry>) -> Result<()> {
-        instructions::create_rule_registry(ctx)
+    /// Create a named rule registry (the empty name is the original registry)
+    pub fn create_rule_registry(ctx: Context<CreateRuleRegistry>, name: String) -> Result<()> {
+        instructions::create_rule_registry(ctx, name)
+    }
+
+    /// Copy the governance authority into the rule registry (permissionless)
+    pub fn sync_rule_registry_authority(ctx: Context<SyncRuleRegistryAuthority>) -> Result<()> {
+        instructions::sync_rule_registry_authority(ctx)
     }
 
     /// Add rule document
@@ -76,48 +157,93 @@ pub mod governance {
         title: String,
         url: String,
         hash: String,
+        effective_from: Option<i64>,
+        allow_custom_category: bool,
+    ) -> Result<()> {
+        instructions::add_rule_document(
+            ctx,
+            category,
+            title,
+            url,
+            hash,
+            effective_from,
+            allow_custom_category,
+        )
+    }
+
+    /// Add several rule documents as one registry version
+    pub fn add_rule_documents_batch(
+        ctx: Context<AddRuleDocumentsBatch>,
+        documents: Vec<RuleDocumentInput>,
     ) -> Result<()> {
-        instructions::add_rule_document(ctx, category, title, url, hash)
+        instructions::add_rule_documents_batch(ctx, documents)
     }
 
-    /// Update rule document
+    /// Update rule document by ID
     pub fn update_rule_document(
         ctx: Context<UpdateRuleDocument>,
-        document_index: u32,
+        document_id: u32,
         new_url: Option<String>,
         new_hash: Option<String>,
+        new_effective_from: Option<i64>,
     ) -> Result<()> {
-        instructions::update_rule_document(ctx, document_index, new_url, new_hash)
+        instructions::update_rule_document(ctx, document_id, new_url, new_hash, new_effective_from)
+    }
+
+    /// Remove rule document by ID
+    pub fn remove_rule_document(ctx: Context<RemoveRuleDocument>, document_id: u32) -> Result<()> {
+        instructions::remove_rule_document(ctx, document_id)
     }
 
-    /// Remove rule document
-    pub fn remove_rule_document(
-        ctx: Context<RemoveRuleDocument>,
-        document_index: u32,
+    /// Set (Some) or remove (None) the admin delegated to a rule category
+    pub fn set_category_admin(
+        ctx: Context<SetCategoryAdmin>,
+        category: String,
+        admin: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::remove_rule_document(ctx, document_index)
+        instructions::set_category_admin(ctx, category, admin)
+    }
+
+    /// Get a page of rule documents, optionally filtered by category and effectiveness
+    pub fn get_rule_documents_page(
+        ctx: Context<GetRuleDocuments>,
+        offset: u32,
+        limit: u32,
+        category: Option<String>,
+        only_effective: bool,
+    ) -> Result<RuleDocumentPage> {
+        instructions::get_rule_documents_page(ctx, offset, limit, category, only_effective)
     }
 
-    /// Get rule documents information
+    /// Get the versions replaced by a rule document's recent updates
+    pub fn get_document_history(
+        ctx: Context<GetRuleDocuments>,
+        document_id: u32,
+    ) -> Result<Vec<RuleDocumentRevision>> {
+        instructions::get_document_history(ctx, document_id)
+    }
+
+    /// Get rule documents information (deprecated: unbounded, use get_rule_documents_page)
     pub fn get_rule_documents(ctx: Context<GetRuleDocuments>) -> Result<Vec<RuleDocument>> {
         instructions::get_rule_documents(ctx)
     }
 
-    /// Verify rule document hash
+    /// Verify rule document hash by ID
     pub fn verify_rule_document(
         ctx: Context<VerifyRuleDocument>,
-        document_index: u32,
+        document_id: u32,
         expected_hash: String,
     ) -> Result<bool> {
-        instructions::verify_rule_document(ctx, document_index, expected_hash)
+        instructions::verify_rule_document(ctx, document_id, expected_hash)
     }
 
-    /// Find rule documents by category
+    /// Find rule documents by category (deprecated: unbounded, use get_rule_documents_page)
     pub fn find_documents_by_category(
         ctx: Context<GetRuleDocuments>,
         category: String,
+        only_effective: bool,
     ) -> Result<Vec<RuleDocument>> {
-        instructions::find_documents_by_category(ctx, category)
+        instructions::find_documents_by_category(ctx, category, only_effective)
     }
 
     // ==================== Committee Member Management Instructions ====================
@@ -135,30 +261,272 @@ pub mod governance {
         instructions::remove_committee_member(ctx, member)
     }
 
+    /// Migrate a legacy committee slot to a committee member account
+    pub fn migrate_committee_member(
+        ctx: Context<MigrateCommitteeMember>,
+        member: Pubkey,
+    ) -> Result<()> {
+        instructions::migrate_committee_member(ctx, member)
+    }
+
+    /// Set committee member display name
+    pub fn set_member_display_name(
+        ctx: Context<UpdateCommitteeMember>,
+        member: Pubkey,
+        display_name: String,
+    ) -> Result<()> {
+        instructions::set_member_display_name(ctx, member, display_name)
+    }
+
+    /// Suspend a committee member who missed enough finalized proposals (permissionless)
+    pub fn flag_inactive_member(ctx: Context<FlagInactiveMember>, member: Pubkey) -> Result<()> {
+        instructions::flag_inactive_member(ctx, member)
+    }
+
+    /// Lift one's own inactivity suspension
+    pub fn reactivate_committee_member(ctx: Context<ReactivateCommitteeMember>) -> Result<()> {
+        instructions::reactivate_committee_member(ctx)
+    }
+
+    /// Lock the required committee token stake toward a committee seat
+    pub fn stake_for_membership(ctx: Context<StakeForMembership>) -> Result<()> {
+        instructions::stake_for_membership(ctx)
+    }
+
+    /// Withdraw a member stake after leaving the committee and the cooldown
+    pub fn withdraw_member_stake(ctx: Context<WithdrawMemberStake>) -> Result<()> {
+        instructions::withdraw_member_stake(ctx)
+    }
+
+    /// Lock committee tokens until unlock time for a voting power multiplier
+    pub fn lock_tokens(ctx: Context<LockTokens>, amount: u64, lock_duration: i64) -> Result<()> {
+        instructions::lock_tokens(ctx, amount, lock_duration)
+    }
+
+    /// Withdraw locked committee tokens once the unlock time has passed
+    pub fn withdraw_locked_tokens(ctx: Context<WithdrawLockedTokens>) -> Result<()> {
+        instructions::withdraw_locked_tokens(ctx)
+    }
+
+    /// Suspend or reinstate committee member
+    pub fn set_member_active(
+        ctx: Context<UpdateCommitteeMember>,
+        member: Pubkey,
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_member_active(ctx, member, active)
+    }
+
+    /// Set committee member term end
+    pub fn set_member_term(
+        ctx: Context<UpdateCommitteeMember>,
+        member: Pubkey,
+        term_end: i64,
+    ) -> Result<()> {
+        instructions::set_member_term(ctx, member, term_end)
+    }
+
+    /// Renew committee member term
+    pub fn renew_member_term(ctx: Context<UpdateCommitteeMember>, member: Pubkey) -> Result<()> {
+        instructions::renew_member_term(ctx, member)
+    }
+
+    /// Create a committee member's voter stats account for the participation bonus
+    pub fn initialize_voter_stats(
+        ctx: Context<InitializeVoterStats>,
+        member: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_voter_stats(ctx, member)
+    }
+
+    /// Designate or clear the alternate who may take over a committee member's seat
+    pub fn set_member_alternate(
+        ctx: Context<UpdateCommitteeMember>,
+        member: Pubkey,
+        alternate: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_member_alternate(ctx, member, alternate)
+    }
+
+    /// Seat a suspended member's alternate for the rest of the member's term
+    pub fn activate_alternate(ctx: Context<ActivateAlternate>, member: Pubkey) -> Result<()> {
+        instructions::activate_alternate(ctx, member)
+    }
+
     /// Close governance configuration
     pub fn close_governance_config(ctx: Context<CloseGovernanceConfig>) -> Result<()> {
         instructions::close_governance_config(ctx)
     }
 
+    /// Propose handing the governance authority to a new administrator
+    pub fn propose_authority_transfer(
+        ctx: Context<ManageAuthorityTransfer>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority_transfer(ctx, new_authority)
+    }
+
+    /// Accept a pending governance authority transfer (signed by the new administrator)
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        instructions::accept_authority_transfer(ctx)
+    }
+
+    /// Cancel a pending governance authority transfer
+    pub fn cancel_authority_transfer(ctx: Context<ManageAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer(ctx)
+    }
+
+    // ==================== Admin Multisig Instructions ====================
+
+    /// Create the M-of-N admin set and enable multisig mode
+    pub fn initialize_admin_set(
+        ctx: Context<InitializeAdminSet>,
+        admins: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::initialize_admin_set(ctx, admins, threshold)
+    }
+
+    /// Replace the admin set and re-enable multisig mode (single-authority mode only)
+    pub fn configure_admin_set(
+        ctx: Context<ConfigureAdminSet>,
+        admins: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::configure_admin_set(ctx, admins, threshold)
+    }
+
+    /// Propose a privileged action for admin approval
+    pub fn propose_admin_action(
+        ctx: Context<ProposeAdminAction>,
+        action: AdminActionKind,
+    ) -> Result<()> {
+        instructions::propose_admin_action(ctx, action)
+    }
+
+    /// Approve a proposed admin action
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>, action_id: u64) -> Result<()> {
+        instructions::approve_admin_action(ctx, action_id)
+    }
+
+    /// Execute an admin action once it has enough approvals
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>, action_id: u64) -> Result<()> {
+        instructions::execute_admin_action(ctx, action_id)
+    }
+
+    // ==================== Execution Adapter Instructions ====================
+
+    /// Create execution adapter registry
+    pub fn create_execution_adapter_registry(
+        ctx: Context<CreateExecutionAdapterRegistry>,
+    ) -> Result<()> {
+        instructions::create_execution_adapter_registry(ctx)
+    }
+
+    /// Register, replace or remove the execution adapter for a proposal type (test mode only)
+    pub fn set_execution_adapter(
+        ctx: Context<UpdateExecutionAdapterRegistry>,
+        proposal_type: ProposalType,
+        adapter: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_execution_adapter(ctx, proposal_type, adapter)
+    }
+
+    /// Queue an execution adapter registration change behind the config timelock
+    pub fn queue_execution_adapter(
+        ctx: Context<UpdateExecutionAdapterRegistry>,
+        proposal_type: ProposalType,
+        adapter: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::queue_execution_adapter(ctx, proposal_type, adapter)
+    }
+
+    /// Apply a queued execution adapter registration change after its eta
+    pub fn apply_execution_adapter(
+        ctx: Context<ApplyExecutionAdapter>,
+        proposal_type: ProposalType,
+    ) -> Result<()> {
+        instructions::apply_execution_adapter(ctx, proposal_type)
+    }
+
+    /// Create the allowlist of programs that may create proposals through CPI
+    pub fn create_cpi_caller_allowlist(ctx: Context<CreateCpiCallerAllowlist>) -> Result<()> {
+        instructions::create_cpi_caller_allowlist(ctx)
+    }
+
+    /// Allow or disallow a program to create proposals through CPI
+    pub fn set_cpi_caller(
+        ctx: Context<UpdateCpiCallerAllowlist>,
+        program_id: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::set_cpi_caller(ctx, program_id, allowed)
+    }
+
+    // ==================== Proposer Policy Instructions ====================
+
+    /// Create proposer policy
+    pub fn create_proposer_policy(
+        ctx: Context<CreateProposerPolicy>,
+        allowlist_only: bool,
+    ) -> Result<()> {
+        instructions::create_proposer_policy(ctx, allowlist_only)
+    }
+
+    /// Ban or unban proposer
+    pub fn set_proposer_ban(
+        ctx: Context<UpdateProposerPolicy>,
+        proposer: Pubkey,
+        banned: bool,
+    ) -> Result<()> {
+        instructions::set_proposer_ban(ctx, proposer, banned)
+    }
+
+    /// Add or remove proposer from allowlist
+    pub fn set_proposer_allowed(
+        ctx: Context<UpdateProposerPolicy>,
+        proposer: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::set_proposer_allowed(ctx, proposer, allowed)
+    }
+
+    /// Toggle allowlist-only proposer mode
+    pub fn set_allowlist_only(ctx: Context<UpdateProposerPolicy>, enabled: bool) -> Result<()> {
+        instructions::set_allowlist_only(ctx, enabled)
+    }
+
     // ==================== Proposal Management Instructions ====================
 
-    /// Create proposal
+    /// Create proposal (returns the new proposal ID, PDA, deposit charged and voting window
+    /// as return data); options make it a multi-choice proposal, and recused members
+    /// (their CommitteeMember PDAs passed as remaining accounts) cannot vote on it
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
+        proposal_id: u64,
         title: String,
         description: String,
         proposal_type: ProposalType,
-        execution_data: Option<ExecutionData>,
-        custom_deposit_raw: Option<u64>,
-    ) -> Result<u64> {
-        instructions::create_proposal(
-            ctx,
-            title,
-            description,
-            proposal_type,
-            execution_data,
-            custom_deposit_raw,
-        )
+        args: CreateProposalArgs,
+    ) -> Result<CreatedProposal> {
+        instructions::create_proposal(ctx, proposal_id, title, description, proposal_type, args)
+    }
+
+    /// Initialize slash history for a merchant (required before slashing it)
+    pub fn initialize_merchant_slash_history(
+        ctx: Context<InitializeMerchantSlashHistory>,
+        merchant: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_merchant_slash_history(ctx, merchant)
+    }
+
+    /// Resubmit a proposal that failed only on participation, at a discounted deposit
+    pub fn resubmit_proposal(
+        ctx: Context<ResubmitProposal>,
+        proposal_id: u64,
+        prior_proposal_id: u64,
+    ) -> Result<CreatedProposal> {
+        instructions::resubmit_proposal(ctx, proposal_id, prior_proposal_id)
     }
 
     /// Cast vote
@@ -166,22 +534,60 @@ pub mod governance {
         instructions::cast_vote(ctx, proposal_id, vote_type)
     }
 
-    /// Finalize proposal
+    /// Cast a vote signed off-chain by the voter, submitted by any fee payer
+    pub fn cast_vote_signed(
+        ctx: Context<CastVoteSigned>,
+        message: SignedVoteMessage,
+    ) -> Result<()> {
+        instructions::cast_vote_signed(ctx, message)
+    }
+
+    /// Finalize proposal (clock_override stands in for the clock on test_mode deployments)
     pub fn finalize_proposal<'info>(
         ctx: Context<'_, '_, 'info, 'info, FinalizeProposal<'info>>,
         proposal_id: u64,
+        clock_override: Option<i64>,
     ) -> Result<()> {
-        instructions::finalize_proposal(ctx, proposal_id)
+        instructions::finalize_proposal(ctx, proposal_id, clock_override)
+    }
+
+    /// Finalize every ready proposal in a batch, skipping those not ready (keeper crank;
+    /// returns the number finalized)
+    pub fn crank_finalize<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankFinalize<'info>>,
+        entries: Vec<CrankFinalizeEntry>,
+        clock_override: Option<i64>,
+    ) -> Result<u8> {
+        instructions::crank_finalize(ctx, entries, clock_override)
     }
 
-    /// Close vote account
+    /// Close vote account (its proposal must no longer be Pending)
     pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
         instructions::close_vote(ctx)
     }
 
-    /// Execute proposal (simplified version)
-    pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
-        instructions::execute_proposal(ctx, proposal_id)
+    /// Execute proposal, invoking the execution adapter registered for its type
+    /// (clock_override stands in for the clock on test_mode deployments)
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+        proposal_id: u64,
+        clock_override: Option<i64>,
+    ) -> Result<()> {
+        instructions::execute_proposal(ctx, proposal_id, clock_override)
+    }
+
+    /// Archive a terminal proposal into a compact summary before it is closed
+    pub fn archive_proposal(ctx: Context<ArchiveProposal>, proposal_id: u64) -> Result<()> {
+        instructions::archive_proposal(ctx, proposal_id)
+    }
+
+    /// Close a terminal proposal and its outcome (requires the archive unless skip_archive)
+    pub fn close_proposal(
+        ctx: Context<CloseProposal>,
+        proposal_id: u64,
+        skip_archive: bool,
+    ) -> Result<()> {
+        instructions::close_proposal(ctx, proposal_id, skip_archive)
     }
 
     // ==================== Query Instructions ====================
@@ -190,14 +596,165 @@ pub mod governance {
     pub fn query_voting_power<'info>(
         ctx: Context<'_, '_, 'info, 'info, QueryVotingPower<'info>>,
         proposal_id: u64,
-    ) -> Result<()> {
+    ) -> Result<VotingPowerQueried> {
         instructions::query_voting_power(ctx, proposal_id)
     }
 
+    /// Preview the status finalize_proposal would set right now (read-only, via return data)
+    pub fn preview_finalize<'info>(
+        ctx: Context<'_, '_, 'info, 'info, QueryVotingPower<'info>>,
+        proposal_id: u64,
+    ) -> Result<FinalizePreviewed> {
+        instructions::preview_finalize(ctx, proposal_id)
+    }
+
+    /// Get the ID the next proposal must be created with (read-only, via return data)
+    pub fn get_next_proposal_id(ctx: Context<GetNextProposalId>) -> Result<u64> {
+        instructions::get_next_proposal_id(ctx)
+    }
+
+    /// Get a compact summary of one proposal (read-only, via return data)
+    pub fn get_proposal_summary(
+        ctx: Context<GetProposal>,
+        proposal_id: u64,
+    ) -> Result<ProposalSummary> {
+        instructions::get_proposal_summary(ctx, proposal_id)
+    }
+
+    /// Check whether a proposal passed, including executed ones (read-only, via return data)
+    pub fn has_passed(ctx: Context<GetProposal>, proposal_id: u64) -> Result<bool> {
+        instructions::has_passed(ctx, proposal_id)
+    }
+
+    /// Get a voter's vote receipt for a proposal (read-only, via return data)
+    pub fn get_vote(ctx: Context<GetVote>, proposal_id: u64, voter: Pubkey) -> Result<VoteReceipt> {
+        instructions::get_vote(ctx, proposal_id, voter)
+    }
+
+    /// Get the program constants and current account sizes (read-only, via return data)
+    pub fn get_program_constants(ctx: Context<GetProgramConstants>) -> Result<ProgramConstants> {
+        instructions::get_program_constants(ctx)
+    }
+
+    /// Dry-run the validation a proposal's execution data goes through (read-only, via
+    /// return data)
+    pub fn validate_execution_data(
+        ctx: Context<ValidateExecutionData>,
+        proposal_type: ProposalType,
+        execution_data: Option<ExecutionData>,
+    ) -> Result<ExecutionDataValidation> {
+        instructions::check_execution_data(ctx, proposal_type, execution_data)
+    }
+
+    /// Get the queued configuration update, if any (read-only, via return data)
+    pub fn get_pending_config_update(
+        ctx: Context<GetPendingConfigUpdate>,
+    ) -> Result<Option<PendingConfigUpdateSummary>> {
+        instructions::get_pending_config_update(ctx)
+    }
+
+    /// Get a summary of the governance state (read-only, via return data)
+    pub fn get_governance_state(
+        ctx: Context<GetGovernanceState>,
+    ) -> Result<GovernanceStateSummary> {
+        instructions::get_governance_state(ctx)
+    }
+
+    /// Create the governance statistics account for a deployment initialized without it
+    pub fn initialize_governance_stats(ctx: Context<InitializeGovernanceStats>) -> Result<()> {
+        instructions::initialize_governance_stats(ctx)
+    }
+
+    /// Get lifetime governance statistics (read-only, via return data)
+    pub fn get_governance_stats(ctx: Context<GetGovernanceStats>) -> Result<GovernanceStats> {
+        instructions::get_governance_stats(ctx)
+    }
+
+    /// Emit a Heartbeat event with a summary of the governance state (permissionless,
+    /// at most once per heartbeat_interval)
+    pub fn touch_governance(ctx: Context<TouchGovernance>) -> Result<()> {
+        instructions::touch_governance(ctx)
+    }
+
+    /// Create the proposal index and start recording proposal status changes
+    pub fn initialize_proposal_index(ctx: Context<InitializeProposalIndex>) -> Result<()> {
+        instructions::initialize_proposal_index(ctx)
+    }
+
+    /// Close the proposal index and stop recording proposal status changes
+    pub fn close_proposal_index(ctx: Context<CloseProposalIndex>) -> Result<()> {
+        instructions::close_proposal_index(ctx)
+    }
+
+    /// Get a page of proposal IDs with the given status group (read-only, via return data)
+    pub fn get_proposal_index_page(
+        ctx: Context<GetProposalIndexPage>,
+        list: ProposalIndexList,
+        offset: u32,
+        limit: u32,
+    ) -> Result<ProposalIdPage> {
+        instructions::get_proposal_index_page(ctx, list, offset, limit)
+    }
+
     // ==================== Deposit Management Instructions ====================
 
-    /// Initialize governance system token vault
+    /// Withdraw accumulated committee fees (vault balance above locked deposits)
+    pub fn withdraw_committee_fees(ctx: Context<WithdrawCommitteeFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_committee_fees(ctx, amount)
+    }
+
+    /// Distribute committee fees to committee members (equally or by participation)
+    pub fn distribute_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeFees<'info>>,
+        amount: u64,
+        mode: FeeDistributionMode,
+    ) -> Result<()> {
+        instructions::distribute_fees(ctx, amount, mode)
+    }
+
+    /// Donate tokens to the governance treasury
+    pub fn donate_to_treasury(
+        ctx: Context<DonateToTreasury>,
+        amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::donate_to_treasury(ctx, amount, memo)
+    }
+
+    /// Verify a deposit vault holds at least its locked deposits
+    pub fn verify_vault_solvency(ctx: Context<VerifyVaultSolvency>) -> Result<()> {
+        instructions::verify_vault_solvency(ctx)
+    }
+
+    /// Reconcile a deposit vault, sweeping untracked surplus into the treasury
+    pub fn reconcile_vault(ctx: Context<ReconcileVault>) -> Result<()> {
+        instructions::reconcile_vault(ctx)
+    }
+
+    /// Initialize governance system token vault for an accepted deposit mint
     pub fn initialize_token_vault(ctx: Context<InitializeTokenVault>) -> Result<()> {
         instructions::initialize_token_vault(ctx)
     }
+
+    /// Initialize the native SOL deposit vault
+    pub fn initialize_native_deposit_vault(
+        ctx: Context<InitializeNativeDepositVault>,
+    ) -> Result<()> {
+        instructions::initialize_native_deposit_vault(ctx)
+    }
+
+    /// Close an empty governance token vault
+    pub fn close_token_vault(ctx: Context<CloseTokenVault>) -> Result<()> {
+        instructions::close_token_vault(ctx)
+    }
+
+    /// Claim a deposit refund that finalization could not push to the proposer
+    pub fn claim_refund(ctx: Context<ClaimRefund>, proposal_id: u64) -> Result<()> {
+        instructions::claim_refund(ctx, proposal_id)
+    }
+
+    /// Move a legacy single-vault balance into the per-mint vault and close it
+    pub fn migrate_legacy_token_vault(ctx: Context<MigrateLegacyTokenVault>) -> Result<()> {
+        instructions::migrate_legacy_token_vault(ctx)
+    }
 }
@@ -0,0 +1,102 @@
+//! Structured operational logs
+//! Hot paths log small Borsh records with sol_log_data instead of formatted msg! strings,
+//! which allocate and cost compute in proportion to their length; the readable messages
+//! are only logged on test_mode deployments (test_msg!)
+//! Each record is one "Program data:" line holding an 8-byte tag and the Borsh-encoded
+//! record; event parsers skip it, since no event has one of these tags as discriminator
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::log::sol_log_data;
+
+use crate::state::ProposalStatus;
+
+/// msg! that is only logged on test_mode deployments
+/// The first argument is anything with a test_mode field (the GovernanceConfig)
+macro_rules! test_msg {
+    ($governance_config:expr, $($arg:tt)+) => {
+        if $governance_config.test_mode {
+            anchor_lang::prelude::msg!($($arg)+);
+        }
+    };
+}
+pub(crate) use test_msg;
+
+/// Structured log record
+pub trait LogRecord: AnchorSerialize {
+    /// Tag the logged data starts with
+    const TAG: [u8; 8];
+
+    /// Log the tagged record
+    fn log(&self) {
+        let mut data = Self::TAG.to_vec();
+        // Serializing into a Vec cannot fail
+        if self.serialize(&mut data).is_ok() {
+            sol_log_data(&[&data]);
+        }
+    }
+}
+
+/// Vote tally a proposal was finalized with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TallySummary {
+    /// Finalized proposal
+    pub proposal_id: u64,
+    /// Status set by the tally
+    pub status: ProposalStatus,
+    /// Committee voting power the votes were measured against
+    pub total_voting_power: u64,
+    /// Yes votes
+    pub yes_votes: u64,
+    /// No votes
+    pub no_votes: u64,
+    /// Abstain votes
+    pub abstain_votes: u64,
+    /// Veto votes
+    pub veto_votes: u64,
+}
+
+impl LogRecord for TallySummary {
+    const TAG: [u8; 8] = *b"tallysum";
+}
+
+/// Kind of proposal deposit movement
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepositMovementKind {
+    /// Posted by the proposer into the vault (or the proposal's escrow)
+    Deposited,
+    /// Refunded to the proposer at settlement
+    Refunded,
+    /// Kept by the vault at settlement (committee fee and confiscation)
+    Retained,
+    /// Refund left in a refund claim for the proposer to pull
+    LeftClaimable,
+    /// Escrow balance moved to the pooled vault when the escrow closed
+    EscrowResidual,
+}
+
+/// Movement of a proposal's deposit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DepositMovement {
+    /// Proposal the deposit belongs to
+    pub proposal_id: u64,
+    /// Movement kind
+    pub kind: DepositMovementKind,
+    /// Amount moved (base units of the deposit mint, or lamports)
+    pub amount: u64,
+}
+
+impl DepositMovement {
+    /// Log a deposit movement
+    pub fn log_movement(proposal_id: u64, kind: DepositMovementKind, amount: u64) {
+        Self {
+            proposal_id,
+            kind,
+            amount,
+        }
+        .log();
+    }
+}
+
+impl LogRecord for DepositMovement {
+    const TAG: [u8; 8] = *b"depositm";
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Offset of OrderParties in an order account: right after the 8-byte account discriminator
+pub const ORDER_PARTIES_OFFSET: usize = 8;
+
+/// Parties of a marketplace order
+/// A registered order program's order accounts must start with these fields, after the
+/// account discriminator, for dispute proposals to be checked against them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderParties {
+    /// Buyer
+    pub user: Pubkey,
+    /// Merchant
+    pub merchant: Pubkey,
+}
+
+impl OrderParties {
+    /// Read the parties from an order account's data
+    pub fn read(data: &[u8]) -> Option<Self> {
+        let mut parties = data.get(ORDER_PARTIES_OFFSET..)?;
+        Self::deserialize(&mut parties).ok()
+    }
+}
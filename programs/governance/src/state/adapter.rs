@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+use super::proposal::ProposalType;
+
+/// Maximum number of registered execution adapters (one per proposal type)
+pub const MAX_EXECUTION_ADAPTERS: usize = 8;
+
+/// Execution adapter programs registered per proposal type
+#[account]
+#[derive(InitSpace)]
+pub struct ExecutionAdapterRegistry {
+    /// Registered adapters
+    #[max_len(8)]
+    pub adapters: Vec<ExecutionAdapter>,
+    /// Registration changes waiting out the config timelock (at most one per proposal type)
+    #[max_len(8)]
+    pub pending_adapters: Vec<PendingExecutionAdapter>,
+    /// Last update time
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Adapter program invoked when a proposal of the given type executes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct ExecutionAdapter {
+    /// Proposal type handled by the adapter
+    pub proposal_type: ProposalType,
+    /// Adapter program
+    pub program_id: Pubkey,
+}
+
+/// Registration change queued by the config admin, applicable from eta
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct PendingExecutionAdapter {
+    /// Proposal type whose registration changes
+    pub proposal_type: ProposalType,
+    /// Adapter program to register (None removes the registration)
+    pub program_id: Option<Pubkey>,
+    /// Earliest time the change can be applied
+    pub eta: i64,
+}
+
+impl ExecutionAdapterRegistry {
+    /// Load the registry, or None when it has not been created
+    /// The address itself is checked by the caller's seeds constraint
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let registry = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(registry))
+    }
+
+    /// Get the adapter registered for a proposal type
+    pub fn adapter_for(&self, proposal_type: &ProposalType) -> Option<Pubkey> {
+        self.adapters
+            .iter()
+            .find(|adapter| adapter.proposal_type == *proposal_type)
+            .map(|adapter| adapter.program_id)
+    }
+
+    /// Register, replace or (with None) remove the adapter for a proposal type
+    /// Returns the previously registered adapter
+    pub fn set_adapter(
+        &mut self,
+        proposal_type: ProposalType,
+        program_id: Option<Pubkey>,
+    ) -> Result<Option<Pubkey>> {
+        let previous = self.adapter_for(&proposal_type);
+        self.adapters
+            .retain(|adapter| adapter.proposal_type != proposal_type);
+        self.pending_adapters
+            .retain(|pending| pending.proposal_type != proposal_type);
+        if let Some(program_id) = program_id {
+            Self::validate_adapter(program_id)?;
+            require!(
+                self.adapters.len() < MAX_EXECUTION_ADAPTERS,
+                crate::error::GovernanceError::InvalidExecutionAdapter
+            );
+            self.adapters.push(ExecutionAdapter {
+                proposal_type,
+                program_id,
+            });
+        }
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(previous)
+    }
+
+    /// Queue a registration change, replacing any change already queued for the type
+    pub fn queue_adapter(
+        &mut self,
+        proposal_type: ProposalType,
+        program_id: Option<Pubkey>,
+        eta: i64,
+    ) -> Result<()> {
+        if let Some(program_id) = program_id {
+            Self::validate_adapter(program_id)?;
+        }
+        self.pending_adapters
+            .retain(|pending| pending.proposal_type != proposal_type);
+        self.pending_adapters.push(PendingExecutionAdapter {
+            proposal_type,
+            program_id,
+            eta,
+        });
+        Ok(())
+    }
+
+    /// Take the change queued for a proposal type, once its eta has passed
+    pub fn take_pending_adapter(
+        &mut self,
+        proposal_type: &ProposalType,
+        now: i64,
+    ) -> Result<PendingExecutionAdapter> {
+        let index = self
+            .pending_adapters
+            .iter()
+            .position(|pending| pending.proposal_type == *proposal_type)
+            .ok_or(crate::error::GovernanceError::ExecutionAdapterNotQueued)?;
+        require!(
+            now >= self.pending_adapters[index].eta,
+            crate::error::GovernanceError::ConfigUpdateTimelocked
+        );
+        Ok(self.pending_adapters.remove(index))
+    }
+
+    /// Adapters may be neither this program nor the default key
+    fn validate_adapter(program_id: Pubkey) -> Result<()> {
+        require!(
+            program_id != crate::ID && program_id != Pubkey::default(),
+            crate::error::GovernanceError::InvalidExecutionAdapter
+        );
+        Ok(())
+    }
+}
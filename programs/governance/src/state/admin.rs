@@ -0,0 +1,406 @@
+use anchor_lang::prelude::*;
+
+use super::governance::{AdminRole, DepositKind, FeeDistributionMode, GovernanceConfigUpdate};
+use super::proposal::ProposalType;
+
+/// Maximum number of admins in the admin set
+pub const MAX_ADMINS: usize = 5;
+
+/// M-of-N admin set controlling privileged instructions while multisig mode is enabled
+#[account]
+#[derive(InitSpace)]
+pub struct AdminSet {
+    /// Admin addresses
+    #[max_len(5)]
+    pub admins: Vec<Pubkey>,
+    /// Approvals required to execute an admin action
+    pub threshold: u8,
+    /// Admin action counter (last assigned action ID)
+    pub action_counter: u64,
+    /// Last update time
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminSet {
+    /// Check if address is an admin
+    pub fn is_admin(&self, admin: &Pubkey) -> bool {
+        self.admins.contains(admin)
+    }
+
+    /// Replace the admin list and threshold
+    /// Requires 1 <= threshold <= admin count <= MAX_ADMINS and no duplicate admins
+    pub fn configure(&mut self, admins: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        require!(
+            !admins.is_empty() && admins.len() <= MAX_ADMINS,
+            crate::error::GovernanceError::InvalidAdminSet
+        );
+        require!(
+            threshold >= 1 && threshold as usize <= admins.len(),
+            crate::error::GovernanceError::InvalidAdminSet
+        );
+        for (index, admin) in admins.iter().enumerate() {
+            require!(
+                *admin != Pubkey::default() && !admins[..index].contains(admin),
+                crate::error::GovernanceError::InvalidAdminSet
+            );
+        }
+
+        self.admins = admins;
+        self.threshold = threshold;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// ID the next proposed admin action gets (its PDA is derived from it)
+    pub fn upcoming_action_id(&self) -> Result<u64> {
+        Ok(self
+            .action_counter
+            .checked_add(1)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?)
+    }
+
+    /// Reserve the next admin action ID
+    pub fn next_action_id(&mut self) -> Result<u64> {
+        self.action_counter = self.upcoming_action_id()?;
+        Ok(self.action_counter)
+    }
+}
+
+/// Privileged action awaiting M-of-N admin approval
+#[account]
+#[derive(InitSpace)]
+pub struct AdminAction {
+    /// Admin action ID
+    pub id: u64,
+    /// Admin who proposed the action
+    pub proposer: Pubkey,
+    /// Action to perform once approved
+    pub action: AdminActionKind,
+    /// Admins who approved (the proposer approves on creation)
+    #[max_len(5)]
+    pub approvals: Vec<Pubkey>,
+    /// Whether the action has been executed
+    pub executed: bool,
+    /// Creation time
+    pub created_at: i64,
+    /// Execution time
+    pub executed_at: Option<i64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminAction {
+    /// Record an admin approval, first dropping approvals of admins no longer in the set
+    pub fn approve(&mut self, admin: Pubkey, admin_set: &AdminSet) -> Result<()> {
+        require!(
+            !self.executed,
+            crate::error::GovernanceError::AdminActionAlreadyExecuted
+        );
+        self.prune_approvals(admin_set);
+        require!(
+            !self.approvals.contains(&admin),
+            crate::error::GovernanceError::AdminActionAlreadyApproved
+        );
+        self.approvals.push(admin);
+        Ok(())
+    }
+
+    /// Check that the action is unexecuted and approved by at least threshold current admins
+    pub fn require_executable(&mut self, admin_set: &AdminSet) -> Result<()> {
+        require!(
+            !self.executed,
+            crate::error::GovernanceError::AdminActionAlreadyExecuted
+        );
+        // Only admins still in the set count toward the threshold
+        require!(
+            self.prune_approvals(admin_set) >= admin_set.threshold as usize,
+            crate::error::GovernanceError::InsufficientAdminApprovals
+        );
+        Ok(())
+    }
+
+    /// Mark the action executed
+    pub fn mark_executed(&mut self, timestamp: i64) {
+        self.executed = true;
+        self.executed_at = Some(timestamp);
+    }
+
+    /// Execute the action as the authorization of a privileged instruction call
+    /// The call must carry exactly the approved action
+    pub fn consume(
+        &mut self,
+        admin_set: &AdminSet,
+        action: &AdminActionKind,
+        timestamp: i64,
+    ) -> Result<()> {
+        self.require_executable(admin_set)?;
+        require!(
+            self.action == *action,
+            crate::error::GovernanceError::AdminActionMismatch
+        );
+        self.mark_executed(timestamp);
+        Ok(())
+    }
+
+    /// Drop approvals of admins removed from the set since they approved
+    /// Returns the number of approvals left, all from current admins
+    pub fn prune_approvals(&mut self, admin_set: &AdminSet) -> usize {
+        self.approvals.retain(|admin| admin_set.is_admin(admin));
+        self.approvals.len()
+    }
+}
+
+/// Privileged instruction variants executable through the admin set
+/// The first four run through execute_admin_action; every other variant is carried out by
+/// its own instruction, called by an admin with the approved action account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum AdminActionKind {
+    /// Same effect as queue_config_update (applied by apply_config_update after the timelock)
+    UpdateConfig(GovernanceConfigUpdate),
+    /// Same effect as add_committee_member
+    AddCommitteeMember(Pubkey),
+    /// Same effect as withdraw_committee_fees
+    WithdrawFees {
+        /// Withdrawn token mint
+        mint: Pubkey,
+        /// Destination token account
+        destination: Pubkey,
+        /// Withdrawn amount
+        amount: u64,
+    },
+    /// Disable multisig mode and return privileged instructions to the single authority
+    RestoreSingleAuthority,
+    /// update_governance_config (test mode only; applied immediately)
+    UpdateGovernanceConfig(GovernanceConfigUpdate),
+    /// cancel_config_update
+    CancelConfigUpdate,
+    /// lock_production
+    LockProduction,
+    /// set_admin_role
+    SetAdminRole {
+        /// Reassigned role
+        role: AdminRole,
+        /// New role holder
+        new_admin: Pubkey,
+    },
+    /// set_deposit_mint
+    SetDepositMint {
+        /// Deposit token mint
+        mint: Pubkey,
+        /// Minimum deposit in whole tokens
+        min_deposit_raw: u64,
+    },
+    /// remove_deposit_mint for the mint
+    RemoveDepositMint(Pubkey),
+    /// set_deposit_kind
+    SetDepositKind {
+        /// New deposit kind
+        deposit_kind: DepositKind,
+        /// Minimum native SOL deposit (lamports)
+        native_min_deposit: u64,
+    },
+    /// set_order_program
+    SetOrderProgram(Option<Pubkey>),
+    /// update_proposal_counter
+    UpdateProposalCounter {
+        /// New counter value
+        new_counter: u64,
+        /// Logged reason
+        #[max_len(100)]
+        reason: String,
+    },
+    /// remove_committee_member for the member
+    RemoveCommitteeMember(Pubkey),
+    /// migrate_committee_member for the member
+    MigrateCommitteeMember(Pubkey),
+    /// set_member_display_name
+    SetMemberDisplayName {
+        /// Committee member
+        member: Pubkey,
+        /// New display name
+        #[max_len(32)]
+        display_name: String,
+    },
+    /// set_member_active
+    SetMemberActive {
+        /// Committee member
+        member: Pubkey,
+        /// Whether the member may vote
+        active: bool,
+    },
+    /// set_member_term
+    SetMemberTerm {
+        /// Committee member
+        member: Pubkey,
+        /// New term end
+        term_end: i64,
+    },
+    /// renew_member_term for the member
+    RenewMemberTerm(Pubkey),
+    /// set_member_alternate
+    SetMemberAlternate {
+        /// Committee member
+        member: Pubkey,
+        /// New alternate (None clears it)
+        alternate: Option<Pubkey>,
+    },
+    /// close_governance_config
+    CloseGovernanceConfig,
+    /// propose_authority_transfer to the new authority
+    ProposeAuthorityTransfer(Pubkey),
+    /// cancel_authority_transfer
+    CancelAuthorityTransfer,
+    /// create_execution_adapter_registry
+    CreateExecutionAdapterRegistry,
+    /// set_execution_adapter
+    SetExecutionAdapter {
+        /// Proposal type
+        proposal_type: ProposalType,
+        /// Adapter program (None removes the registration)
+        adapter: Option<Pubkey>,
+    },
+    /// queue_execution_adapter
+    QueueExecutionAdapter {
+        /// Proposal type
+        proposal_type: ProposalType,
+        /// Adapter program (None removes the registration)
+        adapter: Option<Pubkey>,
+    },
+    /// create_cpi_caller_allowlist
+    CreateCpiCallerAllowlist,
+    /// set_cpi_caller
+    SetCpiCaller {
+        /// Calling program
+        program_id: Pubkey,
+        /// Whether the program is allowed
+        allowed: bool,
+    },
+    /// create_proposer_policy with the allowlist-only flag
+    CreateProposerPolicy(bool),
+    /// set_proposer_ban
+    SetProposerBan {
+        /// Proposer
+        proposer: Pubkey,
+        /// Whether the proposer is banned
+        banned: bool,
+    },
+    /// set_proposer_allowed
+    SetProposerAllowed {
+        /// Proposer
+        proposer: Pubkey,
+        /// Whether the proposer is allowlisted
+        allowed: bool,
+    },
+    /// set_allowlist_only
+    SetAllowlistOnly(bool),
+    /// close_vote for the vote account
+    CloseVote(Pubkey),
+    /// initialize_governance_stats
+    InitializeGovernanceStats,
+    /// initialize_proposal_index
+    InitializeProposalIndex,
+    /// close_proposal_index
+    CloseProposalIndex,
+    /// distribute_fees
+    DistributeFees {
+        /// Distributed token mint
+        mint: Pubkey,
+        /// Distributed amount
+        amount: u64,
+        /// Weighting
+        mode: FeeDistributionMode,
+    },
+    /// initialize_token_vault for the mint
+    InitializeTokenVault(Pubkey),
+    /// initialize_native_deposit_vault
+    InitializeNativeDepositVault,
+    /// close_token_vault for the mint
+    CloseTokenVault(Pubkey),
+    /// migrate_legacy_token_vault
+    MigrateLegacyTokenVault,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn admin_set(admins: &[Pubkey], threshold: u8) -> AdminSet {
+        AdminSet {
+            admins: admins.to_vec(),
+            threshold,
+            action_counter: 0,
+            updated_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn admin_action(approvals: &[Pubkey]) -> AdminAction {
+        AdminAction {
+            id: 1,
+            proposer: approvals[0],
+            action: AdminActionKind::RestoreSingleAuthority,
+            approvals: approvals.to_vec(),
+            executed: false,
+            created_at: 0,
+            executed_at: None,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn removed_admin_approvals_are_pruned() {
+        let [a, b, c, d] = [(); 4].map(|_| Pubkey::new_unique());
+        let mut action = admin_action(&[a, b]);
+
+        // b leaves the set: only a's approval still counts
+        assert_eq!(action.prune_approvals(&admin_set(&[a, c, d], 2)), 1);
+        assert_eq!(action.approvals, vec![a]);
+    }
+
+    #[test]
+    fn approve_prunes_before_recording() {
+        let admins: Vec<Pubkey> = (0..MAX_ADMINS).map(|_| Pubkey::new_unique()).collect();
+        let removed: Vec<Pubkey> = (0..MAX_ADMINS - 1).map(|_| Pubkey::new_unique()).collect();
+        let mut action = admin_action(&removed);
+
+        // Stale approvals never crowd out the current admins' approvals
+        let set = admin_set(&admins, MAX_ADMINS as u8);
+        for admin in &admins {
+            action.approve(*admin, &set).unwrap();
+        }
+        assert_eq!(action.approvals, admins);
+        assert_eq!(
+            action.approve(admins[0], &set),
+            Err(crate::error::GovernanceError::AdminActionAlreadyApproved.into())
+        );
+    }
+
+    #[test]
+    fn consume_requires_the_approved_call() {
+        let [a, b, c] = [(); 3].map(|_| Pubkey::new_unique());
+        let set = admin_set(&[a, b, c], 2);
+        let mut action = admin_action(&[a]);
+        action.action = AdminActionKind::SetAllowlistOnly(true);
+
+        assert_eq!(
+            action.consume(&set, &AdminActionKind::SetAllowlistOnly(true), 10),
+            Err(crate::error::GovernanceError::InsufficientAdminApprovals.into())
+        );
+        action.approve(b, &set).unwrap();
+        assert_eq!(
+            action.consume(&set, &AdminActionKind::SetAllowlistOnly(false), 10),
+            Err(crate::error::GovernanceError::AdminActionMismatch.into())
+        );
+
+        action
+            .consume(&set, &AdminActionKind::SetAllowlistOnly(true), 10)
+            .unwrap();
+        assert_eq!(action.executed_at, Some(10));
+        assert_eq!(
+            action.consume(&set, &AdminActionKind::SetAllowlistOnly(true), 11),
+            Err(crate::error::GovernanceError::AdminActionAlreadyExecuted.into())
+        );
+    }
+}
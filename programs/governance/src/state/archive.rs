@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use super::proposal::{Proposal, ProposalStatus, ProposalType};
+
+/// Compact summary of a terminal proposal, kept on-chain after the proposal is closed
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalArchive {
+    /// Proposal ID
+    pub id: u64,
+    /// Proposal proposer
+    pub proposer: Pubkey,
+    /// Proposal type
+    pub proposal_type: ProposalType,
+    /// Final status
+    pub status: ProposalStatus,
+    /// Yes votes
+    pub yes_votes: u64,
+    /// No votes
+    pub no_votes: u64,
+    /// Abstain votes
+    pub abstain_votes: u64,
+    /// Veto votes
+    pub veto_votes: u64,
+    /// Deposit token mint
+    pub deposit_mint: Pubkey,
+    /// Deposit amount
+    pub deposit_amount: u64,
+    /// Deposit amount refunded to the proposer (including a refund left claimable)
+    pub deposit_refunded: u64,
+    /// Deposit amount kept as committee fee
+    pub deposit_fee: u64,
+    /// Deposit amount confiscated
+    pub deposit_confiscated: u64,
+    /// Part of the confiscated deposit that was burned
+    pub deposit_burned: u64,
+    /// Execution time
+    pub executed_at: Option<i64>,
+    /// SHA-256 of the title length (u32, little-endian), title and description
+    pub content_hash: [u8; 32],
+    /// PDA bump
+    pub bump: u8,
+}
+
+const _: () = assert!(8 + ProposalArchive::INIT_SPACE < 200);
+
+impl ProposalArchive {
+    /// Content hash of a proposal's title and description
+    pub fn content_hash(title: &str, description: &str) -> [u8; 32] {
+        hashv(&[
+            &(title.len() as u32).to_le_bytes(),
+            title.as_bytes(),
+            description.as_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Record the summary of a terminal proposal
+    pub fn record(&mut self, proposal: &Proposal, bump: u8) -> Result<()> {
+        self.id = proposal.id;
+        self.proposer = proposal.proposer;
+        self.proposal_type = proposal.proposal_type()?;
+        self.status = proposal.status()?;
+        self.yes_votes = proposal.yes_votes;
+        self.no_votes = proposal.no_votes;
+        self.abstain_votes = proposal.abstain_votes;
+        self.veto_votes = proposal.veto_votes;
+        self.deposit_mint = proposal.deposit_mint;
+        self.deposit_amount = proposal.deposit_amount;
+        self.deposit_refunded = proposal.deposit_refunded;
+        self.deposit_fee = proposal.deposit_fee;
+        self.deposit_confiscated = proposal.deposit_confiscated;
+        self.deposit_burned = proposal.deposit_burned;
+        self.executed_at = proposal.executed_at();
+        self.content_hash = Self::content_hash(proposal.title(), proposal.description());
+        self.bump = bump;
+        Ok(())
+    }
+}
@@ -0,0 +1,334 @@
+use anchor_lang::prelude::*;
+
+use super::governance::FeeDistributionMode;
+
+/// Maximum committee size
+#[constant]
+pub const MAX_COMMITTEE_MEMBERS: u32 = 32;
+
+/// Committee seat term (180 days)
+pub const MEMBER_TERM_SECONDS: i64 = 15_552_000;
+
+/// Maximum member display name length in UTF-8 bytes (matches the account max_len)
+pub const MAX_MEMBER_DISPLAY_NAME_BYTES: usize = 32;
+
+/// Committee member account, one PDA per member
+#[account]
+#[derive(InitSpace)]
+pub struct CommitteeMember {
+    /// Member address
+    pub member: Pubkey,
+    /// Display name shown by clients
+    #[max_len(32)]
+    pub display_name: String,
+    /// Votes cast by the member (participation counter for fee distribution)
+    pub vote_count: u64,
+    /// Time the member joined the committee
+    pub joined_at: i64,
+    /// Whether the member may vote (false while suspended)
+    pub active: bool,
+    /// End of the member's term; the member cannot vote from this time on
+    pub term_end: i64,
+    /// Last time the member joined, voted or was reinstated; proposals opened after it
+    /// without the member's vote count toward inactivity
+    pub last_active_at: i64,
+    /// Whether the current suspension came from flag_inactive_member (lifted by the member)
+    pub suspended_for_inactivity: bool,
+    /// Alternate designated to take over the seat while the member is suspended
+    pub alternate: Option<Pubkey>,
+    /// Primary member whose seat this member took over as alternate (None for regular seats)
+    pub substitutes_for: Option<Pubkey>,
+    /// Committee tokens locked in the member's stake when seated (voting power source when
+    /// stake_based_voting_power is enabled)
+    pub staked_amount: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CommitteeMember {
+    /// Load a committee member account, or None when no account exists at the address
+    /// The address itself is checked by the caller's seeds constraint
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let committee_member = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(committee_member))
+    }
+
+    /// Write the member back to its account
+    pub fn store(&self, account: &AccountInfo) -> Result<()> {
+        let mut data = account.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+
+    /// Close a committee member account, returning its rent to the destination
+    /// The zeroed data fails deserialization if the account is revived in the same transaction
+    pub fn close<'info>(
+        account: &AccountInfo<'info>,
+        destination: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let lamports = account.lamports();
+        account.sub_lamports(lamports)?;
+        destination.add_lamports(lamports)?;
+        account.try_borrow_mut_data()?.fill(0);
+        Ok(())
+    }
+
+    /// Check if the member's term has ended at the given time
+    pub fn is_term_expired(&self, now: i64) -> bool {
+        now >= self.term_end
+    }
+
+    /// Check if the seat was taken over by an alternate after the given time
+    /// Such seats are outside the committee of proposals created before the takeover
+    pub fn is_substitute_after(&self, as_of: i64) -> bool {
+        self.substitutes_for.is_some() && self.joined_at > as_of
+    }
+
+    /// Extend the term by a full seat term, starting from the later of now and the current term end
+    pub fn renew_term(&mut self, now: i64) -> Result<()> {
+        self.term_end = self
+            .term_end
+            .max(now)
+            .checked_add(MEMBER_TERM_SECONDS)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record a vote cast by the member
+    pub fn record_vote(&mut self, now: i64) -> Result<()> {
+        self.vote_count = self
+            .vote_count
+            .checked_add(1)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        self.last_active_at = now;
+        Ok(())
+    }
+
+    /// Suspend the member for missing too many finalized proposals
+    pub fn suspend_for_inactivity(&mut self) {
+        self.active = false;
+        self.suspended_for_inactivity = true;
+    }
+
+    /// Lift a suspension and restart the inactivity window
+    pub fn reinstate(&mut self, now: i64) {
+        self.active = true;
+        self.suspended_for_inactivity = false;
+        self.last_active_at = now;
+    }
+
+    /// Fee distribution weight of the member
+    pub fn fee_weight(&self, mode: FeeDistributionMode) -> u64 {
+        match mode {
+            FeeDistributionMode::Equal => 1,
+            FeeDistributionMode::Participation => self.vote_count,
+        }
+    }
+}
+
+/// Finalized proposals a VoterStats account remembers
+pub const VOTER_STATS_HISTORY_LEN: u8 = 64;
+
+/// Participation over the bonus window a member needs for the participation bonus (90%)
+pub const PARTICIPATION_BONUS_MIN_RATE_BPS: u64 = 9000;
+
+/// Committee member's signed message nonce, one PDA per member
+/// Created by the first relayed action; a missing account means nonce 0
+#[account]
+#[derive(InitSpace)]
+pub struct MemberNonce {
+    /// Member address
+    pub member: Pubkey,
+    /// Nonce the member's next signed message must carry
+    pub nonce: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MemberNonce {
+    /// Load a member nonce account, or None when no account exists at the address
+    /// The address itself is checked by the caller
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let member_nonce = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(member_nonce))
+    }
+
+    /// Write the nonce back to its account
+    pub fn store(&self, account: &AccountInfo) -> Result<()> {
+        let mut data = account.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+}
+
+/// Committee member's participation in recent finalized proposals, one PDA per member
+/// Recorded at finalization while the participation bonus is enabled
+#[account]
+#[derive(InitSpace)]
+pub struct VoterStats {
+    /// Member address
+    pub member: Pubkey,
+    /// Whether the member voted on each recorded proposal, most recent in the lowest bit
+    pub recent_votes: u64,
+    /// Finalized proposals recorded (stops counting at VOTER_STATS_HISTORY_LEN)
+    pub recorded_proposals: u8,
+    /// Last finalized proposal recorded
+    pub last_proposal_id: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VoterStats {
+    /// Load a voter stats account, or None when no account exists at the address
+    /// The address itself is checked by the caller
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let voter_stats = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(voter_stats))
+    }
+
+    /// Write the stats back to their account
+    pub fn store(&self, account: &AccountInfo) -> Result<()> {
+        let mut data = account.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+
+    /// Record whether the member voted on a finalized proposal
+    pub fn record(&mut self, proposal_id: u64, voted: bool) {
+        self.recent_votes = (self.recent_votes << 1) | voted as u64;
+        self.recorded_proposals = (self.recorded_proposals + 1).min(VOTER_STATS_HISTORY_LEN);
+        self.last_proposal_id = proposal_id;
+    }
+
+    /// Check if the member voted on at least 90% of the last `window` recorded proposals
+    /// Members with fewer recorded proposals than the window do not qualify
+    pub fn earns_participation_bonus(&self, window: u8) -> bool {
+        if window == 0 || self.recorded_proposals < window {
+            return false;
+        }
+        let window_mask = u64::MAX >> (u64::BITS - window as u32);
+        let voted = (self.recent_votes & window_mask).count_ones() as u64;
+        voted * 10_000 >= window as u64 * PARTICIPATION_BONUS_MIN_RATE_BPS
+    }
+}
+
+/// Change in committee composition reported by CommitteeChanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitteeChangeKind {
+    /// Member seated
+    Added,
+    /// Member removed
+    Removed,
+    /// Member suspended (by the config admin or for inactivity)
+    Suspended,
+    /// Suspended member reinstated
+    Reactivated,
+    /// Alternate took over a suspended member's seat
+    AlternateActivated,
+}
+
+/// Committee token stake locked by a prospective member, one PDA per member
+/// The tokens sit in the member's stake vault, owned by the governance authority
+#[account]
+#[derive(InitSpace)]
+pub struct MemberStake {
+    /// Staking member address
+    pub member: Pubkey,
+    /// Committee tokens held in the stake vault
+    pub amount: u64,
+    /// Time the stake was made
+    pub staked_at: i64,
+    /// Earliest time the stake can be withdrawn (pushed back when the member is removed)
+    pub unlocks_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MemberStake {
+    /// Load a member stake account, or None when no account exists at the address
+    /// The address itself is checked by the caller
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let member_stake = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(member_stake))
+    }
+
+    /// Write the stake back to its account
+    pub fn store(&self, account: &AccountInfo) -> Result<()> {
+        let mut data = account.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+
+    /// Restart the withdrawal cooldown from the given time
+    pub fn lock_until_cooldown_ends(&mut self, now: i64, cooldown: i64) -> Result<()> {
+        self.unlocks_at = now
+            .checked_add(cooldown)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Check if the stake can be withdrawn at the given time
+    pub fn is_unlocked(&self, now: i64) -> bool {
+        now >= self.unlocks_at
+    }
+}
+
+/// Committee tokens locked for voting weight, one PDA per member
+/// The tokens sit in the member's lockup vault, owned by the governance authority
+#[account]
+#[derive(InitSpace)]
+pub struct Lockup {
+    /// Locking member address
+    pub member: Pubkey,
+    /// Committee tokens held in the lockup vault
+    pub amount: u64,
+    /// Time the tokens were locked
+    pub locked_at: i64,
+    /// Earliest time the tokens can be withdrawn
+    pub unlock_time: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Lockup {
+    /// Load a lockup account, or None when no account exists at the address
+    /// The address itself is checked by the caller
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let lockup = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(lockup))
+    }
+
+    /// Voting power of the locked tokens as of the given time, scaled by the lock time
+    /// left then
+    pub fn voting_power(
+        &self,
+        governance_config: &super::governance::GovernanceConfig,
+        token_decimals: u8,
+        at: i64,
+    ) -> u64 {
+        let voting_power =
+            super::vote::VotingPowerCalculator::calculate_voting_power(self.amount, token_decimals);
+        governance_config.apply_lock_multiplier(voting_power, self.unlock_time.saturating_sub(at))
+    }
+
+    /// Check if the tokens can be withdrawn at the given time
+    pub fn is_unlocked(&self, now: i64) -> bool {
+        now >= self.unlock_time
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of programs allowed to create proposals through CPI
+pub const MAX_CPI_CALLERS: usize = 8;
+
+/// External programs allowed to create proposals through CPI
+/// Direct transactions never consult it
+#[account]
+#[derive(InitSpace)]
+pub struct CpiCallerAllowlist {
+    /// Allowed calling programs
+    #[max_len(8)]
+    pub callers: Vec<Pubkey>,
+    /// Last update time
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CpiCallerAllowlist {
+    /// Load the allowlist, or None when it has not been created
+    /// The address itself is checked by the caller's seeds constraint
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let allowlist = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(allowlist))
+    }
+
+    /// Check if a program may create proposals through CPI
+    pub fn is_allowed(&self, program_id: &Pubkey) -> bool {
+        self.callers.contains(program_id)
+    }
+
+    /// Add or remove a calling program
+    /// Returns whether the program was allowed before
+    pub fn set_caller(&mut self, program_id: Pubkey, allowed: bool, now: i64) -> Result<bool> {
+        let was_allowed = self.is_allowed(&program_id);
+        if allowed && !was_allowed {
+            require!(
+                program_id != crate::ID && program_id != Pubkey::default(),
+                crate::error::GovernanceError::InvalidCpiCaller
+            );
+            require!(
+                self.callers.len() < MAX_CPI_CALLERS,
+                crate::error::GovernanceError::InvalidCpiCaller
+            );
+            self.callers.push(program_id);
+        } else if !allowed {
+            self.callers.retain(|caller| *caller != program_id);
+        }
+        self.updated_at = now;
+        Ok(was_allowed)
+    }
+}
@@ -1,19 +1,35 @@
 use anchor_lang::prelude::*;
 
+use super::committee::MAX_COMMITTEE_MEMBERS;
+use crate::instructions::common::{GOVERNANCE_AUTHORITY_SEED, GOVERNANCE_TOKEN_VAULT_SEED};
+
+/// Current GovernanceConfig layout version
+pub const GOVERNANCE_CONFIG_SCHEMA_VERSION: u8 = 18;
+
 /// Governance system configuration account
+/// Fields added after schema version 1 go after schema_version, so versioned accounts keep
+/// their byte layout and migrate_governance_config only has to grow them; accounts in the
+/// original layout are decoded as LegacyGovernanceConfigV0 instead
 #[account]
 #[derive(InitSpace)]
 pub struct GovernanceConfig {
-    /// Administrator address
+    /// Governance authority (handed over with the config admin role by the two-step transfer
+    /// while both are held by the same key)
     pub authority: Pubkey,
     /// Committee token mint address (fixed to specified SPL Token)
     pub committee_token_mint: Pubkey,
-    /// Committee member address array (maximum 10 members)
+    /// Legacy committee member slots, emptied by migrate_committee_member
+    /// (members now live in CommitteeMember accounts)
     pub committee_members: [Option<Pubkey>; 10],
-    /// Committee member count
+    /// Committee member count (CommitteeMember accounts plus unmigrated legacy slots)
     pub committee_member_count: u8,
-    /// Proposal deposit amount (100 USDC)
+    /// Votes recorded per legacy slot, carried over to the CommitteeMember account on migration
+    pub committee_vote_counts: [u64; 10],
+    /// Proposal deposit amount (100 USDC), minimum for the primary deposit mint
     pub proposal_deposit: u64,
+    /// Accepted deposit mints with per-mint minimum deposits (first entry is the primary mint)
+    #[max_len(5)]
+    pub deposit_mints: Vec<DepositMint>,
     /// Voting period (14 days, in seconds)
     pub voting_period: u64,
     /// Participation threshold requirement (40% = 4000 basis points)
@@ -24,32 +40,388 @@ pub struct GovernanceConfig {
     pub veto_threshold: u16,
     /// Committee fee rate (10% = 1000 basis points)
     pub fee_rate: u16,
-    /// Total voting power
+    /// Total committee voting power, as of total_voting_power_synced_at
     pub total_voting_power: u64,
     /// Proposal counter
     pub proposal_counter: u64,
+    /// Number of proposals still in Pending status
+    pub pending_proposal_count: u64,
     /// Creation time
     pub created_at: i64,
     /// Last update time
     pub updated_at: i64,
-    /// Test mode flag
+    /// Test mode flag (one-way: once cleared it can never be set again)
     pub test_mode: bool,
+    /// Resubmission deposit rate, fraction of proposal_deposit (50% = 5000 basis points)
+    pub resubmission_deposit_rate: u16,
+    /// Slash deposit increase per prior failed filing against the same merchant (100% = 10000 basis points)
+    pub slash_escalation_rate: u16,
+    /// Maximum slash deposit multiplier (5x = 50000 basis points)
+    pub max_slash_deposit_multiplier: u32,
+    /// What happens to deposits confiscated from vetoed proposals
+    pub confiscation_policy: ConfiscationPolicy,
+    /// Hold each new proposal's deposit in its own escrow account instead of the pooled vault
+    pub deposit_escrow_enabled: bool,
+    /// Deposit slash schedule (basis points of the deposit kept by the committee)
+    pub deposit_slash_schedule: DepositSlashSchedule,
+    /// Whether proposal deposits are SPL tokens or native SOL (one kind per deployment)
+    pub deposit_kind: DepositKind,
+    /// Proposed next administrator, who must accept before the handover takes effect
+    pub pending_authority: Option<Pubkey>,
+    /// Route privileged instructions through M-of-N admin set approval instead of the authority
+    pub admin_multisig_enabled: bool,
+    /// Last time total_voting_power was recomputed from member balances (0 if never)
+    pub total_voting_power_synced_at: i64,
+    /// Time lock_production permanently committed the deployment to production mode
+    pub production_locked_at: Option<i64>,
+    /// Config admin: configuration, committee membership, vaults and role assignment
+    pub config_admin: Pubkey,
+    /// Rule admin: rule registry documents
+    pub rule_admin: Pubkey,
+    /// Treasury admin: committee fee withdrawal, distribution and vault reconciliation
+    pub treasury_admin: Pubkey,
     /// PDA bump
     pub bump: u8,
+    /// Layout version (0 for accounts created before versioning)
+    pub schema_version: u8,
+    /// Consecutive missed finalized proposals after which anyone may suspend a member
+    pub inactivity_threshold: u8,
+    /// Committee tokens (base units) a member must stake before being seated (0: no stake)
+    pub member_stake_requirement: u64,
+    /// Seconds a stake stays locked after staking or after the member's removal
+    pub member_stake_cooldown: i64,
+    /// Measure voting power from the member's stake instead of the live token balance
+    pub stake_based_voting_power: bool,
+    /// Most committee members this deployment seats (at most MAX_COMMITTEE_MEMBERS)
+    pub max_committee_size: u8,
+    /// Most proposals that may be Pending at once
+    pub max_active_proposals: u16,
+    /// Committee composition counter, advanced on every addition, removal, suspension
+    /// and reinstatement
+    pub committee_epoch: u64,
+    /// Furthest into the future a rule document's effective_from may be set (seconds)
+    pub max_rule_effective_delay: i64,
+    /// Whether ipfs:// and ar:// URLs must carry a well-formed CID or transaction ID and
+    /// https:// URLs a host (rule documents and proposal evidence)
+    pub strict_url_validation: bool,
+    /// Whether proposal status changes are recorded in the ProposalIndex
+    /// (set by initialize_proposal_index)
+    pub proposal_index_enabled: bool,
+    /// Whether deposit, refund and fee movements carry an SPL memo with the proposal ID,
+    /// for matching them in exchange and treasury reconciliation
+    pub emit_memos: bool,
+    /// Marketplace order program owning the orders dispute proposals refer to; dispute
+    /// parties are checked against the order account at creation (None: not checked)
+    pub order_program: Option<Pubkey>,
+    /// Voting power bonus in basis points for members who voted on at least 90% of the
+    /// last participation_bonus_window finalized proposals (0: disabled)
+    pub participation_bonus_bps: u16,
+    /// Number of most recent finalized proposals the participation bonus looks at
+    pub participation_bonus_window: u8,
+    /// Voting power the participation bonus cannot raise a member past (0: no cap)
+    pub max_member_voting_power: u64,
+    /// Canonical bump of the governance authority PDA, the signer of vault transfers
+    pub authority_bump: u8,
+    /// Canonical bumps of the token vault PDAs of the accepted SPL deposit mints
+    #[max_len(5)]
+    pub token_vault_bumps: Vec<TokenVaultBump>,
+    /// Lock duration earning the full lockup multiplier (0: lockups disabled)
+    pub max_lock_duration: i64,
+    /// Voting power multiplier of a lockup with max_lock_duration remaining
+    /// (20000 = 2x; shorter locks scale linearly down to 1x)
+    pub max_lock_multiplier_bps: u16,
+    /// Seconds a queued configuration update waits before it can be applied
+    pub config_timelock: i64,
+    /// Whether proposals by current committee members and the authority are created
+    /// without a deposit
+    pub waive_deposit_for_committee: bool,
+    /// Divergence in basis points between a manually set total voting power and the
+    /// member balance sum beyond which update_total_voting_power emits a warning
+    pub voting_power_tolerance_bps: u16,
+    /// Least number of seconds between two touch_governance heartbeats
+    pub heartbeat_interval: i64,
+    /// Time of the last touch_governance heartbeat (0 if never)
+    pub last_heartbeat_at: i64,
+    /// Rounding of participation, approval and veto rates to basis points
+    pub rate_rounding: RoundingMode,
 }
 
 impl GovernanceConfig {
+    /// Check if the account has been migrated to the current layout
+    pub fn is_current_schema(&self) -> bool {
+        self.schema_version == GOVERNANCE_CONFIG_SCHEMA_VERSION
+    }
+
+    /// Fill defaults for the fields added since the account's schema version
+    pub fn migrate_schema(&mut self) {
+        if self.schema_version < 1 {
+            // Configs predating role separation hand every unset role to the authority
+            for role in [AdminRole::Config, AdminRole::Rule, AdminRole::Treasury] {
+                if self.admin_for(role) == Pubkey::default() {
+                    self.set_admin(role, self.authority);
+                }
+            }
+        }
+        if self.schema_version < 2 {
+            self.inactivity_threshold = governance_constants::DEFAULT_INACTIVITY_THRESHOLD;
+        }
+        if self.schema_version < 3 {
+            // Existing committees keep seating members without a stake
+            self.member_stake_requirement = 0;
+            self.member_stake_cooldown = governance_constants::DEFAULT_MEMBER_STAKE_COOLDOWN;
+            self.stake_based_voting_power = false;
+        }
+        if self.schema_version < 4 {
+            self.max_committee_size = MAX_COMMITTEE_MEMBERS as u8;
+            self.max_active_proposals = governance_constants::DEFAULT_MAX_ACTIVE_PROPOSALS;
+        }
+        if self.schema_version < 5 {
+            self.committee_epoch = 0;
+        }
+        if self.schema_version < 6 {
+            self.max_rule_effective_delay = governance_constants::DEFAULT_MAX_RULE_EFFECTIVE_DELAY;
+        }
+        if self.schema_version < 7 {
+            // Existing deployments keep the prefix-only URL check until they opt in
+            self.strict_url_validation = false;
+        }
+        if self.schema_version < 8 {
+            self.proposal_index_enabled = false;
+        }
+        if self.schema_version < 9 {
+            self.emit_memos = false;
+        }
+        if self.schema_version < 10 {
+            self.order_program = None;
+        }
+        if self.schema_version < 11 {
+            self.participation_bonus_bps = 0;
+            self.participation_bonus_window = 0;
+            self.max_member_voting_power = 0;
+        }
+        if self.schema_version < 12 {
+            self.authority_bump = governance_authority_bump();
+            self.token_vault_bumps = self
+                .deposit_mints
+                .iter()
+                .filter(|deposit_mint| deposit_mint.mint != NATIVE_SOL_DEPOSIT_MINT)
+                .map(|deposit_mint| TokenVaultBump::derive(deposit_mint.mint))
+                .collect();
+        }
+        if self.schema_version < 13 {
+            self.max_lock_duration = 0;
+            self.max_lock_multiplier_bps = 0;
+        }
+        if self.schema_version < 14 {
+            self.config_timelock = governance_constants::DEFAULT_CONFIG_TIMELOCK;
+        }
+        if self.schema_version < 15 {
+            self.waive_deposit_for_committee = false;
+        }
+        if self.schema_version < 16 {
+            self.voting_power_tolerance_bps =
+                governance_constants::DEFAULT_VOTING_POWER_TOLERANCE_BPS;
+        }
+        if self.schema_version < 17 {
+            self.heartbeat_interval = governance_constants::DEFAULT_HEARTBEAT_INTERVAL;
+            self.last_heartbeat_at = 0;
+        }
+        if self.schema_version < 18 {
+            self.rate_rounding = RoundingMode::Floor;
+        }
+        self.schema_version = GOVERNANCE_CONFIG_SCHEMA_VERSION;
+    }
+
+    /// Check if finalization applies the participation bonus (and takes voter stats)
+    pub fn participation_bonus_enabled(&self) -> bool {
+        self.participation_bonus_bps > 0
+    }
+
+    /// Voting power with the participation bonus added, capped at max_member_voting_power
+    /// A member already above the cap keeps their voting power but gets no bonus
+    pub fn apply_participation_bonus(&self, voting_power: u64) -> u64 {
+        let bonus = (voting_power as u128 * self.participation_bonus_bps as u128 / 10_000) as u64;
+        let boosted = voting_power.saturating_add(bonus);
+        if self.max_member_voting_power == 0 {
+            boosted
+        } else {
+            boosted.min(self.max_member_voting_power.max(voting_power))
+        }
+    }
+
+    /// Check if lock_tokens is open and finalization takes member lockups
+    pub fn lockups_enabled(&self) -> bool {
+        self.max_lock_duration > 0
+    }
+
+    /// Voting power of locked tokens with remaining_lock seconds left on the lock
+    /// The multiplier grows linearly from 1x for an expired lock to
+    /// max_lock_multiplier_bps for max_lock_duration or more
+    pub fn apply_lock_multiplier(&self, voting_power: u64, remaining_lock: i64) -> u64 {
+        if !self.lockups_enabled() {
+            return voting_power;
+        }
+        let base = governance_constants::BASIS_POINTS_DENOMINATOR as u128;
+        let remaining = remaining_lock.clamp(0, self.max_lock_duration) as u128;
+        let extra = (self.max_lock_multiplier_bps as u128).saturating_sub(base);
+        let multiplier = base + extra * remaining / self.max_lock_duration as u128;
+        u64::try_from(voting_power as u128 * multiplier / base).unwrap_or(u64::MAX)
+    }
+
+    /// Get the stored canonical bump of a deposit mint's token vault PDA
+    pub fn token_vault_bump(&self, mint: &Pubkey) -> Result<u8> {
+        self.token_vault_bumps
+            .iter()
+            .find(|vault_bump| vault_bump.mint == *mint)
+            .map(|vault_bump| vault_bump.bump)
+            .ok_or(crate::error::GovernanceError::DepositMintNotAccepted.into())
+    }
+
+    /// Get the key holding an admin role
+    pub fn admin_for(&self, role: AdminRole) -> Pubkey {
+        match role {
+            AdminRole::Config => self.config_admin,
+            AdminRole::Rule => self.rule_admin,
+            AdminRole::Treasury => self.treasury_admin,
+        }
+    }
+
+    /// Assign an admin role, returning the previous holder
+    pub fn set_admin(&mut self, role: AdminRole, admin: Pubkey) -> Pubkey {
+        let slot = match role {
+            AdminRole::Config => &mut self.config_admin,
+            AdminRole::Rule => &mut self.rule_admin,
+            AdminRole::Treasury => &mut self.treasury_admin,
+        };
+        std::mem::replace(slot, admin)
+    }
+
     /// Get next proposal ID
     pub fn next_proposal_id(&mut self) -> u64 {
         self.proposal_counter += 1;
         self.proposal_counter
     }
 
+    /// Claim the next proposal ID for a new pending proposal
+    /// The caller's expected ID must match, otherwise the derived proposal PDA is stale
+    pub fn reserve_proposal_id(&mut self, expected_proposal_id: u64) -> Result<u64> {
+        let next_id = self
+            .proposal_counter
+            .checked_add(1)
+            .ok_or(crate::error::GovernanceError::MathOverflow)?;
+        require!(
+            expected_proposal_id == next_id,
+            crate::error::GovernanceError::ProposalIdMismatch
+        );
+        require!(
+            self.pending_proposal_count < self.max_active_proposals as u64,
+            crate::error::GovernanceError::ActiveProposalLimitReached
+        );
+
+        self.pending_proposal_count = self
+            .pending_proposal_count
+            .checked_add(1)
+            .ok_or(crate::error::GovernanceError::MathOverflow)?;
+        Ok(self.next_proposal_id())
+    }
+
+    /// Record that a pending proposal has left the Pending status for `status`
+    /// Every status a Pending proposal can move to frees its slot; the match is exhaustive
+    /// so a new status has to decide, and the checked decrement surfaces a leaked count
+    pub fn release_pending_proposal(
+        &mut self,
+        status: &crate::state::proposal::ProposalStatus,
+    ) -> Result<()> {
+        use crate::state::proposal::ProposalStatus;
+
+        match status {
+            ProposalStatus::Passed
+            | ProposalStatus::Rejected
+            | ProposalStatus::Vetoed
+            | ProposalStatus::Cancelled => {}
+            // Still pending, or only reachable from Passed (whose slot is already free)
+            ProposalStatus::Pending | ProposalStatus::Executed => {
+                return err!(crate::error::GovernanceError::ProposalNotFinalized)
+            }
+        }
+        self.pending_proposal_count = self
+            .pending_proposal_count
+            .checked_sub(1)
+            .ok_or(crate::error::GovernanceError::ArithmeticUnderflow)?;
+        Ok(())
+    }
+
+    /// Switch the deployment between SPL token and native SOL deposits
+    /// Only allowed while no deposit is pending or locked, so the two kinds never coexist
+    pub fn set_deposit_kind(
+        &mut self,
+        deposit_kind: DepositKind,
+        native_min_deposit: u64,
+    ) -> Result<()> {
+        require!(
+            self.pending_proposal_count == 0,
+            crate::error::GovernanceError::PendingProposalsExist
+        );
+        require!(
+            self.deposit_mints
+                .iter()
+                .all(|mint| mint.total_deposits_locked == 0),
+            crate::error::GovernanceError::OutstandingFunds
+        );
+        if deposit_kind == DepositKind::NativeSol {
+            require!(
+                !self.deposit_escrow_enabled,
+                crate::error::GovernanceError::DepositEscrowMismatch
+            );
+            self.set_deposit_mint(
+                NATIVE_SOL_DEPOSIT_MINT,
+                NATIVE_SOL_DECIMALS,
+                native_min_deposit,
+            )?;
+        }
+        self.deposit_kind = deposit_kind;
+        Ok(())
+    }
+
+    /// Check whether any vault still owes refunds or holds treasury funds
+    pub fn has_outstanding_funds(&self) -> bool {
+        self.deposit_mints
+            .iter()
+            .any(|mint| mint.total_deposits_locked > 0 || mint.treasury_balance > 0)
+    }
+
     /// Calculate committee fee
     pub fn calculate_committee_fee(&self, amount: u64) -> u64 {
         (amount * self.fee_rate as u64) / 10000
     }
 
+    /// Calculate discounted deposit for a resubmitted proposal
+    pub fn calculate_resubmission_deposit(&self, base_deposit: u64) -> Result<u64> {
+        let deposit = (base_deposit as u128)
+            .checked_mul(self.resubmission_deposit_rate as u128)
+            .ok_or(crate::error::GovernanceError::MathOverflow)?
+            / governance_constants::BASIS_POINTS_DENOMINATOR as u128;
+        Ok(deposit as u64)
+    }
+
+    /// Calculate required deposit for a slash proposal given prior failed filings
+    pub fn calculate_slash_deposit(&self, base_deposit: u64, prior_failures: u32) -> Result<u64> {
+        let multiplier = (governance_constants::BASIS_POINTS_DENOMINATOR as u128)
+            .checked_add(
+                (self.slash_escalation_rate as u128)
+                    .checked_mul(prior_failures as u128)
+                    .ok_or(crate::error::GovernanceError::MathOverflow)?,
+            )
+            .ok_or(crate::error::GovernanceError::MathOverflow)?
+            .min(self.max_slash_deposit_multiplier.max(10000) as u128);
+
+        let deposit = (base_deposit as u128)
+            .checked_mul(multiplier)
+            .ok_or(crate::error::GovernanceError::MathOverflow)?
+            / governance_constants::BASIS_POINTS_DENOMINATOR as u128;
+        u64::try_from(deposit).map_err(|_| crate::error::GovernanceError::MathOverflow.into())
+    }
+
     /// Calculate proposer refund amount
     pub fn calculate_proposer_refund(&self, deposit: u64) -> u64 {
         deposit - self.calculate_committee_fee(deposit)
@@ -66,77 +438,488 @@ impl GovernanceConfig {
             self.participation_threshold,
             self.approval_threshold,
             self.veto_threshold,
+            self.rate_rounding,
         )
     }
 
-    /// Add committee member
-    pub fn add_committee_member(&mut self, member: Pubkey) -> Result<()> {
+    /// Get accepted deposit mint configuration
+    pub fn get_deposit_mint(&self, mint: &Pubkey) -> Result<&DepositMint> {
+        self.deposit_mints
+            .iter()
+            .find(|deposit_mint| deposit_mint.mint == *mint)
+            .ok_or(crate::error::GovernanceError::DepositMintNotAccepted.into())
+    }
+
+    /// Add or update accepted deposit mint
+    pub fn set_deposit_mint(&mut self, mint: Pubkey, decimals: u8, min_deposit: u64) -> Result<()> {
+        if let Some(position) = self.deposit_mints.iter().position(|m| m.mint == mint) {
+            self.deposit_mints[position].min_deposit = min_deposit;
+            if position == 0 {
+                self.proposal_deposit = min_deposit;
+            }
+        } else {
+            require!(
+                self.deposit_mints.len() < MAX_DEPOSIT_MINTS,
+                crate::error::GovernanceError::TooManyDepositMints
+            );
+            self.deposit_mints.push(DepositMint {
+                mint,
+                decimals,
+                min_deposit,
+                total_deposits_locked: 0,
+                cumulative_donations: 0,
+                treasury_balance: 0,
+            });
+            if mint != NATIVE_SOL_DEPOSIT_MINT {
+                self.token_vault_bumps.push(TokenVaultBump::derive(mint));
+            }
+        }
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Record a deposit entering the vault as refundable
+    pub fn lock_deposit(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let deposit_mint = self.get_deposit_mint_mut(mint)?;
+        deposit_mint.total_deposits_locked = deposit_mint
+            .total_deposits_locked
+            .checked_add(amount)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record a deposit leaving the refundable pool (refunded, taken as fee or confiscated)
+    pub fn release_locked_deposit(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let deposit_mint = self.get_deposit_mint_mut(mint)?;
+        deposit_mint.total_deposits_locked = deposit_mint
+            .total_deposits_locked
+            .checked_sub(amount)
+            .ok_or(crate::error::GovernanceError::ArithmeticUnderflow)?;
+        Ok(())
+    }
+
+    /// Record a donation into a deposit mint's vault (never locked, credited to the treasury)
+    pub fn record_donation(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let deposit_mint = self.get_deposit_mint_mut(mint)?;
+        deposit_mint.cumulative_donations =
+            deposit_mint
+                .cumulative_donations
+                .checked_add(amount)
+                .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        self.credit_treasury(mint, amount)
+    }
+
+    /// Attribute vault funds to the committee treasury (fees, confiscations, donations, sweeps)
+    pub fn credit_treasury(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let deposit_mint = self.get_deposit_mint_mut(mint)?;
+        deposit_mint.treasury_balance = deposit_mint
+            .treasury_balance
+            .checked_add(amount)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record treasury funds leaving the vault
+    pub fn debit_treasury(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let deposit_mint = self.get_deposit_mint_mut(mint)?;
+        deposit_mint.treasury_balance = deposit_mint
+            .treasury_balance
+            .checked_sub(amount)
+            .ok_or(crate::error::GovernanceError::InsufficientWithdrawableFees)?;
+        Ok(())
+    }
+
+    /// Get the amount of a vault balance not owed back as refundable deposits
+    /// Fails if the vault holds less than the deposits it owes
+    pub fn vault_surplus(&self, mint: &Pubkey, vault_balance: u64) -> Result<u64> {
+        let deposit_mint = self.get_deposit_mint(mint)?;
+        vault_balance
+            .checked_sub(deposit_mint.total_deposits_locked)
+            .ok_or(crate::error::GovernanceError::VaultInsolvent.into())
+    }
+
+    /// Get the amount of treasury funds that can leave the vault
+    /// Unattributed surplus only becomes withdrawable once reconciled into the treasury
+    pub fn withdrawable_amount(&self, mint: &Pubkey, vault_balance: u64) -> Result<u64> {
+        let surplus = self.vault_surplus(mint, vault_balance)?;
+        let treasury_balance = self.get_deposit_mint(mint)?.treasury_balance;
+        Ok(surplus.min(treasury_balance))
+    }
+
+    fn get_deposit_mint_mut(&mut self, mint: &Pubkey) -> Result<&mut DepositMint> {
+        self.deposit_mints
+            .iter_mut()
+            .find(|deposit_mint| deposit_mint.mint == *mint)
+            .ok_or(crate::error::GovernanceError::DepositMintNotAccepted.into())
+    }
+
+    /// Remove accepted deposit mint (the primary mint cannot be removed)
+    pub fn remove_deposit_mint(&mut self, mint: &Pubkey) -> Result<()> {
+        let position = self
+            .deposit_mints
+            .iter()
+            .position(|m| m.mint == *mint)
+            .ok_or(crate::error::GovernanceError::DepositMintNotAccepted)?;
         require!(
-            self.committee_member_count < 10,
+            position != 0,
+            crate::error::GovernanceError::PrimaryDepositMintRemoval
+        );
+        require!(
+            self.deposit_mints[position].total_deposits_locked == 0
+                && self.deposit_mints[position].treasury_balance == 0,
+            crate::error::GovernanceError::OutstandingFunds
+        );
+        self.deposit_mints.remove(position);
+        self.token_vault_bumps
+            .retain(|vault_bump| vault_bump.mint != *mint);
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Register a new committee member (its CommitteeMember account is created by the caller)
+    pub fn register_committee_member(&mut self, member: &Pubkey) -> Result<()> {
+        require!(
+            (self.committee_member_count as u32) < MAX_COMMITTEE_MEMBERS
+                && self.committee_member_count < self.max_committee_size,
             crate::error::GovernanceError::CommitteeFull
         );
+        require!(
+            !self.is_legacy_committee_member(member),
+            crate::error::GovernanceError::MemberAlreadyExists
+        );
+        self.committee_member_count += 1;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
 
-        // Check if already exists
-        for existing_member in self.committee_members.iter() {
-            if let Some(existing) = existing_member {
-                require!(
-                    *existing != member,
-                    crate::error::GovernanceError::MemberAlreadyExists
-                );
-            }
+    /// Unregister a committee member (its CommitteeMember account is closed by the caller)
+    pub fn unregister_committee_member(&mut self) -> Result<()> {
+        self.committee_member_count = self
+            .committee_member_count
+            .checked_sub(1)
+            .ok_or(crate::error::GovernanceError::MemberNotFound)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Advance the committee epoch, returning the new epoch
+    pub fn advance_committee_epoch(&mut self) -> Result<u64> {
+        self.committee_epoch = self
+            .committee_epoch
+            .checked_add(1)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        Ok(self.committee_epoch)
+    }
+
+    /// Record a touch_governance heartbeat at now
+    /// Fails with HeartbeatTooSoon until heartbeat_interval has passed since the last one
+    pub fn record_heartbeat(&mut self, now: i64) -> Result<()> {
+        if self.last_heartbeat_at > 0 {
+            let next_heartbeat_at = self
+                .last_heartbeat_at
+                .checked_add(self.heartbeat_interval)
+                .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+            require!(
+                now >= next_heartbeat_at,
+                crate::error::GovernanceError::HeartbeatTooSoon
+            );
         }
+        self.last_heartbeat_at = now;
+        Ok(())
+    }
 
-        // Add to the first empty slot
-        for slot in self.committee_members.iter_mut() {
-            if slot.is_none() {
-                *slot = Some(member);
-                self.committee_member_count += 1;
-                self.updated_at = Clock::get()?.unix_timestamp;
-                return Ok(());
-            }
+    /// Check if member still sits in a legacy slot (not yet migrated to a CommitteeMember account)
+    pub fn is_legacy_committee_member(&self, member: &Pubkey) -> bool {
+        self.committee_members.contains(&Some(*member))
+    }
+
+    /// Number of members still in legacy slots
+    pub fn legacy_committee_member_count(&self) -> usize {
+        self.committee_members
+            .iter()
+            .filter(|slot| slot.is_some())
+            .count()
+    }
+
+    /// Clear a member's legacy slot, returning the votes it recorded
+    /// The member stays counted in committee_member_count
+    pub fn take_legacy_committee_member(&mut self, member: &Pubkey) -> Result<u64> {
+        let index = self
+            .committee_members
+            .iter()
+            .position(|slot| *slot == Some(*member))
+            .ok_or(crate::error::GovernanceError::MemberNotFound)?;
+        let vote_count = self.committee_vote_counts[index];
+        self.committee_members[index] = None;
+        self.committee_vote_counts[index] = 0;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(vote_count)
+    }
+}
+
+/// GovernanceConfig in the original, pre-versioning layout
+/// Only migrate_governance_config reads it: these accounts keep this exact size, and the
+/// current layout does not share their byte positions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct LegacyGovernanceConfigV0 {
+    /// Administrator address
+    pub authority: Pubkey,
+    /// Committee token mint address
+    pub committee_token_mint: Pubkey,
+    /// Committee member address array
+    pub committee_members: [Option<Pubkey>; 10],
+    /// Committee member count
+    pub committee_member_count: u8,
+    /// Proposal deposit amount
+    pub proposal_deposit: u64,
+    /// Voting period (seconds)
+    pub voting_period: u64,
+    /// Participation threshold (basis points)
+    pub participation_threshold: u16,
+    /// Approval threshold (basis points)
+    pub approval_threshold: u16,
+    /// Veto threshold (basis points)
+    pub veto_threshold: u16,
+    /// Committee fee rate (basis points)
+    pub fee_rate: u16,
+    /// Total voting power
+    pub total_voting_power: u64,
+    /// Proposal counter
+    pub proposal_counter: u64,
+    /// Creation time
+    pub created_at: i64,
+    /// Last update time
+    pub updated_at: i64,
+    /// Test mode flag
+    pub test_mode: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl LegacyGovernanceConfigV0 {
+    /// Decode an account in the original layout (recognized by its size)
+    pub fn try_load(data: &[u8]) -> Result<Option<Self>> {
+        if data.len() != 8 + Self::INIT_SPACE || data[..8] != *GovernanceConfig::DISCRIMINATOR {
+            return Ok(None);
         }
+        Self::deserialize(&mut &data[8..])
+            .map(Some)
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData.into())
+    }
 
-        Err(crate::error::GovernanceError::CommitteeFull.into())
+    /// Schema version 0 config in the current layout: these fields, every later one zeroed
+    /// (as fields appended after a versioned account's layout read) until migrate_schema
+    pub fn into_current(self) -> Result<GovernanceConfig> {
+        let mut governance_config =
+            GovernanceConfig::deserialize(&mut &vec![0; GovernanceConfig::INIT_SPACE][..])?;
+        governance_config.authority = self.authority;
+        governance_config.committee_token_mint = self.committee_token_mint;
+        governance_config.committee_members = self.committee_members;
+        governance_config.committee_member_count = self.committee_member_count;
+        governance_config.proposal_deposit = self.proposal_deposit;
+        governance_config.voting_period = self.voting_period;
+        governance_config.participation_threshold = self.participation_threshold;
+        governance_config.approval_threshold = self.approval_threshold;
+        governance_config.veto_threshold = self.veto_threshold;
+        governance_config.fee_rate = self.fee_rate;
+        governance_config.total_voting_power = self.total_voting_power;
+        governance_config.proposal_counter = self.proposal_counter;
+        governance_config.created_at = self.created_at;
+        governance_config.updated_at = self.updated_at;
+        governance_config.test_mode = self.test_mode;
+        governance_config.bump = self.bump;
+        Ok(governance_config)
     }
+}
 
-    /// Remove committee member
-    pub fn remove_committee_member(&mut self, member: Pubkey) -> Result<()> {
-        for slot in self.committee_members.iter_mut() {
-            if let Some(existing) = slot {
-                if *existing == member {
-                    *slot = None;
-                    self.committee_member_count -= 1;
-                    self.updated_at = Clock::get()?.unix_timestamp;
-                    return Ok(());
-                }
+/// Deposit slash schedule by finalization outcome (basis points of the deposit)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct DepositSlashSchedule {
+    /// Kept from passed proposals (the committee fee)
+    pub passed_rate: u16,
+    /// Kept from rejected proposals at or above the approval floor
+    pub rejected_rate: u16,
+    /// Approval rate below which a rejected proposal takes the elevated slash
+    pub low_approval_floor: u16,
+    /// Kept from rejected proposals below the approval floor
+    pub low_approval_rejected_rate: u16,
+    /// Kept from vetoed proposals
+    pub vetoed_rate: u16,
+}
+
+impl Default for DepositSlashSchedule {
+    fn default() -> Self {
+        Self {
+            passed_rate: governance_constants::DEFAULT_PASSED_SLASH_RATE,
+            rejected_rate: governance_constants::DEFAULT_REJECTED_SLASH_RATE,
+            low_approval_floor: governance_constants::DEFAULT_LOW_APPROVAL_FLOOR,
+            low_approval_rejected_rate:
+                governance_constants::DEFAULT_LOW_APPROVAL_REJECTED_SLASH_RATE,
+            vetoed_rate: governance_constants::DEFAULT_VETOED_SLASH_RATE,
+        }
+    }
+}
+
+impl DepositSlashSchedule {
+    /// Get the slash rate for a finalization outcome (basis points)
+    pub fn slash_rate(
+        &self,
+        status: &crate::state::proposal::ProposalStatus,
+        approval_rate: u16,
+    ) -> u16 {
+        use crate::state::proposal::ProposalStatus;
+
+        match status {
+            ProposalStatus::Passed | ProposalStatus::Executed => self.passed_rate,
+            ProposalStatus::Rejected if approval_rate >= self.low_approval_floor => {
+                self.rejected_rate
             }
+            ProposalStatus::Rejected => self.low_approval_rejected_rate,
+            ProposalStatus::Vetoed => self.vetoed_rate,
+            ProposalStatus::Pending | ProposalStatus::Cancelled => 0,
         }
+    }
 
-        Err(crate::error::GovernanceError::MemberNotFound.into())
+    /// Validate the schedule: rates within 100% and never lighter for a worse outcome
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.low_approval_floor <= 10000 && self.vetoed_rate <= 10000,
+            crate::error::GovernanceError::InvalidThreshold
+        );
+        require!(
+            self.passed_rate <= self.rejected_rate
+                && self.rejected_rate <= self.low_approval_rejected_rate
+                && self.low_approval_rejected_rate <= self.vetoed_rate,
+            crate::error::GovernanceError::InvalidSlashSchedule
+        );
+        Ok(())
     }
+}
 
-    /// Check if is committee member
-    pub fn is_committee_member(&self, member: &Pubkey) -> bool {
-        self.committee_members.iter().any(|slot| {
-            if let Some(existing) = slot {
-                existing == member
-            } else {
-                false
-            }
-        })
+/// Kind of asset posted as proposal deposits
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum DepositKind {
+    /// Deposits are SPL tokens of an accepted deposit mint
+    SplToken,
+    /// Deposits are lamports held in the native deposit vault
+    NativeSol,
+}
+
+/// Rounding of a rate to whole basis points
+/// Floor never reports a rate above the exact one, so a threshold is only met once the
+/// votes fully reach it; HalfUp reports the nearest basis point (4999.5 reads 5000).
+/// Thresholds keep their comparison either way: approval must still exceed its threshold
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum RoundingMode {
+    /// Round down
+    #[default]
+    Floor,
+    /// Round to nearest, halves up
+    HalfUp,
+}
+
+impl RoundingMode {
+    /// numerator / denominator in basis points, saturating at u16::MAX (0 for a zero
+    /// denominator)
+    pub fn rate_bps(&self, numerator: u64, denominator: u64) -> u16 {
+        if denominator == 0 {
+            return 0;
+        }
+        let scaled = numerator as u128 * governance_constants::BASIS_POINTS_DENOMINATOR as u128;
+        let denominator = denominator as u128;
+        let rate = match self {
+            RoundingMode::Floor => scaled / denominator,
+            RoundingMode::HalfUp => (2 * scaled + denominator) / (2 * denominator),
+        };
+        rate.min(u16::MAX as u128) as u16
     }
+}
 
-    /// Get all committee members
-    pub fn get_committee_members(&self) -> Vec<Pubkey> {
-        self.committee_members
-            .iter()
-            .filter_map(|slot| *slot)
-            .collect()
+/// Handling of deposits confiscated from vetoed proposals
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ConfiscationPolicy {
+    /// Confiscated deposit stays in the committee treasury
+    KeepInTreasury,
+    /// Confiscated deposit is burned from the vault
+    Burn,
+}
+
+/// Administrative roles, each held by its own key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AdminRole {
+    /// Configuration, committee membership, vaults and role assignment
+    Config,
+    /// Rule registry documents
+    Rule,
+    /// Committee fee withdrawal, distribution and vault reconciliation
+    Treasury,
+}
+
+/// Committee fee distribution weighting
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum FeeDistributionMode {
+    /// Equal share per committee member
+    Equal,
+    /// Share proportional to votes cast
+    Participation,
+}
+
+/// Accepted deposit mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct DepositMint {
+    /// Token mint address
+    pub mint: Pubkey,
+    /// Token mint decimals
+    pub decimals: u8,
+    /// Minimum proposal deposit in this mint (with decimals)
+    pub min_deposit: u64,
+    /// Refundable deposits currently held in this mint's vault
+    pub total_deposits_locked: u64,
+    /// Cumulative donations received into this mint's vault
+    pub cumulative_donations: u64,
+    /// Vault funds attributed to the committee treasury (fees, confiscations, donations)
+    pub treasury_balance: u64,
+}
+
+/// Canonical bump of a deposit mint's token vault PDA
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct TokenVaultBump {
+    /// Token mint address
+    pub mint: Pubkey,
+    /// Bump of the vault at [GOVERNANCE_TOKEN_VAULT_SEED, mint]
+    pub bump: u8,
+}
+
+impl TokenVaultBump {
+    /// Derive the canonical vault bump for a mint (done once, when the mint is accepted)
+    pub fn derive(mint: Pubkey) -> Self {
+        let (_, bump) =
+            Pubkey::find_program_address(&[GOVERNANCE_TOKEN_VAULT_SEED, mint.as_ref()], &crate::ID);
+        Self { mint, bump }
     }
 }
 
+/// Derive the canonical governance authority PDA bump (done once, at initialization)
+pub fn governance_authority_bump() -> u8 {
+    Pubkey::find_program_address(&[GOVERNANCE_AUTHORITY_SEED], &crate::ID).1
+}
+
+/// Maximum number of accepted deposit mints
+pub const MAX_DEPOSIT_MINTS: usize = 5;
+
+/// Maximum deposit mint decimals (keeps whole-token deposit amounts within u64)
+pub const MAX_DEPOSIT_MINT_DECIMALS: u8 = 12;
+
+/// Deposit mint entry standing for native SOL (no token mint can live at this address)
+pub const NATIVE_SOL_DEPOSIT_MINT: Pubkey = anchor_lang::system_program::ID;
+
+/// Native SOL decimals (lamports per SOL)
+pub const NATIVE_SOL_DECIMALS: u8 = 9;
+
 /// Governance configuration update parameters
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub struct GovernanceConfigUpdate {
     pub proposal_deposit: Option<u64>,
     pub voting_period: Option<u64>,
@@ -145,11 +928,102 @@ pub struct GovernanceConfigUpdate {
     pub veto_threshold: Option<u16>,
     pub fee_rate: Option<u16>,
     pub test_mode: Option<bool>,
+    pub resubmission_deposit_rate: Option<u16>,
+    pub slash_escalation_rate: Option<u16>,
+    pub max_slash_deposit_multiplier: Option<u32>,
+    pub confiscation_policy: Option<ConfiscationPolicy>,
+    pub deposit_escrow_enabled: Option<bool>,
+    pub deposit_slash_schedule: Option<DepositSlashSchedule>,
+    pub inactivity_threshold: Option<u8>,
+    pub member_stake_requirement: Option<u64>,
+    pub member_stake_cooldown: Option<i64>,
+    pub stake_based_voting_power: Option<bool>,
+    pub max_committee_size: Option<u8>,
+    pub max_active_proposals: Option<u16>,
+    pub max_rule_effective_delay: Option<i64>,
+    pub strict_url_validation: Option<bool>,
+    pub emit_memos: Option<bool>,
+    pub config_timelock: Option<i64>,
+    pub waive_deposit_for_committee: Option<bool>,
+    pub voting_power_tolerance_bps: Option<u16>,
+    pub heartbeat_interval: Option<i64>,
+    pub rate_rounding: Option<RoundingMode>,
+    pub participation_bonus_bps: Option<u16>,
+    pub participation_bonus_window: Option<u8>,
+    pub max_member_voting_power: Option<u64>,
+    pub max_lock_duration: Option<i64>,
+    pub max_lock_multiplier_bps: Option<u16>,
+}
+
+/// Configuration update queued by the config admin, applicable from eta
+/// One update is queued at a time (single PDA); a new one needs the current one applied
+/// or cancelled first
+#[account]
+#[derive(InitSpace)]
+pub struct PendingConfigUpdate {
+    /// Queued update
+    pub update: GovernanceConfigUpdate,
+    /// Config admin that queued the update (receives the account rent back)
+    pub queued_by: Pubkey,
+    /// Time the update was queued
+    pub queued_at: i64,
+    /// Earliest time the update can be applied
+    pub eta: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Pair a field's current value with a requested one, or (None, None) when nothing changes
+fn field_change<T: Copy + PartialEq>(current: T, requested: Option<T>) -> (Option<T>, Option<T>) {
+    match requested {
+        Some(value) if value != current => (Some(current), Some(value)),
+        _ => (None, None),
+    }
+}
+
+/// Validate the approval threshold against its usable range
+/// Values above 10000 fail with InvalidThreshold; 0 and 10000 are in range but unusable
+pub fn validate_approval_threshold(approval_threshold: u16) -> Result<()> {
+    require!(
+        approval_threshold <= 10000,
+        crate::error::GovernanceError::InvalidThreshold
+    );
+    require!(
+        (governance_constants::MIN_APPROVAL_THRESHOLD
+            ..=governance_constants::MAX_APPROVAL_THRESHOLD)
+            .contains(&approval_threshold),
+        crate::error::GovernanceError::ApprovalThresholdOutOfRange
+    );
+    Ok(())
+}
+
+/// Validate the veto threshold against its usable range
+/// Veto is checked first and inclusively, so 0 vetoes every proposal and small values let a
+/// single veto vote override any approval; values above 10000 - approval_threshold never
+/// trigger once a proposal is approved, since yes and veto votes share the same total
+pub fn validate_veto_threshold(veto_threshold: u16) -> Result<()> {
+    require!(
+        veto_threshold <= 10000,
+        crate::error::GovernanceError::InvalidThreshold
+    );
+    require!(
+        veto_threshold >= governance_constants::MIN_VETO_THRESHOLD,
+        crate::error::GovernanceError::VetoThresholdTooLow
+    );
+    Ok(())
 }
 
 impl GovernanceConfigUpdate {
     /// Validate the validity of update parameters
     pub fn validate(&self, current_test_mode: bool) -> Result<()> {
+        // Test mode allows second-long voting periods, so production can never re-enter it
+        if self.test_mode == Some(true) {
+            require!(
+                current_test_mode,
+                crate::error::GovernanceError::TestModeLocked
+            );
+        }
+
         if let Some(participation_threshold) = self.participation_threshold {
             require!(
                 participation_threshold <= 10000,
@@ -158,17 +1032,11 @@ impl GovernanceConfigUpdate {
         }
 
         if let Some(approval_threshold) = self.approval_threshold {
-            require!(
-                approval_threshold <= 10000,
-                crate::error::GovernanceError::InvalidThreshold
-            );
+            validate_approval_threshold(approval_threshold)?;
         }
 
         if let Some(veto_threshold) = self.veto_threshold {
-            require!(
-                veto_threshold <= 10000,
-                crate::error::GovernanceError::InvalidThreshold
-            );
+            validate_veto_threshold(veto_threshold)?;
         }
 
         if let Some(fee_rate) = self.fee_rate {
@@ -178,6 +1046,105 @@ impl GovernanceConfigUpdate {
             );
         }
 
+        if let Some(resubmission_deposit_rate) = self.resubmission_deposit_rate {
+            require!(
+                resubmission_deposit_rate <= 10000,
+                crate::error::GovernanceError::InvalidFeeRate
+            );
+        }
+
+        if let Some(max_slash_deposit_multiplier) = self.max_slash_deposit_multiplier {
+            require!(
+                max_slash_deposit_multiplier >= 10000,
+                crate::error::GovernanceError::InvalidSlashDepositMultiplier
+            );
+        }
+
+        if let Some(deposit_slash_schedule) = &self.deposit_slash_schedule {
+            deposit_slash_schedule.validate()?;
+        }
+
+        if let Some(inactivity_threshold) = self.inactivity_threshold {
+            require!(
+                inactivity_threshold >= 1,
+                crate::error::GovernanceError::InvalidInactivityThreshold
+            );
+        }
+
+        // Lowering the committee size below the current membership is checked on apply
+        if let Some(max_committee_size) = self.max_committee_size {
+            require!(
+                (1..=MAX_COMMITTEE_MEMBERS).contains(&(max_committee_size as u32)),
+                crate::error::GovernanceError::InvalidGovernanceLimit
+            );
+        }
+
+        if let Some(max_active_proposals) = self.max_active_proposals {
+            require!(
+                max_active_proposals >= 1,
+                crate::error::GovernanceError::InvalidGovernanceLimit
+            );
+        }
+
+        if let Some(max_rule_effective_delay) = self.max_rule_effective_delay {
+            require!(
+                max_rule_effective_delay >= 0,
+                crate::error::GovernanceError::InvalidDuration
+            );
+        }
+
+        if let Some(member_stake_cooldown) = self.member_stake_cooldown {
+            require!(
+                member_stake_cooldown >= 0,
+                crate::error::GovernanceError::InvalidDuration
+            );
+        }
+
+        if let Some(config_timelock) = self.config_timelock {
+            require!(
+                config_timelock >= 0,
+                crate::error::GovernanceError::InvalidDuration
+            );
+        }
+
+        if let Some(heartbeat_interval) = self.heartbeat_interval {
+            require!(
+                heartbeat_interval > 0,
+                crate::error::GovernanceError::InvalidDuration
+            );
+        }
+
+        if let Some(voting_power_tolerance_bps) = self.voting_power_tolerance_bps {
+            require!(
+                voting_power_tolerance_bps <= 10000,
+                crate::error::GovernanceError::InvalidGovernanceLimit
+            );
+        }
+
+        // A non-zero bonus without a window is checked on apply
+        if let Some(participation_bonus_bps) = self.participation_bonus_bps {
+            require!(
+                participation_bonus_bps <= 10000,
+                crate::error::GovernanceError::InvalidParticipationBonus
+            );
+        }
+
+        if let Some(participation_bonus_window) = self.participation_bonus_window {
+            require!(
+                participation_bonus_window <= super::committee::VOTER_STATS_HISTORY_LEN,
+                crate::error::GovernanceError::InvalidParticipationBonus
+            );
+        }
+
+        // A multiplier outside 1x..=MAX_LOCK_MULTIPLIER_BPS with lockups enabled is checked
+        // on apply
+        if let Some(max_lock_duration) = self.max_lock_duration {
+            require!(
+                max_lock_duration >= 0,
+                crate::error::GovernanceError::InvalidLockupParameters
+            );
+        }
+
         if let Some(voting_period) = self.voting_period {
             // Validate voting period based on test mode or test mode flag in update
             let test_mode = self.test_mode.unwrap_or(current_test_mode);
@@ -207,9 +1174,165 @@ impl GovernanceConfigUpdate {
     }
 
     /// Apply update to configuration
-    pub fn apply_to(&self, config: &mut GovernanceConfig) {
+    /// Split the update into previous and new values of the fields it actually changes
+    /// Fields left unset or set to their current value are None in both halves
+    pub fn changes(&self, config: &GovernanceConfig) -> (Self, Self) {
+        let (previous_proposal_deposit, proposal_deposit) =
+            field_change(config.proposal_deposit, self.proposal_deposit);
+        let (previous_voting_period, voting_period) =
+            field_change(config.voting_period, self.voting_period);
+        let (previous_participation_threshold, participation_threshold) =
+            field_change(config.participation_threshold, self.participation_threshold);
+        let (previous_approval_threshold, approval_threshold) =
+            field_change(config.approval_threshold, self.approval_threshold);
+        let (previous_veto_threshold, veto_threshold) =
+            field_change(config.veto_threshold, self.veto_threshold);
+        let (previous_fee_rate, fee_rate) = field_change(config.fee_rate, self.fee_rate);
+        let (previous_test_mode, test_mode) = field_change(config.test_mode, self.test_mode);
+        let (previous_resubmission_deposit_rate, resubmission_deposit_rate) = field_change(
+            config.resubmission_deposit_rate,
+            self.resubmission_deposit_rate,
+        );
+        let (previous_slash_escalation_rate, slash_escalation_rate) =
+            field_change(config.slash_escalation_rate, self.slash_escalation_rate);
+        let (previous_max_slash_deposit_multiplier, max_slash_deposit_multiplier) = field_change(
+            config.max_slash_deposit_multiplier,
+            self.max_slash_deposit_multiplier,
+        );
+        let (previous_confiscation_policy, confiscation_policy) =
+            field_change(config.confiscation_policy, self.confiscation_policy);
+        let (previous_deposit_escrow_enabled, deposit_escrow_enabled) =
+            field_change(config.deposit_escrow_enabled, self.deposit_escrow_enabled);
+        let (previous_deposit_slash_schedule, deposit_slash_schedule) =
+            field_change(config.deposit_slash_schedule, self.deposit_slash_schedule);
+        let (previous_inactivity_threshold, inactivity_threshold) =
+            field_change(config.inactivity_threshold, self.inactivity_threshold);
+        let (previous_member_stake_requirement, member_stake_requirement) = field_change(
+            config.member_stake_requirement,
+            self.member_stake_requirement,
+        );
+        let (previous_member_stake_cooldown, member_stake_cooldown) =
+            field_change(config.member_stake_cooldown, self.member_stake_cooldown);
+        let (previous_stake_based_voting_power, stake_based_voting_power) = field_change(
+            config.stake_based_voting_power,
+            self.stake_based_voting_power,
+        );
+        let (previous_max_committee_size, max_committee_size) =
+            field_change(config.max_committee_size, self.max_committee_size);
+        let (previous_max_active_proposals, max_active_proposals) =
+            field_change(config.max_active_proposals, self.max_active_proposals);
+        let (previous_max_rule_effective_delay, max_rule_effective_delay) = field_change(
+            config.max_rule_effective_delay,
+            self.max_rule_effective_delay,
+        );
+        let (previous_strict_url_validation, strict_url_validation) =
+            field_change(config.strict_url_validation, self.strict_url_validation);
+        let (previous_emit_memos, emit_memos) = field_change(config.emit_memos, self.emit_memos);
+        let (previous_config_timelock, config_timelock) =
+            field_change(config.config_timelock, self.config_timelock);
+        let (previous_waive_deposit_for_committee, waive_deposit_for_committee) = field_change(
+            config.waive_deposit_for_committee,
+            self.waive_deposit_for_committee,
+        );
+        let (previous_voting_power_tolerance_bps, voting_power_tolerance_bps) = field_change(
+            config.voting_power_tolerance_bps,
+            self.voting_power_tolerance_bps,
+        );
+        let (previous_heartbeat_interval, heartbeat_interval) =
+            field_change(config.heartbeat_interval, self.heartbeat_interval);
+        let (previous_rate_rounding, rate_rounding) =
+            field_change(config.rate_rounding, self.rate_rounding);
+        let (previous_participation_bonus_bps, participation_bonus_bps) =
+            field_change(config.participation_bonus_bps, self.participation_bonus_bps);
+        let (previous_participation_bonus_window, participation_bonus_window) = field_change(
+            config.participation_bonus_window,
+            self.participation_bonus_window,
+        );
+        let (previous_max_member_voting_power, max_member_voting_power) =
+            field_change(config.max_member_voting_power, self.max_member_voting_power);
+        let (previous_max_lock_duration, max_lock_duration) =
+            field_change(config.max_lock_duration, self.max_lock_duration);
+        let (previous_max_lock_multiplier_bps, max_lock_multiplier_bps) =
+            field_change(config.max_lock_multiplier_bps, self.max_lock_multiplier_bps);
+
+        (
+            Self {
+                proposal_deposit: previous_proposal_deposit,
+                voting_period: previous_voting_period,
+                participation_threshold: previous_participation_threshold,
+                approval_threshold: previous_approval_threshold,
+                veto_threshold: previous_veto_threshold,
+                fee_rate: previous_fee_rate,
+                test_mode: previous_test_mode,
+                resubmission_deposit_rate: previous_resubmission_deposit_rate,
+                slash_escalation_rate: previous_slash_escalation_rate,
+                max_slash_deposit_multiplier: previous_max_slash_deposit_multiplier,
+                confiscation_policy: previous_confiscation_policy,
+                deposit_escrow_enabled: previous_deposit_escrow_enabled,
+                deposit_slash_schedule: previous_deposit_slash_schedule,
+                inactivity_threshold: previous_inactivity_threshold,
+                member_stake_requirement: previous_member_stake_requirement,
+                member_stake_cooldown: previous_member_stake_cooldown,
+                stake_based_voting_power: previous_stake_based_voting_power,
+                max_committee_size: previous_max_committee_size,
+                max_active_proposals: previous_max_active_proposals,
+                max_rule_effective_delay: previous_max_rule_effective_delay,
+                strict_url_validation: previous_strict_url_validation,
+                emit_memos: previous_emit_memos,
+                config_timelock: previous_config_timelock,
+                waive_deposit_for_committee: previous_waive_deposit_for_committee,
+                voting_power_tolerance_bps: previous_voting_power_tolerance_bps,
+                heartbeat_interval: previous_heartbeat_interval,
+                rate_rounding: previous_rate_rounding,
+                participation_bonus_bps: previous_participation_bonus_bps,
+                participation_bonus_window: previous_participation_bonus_window,
+                max_member_voting_power: previous_max_member_voting_power,
+                max_lock_duration: previous_max_lock_duration,
+                max_lock_multiplier_bps: previous_max_lock_multiplier_bps,
+            },
+            Self {
+                proposal_deposit,
+                voting_period,
+                participation_threshold,
+                approval_threshold,
+                veto_threshold,
+                fee_rate,
+                test_mode,
+                resubmission_deposit_rate,
+                slash_escalation_rate,
+                max_slash_deposit_multiplier,
+                confiscation_policy,
+                deposit_escrow_enabled,
+                deposit_slash_schedule,
+                inactivity_threshold,
+                member_stake_requirement,
+                member_stake_cooldown,
+                stake_based_voting_power,
+                max_committee_size,
+                max_active_proposals,
+                max_rule_effective_delay,
+                strict_url_validation,
+                emit_memos,
+                config_timelock,
+                waive_deposit_for_committee,
+                voting_power_tolerance_bps,
+                heartbeat_interval,
+                rate_rounding,
+                participation_bonus_bps,
+                participation_bonus_window,
+                max_member_voting_power,
+                max_lock_duration,
+                max_lock_multiplier_bps,
+            },
+        )
+    }
+
+    pub fn apply_to(&self, config: &mut GovernanceConfig, now: i64) {
         if let Some(proposal_deposit) = self.proposal_deposit {
             config.proposal_deposit = proposal_deposit;
+            if let Some(primary) = config.deposit_mints.first_mut() {
+                primary.min_deposit = proposal_deposit;
+            }
         }
         if let Some(voting_period) = self.voting_period {
             config.voting_period = voting_period;
@@ -229,7 +1352,82 @@ impl GovernanceConfigUpdate {
         if let Some(test_mode) = self.test_mode {
             config.test_mode = test_mode;
         }
-        config.updated_at = Clock::get().unwrap().unix_timestamp;
+        if let Some(resubmission_deposit_rate) = self.resubmission_deposit_rate {
+            config.resubmission_deposit_rate = resubmission_deposit_rate;
+        }
+        if let Some(slash_escalation_rate) = self.slash_escalation_rate {
+            config.slash_escalation_rate = slash_escalation_rate;
+        }
+        if let Some(max_slash_deposit_multiplier) = self.max_slash_deposit_multiplier {
+            config.max_slash_deposit_multiplier = max_slash_deposit_multiplier;
+        }
+        if let Some(confiscation_policy) = self.confiscation_policy {
+            config.confiscation_policy = confiscation_policy;
+        }
+        if let Some(deposit_escrow_enabled) = self.deposit_escrow_enabled {
+            config.deposit_escrow_enabled = deposit_escrow_enabled;
+        }
+        if let Some(deposit_slash_schedule) = self.deposit_slash_schedule {
+            config.deposit_slash_schedule = deposit_slash_schedule;
+        }
+        if let Some(inactivity_threshold) = self.inactivity_threshold {
+            config.inactivity_threshold = inactivity_threshold;
+        }
+        if let Some(member_stake_requirement) = self.member_stake_requirement {
+            config.member_stake_requirement = member_stake_requirement;
+        }
+        if let Some(member_stake_cooldown) = self.member_stake_cooldown {
+            config.member_stake_cooldown = member_stake_cooldown;
+        }
+        if let Some(stake_based_voting_power) = self.stake_based_voting_power {
+            config.stake_based_voting_power = stake_based_voting_power;
+        }
+        if let Some(max_committee_size) = self.max_committee_size {
+            config.max_committee_size = max_committee_size;
+        }
+        if let Some(max_active_proposals) = self.max_active_proposals {
+            config.max_active_proposals = max_active_proposals;
+        }
+        if let Some(max_rule_effective_delay) = self.max_rule_effective_delay {
+            config.max_rule_effective_delay = max_rule_effective_delay;
+        }
+        if let Some(strict_url_validation) = self.strict_url_validation {
+            config.strict_url_validation = strict_url_validation;
+        }
+        if let Some(emit_memos) = self.emit_memos {
+            config.emit_memos = emit_memos;
+        }
+        if let Some(config_timelock) = self.config_timelock {
+            config.config_timelock = config_timelock;
+        }
+        if let Some(waive_deposit_for_committee) = self.waive_deposit_for_committee {
+            config.waive_deposit_for_committee = waive_deposit_for_committee;
+        }
+        if let Some(voting_power_tolerance_bps) = self.voting_power_tolerance_bps {
+            config.voting_power_tolerance_bps = voting_power_tolerance_bps;
+        }
+        if let Some(heartbeat_interval) = self.heartbeat_interval {
+            config.heartbeat_interval = heartbeat_interval;
+        }
+        if let Some(rate_rounding) = self.rate_rounding {
+            config.rate_rounding = rate_rounding;
+        }
+        if let Some(participation_bonus_bps) = self.participation_bonus_bps {
+            config.participation_bonus_bps = participation_bonus_bps;
+        }
+        if let Some(participation_bonus_window) = self.participation_bonus_window {
+            config.participation_bonus_window = participation_bonus_window;
+        }
+        if let Some(max_member_voting_power) = self.max_member_voting_power {
+            config.max_member_voting_power = max_member_voting_power;
+        }
+        if let Some(max_lock_duration) = self.max_lock_duration {
+            config.max_lock_duration = max_lock_duration;
+        }
+        if let Some(max_lock_multiplier_bps) = self.max_lock_multiplier_bps {
+            config.max_lock_multiplier_bps = max_lock_multiplier_bps;
+        }
+        config.updated_at = now;
     }
 }
 
@@ -245,9 +1443,126 @@ pub mod governance_constants {
     pub const DEFAULT_VETO_THRESHOLD: u16 = 3000;
     /// Default committee fee rate (10%)
     pub const DEFAULT_FEE_RATE: u16 = 1000;
+    /// Default resubmission deposit rate (50% of the proposal deposit)
+    pub const DEFAULT_RESUBMISSION_DEPOSIT_RATE: u16 = 5000;
+    /// Default slash deposit escalation (+100% per prior failed filing)
+    pub const DEFAULT_SLASH_ESCALATION_RATE: u16 = 10000;
+    /// Default maximum slash deposit multiplier (5x)
+    pub const DEFAULT_MAX_SLASH_DEPOSIT_MULTIPLIER: u32 = 50000;
+    /// Default deposit slash for passed proposals (10%, the committee fee)
+    pub const DEFAULT_PASSED_SLASH_RATE: u16 = 1000;
+    /// Default deposit slash for rejected proposals at or above the approval floor (10%)
+    pub const DEFAULT_REJECTED_SLASH_RATE: u16 = 1000;
+    /// Default approval floor for the elevated rejection slash (20%)
+    pub const DEFAULT_LOW_APPROVAL_FLOOR: u16 = 2000;
+    /// Default deposit slash for rejected proposals below the approval floor (50%)
+    pub const DEFAULT_LOW_APPROVAL_REJECTED_SLASH_RATE: u16 = 5000;
+    /// Default deposit slash for vetoed proposals (100%)
+    pub const DEFAULT_VETOED_SLASH_RATE: u16 = 10000;
     /// Default proposal deposit (100 USDC, needs adjustment based on precision)
     pub const DEFAULT_PROPOSAL_DEPOSIT: u64 = 100_000_000; // Assuming USDC 6 decimal places
 
+    /// Default consecutive missed finalized proposals before a member can be flagged inactive
+    pub const DEFAULT_INACTIVITY_THRESHOLD: u8 = 3;
+    /// Default member stake lock after staking or removal (7 days)
+    pub const DEFAULT_MEMBER_STAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+    /// Default maximum number of proposals pending at once
+    pub const DEFAULT_MAX_ACTIVE_PROPOSALS: u16 = 50;
+    /// Default furthest a rule document's effective date may be scheduled (365 days)
+    pub const DEFAULT_MAX_RULE_EFFECTIVE_DELAY: i64 = 365 * 24 * 60 * 60;
+    /// Default delay between queueing and applying a configuration update (2 days)
+    pub const DEFAULT_CONFIG_TIMELOCK: i64 = 2 * 24 * 60 * 60;
+    /// Default divergence from the member balance sum tolerated for a manually set total
+    /// voting power (5%)
+    pub const DEFAULT_VOTING_POWER_TOLERANCE_BPS: u16 = 500;
+    /// Default least time between two touch_governance heartbeats (1 hour)
+    pub const DEFAULT_HEARTBEAT_INTERVAL: i64 = 60 * 60;
     /// Basis points denominator (100% = 10000 basis points)
     pub const BASIS_POINTS_DENOMINATOR: u64 = 10000;
+    /// Lowest usable approval threshold (0 lets a single yes vote among abstentions pass)
+    pub const MIN_APPROVAL_THRESHOLD: u16 = 1;
+    /// Highest usable approval threshold (approval is strictly greater, so 10000 never passes)
+    pub const MAX_APPROVAL_THRESHOLD: u16 = 9999;
+    /// Lowest usable veto threshold (5%, veto is inclusive, so 0 vetoes every proposal)
+    pub const MIN_VETO_THRESHOLD: u16 = 500;
+    /// Highest lockup voting power multiplier (2x)
+    pub const MAX_LOCK_MULTIPLIER_BPS: u16 = 20000;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn original_layout_config_migrates() {
+        let authority = Pubkey::new_unique();
+        let committee_token_mint = Pubkey::new_unique();
+        let member = Pubkey::new_unique();
+
+        // Bytes as the original program wrote them: discriminator, the fields in declaration
+        // order (one occupied committee slot), then the zeros left of the account allocation
+        let mut data = GovernanceConfig::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(committee_token_mint.as_ref());
+        data.push(0); // committee_members[0]: None
+        data.push(1); // committee_members[1]: Some(member)
+        data.extend_from_slice(member.as_ref());
+        data.extend_from_slice(&[0; 8]); // committee_members[2..]: None
+        data.push(1); // committee_member_count
+        data.extend_from_slice(&100_000_000u64.to_le_bytes()); // proposal_deposit
+        data.extend_from_slice(&1_209_600u64.to_le_bytes()); // voting_period
+        data.extend_from_slice(&4000u16.to_le_bytes()); // participation_threshold
+        data.extend_from_slice(&5000u16.to_le_bytes()); // approval_threshold
+        data.extend_from_slice(&3000u16.to_le_bytes()); // veto_threshold
+        data.extend_from_slice(&1000u16.to_le_bytes()); // fee_rate
+        data.extend_from_slice(&7u64.to_le_bytes()); // total_voting_power
+        data.extend_from_slice(&42u64.to_le_bytes()); // proposal_counter
+        data.extend_from_slice(&1_000i64.to_le_bytes()); // created_at
+        data.extend_from_slice(&2_000i64.to_le_bytes()); // updated_at
+        data.push(1); // test_mode
+        data.push(254); // bump
+        data.resize(461, 0);
+
+        let mut governance_config = LegacyGovernanceConfigV0::try_load(&data)
+            .unwrap()
+            .unwrap()
+            .into_current()
+            .unwrap();
+        assert_eq!(governance_config.schema_version, 0);
+        governance_config.migrate_schema();
+
+        assert_eq!(governance_config.authority, authority);
+        assert_eq!(governance_config.committee_token_mint, committee_token_mint);
+        assert_eq!(governance_config.committee_members[0], None);
+        assert_eq!(governance_config.committee_members[1], Some(member));
+        assert_eq!(governance_config.committee_member_count, 1);
+        assert_eq!(governance_config.proposal_deposit, 100_000_000);
+        assert_eq!(governance_config.voting_period, 1_209_600);
+        assert_eq!(governance_config.participation_threshold, 4000);
+        assert_eq!(governance_config.approval_threshold, 5000);
+        assert_eq!(governance_config.veto_threshold, 3000);
+        assert_eq!(governance_config.fee_rate, 1000);
+        assert_eq!(governance_config.total_voting_power, 7);
+        assert_eq!(governance_config.proposal_counter, 42);
+        assert_eq!(governance_config.created_at, 1_000);
+        assert!(governance_config.test_mode);
+        assert_eq!(governance_config.bump, 254);
+        assert_eq!(governance_config.config_admin, authority);
+        assert_eq!(
+            governance_config.inactivity_threshold,
+            governance_constants::DEFAULT_INACTIVITY_THRESHOLD
+        );
+        assert!(governance_config.is_current_schema());
+
+        // The migrated account is read back in the current layout, never as a legacy one
+        let mut migrated = Vec::new();
+        governance_config.try_serialize(&mut migrated).unwrap();
+        migrated.resize(8 + GovernanceConfig::INIT_SPACE, 0);
+        assert!(LegacyGovernanceConfigV0::try_load(&migrated)
+            .unwrap()
+            .is_none());
+        let reloaded = GovernanceConfig::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(reloaded.proposal_counter, 42);
+        assert_eq!(reloaded.committee_members[1], Some(member));
+    }
 }
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Per-merchant slash proposal history (drives deposit escalation)
+#[account]
+#[derive(InitSpace)]
+pub struct MerchantSlashHistory {
+    /// Merchant address
+    pub merchant: Pubkey,
+    /// Slash proposals against this merchant that passed
+    pub passed_count: u32,
+    /// Slash proposals against this merchant that were rejected
+    pub rejected_count: u32,
+    /// Slash proposals against this merchant that were vetoed
+    pub vetoed_count: u32,
+    /// Failed filings since the last passed slash (reset on pass)
+    pub consecutive_failures: u32,
+    /// Last update time
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MerchantSlashHistory {
+    /// Record the outcome of a finalized slash proposal
+    pub fn record_outcome(&mut self, status: &crate::state::ProposalStatus) -> Result<()> {
+        use crate::state::ProposalStatus;
+
+        match status {
+            ProposalStatus::Passed | ProposalStatus::Executed => {
+                self.passed_count = self.passed_count.saturating_add(1);
+                self.consecutive_failures = 0;
+            }
+            ProposalStatus::Rejected => {
+                self.rejected_count = self.rejected_count.saturating_add(1);
+                self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+            }
+            ProposalStatus::Vetoed => {
+                self.vetoed_count = self.vetoed_count.saturating_add(1);
+                self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+            }
+            ProposalStatus::Pending | ProposalStatus::Cancelled => return Ok(()),
+        }
+
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
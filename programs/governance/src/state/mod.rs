@@ -1,13 +1,59 @@
+pub mod adapter;
+pub mod admin;
+pub mod archive;
+pub mod committee;
+pub mod cpi_caller;
 pub mod governance;
+pub mod merchant;
+pub mod native;
+pub mod outcome;
+pub mod policy;
 pub mod proposal;
+pub mod proposal_index;
+pub mod refund;
 pub mod rules;
+pub mod stats;
 pub mod vote;
 
 // Re-export main structures to avoid naming conflicts
-pub use governance::{governance_constants, GovernanceConfig, GovernanceConfigUpdate};
+pub use adapter::{
+    ExecutionAdapter, ExecutionAdapterRegistry, PendingExecutionAdapter, MAX_EXECUTION_ADAPTERS,
+};
+pub use admin::{AdminAction, AdminActionKind, AdminSet, MAX_ADMINS};
+pub use archive::ProposalArchive;
+pub use committee::{
+    CommitteeChangeKind, CommitteeMember, Lockup, MemberNonce, MemberStake, VoterStats,
+    MAX_COMMITTEE_MEMBERS, MAX_MEMBER_DISPLAY_NAME_BYTES, MEMBER_TERM_SECONDS,
+    PARTICIPATION_BONUS_MIN_RATE_BPS, VOTER_STATS_HISTORY_LEN,
+};
+pub use cpi_caller::{CpiCallerAllowlist, MAX_CPI_CALLERS};
+pub use governance::{
+    governance_authority_bump, governance_constants, validate_approval_threshold,
+    validate_veto_threshold, AdminRole, ConfiscationPolicy, DepositKind, DepositMint,
+    DepositSlashSchedule, FeeDistributionMode, GovernanceConfig, GovernanceConfigUpdate,
+    LegacyGovernanceConfigV0, PendingConfigUpdate, RoundingMode, TokenVaultBump, GOVERNANCE_CONFIG_SCHEMA_VERSION,
+    MAX_DEPOSIT_MINTS, MAX_DEPOSIT_MINT_DECIMALS, NATIVE_SOL_DECIMALS, NATIVE_SOL_DEPOSIT_MINT,
+};
+pub use merchant::MerchantSlashHistory;
+pub use native::NativeDepositVault;
+pub use outcome::ProposalOutcome;
+pub use policy::{ProposerPolicy, ProposerReputation, ProposerState};
 pub use proposal::{
-    ArbitrationDecision, ConfigUpdateData, DisputeProposalData, ExecutionData, Proposal,
-    ProposalStatus, ProposalType, RuleOperation, RuleUpdateData, SlashProposalData, VoteType,
+    ArbitrationDecision, CommitteeChangeAction, CommitteeChangeData, ConfigUpdateData,
+    DisputeProposalData, ExecutionData, ProgramUpgradeData, Proposal, ProposalStatus, ProposalType,
+    RuleOperation, RuleUpdateData, SlashProposalData, VoteType,
+};
+pub use proposal_index::{
+    ProposalIndex, ProposalIndexList, MAX_INDEXED_CLOSED, MAX_INDEXED_PASSED, MAX_INDEXED_PENDING,
+    MAX_PROPOSAL_INDEX_PAGE,
+};
+pub use refund::RefundClaim;
+pub use rules::{
+    rule_categories, CategoryAdmin, RuleCategory, RuleDocument, RuleDocumentInput,
+    RuleDocumentRevision, RuleRegistry, MAX_CATEGORY_ADMINS, MAX_DOCUMENT_HISTORY,
+    MAX_RULE_DOCUMENT_BATCH, MAX_RULE_REGISTRY_NAME_LENGTH,
+};
+pub use stats::{GovernanceStats, PROPOSAL_TYPE_COUNT};
+pub use vote::{
+    vote_constants, Vote, VoteDelegation, VoteStats, VoteTally, VotingPowerCalculator,
 };
-pub use rules::{rule_categories, RuleCategory, RuleDocument, RuleRegistry};
-pub use vote::{vote_constants, Vote, VoteDelegation, VoteStats, VotingPowerCalculator};
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Program-owned vault holding native SOL proposal deposits
+/// Lamports above the rent-exempt minimum are deposits and treasury funds
+#[account]
+#[derive(InitSpace)]
+pub struct NativeDepositVault {
+    /// Creation time
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
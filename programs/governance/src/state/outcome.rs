@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use super::proposal::{Proposal, ProposalStatus};
+
+/// Final result of a proposal, written once at finalization for other programs to read
+/// Never modified after creation; it shares the lifetime of its proposal and is closed
+/// with it by close_proposal
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalOutcome {
+    /// Proposal ID
+    pub id: u64,
+    /// Status the proposal was finalized with
+    pub status: ProposalStatus,
+    /// Finalization time
+    pub finalized_at: i64,
+    /// Yes votes
+    pub yes: u64,
+    /// No votes
+    pub no: u64,
+    /// Abstain votes
+    pub abstain: u64,
+    /// Veto votes
+    pub veto: u64,
+    /// Committee voting power the proposal was finalized against
+    pub total_power: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ProposalOutcome {
+    /// Outcome of a just finalized proposal
+    pub fn new(proposal: &Proposal, total_power: u64, finalized_at: i64, bump: u8) -> Result<Self> {
+        Ok(Self {
+            id: proposal.id,
+            status: proposal.status()?,
+            finalized_at,
+            yes: proposal.yes_votes,
+            no: proposal.no_votes,
+            abstain: proposal.abstain_votes,
+            veto: proposal.veto_votes,
+            total_power,
+            bump,
+        })
+    }
+
+    /// Record the result of a just finalized proposal
+    pub fn record(
+        &mut self,
+        proposal: &Proposal,
+        total_power: u64,
+        finalized_at: i64,
+        bump: u8,
+    ) -> Result<()> {
+        *self = Self::new(proposal, total_power, finalized_at, bump)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+
+use super::proposal::ProposalStatus;
+
+/// Proposer policy account (deny list and optional allowlist-only mode)
+#[account]
+#[derive(InitSpace)]
+pub struct ProposerPolicy {
+    /// Administrator address
+    pub authority: Pubkey,
+    /// Only allowlisted proposers may create proposals
+    pub allowlist_only: bool,
+    /// Banned proposer addresses
+    #[max_len(50)]
+    pub denied_proposers: Vec<Pubkey>,
+    /// Allowed proposer addresses (only used in allowlist-only mode)
+    #[max_len(50)]
+    pub allowed_proposers: Vec<Pubkey>,
+    /// Last update time
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ProposerPolicy {
+    /// Check if proposer is banned
+    pub fn is_denied(&self, proposer: &Pubkey) -> bool {
+        self.denied_proposers.contains(proposer)
+    }
+
+    /// Check if proposer is explicitly allowed
+    pub fn is_allowed(&self, proposer: &Pubkey) -> bool {
+        self.allowed_proposers.contains(proposer)
+    }
+
+    /// Validate that proposer may create proposals under this policy
+    pub fn check_proposer(&self, proposer: &Pubkey) -> Result<()> {
+        require!(
+            !self.is_denied(proposer),
+            crate::error::GovernanceError::ProposerBanned
+        );
+        if self.allowlist_only {
+            require!(
+                self.is_allowed(proposer),
+                crate::error::GovernanceError::ProposerNotAllowed
+            );
+        }
+        Ok(())
+    }
+
+    /// Ban proposer
+    pub fn deny(&mut self, proposer: Pubkey) -> Result<()> {
+        Self::insert(&mut self.denied_proposers, proposer)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Lift proposer ban
+    pub fn undeny(&mut self, proposer: Pubkey) -> Result<()> {
+        Self::remove(&mut self.denied_proposers, proposer)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Add proposer to allowlist
+    pub fn allow(&mut self, proposer: Pubkey) -> Result<()> {
+        Self::insert(&mut self.allowed_proposers, proposer)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Remove proposer from allowlist
+    pub fn disallow(&mut self, proposer: Pubkey) -> Result<()> {
+        Self::remove(&mut self.allowed_proposers, proposer)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    fn insert(list: &mut Vec<Pubkey>, proposer: Pubkey) -> Result<()> {
+        require!(
+            !list.contains(&proposer),
+            crate::error::GovernanceError::ProposerAlreadyListed
+        );
+        require!(
+            list.len() < MAX_POLICY_PROPOSERS,
+            crate::error::GovernanceError::ProposerListFull
+        );
+        list.push(proposer);
+        Ok(())
+    }
+
+    fn remove(list: &mut Vec<Pubkey>, proposer: Pubkey) -> Result<()> {
+        let position = list
+            .iter()
+            .position(|existing| *existing == proposer)
+            .ok_or(crate::error::GovernanceError::ProposerNotListed)?;
+        list.remove(position);
+        Ok(())
+    }
+}
+
+/// Maximum addresses in each proposer policy list
+pub const MAX_POLICY_PROPOSERS: usize = 50;
+
+/// Proposer's lifetime proposal record, one PDA per proposer
+/// Created with the proposer's first proposal and updated at finalization
+#[account]
+#[derive(InitSpace)]
+pub struct ProposerState {
+    /// Proposer address
+    pub proposer: Pubkey,
+    /// Outcomes of the proposer's finalized proposals
+    pub reputation: ProposerReputation,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Lifetime counts of a proposer's proposal outcomes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct ProposerReputation {
+    /// Proposals that passed
+    pub passed: u32,
+    /// Proposals that were rejected
+    pub rejected: u32,
+    /// Proposals that were vetoed
+    pub vetoed: u32,
+    /// Proposals that were cancelled
+    pub cancelled: u32,
+}
+
+impl ProposerReputation {
+    /// Count a proposal that left Pending with the given status
+    pub fn record(&mut self, status: &ProposalStatus) {
+        let counter = match status {
+            ProposalStatus::Passed => &mut self.passed,
+            ProposalStatus::Rejected => &mut self.rejected,
+            ProposalStatus::Vetoed => &mut self.vetoed,
+            ProposalStatus::Cancelled => &mut self.cancelled,
+            ProposalStatus::Pending | ProposalStatus::Executed => return,
+        };
+        *counter = counter.saturating_add(1);
+    }
+}
+
+impl ProposerState {
+    /// Load a proposer state account, or None when no account exists at the address
+    /// The address itself is checked by the caller
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let proposer_state = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(proposer_state))
+    }
+
+    /// Write the state back to its account
+    pub fn store(&self, account: &AccountInfo) -> Result<()> {
+        let mut data = account.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+}
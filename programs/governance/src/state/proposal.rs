@@ -1,21 +1,26 @@
 use anchor_lang::prelude::*;
 
+use crate::instructions::common::{
+    MAX_PROPOSAL_DESCRIPTION_BYTES, MAX_PROPOSAL_OPTIONS, MAX_PROPOSAL_OPTION_BYTES,
+    MAX_PROPOSAL_TITLE_BYTES, MAX_RECUSED_MEMBERS,
+};
+use crate::state::{DepositSlashSchedule, ProposerReputation, VoteTally};
+
+/// Maximum execution result length in UTF-8 bytes
+pub const MAX_EXECUTION_RESULT_BYTES: usize = 500;
+
+/// Execution data buffer length: the largest Borsh-encoded ExecutionData, rounded up to
+/// a multiple of 8 so the Proposal layout needs no padding
+pub const MAX_EXECUTION_DATA_BYTES: usize = ExecutionData::INIT_SPACE.div_ceil(8) * 8;
+
 /// Proposal account
-#[account]
-#[derive(InitSpace)]
+/// Zero-copy: handlers map the account data in place instead of deserializing and
+/// re-serializing the whole proposal. Enums, options, text and execution data live in
+/// fixed-size fields; read and write them through the accessors below
+#[account(zero_copy)]
 pub struct Proposal {
     /// Proposal ID
     pub id: u64,
-    /// Proposal proposer
-    pub proposer: Pubkey,
-    /// Proposal type
-    pub proposal_type: ProposalType,
-    /// Proposal title
-    #[max_len(100)]
-    pub title: String,
-    /// Proposal description
-    #[max_len(800)]
-    pub description: String,
     /// Deposit amount
     pub deposit_amount: u64,
     /// Creation time
@@ -24,8 +29,6 @@ pub struct Proposal {
     pub voting_start: i64,
     /// Voting end time
     pub voting_end: i64,
-    /// Proposal status
-    pub status: ProposalStatus,
     /// Yes votes
     pub yes_votes: u64,
     /// No votes
@@ -36,25 +39,466 @@ pub struct Proposal {
     pub veto_votes: u64,
     /// Total votes
     pub total_votes: u64,
-    /// Execution data
-    pub execution_data: Option<ExecutionData>,
-    /// Execution result
-    #[max_len(500)]
-    pub execution_result: Option<String>,
+    /// Proposal that must be executed before this one can be executed (0: none)
+    depends_on: u64,
+    /// Deposit amount refunded to the proposer
+    pub deposit_refunded: u64,
+    /// Deposit amount kept as committee fee
+    pub deposit_fee: u64,
+    /// Deposit amount confiscated (vetoed proposals)
+    pub deposit_confiscated: u64,
+    /// Part of the confiscated deposit that was burned
+    pub deposit_burned: u64,
+    /// ID of the discounted resubmission of this proposal (0: none)
+    resubmitted_as: u64,
+    /// ID of the proposal this one is a discounted resubmission of (0: none)
+    resubmitted_from: u64,
+    /// Committee total voting power synced at creation (finalization recomputes from balances)
+    pub total_voting_power_snapshot: u64,
+    /// Committee epoch at creation, identifying the committee the proposal was opened under
+    pub committee_epoch: u64,
+    /// Committee voting power the proposal was finalized against (0 until finalized)
+    pub finalized_voting_power: u64,
+    /// Execution time (0: not executed)
+    executed_at: i64,
+    /// Votes per option (multi-choice proposals; set at finalization like the other tallies)
+    pub options_tally: [u64; MAX_PROPOSAL_OPTIONS as usize],
+    /// Proposal proposer
+    pub proposer: Pubkey,
+    /// Deposit token mint
+    pub deposit_mint: Pubkey,
+    /// Committee members recused from voting (recused_count used; fixed at creation)
+    recused_members: [Pubkey; MAX_RECUSED_MEMBERS as usize],
+    /// Proposer's passed proposals when this one was created
+    pub proposer_passed_count: u32,
+    /// Proposer's rejected proposals when this one was created
+    pub proposer_rejected_count: u32,
+    /// Proposer's vetoed proposals when this one was created
+    pub proposer_vetoed_count: u32,
+    /// Proposer's cancelled proposals when this one was created
+    pub proposer_cancelled_count: u32,
+    /// Title length in bytes
+    title_len: u16,
+    /// Description length in bytes
+    description_len: u16,
+    /// Execution result length in bytes (0: none)
+    execution_result_len: u16,
+    /// Encoded execution data length in bytes (0: none)
+    execution_data_len: u16,
+    /// Deposit slash rate fixed at finalization (basis points)
+    pub deposit_slash_rate: u16,
+    /// Participation rate at finalization (basis points)
+    pub participation_rate: u16,
+    /// Approval rate at finalization (basis points)
+    pub approval_rate: u16,
+    /// Participation threshold the proposal was finalized under (basis points)
+    pub participation_threshold: u16,
+    /// Approval threshold the proposal was finalized under (basis points)
+    pub approval_threshold: u16,
+    /// Committee fee rate at creation (basis points); later config updates do not apply
+    pub fee_rate: u16,
+    /// Deposit slash schedule at creation, applied at finalization: passed rate
+    slash_passed_rate: u16,
+    /// Deposit slash schedule: rejected rate
+    slash_rejected_rate: u16,
+    /// Deposit slash schedule: low approval floor
+    slash_low_approval_floor: u16,
+    /// Deposit slash schedule: low approval rejected rate
+    slash_low_approval_rejected_rate: u16,
+    /// Deposit slash schedule: vetoed rate
+    slash_vetoed_rate: u16,
+    /// Proposal type (ProposalType variant index)
+    proposal_type: u8,
+    /// Proposal status (ProposalStatus variant index)
+    status: u8,
+    /// Whether the deposit is held in the proposal's own escrow account (0 or 1)
+    deposit_escrowed: u8,
+    /// Whether the deposit has been settled (0 or 1)
+    deposit_settled: u8,
     /// PDA bump
     pub bump: u8,
+    /// Number of options (0: binary proposal)
+    option_count: u8,
+    /// Winning option index + 1, set when a multi-choice proposal passes (0: none)
+    winning_option: u8,
+    /// Option label lengths in bytes
+    option_lens: [u8; MAX_PROPOSAL_OPTIONS as usize],
+    /// Number of recused members
+    recused_count: u8,
+    /// Reserved (keeps the byte buffers 8-byte aligned)
+    reserved: [u8; 5],
+    /// Proposal title (UTF-8, title_len bytes used)
+    title: [u8; MAX_PROPOSAL_TITLE_BYTES as usize],
+    /// Proposal description (UTF-8, description_len bytes used)
+    description: [u8; MAX_PROPOSAL_DESCRIPTION_BYTES as usize],
+    /// Option labels (UTF-8, MAX_PROPOSAL_OPTION_BYTES per option, option_lens bytes used)
+    options: [u8; (MAX_PROPOSAL_OPTIONS * MAX_PROPOSAL_OPTION_BYTES) as usize],
+    /// Execution result (UTF-8, execution_result_len bytes used)
+    execution_result: [u8; MAX_EXECUTION_RESULT_BYTES],
+    /// Borsh-encoded ExecutionData (execution_data_len bytes used)
+    execution_data: [u8; MAX_EXECUTION_DATA_BYTES],
+}
+
+/// Write `bytes` into a fixed-size field, returning the used length
+fn write_bytes(
+    field: &mut [u8],
+    bytes: &[u8],
+    error: crate::error::GovernanceError,
+) -> Result<u16> {
+    if bytes.len() > field.len() {
+        return Err(error.into());
+    }
+    field[..bytes.len()].copy_from_slice(bytes);
+    field[bytes.len()..].fill(0);
+    Ok(bytes.len() as u16)
+}
+
+/// Read the used part of a fixed-size text field
+fn read_str(field: &[u8], len: u16) -> &str {
+    std::str::from_utf8(&field[..len as usize]).unwrap_or_default()
 }
 
 impl Proposal {
+    /// Account size after the discriminator
+    pub const INIT_SPACE: usize = std::mem::size_of::<Self>();
+
+    /// Map a proposal in borrowed account data (owner already checked by the caller)
+    pub fn from_account_data(data: &[u8]) -> Result<&Self> {
+        require!(
+            data.len() >= 8 + Self::INIT_SPACE && data[..8] == *Self::DISCRIMINATOR,
+            crate::error::GovernanceError::InvalidAccountData
+        );
+        Ok(bytemuck::from_bytes(&data[8..8 + Self::INIT_SPACE]))
+    }
+
+    /// Proposal type
+    /// Unknown discriminants fail with InvalidAccountData rather than mapping to a type
+    pub fn proposal_type(&self) -> Result<ProposalType> {
+        Ok(match self.proposal_type {
+            0 => ProposalType::SlashMerchant,
+            1 => ProposalType::DisputeArbitration,
+            2 => ProposalType::RuleUpdate,
+            3 => ProposalType::ConfigUpdate,
+            4 => ProposalType::CommitteeChange,
+            5 => ProposalType::ProgramUpgrade,
+            _ => return err!(crate::error::GovernanceError::InvalidAccountData),
+        })
+    }
+
+    /// Set the proposal type
+    pub fn set_proposal_type(&mut self, proposal_type: ProposalType) {
+        self.proposal_type = proposal_type as u8;
+    }
+
+    /// Proposal status
+    /// Unknown discriminants fail with InvalidAccountData rather than mapping to a status
+    pub fn status(&self) -> Result<ProposalStatus> {
+        Ok(match self.status {
+            0 => ProposalStatus::Pending,
+            1 => ProposalStatus::Passed,
+            2 => ProposalStatus::Rejected,
+            3 => ProposalStatus::Vetoed,
+            4 => ProposalStatus::Executed,
+            5 => ProposalStatus::Cancelled,
+            _ => return err!(crate::error::GovernanceError::InvalidAccountData),
+        })
+    }
+
+    /// Set the proposal status
+    pub fn set_status(&mut self, status: ProposalStatus) {
+        self.status = status as u8;
+    }
+
+    /// Proposal title
+    pub fn title(&self) -> &str {
+        read_str(&self.title, self.title_len)
+    }
+
+    /// Proposal description
+    pub fn description(&self) -> &str {
+        read_str(&self.description, self.description_len)
+    }
+
+    /// Set title and description (validated by validate_proposal_content)
+    pub fn set_content(&mut self, title: &str, description: &str) -> Result<()> {
+        self.title_len = write_bytes(
+            &mut self.title,
+            title.as_bytes(),
+            crate::error::GovernanceError::InvalidProposalTitleLength,
+        )?;
+        self.description_len = write_bytes(
+            &mut self.description,
+            description.as_bytes(),
+            crate::error::GovernanceError::InvalidProposalDescriptionLength,
+        )?;
+        Ok(())
+    }
+
+    /// Option labels (empty for binary proposals)
+    pub fn options(&self) -> Vec<&str> {
+        self.options
+            .chunks(MAX_PROPOSAL_OPTION_BYTES as usize)
+            .zip(self.option_lens)
+            .take(self.option_count as usize)
+            .map(|(field, len)| read_str(field, len as u16))
+            .collect()
+    }
+
+    /// Number of options (0: binary proposal)
+    pub fn option_count(&self) -> u8 {
+        self.option_count
+    }
+
+    /// Whether votes choose between options instead of for or against
+    pub fn is_multi_choice(&self) -> bool {
+        self.option_count > 0
+    }
+
+    /// Set the option labels (validated by validate_proposal_options)
+    pub fn set_options(&mut self, options: &[String]) -> Result<()> {
+        require!(
+            options.len() <= MAX_PROPOSAL_OPTIONS as usize,
+            crate::error::GovernanceError::InvalidProposalOptions
+        );
+        self.options.fill(0);
+        self.option_lens.fill(0);
+        for (index, (field, option)) in self
+            .options
+            .chunks_mut(MAX_PROPOSAL_OPTION_BYTES as usize)
+            .zip(options)
+            .enumerate()
+        {
+            self.option_lens[index] = write_bytes(
+                field,
+                option.as_bytes(),
+                crate::error::GovernanceError::InvalidProposalOptions,
+            )? as u8;
+        }
+        self.option_count = options.len() as u8;
+        Ok(())
+    }
+
+    /// Committee members recused from voting on the proposal
+    pub fn recused_members(&self) -> &[Pubkey] {
+        &self.recused_members[..self.recused_count as usize]
+    }
+
+    /// Whether `member` is recused from voting on the proposal
+    pub fn is_recused(&self, member: &Pubkey) -> bool {
+        self.recused_members().contains(member)
+    }
+
+    /// Set the recused members (validated by validate_recused_members)
+    pub fn set_recused_members(&mut self, recused_members: &[Pubkey]) -> Result<()> {
+        require!(
+            recused_members.len() <= MAX_RECUSED_MEMBERS as usize,
+            crate::error::GovernanceError::InvalidRecusedMembers
+        );
+        self.recused_members = [Pubkey::default(); MAX_RECUSED_MEMBERS as usize];
+        self.recused_members[..recused_members.len()].copy_from_slice(recused_members);
+        self.recused_count = recused_members.len() as u8;
+        Ok(())
+    }
+
+    /// Winning option of a passed multi-choice proposal
+    pub fn winning_option(&self) -> Option<u8> {
+        self.winning_option.checked_sub(1)
+    }
+
+    /// Tallies as recorded on the proposal
+    pub fn tally(&self) -> VoteTally {
+        VoteTally {
+            yes_votes: self.yes_votes,
+            no_votes: self.no_votes,
+            abstain_votes: self.abstain_votes,
+            veto_votes: self.veto_votes,
+            options_tally: self.options_tally,
+        }
+    }
+
+    /// Record the tallies counted from the vote accounts
+    pub fn record_tally(&mut self, tally: &VoteTally) -> Result<()> {
+        let total_votes = [
+            tally.yes_votes,
+            tally.no_votes,
+            tally.abstain_votes,
+            tally.veto_votes,
+        ]
+        .into_iter()
+        .chain(tally.options_tally)
+        .try_fold(0u64, |sum, votes| sum.checked_add(votes))
+        .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+
+        self.yes_votes = tally.yes_votes;
+        self.no_votes = tally.no_votes;
+        self.abstain_votes = tally.abstain_votes;
+        self.veto_votes = tally.veto_votes;
+        self.options_tally = tally.options_tally;
+        self.total_votes = total_votes;
+        Ok(())
+    }
+
+    /// Execution result (None until executed)
+    pub fn execution_result(&self) -> Option<&str> {
+        (self.execution_result_len > 0)
+            .then(|| read_str(&self.execution_result, self.execution_result_len))
+    }
+
+    /// Borsh-encoded execution data (empty when the proposal has none)
+    pub fn execution_data_bytes(&self) -> &[u8] {
+        &self.execution_data[..self.execution_data_len as usize]
+    }
+
+    /// Decode the execution data
+    pub fn execution_data(&self) -> Result<Option<ExecutionData>> {
+        let bytes = self.execution_data_bytes();
+        if bytes.is_empty() {
+            return Ok(None);
+        }
+        let data = ExecutionData::try_from_slice(bytes)
+            .map_err(|_| crate::error::GovernanceError::InvalidExecutionData)?;
+        Ok(Some(data))
+    }
+
+    /// Encode and store the execution data
+    pub fn set_execution_data(&mut self, execution_data: &Option<ExecutionData>) -> Result<()> {
+        let mut bytes = Vec::new();
+        if let Some(data) = execution_data {
+            data.serialize(&mut bytes)?;
+        }
+        self.execution_data_len = write_bytes(
+            &mut self.execution_data,
+            &bytes,
+            crate::error::GovernanceError::InvalidExecutionData,
+        )?;
+        Ok(())
+    }
+
+    /// Copy type, content, options, recusals, execution data and dependency from another
+    /// proposal
+    /// (the encoded fields are copied as-is, without decoding them)
+    pub fn copy_content_from(&mut self, other: &Proposal) {
+        self.proposal_type = other.proposal_type;
+        self.title = other.title;
+        self.title_len = other.title_len;
+        self.description = other.description;
+        self.description_len = other.description_len;
+        self.execution_data = other.execution_data;
+        self.execution_data_len = other.execution_data_len;
+        self.options = other.options;
+        self.option_lens = other.option_lens;
+        self.option_count = other.option_count;
+        self.recused_members = other.recused_members;
+        self.recused_count = other.recused_count;
+        self.depends_on = other.depends_on;
+    }
+
+    /// Proposal that must be executed before this one can be executed
+    pub fn depends_on(&self) -> Option<u64> {
+        (self.depends_on != 0).then_some(self.depends_on)
+    }
+
+    /// Set the dependency (IDs start at 1, so 0 is never a valid dependency)
+    pub fn set_depends_on(&mut self, depends_on: Option<u64>) {
+        self.depends_on = depends_on.unwrap_or(0);
+    }
+
+    /// ID of the discounted resubmission of this proposal
+    pub fn resubmitted_as(&self) -> Option<u64> {
+        (self.resubmitted_as != 0).then_some(self.resubmitted_as)
+    }
+
+    /// Record the discounted resubmission of this proposal
+    pub fn set_resubmitted_as(&mut self, proposal_id: u64) {
+        self.resubmitted_as = proposal_id;
+    }
+
+    /// ID of the proposal this one is a discounted resubmission of
+    pub fn resubmitted_from(&self) -> Option<u64> {
+        (self.resubmitted_from != 0).then_some(self.resubmitted_from)
+    }
+
+    /// Record the proposal this one is a discounted resubmission of
+    pub fn set_resubmitted_from(&mut self, proposal_id: u64) {
+        self.resubmitted_from = proposal_id;
+    }
+
+    /// Proposer's record when the proposal was created
+    pub fn proposer_reputation(&self) -> ProposerReputation {
+        ProposerReputation {
+            passed: self.proposer_passed_count,
+            rejected: self.proposer_rejected_count,
+            vetoed: self.proposer_vetoed_count,
+            cancelled: self.proposer_cancelled_count,
+        }
+    }
+
+    /// Snapshot the proposer's record onto the proposal
+    pub fn set_proposer_reputation(&mut self, reputation: &ProposerReputation) {
+        self.proposer_passed_count = reputation.passed;
+        self.proposer_rejected_count = reputation.rejected;
+        self.proposer_vetoed_count = reputation.vetoed;
+        self.proposer_cancelled_count = reputation.cancelled;
+    }
+
+    /// Execution time
+    pub fn executed_at(&self) -> Option<i64> {
+        (self.executed_at != 0).then_some(self.executed_at)
+    }
+
+    /// Whether the deposit is held in the proposal's own escrow account
+    pub fn deposit_escrowed(&self) -> bool {
+        self.deposit_escrowed != 0
+    }
+
+    /// Set whether the deposit is held in the proposal's own escrow account
+    pub fn set_deposit_escrowed(&mut self, escrowed: bool) {
+        self.deposit_escrowed = escrowed as u8;
+    }
+
+    /// Whether the deposit has been settled
+    pub fn deposit_settled(&self) -> bool {
+        self.deposit_settled != 0
+    }
+
+    /// Deposit slash schedule at creation, applied at finalization
+    pub fn deposit_slash_schedule(&self) -> DepositSlashSchedule {
+        DepositSlashSchedule {
+            passed_rate: self.slash_passed_rate,
+            rejected_rate: self.slash_rejected_rate,
+            low_approval_floor: self.slash_low_approval_floor,
+            low_approval_rejected_rate: self.slash_low_approval_rejected_rate,
+            vetoed_rate: self.slash_vetoed_rate,
+        }
+    }
+
+    /// Fix the deposit slash schedule at creation
+    pub fn set_deposit_slash_schedule(&mut self, schedule: &DepositSlashSchedule) {
+        self.slash_passed_rate = schedule.passed_rate;
+        self.slash_rejected_rate = schedule.rejected_rate;
+        self.slash_low_approval_floor = schedule.low_approval_floor;
+        self.slash_low_approval_rejected_rate = schedule.low_approval_rejected_rate;
+        self.slash_vetoed_rate = schedule.vetoed_rate;
+    }
+
     /// Check if voting is allowed
-    pub fn can_vote(&self) -> bool {
-        self.status == ProposalStatus::Pending
-            && Clock::get().unwrap().unix_timestamp <= self.voting_end
+    pub fn can_vote(&self, now: i64) -> bool {
+        self.is_votable() && now <= self.voting_end
+    }
+
+    /// Check if the proposal is still open for votes (status only, ignores deadline)
+    pub fn is_votable(&self) -> bool {
+        self.status().is_ok_and(|status| status.is_votable())
+    }
+
+    /// Check if the proposal has reached a status with no further transitions
+    pub fn is_terminal(&self) -> bool {
+        self.status().is_ok_and(|status| status.is_terminal())
     }
 
     /// Check if voting has ended
-    pub fn is_voting_ended(&self) -> bool {
-        Clock::get().unwrap().unix_timestamp > self.voting_end
+    pub fn is_voting_ended(&self, now: i64) -> bool {
+        now > self.voting_end
     }
 
     /// Finalize proposal
@@ -62,47 +506,123 @@ impl Proposal {
         &mut self,
         governance_config: &crate::state::GovernanceConfig,
         total_voting_power: u64,
+        now: i64,
     ) -> Result<()> {
         require!(
-            self.is_voting_ended(),
+            self.is_voting_ended(now),
             crate::error::GovernanceError::VotingPeriodNotEnded
         );
         require!(
-            self.status == ProposalStatus::Pending,
+            self.is_votable(),
             crate::error::GovernanceError::ProposalNotActive
         );
 
-        // Use VoteStats to uniformly determine proposal status
-        let vote_stats = crate::state::vote::VoteStats {
-            total_votes: self.total_votes,
-            yes_votes: self.yes_votes,
-            no_votes: self.no_votes,
-            abstain_votes: self.abstain_votes,
-            veto_votes: self.veto_votes,
-            voter_count: 0, // Set to 0 temporarily, can be calculated from vote records if needed
-        };
+        // Use VoteStats to uniformly determine proposal status (multi-choice proposals
+        // are approved by their leading option)
+        let tally = self.tally();
+        let vote_stats = tally.vote_stats();
 
-        self.status = governance_config
+        let status = governance_config
             .determine_proposal_status_with_vote_stats(&vote_stats, total_voting_power);
+        if status == ProposalStatus::Passed && self.is_multi_choice() {
+            self.winning_option = tally.leading_option().map_or(0, |index| index + 1);
+        }
+        self.participation_rate = vote_stats
+            .calculate_participation_rate(total_voting_power, governance_config.rate_rounding);
+        self.finalized_voting_power = total_voting_power;
+        self.approval_rate = vote_stats.calculate_approval_rate(governance_config.rate_rounding);
+        self.participation_threshold = governance_config.participation_threshold;
+        self.approval_threshold = governance_config.approval_threshold;
+        self.deposit_slash_rate = self
+            .deposit_slash_schedule()
+            .slash_rate(&status, self.approval_rate);
+        self.set_status(status);
 
         Ok(())
     }
 
+    /// Reset tallies, outcome and deposit bookkeeping for a newly created proposal
+    pub fn reset_outcome(&mut self) {
+        self.set_status(ProposalStatus::Pending);
+        self.yes_votes = 0;
+        self.no_votes = 0;
+        self.abstain_votes = 0;
+        self.veto_votes = 0;
+        self.total_votes = 0;
+        self.options_tally = [0; MAX_PROPOSAL_OPTIONS as usize];
+        self.winning_option = 0;
+        self.execution_result_len = 0;
+        self.deposit_refunded = 0;
+        self.deposit_fee = 0;
+        self.deposit_confiscated = 0;
+        self.deposit_burned = 0;
+        self.deposit_settled = 0;
+        self.deposit_slash_rate = 0;
+        self.participation_rate = 0;
+        self.approval_rate = 0;
+        self.participation_threshold = 0;
+        self.approval_threshold = 0;
+        self.resubmitted_as = 0;
+        self.finalized_voting_power = 0;
+        self.executed_at = 0;
+    }
+
+    /// Check if proposal was rejected with enough approval but too little participation,
+    /// against the thresholds it was finalized under
+    pub fn failed_only_on_participation(&self) -> bool {
+        self.status == ProposalStatus::Rejected as u8
+            && self.approval_rate > self.approval_threshold
+            && self.participation_rate < self.participation_threshold
+    }
+
     /// Mark as executed
-    pub fn mark_executed(&mut self, result: String) -> Result<()> {
+    pub fn mark_executed(&mut self, result: &str, executed_at: i64) -> Result<()> {
         require!(
-            self.status == ProposalStatus::Passed,
+            self.status == ProposalStatus::Passed as u8,
             crate::error::GovernanceError::ProposalNotExecutable
         );
 
-        self.status = ProposalStatus::Executed;
-        self.execution_result = Some(result);
+        self.set_status(ProposalStatus::Executed);
+        self.execution_result_len = write_bytes(
+            &mut self.execution_result,
+            result.as_bytes(),
+            crate::error::GovernanceError::ExecutionResultTooLong,
+        )?;
+        self.executed_at = executed_at;
+        Ok(())
+    }
+
+    /// Record how the deposit was split; the three parts must add up to the deposit
+    pub fn record_deposit_settlement(
+        &mut self,
+        refunded: u64,
+        fee: u64,
+        confiscated: u64,
+    ) -> Result<()> {
+        require!(
+            !self.deposit_settled(),
+            crate::error::GovernanceError::DepositAlreadySettled
+        );
+
+        let total = refunded
+            .checked_add(fee)
+            .and_then(|sum| sum.checked_add(confiscated))
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        require!(
+            total == self.deposit_amount,
+            crate::error::GovernanceError::DepositSettlementMismatch
+        );
+
+        self.deposit_refunded = refunded;
+        self.deposit_fee = fee;
+        self.deposit_confiscated = confiscated;
+        self.deposit_settled = 1;
         Ok(())
     }
 
     /// Check if can be executed
     pub fn can_execute(&self) -> bool {
-        self.status == ProposalStatus::Passed
+        self.status == ProposalStatus::Passed as u8
     }
 }
 
@@ -117,6 +637,10 @@ pub enum ProposalType {
     RuleUpdate,
     /// Configuration update
     ConfigUpdate,
+    /// Committee membership change
+    CommitteeChange,
+    /// Upgrade of a program whose upgrade authority is the governance authority PDA
+    ProgramUpgrade,
 }
 
 /// Proposal status
@@ -132,6 +656,33 @@ pub enum ProposalStatus {
     Vetoed,
     /// Executed
     Executed,
+    /// Cancelled before finalization
+    Cancelled,
+}
+
+impl ProposalStatus {
+    /// Only pending proposals accept votes and can be finalized
+    pub fn is_votable(&self) -> bool {
+        match self {
+            ProposalStatus::Pending => true,
+            ProposalStatus::Passed
+            | ProposalStatus::Rejected
+            | ProposalStatus::Vetoed
+            | ProposalStatus::Executed
+            | ProposalStatus::Cancelled => false,
+        }
+    }
+
+    /// Terminal statuses allow no further transitions (Passed still awaits execution)
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            ProposalStatus::Pending | ProposalStatus::Passed => false,
+            ProposalStatus::Rejected
+            | ProposalStatus::Vetoed
+            | ProposalStatus::Executed
+            | ProposalStatus::Cancelled => true,
+        }
+    }
 }
 
 /// Vote type
@@ -141,6 +692,8 @@ pub enum VoteType {
     No,
     Abstain,
     NoWithVeto,
+    /// Option index on a multi-choice proposal
+    Choice(u8),
 }
 
 /// Execution data
@@ -154,6 +707,10 @@ pub enum ExecutionData {
     RuleUpdate(RuleUpdateData),
     /// Configuration update data
     ConfigUpdate(ConfigUpdateData),
+    /// Committee membership change data
+    CommitteeChange(CommitteeChangeData),
+    /// Program upgrade data
+    ProgramUpgrade(ProgramUpgradeData),
 }
 
 /// Illegal product slash proposal data
@@ -193,12 +750,15 @@ pub struct DisputeProposalData {
     /// Requested resolution
     #[max_len(1000)]
     pub requested_resolution: String,
-    /// Arbitration decision (filled after voting)
+    /// Arbitration decision (filled at execution; None at creation)
     pub arbitration_decision: Option<ArbitrationDecision>,
+    /// Decision a Yes vote ratifies; execution records exactly this decision (None: the
+    /// outcome is left to requested_resolution)
+    pub proposed_decision: Option<ArbitrationDecision>,
 }
 
 /// Arbitration decision
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum ArbitrationDecision {
     /// Support user, full refund
     RefundUser,
@@ -210,15 +770,41 @@ pub enum ArbitrationDecision {
     RequireOfflineResolution,
 }
 
+impl ArbitrationDecision {
+    /// Check the decision can be executed as proposed (partial refunds must refund something)
+    pub fn validate(&self) -> Result<()> {
+        if let ArbitrationDecision::PartialRefund(amount) = self {
+            require!(
+                *amount > 0,
+                crate::error::GovernanceError::InvalidArbitrationDecision
+            );
+        }
+        Ok(())
+    }
+}
+
+impl DisputeProposalData {
+    /// Record the ratified decision, returning it (None when no decision was proposed)
+    pub fn ratify_proposed_decision(&mut self) -> Option<ArbitrationDecision> {
+        self.arbitration_decision = self.proposed_decision.clone();
+        self.arbitration_decision.clone()
+    }
+}
+
 /// Rule update data
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
 pub struct RuleUpdateData {
+    /// Name of the targeted rule registry (empty for the original registry)
+    #[max_len(32)]
+    pub registry_name: String,
     /// Operation type
     pub operation: RuleOperation,
-    /// Document index (for update/delete)
-    pub document_index: Option<u32>,
+    /// Document ID (for update/delete)
+    pub document_id: Option<u32>,
     /// New document data (for add/update)
     pub document_data: Option<crate::state::RuleDocument>,
+    /// Time the added or updated document comes into force (None: at execution)
+    pub effective_from: Option<i64>,
 }
 
 /// Rule operation type
@@ -235,3 +821,268 @@ pub struct ConfigUpdateData {
     /// Configuration update parameters
     pub config_update: crate::state::GovernanceConfigUpdate,
 }
+
+/// Committee membership change data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct CommitteeChangeData {
+    /// Membership operation
+    pub action: CommitteeChangeAction,
+    /// Member added or removed
+    pub member: Pubkey,
+}
+
+/// Program upgrade data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct ProgramUpgradeData {
+    /// Upgraded program
+    pub program_id: Pubkey,
+    /// Upgradeable loader buffer holding the new program bytes (authority: governance PDA)
+    pub buffer: Pubkey,
+}
+
+/// Committee membership operation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum CommitteeChangeAction {
+    Add,
+    Remove,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::GovernanceError;
+    use crate::state::GovernanceConfig;
+
+    const VOTING_END: i64 = 1_000;
+
+    fn pending_proposal() -> Proposal {
+        let mut proposal: Proposal = bytemuck::Zeroable::zeroed();
+        proposal.set_status(ProposalStatus::Pending);
+        proposal.voting_end = VOTING_END;
+        proposal
+    }
+
+    /// Config with 50% participation, 50% approval and 33.34% veto thresholds
+    /// (every other field zeroed, which Borsh decodes as defaults)
+    fn governance_config() -> GovernanceConfig {
+        let mut config =
+            GovernanceConfig::deserialize(&mut &vec![0u8; GovernanceConfig::INIT_SPACE][..])
+                .unwrap();
+        config.participation_threshold = 5000;
+        config.approval_threshold = 5000;
+        config.veto_threshold = 3334;
+        config
+    }
+
+    /// Every status with whether it is votable and terminal, and the settlement of a
+    /// 1000 deposit slashed at 5% as (refund, fee, confiscated) when the deposit is processed
+    const STATUS_TABLE: [(ProposalStatus, bool, bool, Option<(u64, u64, u64)>); 6] = [
+        (ProposalStatus::Pending, true, false, None),
+        (ProposalStatus::Passed, false, false, Some((950, 50, 0))),
+        (ProposalStatus::Rejected, false, true, Some((950, 50, 0))),
+        (ProposalStatus::Vetoed, false, true, Some((950, 0, 50))),
+        (ProposalStatus::Executed, false, true, Some((950, 50, 0))),
+        (ProposalStatus::Cancelled, false, true, None),
+    ];
+
+    #[test]
+    fn status_helpers_cover_every_status() {
+        for (status, votable, terminal, settlement) in STATUS_TABLE {
+            let mut proposal = pending_proposal();
+            proposal.set_status(status.clone());
+            proposal.deposit_amount = 1_000;
+            proposal.deposit_slash_rate = 500;
+            assert_eq!(proposal.status().unwrap(), status);
+            assert_eq!(status.is_votable(), votable, "{:?}", status);
+            assert_eq!(status.is_terminal(), terminal, "{:?}", status);
+            assert_eq!(proposal.is_votable(), votable);
+            assert_eq!(proposal.is_terminal(), terminal);
+
+            let split = crate::instructions::proposal::scheduled_deposit_split(&proposal)
+                .unwrap()
+                .map(|split| (split.refund_amount, split.program_fee, split.confiscated));
+            assert_eq!(split, settlement, "{:?}", status);
+        }
+    }
+
+    #[test]
+    fn unknown_discriminants_are_rejected() {
+        let mut proposal = pending_proposal();
+        proposal.status = 6;
+        proposal.proposal_type = 6;
+        assert_eq!(
+            proposal.status(),
+            Err(GovernanceError::InvalidAccountData.into())
+        );
+        assert_eq!(
+            proposal.proposal_type(),
+            Err(GovernanceError::InvalidAccountData.into())
+        );
+        assert!(!proposal.is_votable());
+        assert!(!proposal.is_terminal());
+        assert!(!proposal.can_execute());
+    }
+
+    #[test]
+    fn can_vote_until_voting_end_inclusive() {
+        let proposal = pending_proposal();
+        assert!(proposal.can_vote(VOTING_END - 1));
+        assert!(proposal.can_vote(VOTING_END));
+        assert!(!proposal.can_vote(VOTING_END + 1));
+    }
+
+    #[test]
+    fn can_vote_requires_votable_status() {
+        let mut proposal = pending_proposal();
+        proposal.set_status(ProposalStatus::Passed);
+        assert!(!proposal.can_vote(VOTING_END - 1));
+    }
+
+    #[test]
+    fn voting_ends_strictly_after_voting_end() {
+        let proposal = pending_proposal();
+        assert!(!proposal.is_voting_ended(VOTING_END));
+        assert!(proposal.is_voting_ended(VOTING_END + 1));
+    }
+
+    #[test]
+    fn finalize_before_voting_end_fails() {
+        let mut proposal = pending_proposal();
+        assert_eq!(
+            proposal.finalize(&governance_config(), 100, VOTING_END),
+            Err(GovernanceError::VotingPeriodNotEnded.into())
+        );
+        assert_eq!(proposal.status().unwrap(), ProposalStatus::Pending);
+    }
+
+    #[test]
+    fn finalize_twice_fails() {
+        let mut proposal = pending_proposal();
+        proposal
+            .record_tally(&VoteTally {
+                yes_votes: 60,
+                ..Default::default()
+            })
+            .unwrap();
+        proposal
+            .finalize(&governance_config(), 100, VOTING_END + 1)
+            .unwrap();
+        assert_eq!(
+            proposal.finalize(&governance_config(), 100, VOTING_END + 1),
+            Err(GovernanceError::ProposalNotActive.into())
+        );
+    }
+
+    #[test]
+    fn finalize_passes_with_participation_and_approval() {
+        let mut proposal = pending_proposal();
+        proposal
+            .record_tally(&VoteTally {
+                yes_votes: 40,
+                no_votes: 20,
+                ..Default::default()
+            })
+            .unwrap();
+        proposal
+            .finalize(&governance_config(), 100, VOTING_END + 1)
+            .unwrap();
+        assert_eq!(proposal.status().unwrap(), ProposalStatus::Passed);
+        assert_eq!(proposal.participation_rate, 6000);
+        assert_eq!(proposal.approval_rate, 6666);
+        assert_eq!(proposal.finalized_voting_power, 100);
+    }
+
+    #[test]
+    fn finalize_rejects_without_participation() {
+        let mut proposal = pending_proposal();
+        proposal
+            .record_tally(&VoteTally {
+                yes_votes: 40,
+                ..Default::default()
+            })
+            .unwrap();
+        proposal
+            .finalize(&governance_config(), 100, VOTING_END + 1)
+            .unwrap();
+        assert_eq!(proposal.status().unwrap(), ProposalStatus::Rejected);
+    }
+
+    #[test]
+    fn quorum_failure_is_judged_against_finalization_thresholds() {
+        let mut proposal = pending_proposal();
+        proposal
+            .record_tally(&VoteTally {
+                yes_votes: 40,
+                ..Default::default()
+            })
+            .unwrap();
+        proposal
+            .finalize(&governance_config(), 100, VOTING_END + 1)
+            .unwrap();
+        assert_eq!(proposal.participation_threshold, 5000);
+        assert_eq!(proposal.approval_threshold, 5000);
+        assert!(proposal.failed_only_on_participation());
+
+        // A proposal finalized under a stricter approval threshold did not clear approval
+        let mut stricter = pending_proposal();
+        stricter
+            .record_tally(&VoteTally {
+                yes_votes: 40,
+                ..Default::default()
+            })
+            .unwrap();
+        let mut config = governance_config();
+        config.approval_threshold = 10000;
+        stricter.finalize(&config, 100, VOTING_END + 1).unwrap();
+        assert!(!stricter.failed_only_on_participation());
+    }
+
+    #[test]
+    fn finalize_veto_takes_priority() {
+        let mut proposal = pending_proposal();
+        proposal
+            .record_tally(&VoteTally {
+                yes_votes: 60,
+                veto_votes: 40,
+                ..Default::default()
+            })
+            .unwrap();
+        proposal
+            .finalize(&governance_config(), 100, VOTING_END + 1)
+            .unwrap();
+        assert_eq!(proposal.status().unwrap(), ProposalStatus::Vetoed);
+    }
+
+    #[test]
+    fn finalize_records_winning_option() {
+        let mut proposal = pending_proposal();
+        proposal
+            .set_options(&["Refund".to_string(), "Replace".to_string()])
+            .unwrap();
+        proposal
+            .record_tally(&VoteTally {
+                options_tally: [20, 50, 0, 0, 0],
+                ..Default::default()
+            })
+            .unwrap();
+        proposal
+            .finalize(&governance_config(), 100, VOTING_END + 1)
+            .unwrap();
+        assert_eq!(proposal.status().unwrap(), ProposalStatus::Passed);
+        assert_eq!(proposal.winning_option(), Some(1));
+    }
+
+    #[test]
+    fn record_tally_rejects_overflowing_total() {
+        let mut proposal = pending_proposal();
+        assert_eq!(
+            proposal.record_tally(&VoteTally {
+                yes_votes: u64::MAX,
+                options_tally: [1, 0, 0, 0, 0],
+                ..Default::default()
+            }),
+            Err(GovernanceError::ArithmeticOverflow.into())
+        );
+        assert_eq!(proposal.total_votes, 0);
+    }
+}
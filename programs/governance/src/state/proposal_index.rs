@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use super::proposal::ProposalStatus;
+
+/// Most pending proposal IDs the index holds (create_proposal fails beyond it)
+pub const MAX_INDEXED_PENDING: usize = 128;
+
+/// Most passed proposal IDs the index holds (the oldest is dropped beyond it)
+pub const MAX_INDEXED_PASSED: usize = 128;
+
+/// Most closed proposal IDs the index holds (the oldest is dropped beyond it)
+pub const MAX_INDEXED_CLOSED: usize = 256;
+
+/// Most proposal IDs returned by one get_proposal_index_page call
+pub const MAX_PROPOSAL_INDEX_PAGE: u32 = 100;
+
+/// Proposal IDs grouped by status, kept up to date while proposal_index_enabled is set
+/// Lists are in the order proposals entered them; proposals created before the index
+/// was enabled appear once they change status
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalIndex {
+    /// Pending proposals (max_len must equal MAX_INDEXED_PENDING)
+    #[max_len(128)]
+    pub pending: Vec<u64>,
+    /// Passed proposals awaiting execution, most recent MAX_INDEXED_PASSED (max_len must equal it)
+    #[max_len(128)]
+    pub passed: Vec<u64>,
+    /// Rejected, vetoed, executed and cancelled proposals, most recent MAX_INDEXED_CLOSED
+    /// (max_len must equal it)
+    #[max_len(256)]
+    pub closed: Vec<u64>,
+    /// Last update time
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Status group of the proposal index
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalIndexList {
+    /// Pending proposals
+    Pending,
+    /// Passed proposals awaiting execution
+    Passed,
+    /// Proposals with a terminal status
+    Closed,
+}
+
+impl ProposalIndexList {
+    /// Index list a proposal with the given status belongs to
+    pub fn for_status(status: &ProposalStatus) -> Self {
+        match status {
+            ProposalStatus::Pending => ProposalIndexList::Pending,
+            ProposalStatus::Passed => ProposalIndexList::Passed,
+            ProposalStatus::Rejected
+            | ProposalStatus::Vetoed
+            | ProposalStatus::Executed
+            | ProposalStatus::Cancelled => ProposalIndexList::Closed,
+        }
+    }
+}
+
+impl ProposalIndex {
+    /// IDs in one list
+    pub fn list(&self, list: ProposalIndexList) -> &[u64] {
+        match list {
+            ProposalIndexList::Pending => &self.pending,
+            ProposalIndexList::Passed => &self.passed,
+            ProposalIndexList::Closed => &self.closed,
+        }
+    }
+
+    /// Move a proposal to the list of its new status (None: newly created)
+    pub fn record(
+        &mut self,
+        proposal_id: u64,
+        previous: Option<&ProposalStatus>,
+        status: &ProposalStatus,
+        now: i64,
+    ) -> Result<()> {
+        if let Some(previous) = previous {
+            let list = self.list_mut(ProposalIndexList::for_status(previous));
+            list.retain(|id| *id != proposal_id);
+        }
+
+        match ProposalIndexList::for_status(status) {
+            ProposalIndexList::Pending => {
+                require!(
+                    self.pending.len() < MAX_INDEXED_PENDING,
+                    crate::error::GovernanceError::ProposalIndexFull
+                );
+                self.pending.push(proposal_id);
+            }
+            ProposalIndexList::Passed => {
+                Self::push_recent(&mut self.passed, proposal_id, MAX_INDEXED_PASSED)
+            }
+            ProposalIndexList::Closed => {
+                Self::push_recent(&mut self.closed, proposal_id, MAX_INDEXED_CLOSED)
+            }
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    fn list_mut(&mut self, list: ProposalIndexList) -> &mut Vec<u64> {
+        match list {
+            ProposalIndexList::Pending => &mut self.pending,
+            ProposalIndexList::Passed => &mut self.passed,
+            ProposalIndexList::Closed => &mut self.closed,
+        }
+    }
+
+    /// Append an ID, dropping the oldest one once the list holds `capacity` IDs
+    fn push_recent(list: &mut Vec<u64>, proposal_id: u64, capacity: usize) {
+        if list.len() >= capacity {
+            list.remove(0);
+        }
+        list.push(proposal_id);
+    }
+}
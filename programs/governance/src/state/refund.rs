@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Deposit refund the proposer can claim when finalize could not push it
+#[account]
+#[derive(InitSpace)]
+pub struct RefundClaim {
+    /// Proposal ID the refund belongs to
+    pub proposal_id: u64,
+    /// Proposer entitled to the refund
+    pub proposer: Pubkey,
+    /// Deposit token mint
+    pub mint: Pubkey,
+    /// Claimable amount
+    pub amount: u64,
+    /// Creation time
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
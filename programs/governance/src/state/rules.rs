@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::MAX_RULE_DOCUMENTS;
+
 /// Rule registry account
 #[account]
 #[derive(InitSpace)]
 pub struct RuleRegistry {
-    /// Administrator address
+    /// Governance authority (kept equal to governance_config.authority; rules management
+    /// itself is permissioned by the rule admin and category admins)
     pub authority: Pubkey,
-    /// Rule document list
+    /// Rule document list (max_len must equal MAX_RULE_DOCUMENTS)
     #[max_len(50)]
     pub rule_documents: Vec<RuleDocument>,
     /// Last update time
@@ -17,13 +20,252 @@ pub struct RuleRegistry {
     pub created_at: i64,
     /// PDA bump
     pub bump: u8,
+    /// ID assigned to the next added document (IDs are never reused)
+    pub next_document_id: u32,
+    /// Admins delegated to manage the documents of a single category
+    #[max_len(10)]
+    pub category_admins: Vec<CategoryAdmin>,
+    /// Registry name, part of the PDA seeds (empty for the original registry)
+    #[max_len(32)]
+    pub name: String,
 }
 
+// The registry's maximum size must hold exactly MAX_RULE_DOCUMENTS full documents,
+// MAX_CATEGORY_ADMINS category admins and the longest name
+const _: () = assert!(
+    RuleRegistry::INIT_SPACE
+        == RuleRegistry::EMPTY_SERIALIZED_LEN
+            + MAX_RULE_DOCUMENTS as usize * RuleDocument::INIT_SPACE
+            + MAX_CATEGORY_ADMINS * CategoryAdmin::INIT_SPACE
+            + MAX_RULE_REGISTRY_NAME_LENGTH
+);
+
 impl RuleRegistry {
-    /// Add rule document
-    pub fn add_document(&mut self, document: RuleDocument) -> Result<()> {
+    /// Serialized length of an empty, unnamed registry (without the discriminator)
+    pub const EMPTY_SERIALIZED_LEN: usize = 32 + 4 + 8 + 4 + 8 + 1 + 4 + 4 + 4;
+
+    /// Account size of a new, empty registry with the given name
+    pub fn account_size_for_name(name: &str) -> Result<usize> {
         require!(
-            self.rule_documents.len() < MAX_RULE_DOCUMENTS,
+            name.len() <= MAX_RULE_REGISTRY_NAME_LENGTH,
+            crate::error::GovernanceError::InvalidRuleRegistryName
+        );
+        Self::account_size_for(Self::EMPTY_SERIALIZED_LEN + name.len())
+    }
+
+    /// Record the current governance authority
+    pub fn sync_authority(&mut self, authority: Pubkey, now: i64) {
+        self.authority = authority;
+        self.last_updated = now;
+    }
+
+    /// Serialized length of the registry data (without the discriminator)
+    pub fn serialized_len(&self) -> usize {
+        Self::EMPTY_SERIALIZED_LEN
+            + self
+                .rule_documents
+                .iter()
+                .map(RuleDocument::serialized_len)
+                .sum::<usize>()
+            + self
+                .category_admins
+                .iter()
+                .map(CategoryAdmin::serialized_len)
+                .sum::<usize>()
+            + self.name.len()
+    }
+
+    /// Account size once the admin of the given category is set (Some) or removed (None)
+    pub fn account_size_after_category_admin(
+        &self,
+        category: &str,
+        admin: Option<Pubkey>,
+    ) -> Result<usize> {
+        let category = RuleCategory::canonical(category);
+        let current_len = self
+            .category_admins
+            .iter()
+            .find(|entry| entry.category == category)
+            .map_or(0, CategoryAdmin::serialized_len);
+        let new_len = admin.map_or(0, |_| CategoryAdmin::serialized_len_of(&category));
+        Self::account_size_for(self.serialized_len() - current_len + new_len)
+    }
+
+    /// Admin delegated to the given category
+    pub fn category_admin(&self, category: &str) -> Option<Pubkey> {
+        let category = RuleCategory::canonical(category);
+        self.category_admins
+            .iter()
+            .find(|entry| entry.category == category)
+            .map(|entry| entry.admin)
+    }
+
+    /// Check if the signer may manage documents of the given category
+    /// The rule admin manages every category; a category admin only their own
+    pub fn can_manage_category(
+        &self,
+        category: &str,
+        signer: &Pubkey,
+        rule_admin: &Pubkey,
+    ) -> bool {
+        signer == rule_admin || self.category_admin(category).as_ref() == Some(signer)
+    }
+
+    /// Check if the signer may manage the document with the given ID
+    /// Unknown IDs are left to the rule admin (the handler rejects them)
+    pub fn can_manage_document(
+        &self,
+        document_id: u32,
+        signer: &Pubkey,
+        rule_admin: &Pubkey,
+    ) -> bool {
+        match self.document(document_id) {
+            Some(document) => self.can_manage_category(&document.category, signer, rule_admin),
+            None => signer == rule_admin,
+        }
+    }
+
+    /// Set (Some) or remove (None) the admin delegated to a category
+    /// Known categories are stored by their canonical string
+    pub fn set_category_admin(&mut self, category: &str, admin: Option<Pubkey>) -> Result<()> {
+        let category = RuleCategory::canonical(category);
+        require!(
+            !category.is_empty() && category.len() <= MAX_CATEGORY_LENGTH,
+            crate::error::GovernanceError::InvalidCategoryLength
+        );
+        let position = self
+            .category_admins
+            .iter()
+            .position(|entry| entry.category == category);
+
+        match (position, admin) {
+            (Some(index), Some(admin)) => self.category_admins[index].admin = admin,
+            (Some(index), None) => {
+                self.category_admins.remove(index);
+            }
+            (None, Some(admin)) => {
+                require!(
+                    self.category_admins.len() < MAX_CATEGORY_ADMINS,
+                    crate::error::GovernanceError::TooManyCategoryAdmins
+                );
+                self.category_admins.push(CategoryAdmin { category, admin });
+            }
+            (None, None) => return err!(crate::error::GovernanceError::CategoryAdminNotFound),
+        }
+
+        self.version += 1;
+        self.last_updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Account size for registry data of the given serialized length
+    /// Leaves one document (without history) of headroom, capped at the maximum registry size
+    pub fn account_size_for(data_len: usize) -> Result<usize> {
+        let max_size = 8 + Self::INIT_SPACE;
+        let size = 8 + data_len;
+        require!(
+            size <= max_size,
+            crate::error::GovernanceError::TooManyRuleDocuments
+        );
+        Ok((size + RuleDocument::CONTENT_SPACE).min(max_size))
+    }
+
+    /// Account size once a document with the given fields is added
+    pub fn account_size_after_add(
+        &self,
+        category: &str,
+        title: &str,
+        url: &str,
+        hash: &str,
+    ) -> Result<usize> {
+        let document_len =
+            RuleDocument::serialized_len_of(&RuleCategory::canonical(category), title, url, hash);
+        Self::account_size_for(self.serialized_len() + document_len)
+    }
+
+    /// Account size once the given batch of documents is added
+    pub fn account_size_after_batch(&self, documents: &[RuleDocumentInput]) -> Result<usize> {
+        let batch_len = documents
+            .iter()
+            .map(|input| {
+                RuleDocument::serialized_len_of(
+                    &RuleCategory::canonical(&input.category),
+                    &input.title,
+                    &input.url,
+                    &input.hash,
+                )
+            })
+            .sum::<usize>();
+        Self::account_size_for(self.serialized_len() + batch_len)
+    }
+
+    /// Account size once the document with the given ID is updated
+    /// An unknown ID sizes for the current content (the handler rejects it)
+    pub fn account_size_after_update(
+        &self,
+        document_id: u32,
+        new_url: Option<&str>,
+        new_hash: Option<&str>,
+    ) -> Result<usize> {
+        let data_len = match self.document(document_id) {
+            Some(document) => {
+                self.serialized_len() - document.serialized_len()
+                    + document.serialized_len_updated(new_url, new_hash)
+            }
+            None => self.serialized_len(),
+        };
+        Self::account_size_for(data_len)
+    }
+
+    /// Account size once the document with the given ID is removed
+    /// An unknown ID sizes for the current content (the handler rejects it)
+    pub fn account_size_after_remove(&self, document_id: u32) -> Result<usize> {
+        let data_len = match self.document(document_id) {
+            Some(document) => self.serialized_len() - document.serialized_len(),
+            None => self.serialized_len(),
+        };
+        Self::account_size_for(data_len)
+    }
+
+    /// Document with the given ID
+    pub fn document(&self, document_id: u32) -> Option<&RuleDocument> {
+        self.rule_documents.iter().find(|doc| doc.id == document_id)
+    }
+
+    /// Position of the document with the given ID in the document list
+    fn document_position(&self, document_id: u32) -> Result<usize> {
+        let index = self
+            .rule_documents
+            .iter()
+            .position(|doc| doc.id == document_id)
+            .ok_or(crate::error::GovernanceError::RuleDocumentNotFound)?;
+        Ok(index)
+    }
+
+    /// Add rule document, assigning it the next document ID
+    pub fn add_document(&mut self, document: RuleDocument) -> Result<u32> {
+        let document_id = self.insert_document(document)?;
+        self.version += 1;
+        self.last_updated = Clock::get()?.unix_timestamp;
+        Ok(document_id)
+    }
+
+    /// Add several rule documents as one registry version, assigning consecutive IDs
+    /// Returns the first assigned ID
+    pub fn add_documents(&mut self, documents: Vec<RuleDocument>) -> Result<u32> {
+        let first_document_id = self.next_document_id;
+        for document in documents {
+            self.insert_document(document)?;
+        }
+        self.version += 1;
+        self.last_updated = Clock::get()?.unix_timestamp;
+        Ok(first_document_id)
+    }
+
+    /// Append a document after the limit and duplicate checks, without bumping the version
+    fn insert_document(&mut self, mut document: RuleDocument) -> Result<u32> {
+        require!(
+            self.rule_documents.len() < MAX_RULE_DOCUMENTS as usize,
             crate::error::GovernanceError::TooManyRuleDocuments
         );
 
@@ -36,29 +278,31 @@ impl RuleRegistry {
             );
         }
 
+        document.id = self.next_document_id;
+        self.next_document_id = self
+            .next_document_id
+            .checked_add(1)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        let document_id = document.id;
         self.rule_documents.push(document);
-        self.version += 1;
-        self.last_updated = Clock::get()?.unix_timestamp;
-        Ok(())
+        Ok(document_id)
     }
 
     /// Update rule document
     pub fn update_document(
         &mut self,
-        index: usize,
+        document_id: u32,
         new_url: Option<String>,
         new_hash: Option<String>,
+        new_effective_from: Option<i64>,
     ) -> Result<()> {
-        require!(
-            index < self.rule_documents.len(),
-            crate::error::GovernanceError::RuleDocumentNotFound
-        );
-
+        let index = self.document_position(document_id)?;
         let document = &mut self.rule_documents[index];
+        document.record_revision();
 
         if let Some(url) = new_url {
             require!(
-                url.len() <= MAX_URL_LENGTH,
+                url.len() <= MAX_URL_LENGTH as usize,
                 crate::error::GovernanceError::InvalidUrlLength
             );
             document.url = url;
@@ -66,12 +310,16 @@ impl RuleRegistry {
 
         if let Some(hash) = new_hash {
             require!(
-                hash.len() <= MAX_HASH_LENGTH,
+                hash.len() <= MAX_HASH_LENGTH as usize,
                 crate::error::GovernanceError::InvalidHashLength
             );
             document.hash = hash;
         }
 
+        if let Some(effective_from) = new_effective_from {
+            document.effective_from = effective_from;
+        }
+
         document.updated_at = Clock::get()?.unix_timestamp;
         self.version += 1;
         self.last_updated = Clock::get()?.unix_timestamp;
@@ -79,29 +327,54 @@ impl RuleRegistry {
     }
 
     /// Remove rule document
-    pub fn remove_document(&mut self, index: usize) -> Result<()> {
-        require!(
-            index < self.rule_documents.len(),
-            crate::error::GovernanceError::RuleDocumentNotFound
-        );
-
+    pub fn remove_document(&mut self, document_id: u32) -> Result<()> {
+        let index = self.document_position(document_id)?;
         self.rule_documents.remove(index);
         self.version += 1;
         self.last_updated = Clock::get()?.unix_timestamp;
         Ok(())
     }
 
-    /// Find documents by category
-    pub fn find_documents_by_category(&self, category: &str) -> Vec<&RuleDocument> {
+    /// Find documents by category, optionally only those in force at the given time
+    pub fn find_documents_by_category(
+        &self,
+        category: &str,
+        only_effective: bool,
+        now: i64,
+    ) -> Vec<&RuleDocument> {
         self.rule_documents
             .iter()
-            .filter(|doc| doc.category == category)
+            .filter(|doc| RuleCategory::matches(&doc.category, category))
+            .filter(|doc| !only_effective || doc.is_effective(now))
             .collect()
     }
 
+    /// Page of documents, optionally filtered by category and effectiveness
+    /// Returns the number of documents matching the filter and the requested slice of them
+    pub fn documents_page(
+        &self,
+        category: Option<&str>,
+        only_effective: bool,
+        now: i64,
+        offset: usize,
+        limit: usize,
+    ) -> (u32, Vec<RuleDocument>) {
+        let matching = self
+            .rule_documents
+            .iter()
+            .filter(|doc| match category {
+                Some(category) => RuleCategory::matches(&doc.category, category),
+                None => true,
+            })
+            .filter(|doc| !only_effective || doc.is_effective(now));
+        let total = matching.clone().count() as u32;
+        let page = matching.skip(offset).take(limit).cloned().collect();
+        (total, page)
+    }
+
     /// Verify document hash
-    pub fn verify_document_hash(&self, index: usize, expected_hash: &str) -> bool {
-        if let Some(document) = self.rule_documents.get(index) {
+    pub fn verify_document_hash(&self, document_id: u32, expected_hash: &str) -> bool {
+        if let Some(document) = self.document(document_id) {
             document.hash == expected_hash
         } else {
             false
@@ -112,6 +385,8 @@ impl RuleRegistry {
 /// Rule document structure
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub struct RuleDocument {
+    /// Stable document ID assigned by the registry
+    pub id: u32,
     /// Rule category
     #[max_len(50)]
     pub category: String,
@@ -128,11 +403,73 @@ pub struct RuleDocument {
     pub created_at: i64,
     /// Update time
     pub updated_at: i64,
+    /// Time the document comes into force
+    pub effective_from: i64,
+    /// Versions replaced by the last MAX_DOCUMENT_HISTORY updates, oldest first
+    #[max_len(5)]
+    pub history: Vec<RuleDocumentRevision>,
 }
 
 impl RuleDocument {
-    /// Create new rule document
-    pub fn new(category: String, title: String, url: String, hash: String) -> Result<Self> {
+    /// Maximum space of a document's own fields, excluding its history
+    pub const CONTENT_SPACE: usize =
+        Self::INIT_SPACE - MAX_DOCUMENT_HISTORY * RuleDocumentRevision::INIT_SPACE;
+
+    /// Serialized length of a new document (empty history) with the given fields
+    pub fn serialized_len_of(category: &str, title: &str, url: &str, hash: &str) -> usize {
+        4 + 4 + category.len() + 4 + title.len() + 4 + url.len() + 4 + hash.len() + 8 + 8 + 8 + 4
+    }
+
+    /// Serialized length of the document
+    pub fn serialized_len(&self) -> usize {
+        Self::serialized_len_of(&self.category, &self.title, &self.url, &self.hash)
+            + self
+                .history
+                .iter()
+                .map(RuleDocumentRevision::serialized_len)
+                .sum::<usize>()
+    }
+
+    /// Serialized length of the document after an update of its URL and/or hash
+    /// The update also moves the current URL and hash into the history
+    pub fn serialized_len_updated(&self, new_url: Option<&str>, new_hash: Option<&str>) -> usize {
+        let evicted = (self.history.len() + 1).saturating_sub(MAX_DOCUMENT_HISTORY);
+        let history_len = self
+            .history
+            .iter()
+            .skip(evicted)
+            .map(RuleDocumentRevision::serialized_len)
+            .sum::<usize>()
+            + RuleDocumentRevision::serialized_len_of(&self.url, &self.hash);
+        Self::serialized_len_of(
+            &self.category,
+            &self.title,
+            new_url.unwrap_or(&self.url),
+            new_hash.unwrap_or(&self.hash),
+        ) + history_len
+    }
+
+    /// Move the current URL and hash into the history, dropping the oldest entry when full
+    pub fn record_revision(&mut self) {
+        self.history.push(RuleDocumentRevision {
+            hash: self.hash.clone(),
+            url: self.url.clone(),
+            updated_at: self.updated_at,
+        });
+        if self.history.len() > MAX_DOCUMENT_HISTORY {
+            self.history.remove(0);
+        }
+    }
+
+    /// Create new rule document (the registry assigns its ID when it is added)
+    /// The document is in force from effective_from, or immediately when None
+    pub fn new(
+        category: String,
+        title: String,
+        url: String,
+        hash: String,
+        effective_from: Option<i64>,
+    ) -> Result<Self> {
         require!(
             category.len() <= MAX_CATEGORY_LENGTH,
             crate::error::GovernanceError::InvalidCategoryLength
@@ -142,25 +479,45 @@ impl RuleDocument {
             crate::error::GovernanceError::InvalidTitleLength
         );
         require!(
-            url.len() <= MAX_URL_LENGTH,
+            url.len() <= MAX_URL_LENGTH as usize,
             crate::error::GovernanceError::InvalidUrlLength
         );
         require!(
-            hash.len() <= MAX_HASH_LENGTH,
+            hash.len() <= MAX_HASH_LENGTH as usize,
             crate::error::GovernanceError::InvalidHashLength
         );
 
         let now = Clock::get()?.unix_timestamp;
         Ok(Self {
+            id: 0,
             category,
             title,
             url,
             hash,
             created_at: now,
             updated_at: now,
+            effective_from: effective_from.unwrap_or(now),
+            history: Vec::new(),
         })
     }
 
+    /// Check an effective date against the scheduling horizon (max_delay seconds from now)
+    pub fn require_effective_within(effective_from: i64, now: i64, max_delay: i64) -> Result<()> {
+        let horizon = now
+            .checked_add(max_delay)
+            .ok_or(crate::error::GovernanceError::ArithmeticOverflow)?;
+        require!(
+            effective_from <= horizon,
+            crate::error::GovernanceError::InvalidEffectiveDate
+        );
+        Ok(())
+    }
+
+    /// Check if the document is in force at the given time
+    pub fn is_effective(&self, now: i64) -> bool {
+        now >= self.effective_from
+    }
+
     /// Validate URL format
     pub fn validate_url(&self) -> bool {
         // Simple URL format validation
@@ -176,6 +533,70 @@ impl RuleDocument {
     }
 }
 
+/// Earlier version of a rule document, kept in its history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct RuleDocumentRevision {
+    /// Document hash of the version
+    #[max_len(64)]
+    pub hash: String,
+    /// Document URL of the version
+    #[max_len(500)]
+    pub url: String,
+    /// Time the version was set
+    pub updated_at: i64,
+}
+
+impl RuleDocumentRevision {
+    /// Serialized length of a revision with the given URL and hash
+    pub fn serialized_len_of(url: &str, hash: &str) -> usize {
+        4 + hash.len() + 4 + url.len() + 8
+    }
+
+    /// Serialized length of the revision
+    pub fn serialized_len(&self) -> usize {
+        Self::serialized_len_of(&self.url, &self.hash)
+    }
+}
+
+/// Admin delegated to manage one rule category
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct CategoryAdmin {
+    /// Managed category
+    #[max_len(50)]
+    pub category: String,
+    /// Delegated admin address
+    pub admin: Pubkey,
+}
+
+impl CategoryAdmin {
+    /// Serialized length of an entry for the given category
+    pub fn serialized_len_of(category: &str) -> usize {
+        4 + category.len() + 32
+    }
+
+    /// Serialized length of the entry
+    pub fn serialized_len(&self) -> usize {
+        Self::serialized_len_of(&self.category)
+    }
+}
+
+/// Rule document fields supplied to add_rule_documents_batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct RuleDocumentInput {
+    /// Rule category
+    pub category: String,
+    /// Rule title
+    pub title: String,
+    /// IPFS/Arweave URL
+    pub url: String,
+    /// Document hash
+    pub hash: String,
+    /// Time the document comes into force (None: immediately)
+    pub effective_from: Option<i64>,
+    /// Accept a category outside the known set (otherwise rejected as UnknownRuleCategory)
+    pub allow_custom_category: bool,
+}
+
 /// Rule category enumeration
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub enum RuleCategory {
@@ -193,36 +614,75 @@ pub enum RuleCategory {
     Other(String),
 }
 
-impl RuleCategory {
-    pub fn to_string(&self) -> String {
+impl core::fmt::Display for RuleCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            RuleCategory::ProductStandards => "product_standards".to_string(),
-            RuleCategory::TradingRules => "trading_rules".to_string(),
-            RuleCategory::ViolationDefinitions => "violation_definitions".to_string(),
-            RuleCategory::DisputeResolution => "dispute_resolution".to_string(),
-            RuleCategory::PlatformPolicies => "platform_policies".to_string(),
-            RuleCategory::Other(s) => s.clone(),
+            RuleCategory::ProductStandards => f.write_str("product_standards"),
+            RuleCategory::TradingRules => f.write_str("trading_rules"),
+            RuleCategory::ViolationDefinitions => f.write_str("violation_definitions"),
+            RuleCategory::DisputeResolution => f.write_str("dispute_resolution"),
+            RuleCategory::PlatformPolicies => f.write_str("platform_policies"),
+            RuleCategory::Other(s) => f.write_str(s),
         }
     }
+}
 
+impl RuleCategory {
+    /// Parse a category, ignoring case and '_', '-' and ' ' separators for known categories
     pub fn from_string(s: &str) -> Self {
-        match s {
-            "product_standards" => RuleCategory::ProductStandards,
-            "trading_rules" => RuleCategory::TradingRules,
-            "violation_definitions" => RuleCategory::ViolationDefinitions,
-            "dispute_resolution" => RuleCategory::DisputeResolution,
-            "platform_policies" => RuleCategory::PlatformPolicies,
+        let key: String = s
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-' | ' '))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        match key.as_str() {
+            "productstandards" => RuleCategory::ProductStandards,
+            "tradingrules" => RuleCategory::TradingRules,
+            "violationdefinitions" => RuleCategory::ViolationDefinitions,
+            "disputeresolution" => RuleCategory::DisputeResolution,
+            "platformpolicies" => RuleCategory::PlatformPolicies,
             _ => RuleCategory::Other(s.to_string()),
         }
     }
+
+    /// Canonical string of a category: known categories normalized, others unchanged
+    pub fn canonical(s: &str) -> String {
+        Self::from_string(s).to_string()
+    }
+
+    /// Category string to store for a new document
+    /// Categories outside the known set are only accepted when explicitly allowed
+    pub fn validate(s: &str, allow_custom: bool) -> Result<String> {
+        match Self::from_string(s) {
+            RuleCategory::Other(custom) => {
+                require!(
+                    allow_custom,
+                    crate::error::GovernanceError::UnknownRuleCategory
+                );
+                Ok(custom)
+            }
+            known => Ok(known.to_string()),
+        }
+    }
+
+    /// Check if a stored category matches a queried one (case-insensitive, known
+    /// categories matched by their canonical string)
+    pub fn matches(stored: &str, query: &str) -> bool {
+        stored.eq_ignore_ascii_case(&Self::canonical(query))
+    }
 }
 
 /// Rule management constants
-pub const MAX_RULE_DOCUMENTS: usize = 100;
+pub const MAX_RULE_DOCUMENT_BATCH: usize = 10;
+pub const MAX_CATEGORY_ADMINS: usize = 10;
+pub const MAX_DOCUMENT_HISTORY: usize = 5;
 pub const MAX_CATEGORY_LENGTH: usize = 50;
 pub const MAX_TITLE_LENGTH: usize = 200;
-pub const MAX_URL_LENGTH: usize = 500;
-pub const MAX_HASH_LENGTH: usize = 64;
+#[constant]
+pub const MAX_URL_LENGTH: u32 = 500;
+#[constant]
+pub const MAX_HASH_LENGTH: u32 = 64;
+pub const MAX_RULE_REGISTRY_NAME_LENGTH: usize = 32;
 
 /// Predefined rule categories
 pub mod rule_categories {
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use super::proposal::{Proposal, ProposalStatus, ProposalType};
+
+/// Number of ProposalType variants (length of GovernanceStats::proposals_created)
+pub const PROPOSAL_TYPE_COUNT: usize = 6;
+
+const _: () = assert!(ProposalType::ProgramUpgrade as usize + 1 == PROPOSAL_TYPE_COUNT);
+
+/// Lifetime governance totals, updated by the proposal and vote handlers
+/// Counters saturate instead of failing, so updating them never aborts the proposal flow
+/// Amounts are raw units summed across deposit mints
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceStats {
+    /// Proposals created, indexed by ProposalType variant order
+    /// (length must equal PROPOSAL_TYPE_COUNT)
+    pub proposals_created: [u64; 6],
+    /// Proposals finalized as Passed
+    pub proposals_passed: u64,
+    /// Proposals finalized as Rejected
+    pub proposals_rejected: u64,
+    /// Proposals finalized as Vetoed
+    pub proposals_vetoed: u64,
+    /// Proposals executed
+    pub proposals_executed: u64,
+    /// Deposits collected at proposal creation
+    pub total_deposits: u64,
+    /// Deposit parts kept as committee fee at settlement
+    pub total_fees: u64,
+    /// Deposit parts confiscated from vetoed proposals
+    pub total_confiscated: u64,
+    /// Deposit parts refunded at settlement (including refunds left claimable)
+    pub total_refunds: u64,
+    /// Votes cast
+    pub votes_cast: u64,
+    /// Last update time
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl GovernanceStats {
+    /// Load the stats from an account, if it has been initialized
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        let stats = Self::try_deserialize(&mut account.data.borrow().as_ref())
+            .map_err(|_| crate::error::GovernanceError::InvalidAccountData)?;
+        Ok(Some(stats))
+    }
+
+    /// Write the stats back to their account
+    pub fn store(&self, account: &AccountInfo) -> Result<()> {
+        let mut data = account.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+
+    /// Count a new proposal and its deposit
+    pub fn record_proposal_created(&mut self, proposal_type: &ProposalType, deposit: u64) {
+        let index = proposal_type.clone() as usize;
+        self.proposals_created[index] = self.proposals_created[index].saturating_add(1);
+        self.total_deposits = self.total_deposits.saturating_add(deposit);
+    }
+
+    /// Count a cast vote
+    pub fn record_vote(&mut self) {
+        self.votes_cast = self.votes_cast.saturating_add(1);
+    }
+
+    /// Count a finalization; deposit amounts only when `settled` (settled by this call)
+    pub fn record_finalized(&mut self, proposal: &Proposal, settled: bool) {
+        match proposal.status() {
+            Ok(ProposalStatus::Passed) => {
+                self.proposals_passed = self.proposals_passed.saturating_add(1)
+            }
+            Ok(ProposalStatus::Rejected) => {
+                self.proposals_rejected = self.proposals_rejected.saturating_add(1)
+            }
+            Ok(ProposalStatus::Vetoed) => {
+                self.proposals_vetoed = self.proposals_vetoed.saturating_add(1)
+            }
+            _ => {}
+        }
+        if settled {
+            self.total_fees = self.total_fees.saturating_add(proposal.deposit_fee);
+            self.total_confiscated = self
+                .total_confiscated
+                .saturating_add(proposal.deposit_confiscated);
+            self.total_refunds = self.total_refunds.saturating_add(proposal.deposit_refunded);
+        }
+    }
+
+    /// Count an execution
+    pub fn record_executed(&mut self) {
+        self.proposals_executed = self.proposals_executed.saturating_add(1);
+    }
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::instructions::common::MAX_PROPOSAL_OPTIONS;
+use crate::state::governance::RoundingMode;
+
 /// Vote record account
 #[account]
 #[derive(InitSpace)]
@@ -29,13 +32,14 @@ impl Vote {
         voter: Pubkey,
         vote_type: VoteType,
         token_balance_snapshot: u64,
+        timestamp: i64,
         bump: u8,
     ) -> Self {
         Self {
             proposal_id,
             voter,
             vote_type,
-            timestamp: Clock::get().unwrap().unix_timestamp,
+            timestamp,
             token_balance_snapshot,
             is_revoked: false,
             revoked_at: None,
@@ -92,42 +96,42 @@ pub struct VoteStats {
 
 impl VoteStats {
     /// Calculate participation rate
-    pub fn calculate_participation_rate(&self, total_voting_power: u64) -> u16 {
-        if total_voting_power == 0 {
-            return 0;
-        }
-        ((self.total_votes * 10000) / total_voting_power) as u16
+    pub fn calculate_participation_rate(
+        &self,
+        total_voting_power: u64,
+        rounding: RoundingMode,
+    ) -> u16 {
+        rounding.rate_bps(self.total_votes, total_voting_power)
     }
 
     /// Calculate approval rate
-    pub fn calculate_approval_rate(&self) -> u16 {
-        if self.total_votes == 0 {
-            return 0;
-        }
-        ((self.yes_votes * 10000) / self.total_votes) as u16
+    pub fn calculate_approval_rate(&self, rounding: RoundingMode) -> u16 {
+        rounding.rate_bps(self.yes_votes, self.total_votes)
     }
 
     /// Calculate veto rate
-    pub fn calculate_veto_rate(&self) -> u16 {
-        if self.total_votes == 0 {
-            return 0;
-        }
-        ((self.veto_votes * 10000) / self.total_votes) as u16
+    pub fn calculate_veto_rate(&self, rounding: RoundingMode) -> u16 {
+        rounding.rate_bps(self.veto_votes, self.total_votes)
     }
 
     /// Check if participation threshold is met
-    pub fn meets_participation_threshold(&self, total_voting_power: u64, threshold: u16) -> bool {
-        self.calculate_participation_rate(total_voting_power) >= threshold
+    pub fn meets_participation_threshold(
+        &self,
+        total_voting_power: u64,
+        threshold: u16,
+        rounding: RoundingMode,
+    ) -> bool {
+        self.calculate_participation_rate(total_voting_power, rounding) >= threshold
     }
 
     /// Check if proposal passes (strictly greater than threshold, equal to threshold is considered not passed)
-    pub fn is_approved(&self, threshold: u16) -> bool {
-        self.calculate_approval_rate() > threshold
+    pub fn is_approved(&self, threshold: u16, rounding: RoundingMode) -> bool {
+        self.calculate_approval_rate(rounding) > threshold
     }
 
     /// Check if proposal is vetoed
-    pub fn is_vetoed(&self, threshold: u16) -> bool {
-        self.calculate_veto_rate() >= threshold
+    pub fn is_vetoed(&self, threshold: u16, rounding: RoundingMode) -> bool {
+        self.calculate_veto_rate(rounding) >= threshold
     }
 
     /// Comprehensively determine the final status of the proposal
@@ -138,21 +142,26 @@ impl VoteStats {
         participation_threshold: u16,
         approval_threshold: u16,
         veto_threshold: u16,
+        rounding: RoundingMode,
     ) -> crate::state::proposal::ProposalStatus {
         use crate::state::proposal::ProposalStatus;
 
         // 1. First check if vetoed (highest priority)
-        if self.is_vetoed(veto_threshold) {
+        if self.is_vetoed(veto_threshold, rounding) {
             return ProposalStatus::Vetoed;
         }
 
         // 2. Check if participation rate meets requirements
-        if !self.meets_participation_threshold(total_voting_power, participation_threshold) {
+        if !self.meets_participation_threshold(
+            total_voting_power,
+            participation_threshold,
+            rounding,
+        ) {
             return ProposalStatus::Rejected;
         }
 
         // 3. Check if proposal passes
-        if self.is_approved(approval_threshold) {
+        if self.is_approved(approval_threshold, rounding) {
             ProposalStatus::Passed
         } else {
             ProposalStatus::Rejected
@@ -160,6 +169,77 @@ impl VoteStats {
     }
 }
 
+/// Voting power tallied per vote type for one proposal
+#[derive(Clone, Debug, Default)]
+pub struct VoteTally {
+    /// Yes votes
+    pub yes_votes: u64,
+    /// No votes
+    pub no_votes: u64,
+    /// Abstain votes
+    pub abstain_votes: u64,
+    /// Veto votes
+    pub veto_votes: u64,
+    /// Votes per option (multi-choice proposals)
+    pub options_tally: [u64; MAX_PROPOSAL_OPTIONS as usize],
+}
+
+impl VoteTally {
+    /// Add a vote's voting power (choice indexes are checked when the vote is cast)
+    pub fn add(&mut self, vote_type: &VoteType, voting_power: u64) {
+        match vote_type {
+            VoteType::Yes => self.yes_votes += voting_power,
+            VoteType::No => self.no_votes += voting_power,
+            VoteType::Abstain => self.abstain_votes += voting_power,
+            VoteType::NoWithVeto => self.veto_votes += voting_power,
+            VoteType::Choice(index) => {
+                if let Some(option_votes) = self.options_tally.get_mut(*index as usize) {
+                    *option_votes += voting_power;
+                }
+            }
+        }
+    }
+
+    /// Votes cast on all options
+    pub fn option_votes(&self) -> u64 {
+        self.options_tally.iter().sum()
+    }
+
+    /// Option with the most votes (None without option votes or on a tie for the lead)
+    pub fn leading_option(&self) -> Option<u8> {
+        let most_votes = *self.options_tally.iter().max()?;
+        let mut leaders = self
+            .options_tally
+            .iter()
+            .enumerate()
+            .filter(|(_, &votes)| votes == most_votes);
+        match (leaders.next(), leaders.next()) {
+            (Some((index, _)), None) if most_votes > 0 => Some(index as u8),
+            _ => None,
+        }
+    }
+
+    /// Vote statistics the proposal status is determined from
+    /// Binary proposals have no option votes; on multi-choice proposals the leading option
+    /// counts as approval and the other options as rejection, so approval is the leading
+    /// option's share of all votes
+    pub fn vote_stats(&self) -> VoteStats {
+        let leading_votes = self
+            .leading_option()
+            .map_or(0, |index| self.options_tally[index as usize]);
+        let yes_votes = self.yes_votes + leading_votes;
+        let no_votes = self.no_votes + (self.option_votes() - leading_votes);
+        VoteStats {
+            total_votes: yes_votes + no_votes + self.abstain_votes + self.veto_votes,
+            yes_votes,
+            no_votes,
+            abstain_votes: self.abstain_votes,
+            veto_votes: self.veto_votes,
+            voter_count: 0,
+        }
+    }
+}
+
 /// Voting power calculator
 pub struct VotingPowerCalculator;
 
@@ -191,15 +271,23 @@ impl VotingPowerCalculator {
     /// Verify committee member identity and calculate voting power
     pub fn calculate_committee_voting_power(
         member: &Pubkey,
-        governance_config: &crate::state::GovernanceConfig,
+        committee_member: &crate::state::CommitteeMember,
         token_balance: u64,
         token_decimals: u8,
     ) -> Result<u64> {
         // Verify if is committee member
         require!(
-            governance_config.is_committee_member(member),
+            committee_member.member == *member,
             crate::error::GovernanceError::NotCommitteeMember
         );
+        require!(
+            committee_member.active,
+            crate::error::GovernanceError::MemberSuspended
+        );
+        require!(
+            !committee_member.is_term_expired(Clock::get()?.unix_timestamp),
+            crate::error::GovernanceError::MemberTermExpired
+        );
 
         // Calculate voting power
         let voting_power = Self::calculate_voting_power(token_balance, token_decimals);
@@ -253,3 +341,229 @@ pub mod vote_constants {
     /// Maximum delegation count
     pub const MAX_DELEGATIONS_PER_USER: usize = 10;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::proposal::ProposalStatus;
+    use proptest::prelude::*;
+
+    const PARTICIPATION: u16 = 5000;
+    const APPROVAL: u16 = 5000;
+    const VETO: u16 = 3334;
+
+    fn stats(yes_votes: u64, no_votes: u64, abstain_votes: u64, veto_votes: u64) -> VoteStats {
+        VoteTally {
+            yes_votes,
+            no_votes,
+            abstain_votes,
+            veto_votes,
+            ..Default::default()
+        }
+        .vote_stats()
+    }
+
+    fn status(vote_stats: &VoteStats, total_voting_power: u64) -> ProposalStatus {
+        rounded_status(vote_stats, total_voting_power, RoundingMode::Floor)
+    }
+
+    fn rounded_status(
+        vote_stats: &VoteStats,
+        total_voting_power: u64,
+        rounding: RoundingMode,
+    ) -> ProposalStatus {
+        vote_stats.determine_proposal_status(
+            total_voting_power,
+            PARTICIPATION,
+            APPROVAL,
+            VETO,
+            rounding,
+        )
+    }
+
+    #[test]
+    fn approval_at_threshold_is_rejected() {
+        assert_eq!(status(&stats(50, 50, 0, 0), 100), ProposalStatus::Rejected);
+        assert_eq!(
+            status(&stats(5001, 4999, 0, 0), 10000),
+            ProposalStatus::Passed
+        );
+    }
+
+    #[test]
+    fn veto_at_threshold_is_vetoed() {
+        assert_eq!(
+            status(&stats(6666, 0, 0, 3334), 10000),
+            ProposalStatus::Vetoed
+        );
+        assert_eq!(
+            status(&stats(6667, 0, 0, 3333), 10000),
+            ProposalStatus::Passed
+        );
+    }
+
+    #[test]
+    fn participation_at_threshold_is_met() {
+        assert_eq!(status(&stats(50, 0, 0, 0), 100), ProposalStatus::Passed);
+        assert_eq!(
+            status(&stats(4999, 0, 0, 0), 10000),
+            ProposalStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn abstentions_count_toward_participation_not_approval() {
+        assert_eq!(status(&stats(10, 0, 40, 0), 100), ProposalStatus::Rejected);
+        assert_eq!(status(&stats(26, 0, 24, 0), 100), ProposalStatus::Passed);
+    }
+
+    #[test]
+    fn zero_voting_power_never_passes() {
+        assert_eq!(status(&stats(0, 0, 0, 0), 0), ProposalStatus::Rejected);
+        assert_eq!(status(&stats(10, 0, 0, 0), 0), ProposalStatus::Rejected);
+    }
+
+    #[test]
+    fn leading_option_counts_as_approval() {
+        let vote_stats = VoteTally {
+            options_tally: [30, 20, 10, 0, 0],
+            ..Default::default()
+        }
+        .vote_stats();
+        assert_eq!(vote_stats.yes_votes, 30);
+        assert_eq!(vote_stats.no_votes, 30);
+        assert_eq!(status(&vote_stats, 100), ProposalStatus::Rejected);
+    }
+
+    #[test]
+    fn rates_round_by_mode() {
+        // (numerator, denominator, floor, half up)
+        for (numerator, denominator, floor, half_up) in [
+            (49994, 100000, 4999, 4999),
+            (49995, 100000, 4999, 5000),
+            (49996, 100000, 4999, 5000),
+            (1, 3, 3333, 3333),
+            (2, 3, 6666, 6667),
+            (5, 5, 10000, 10000),
+            (1, 0, 0, 0),
+        ] {
+            assert_eq!(RoundingMode::Floor.rate_bps(numerator, denominator), floor);
+            assert_eq!(
+                RoundingMode::HalfUp.rate_bps(numerator, denominator),
+                half_up
+            );
+        }
+    }
+
+    #[test]
+    fn rates_do_not_overflow() {
+        for rounding in [RoundingMode::Floor, RoundingMode::HalfUp] {
+            assert_eq!(rounding.rate_bps(u64::MAX, u64::MAX), 10000);
+            assert_eq!(rounding.rate_bps(u64::MAX, 1), u16::MAX);
+        }
+        assert_eq!(RoundingMode::Floor.rate_bps(u64::MAX / 2, u64::MAX), 4999);
+        assert_eq!(RoundingMode::HalfUp.rate_bps(u64::MAX / 2, u64::MAX), 5000);
+    }
+
+    #[test]
+    fn half_up_meets_participation_half_a_point_below() {
+        // 4999.5 bps participation
+        let vote_stats = stats(9999, 0, 0, 0);
+        assert_eq!(
+            rounded_status(&vote_stats, 20000, RoundingMode::Floor),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            rounded_status(&vote_stats, 20000, RoundingMode::HalfUp),
+            ProposalStatus::Passed
+        );
+    }
+
+    #[test]
+    fn half_up_keeps_approval_strictly_above_threshold() {
+        // 5000.6 bps approval passes only when rounded up
+        let vote_stats = stats(50006, 49994, 0, 0);
+        assert_eq!(
+            rounded_status(&vote_stats, 100000, RoundingMode::Floor),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            rounded_status(&vote_stats, 100000, RoundingMode::HalfUp),
+            ProposalStatus::Passed
+        );
+
+        // 4999.6 bps approval reads 5000, which does not exceed the threshold
+        let vote_stats = stats(49996, 50004, 0, 0);
+        assert_eq!(
+            vote_stats.calculate_approval_rate(RoundingMode::HalfUp),
+            5000
+        );
+        assert_eq!(
+            rounded_status(&vote_stats, 100000, RoundingMode::HalfUp),
+            ProposalStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn half_up_vetoes_half_a_point_below() {
+        // 3333.5 bps veto, 6666.5 bps approval
+        let vote_stats = stats(13333, 0, 0, 6667);
+        assert_eq!(
+            rounded_status(&vote_stats, 20000, RoundingMode::Floor),
+            ProposalStatus::Passed
+        );
+        assert_eq!(
+            rounded_status(&vote_stats, 20000, RoundingMode::HalfUp),
+            ProposalStatus::Vetoed
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn more_yes_votes_never_unpass(
+            yes in 0u64..1_000_000,
+            no in 0u64..1_000_000,
+            abstain in 0u64..1_000_000,
+            veto in 0u64..1_000_000,
+            extra_yes in 0u64..1_000_000,
+            spare_power in 0u64..1_000_000,
+        ) {
+            let total_voting_power = yes + no + abstain + veto + extra_yes + spare_power;
+            let before = status(&stats(yes, no, abstain, veto), total_voting_power);
+            let after = status(&stats(yes + extra_yes, no, abstain, veto), total_voting_power);
+            if before == ProposalStatus::Passed {
+                prop_assert_eq!(after, ProposalStatus::Passed);
+            }
+        }
+
+        #[test]
+        fn veto_at_or_above_threshold_always_vetoes(
+            yes in 0u64..1_000_000,
+            no in 0u64..1_000_000,
+            abstain in 0u64..1_000_000,
+            veto in 1u64..1_000_000,
+            total_voting_power in 0u64..10_000_000,
+        ) {
+            let vote_stats = stats(yes, no, abstain, veto);
+            prop_assume!(vote_stats.calculate_veto_rate(RoundingMode::Floor) >= VETO);
+            prop_assert_eq!(status(&vote_stats, total_voting_power), ProposalStatus::Vetoed);
+        }
+
+        #[test]
+        fn passed_meets_every_threshold(
+            yes in 0u64..1_000_000,
+            no in 0u64..1_000_000,
+            abstain in 0u64..1_000_000,
+            veto in 0u64..1_000_000,
+            spare_power in 0u64..1_000_000,
+        ) {
+            let vote_stats = stats(yes, no, abstain, veto);
+            let total_voting_power = vote_stats.total_votes + spare_power;
+            if status(&vote_stats, total_voting_power) == ProposalStatus::Passed {
+                prop_assert!(vote_stats.calculate_participation_rate(total_voting_power, RoundingMode::Floor) >= PARTICIPATION);
+                prop_assert!(vote_stats.calculate_approval_rate(RoundingMode::Floor) > APPROVAL);
+                prop_assert!(vote_stats.calculate_veto_rate(RoundingMode::Floor) < VETO);
+            }
+        }
+    }
+}
@@ -1279,7 +1279,7 @@ async function runBusinessFlow(
     if (!ruleRegistryAccount) {
       // Create rule registry
       const createRegistryTx = await program.methods
-        .createRuleRegistry()
+        .createRuleRegistry("")
         .accounts({
           authority: authority.publicKey,
           systemProgram: SystemProgram.programId,
@@ -2048,13 +2048,13 @@ async function createAndVoteProposal(
     // Create proposal - use correct parameters (support custom deposit)
     // Temporarily use null to indicate default deposit, subsequently can pass custom deposit through parameters
     const createProposalTx = await program.methods
-      .createProposal(
-        scenario.title,
-        scenario.description,
-        scenario.proposalType,
-        null, // executionData - null indicates no execution data
-        null // customDeposit - null indicates use default deposit
-      )
+      .createProposal(proposalId, scenario.title, scenario.description, scenario.proposalType, {
+        executionData: null, // null indicates no execution data
+        customDepositRaw: null, // null indicates use default deposit
+        dependsOn: null,
+        options: null,
+        recusedMembers: null,
+      })
       .accounts({
         proposer: authority.publicKey,
         governanceConfig: governanceConfigPda,
@@ -2321,7 +2321,7 @@ async function createAndVoteProposal(
     });
 
     const finalizeProposalTx = await program.methods
-      .finalizeProposal(proposalId)
+      .finalizeProposal(proposalId, null)
       .accounts({
         proposal: proposalPda,
         governanceConfig: governanceConfigPda,
@@ -2329,7 +2329,13 @@ async function createAndVoteProposal(
         proposerTokenAccount: proposerTokenAccount,
         governanceTokenVault: governanceTokenVaultForFinalize,
         governanceAuthority: governanceAuthority,
+        proposalOutcome: PublicKey.findProgramAddressSync(
+          [Buffer.from("proposal_outcome"), proposalId.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        )[0],
+        payer: authority.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       } as any)
       .remainingAccounts(allRemainingAccounts)
       .preInstructions([computeBudgetIx]) // Add compute unit limit instruction
@@ -2384,7 +2390,7 @@ async function createAndVoteProposal(
 
       try {
         const executeProposalTx = await program.methods
-          .executeProposal(proposalId)
+          .executeProposal(proposalId, null)
           .accounts({
             proposal: proposalPda,
             governanceConfig: governanceConfigPda,
@@ -231,31 +231,24 @@ async function queryVotingPowerOnChain(
 
     console.log(`   • Committee Token Mint: ${committeeTokenMint.toString()}`);
 
-    // Prepare remaining_accounts: committee member token accounts
+    // Prepare remaining_accounts: (CommitteeMember PDA, member token account) pairs,
+    // the same list finalize_proposal takes
     const remainingAccounts = [];
 
-    console.log("🔍 Collecting committee member token accounts...");
-    for (let i = 0; i < governanceConfig.committeeMemberCount; i++) {
-      const memberPubkey = governanceConfig.committeeMembers[i];
-      if (memberPubkey) {
-        try {
-          const tokenAccounts = await connection.getTokenAccountsByOwner(memberPubkey, {
-            mint: committeeTokenMint,
-          });
-
-          for (const tokenAccount of tokenAccounts.value) {
-            remainingAccounts.push({
-              pubkey: tokenAccount.pubkey,
-              isWritable: false,
-              isSigner: false,
-            });
-          }
-          console.log(`   ✅ Member ${i + 1}: Found ${tokenAccounts.value.length} token accounts`);
-        } catch (error) {
-          console.log(`   ❌ Member ${i + 1}: Unable to get token accounts`);
-        }
-      }
+    console.log("🔍 Collecting committee members...");
+    const committeeMembers = await program.account.committeeMember.all();
+    for (const [i, { publicKey, account }] of committeeMembers.entries()) {
+      const tokenAccounts = await connection.getTokenAccountsByOwner(account.member, {
+        mint: committeeTokenMint,
+      });
+      const tokenAccount = tokenAccounts.value[0]?.pubkey ?? account.member;
+      remainingAccounts.push(
+        { pubkey: publicKey, isWritable: false, isSigner: false },
+        { pubkey: tokenAccount, isWritable: false, isSigner: false }
+      );
+      console.log(`   ✅ Member ${i + 1}: ${account.member.toString()}`);
     }
+    const memberAccountCount = remainingAccounts.length;
 
     // Add vote accounts to remaining_accounts
     console.log("📋 Querying vote accounts...");
@@ -294,7 +287,7 @@ async function queryVotingPowerOnChain(
 
       console.log(
         `   Filtered ${
-          remainingAccounts.length - governanceConfig.committeeMemberCount
+          remainingAccounts.length - memberAccountCount
         } related vote accounts`
       );
     } catch (error) {
@@ -306,22 +299,20 @@ async function queryVotingPowerOnChain(
     console.log(`   • Proposal ID: ${proposalId}`);
     console.log(`   • Remaining accounts count: ${remainingAccounts.length}`);
 
-    // Simplified test: don't pass remaining_accounts for now
-    console.log("🧪 Simplified test: not passing remaining_accounts");
-    const simpleRemainingAccounts = [];
-
     console.log("\n🚀 Building query transaction...");
 
     // Use current Provider's wallet as fee payer to ensure account exists on-chain
     const feePayerPubkey = (program.provider as anchor.AnchorProvider).wallet.publicKey;
 
-    // Build query transaction - add Clock sysvar account
+    // Build query transaction
     let tx = await program.methods
       .queryVotingPower(new anchor.BN(proposalId))
       .accounts({
-        clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        proposal: proposalPda,
+        governanceConfig: governanceConfigPda,
+        committeeTokenMint,
       })
-      .remainingAccounts(simpleRemainingAccounts)
+      .remainingAccounts(remainingAccounts)
       .transaction();
 
     // Set fee payer
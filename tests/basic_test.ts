@@ -9,6 +9,7 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { fetchProposal } from "./utils/proposal";
 
 describe("Basic Governance System Test", () => {
   // Configure the client to use the local cluster.
@@ -119,9 +120,12 @@ describe("Basic Governance System Test", () => {
     const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
     expect(governanceConfig.committeeMemberCount).to.equal(1);
 
-    const members = governanceConfig.committeeMembers.filter((member) => member !== null);
-    expect(members).to.have.lengthOf(1);
-    expect(members[0].toString()).to.equal(member1.publicKey.toString());
+    const [committeeMemberPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("committee_member"), member1.publicKey.toBuffer()],
+      program.programId
+    );
+    const committeeMember = await program.account.committeeMember.fetch(committeeMemberPda);
+    expect(committeeMember.member.toString()).to.equal(member1.publicKey.toString());
 
     console.log("✅ Committee member added successfully");
   });
@@ -133,7 +137,7 @@ describe("Basic Governance System Test", () => {
     );
 
     const tx = await program.methods
-      .createRuleRegistry()
+      .createRuleRegistry("")
       .accounts({
         ruleRegistry: ruleRegistryPda,
         governanceConfig: governanceConfigPda,
@@ -171,10 +175,9 @@ describe("Basic Governance System Test", () => {
         tokenVault: governanceTokenVaultPda,
         mint: committeeTokenMint,
         governanceAuthority: governanceAuthorityPda,
-        payer: authority.publicKey,
+        authority: authority.publicKey,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
-        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
       .signers([authority])
       .rpc();
@@ -241,12 +244,19 @@ describe("Basic Governance System Test", () => {
     };
 
     const tx = await program.methods
-      .createProposal(title, description, proposalType, executionData)
+      .createProposal(nextProposalId, title, description, proposalType, {
+        executionData,
+        customDepositRaw: null,
+        dependsOn: null,
+        options: null,
+        recusedMembers: null,
+      })
       .accounts({
         proposal: proposalPda,
         governanceConfig: governanceConfigPda,
         proposer: member1.publicKey,
         proposerTokenAccount: member1TokenAccount,
+        depositMint: committeeTokenMint,
         governanceTokenVault: governanceTokenVaultPda,
         governanceAuthority: governanceAuthorityPda,
         systemProgram: SystemProgram.programId,
@@ -258,7 +268,7 @@ describe("Basic Governance System Test", () => {
     console.log("Create proposal transaction signature:", tx);
 
     // Verify proposal was created
-    const proposal = await program.account.proposal.fetch(proposalPda);
+    const proposal = await fetchProposal(program, proposalPda);
     expect(proposal.id.toString()).to.equal("1"); // First proposal should have ID 1 (counter starts at 0, increments to 1)
     expect(proposal.proposer.toString()).to.equal(member1.publicKey.toString());
     expect(proposal.title).to.equal(title);
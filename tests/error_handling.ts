@@ -1,14 +1,19 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Governance } from "../target/types/governance";
+import { GovernanceCpiCaller } from "../target/types/governance_cpi_caller";
+import { GovernanceMockOrder } from "../target/types/governance_mock_order";
 import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import {
   createMint,
   createAssociatedTokenAccount,
+  getOrCreateAssociatedTokenAccount,
   mintTo,
+  transfer,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { fetchProposal } from "./utils/proposal";
 
 describe("Error Handling and Edge Cases", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -69,7 +74,7 @@ describe("Error Handling and Edge Cases", () => {
     );
 
     [governanceTokenVaultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("governance_token_vault")],
+      [Buffer.from("governance_token_vault"), committeeTokenMint.toBuffer()],
       program.programId
     );
 
@@ -126,6 +131,7 @@ describe("Error Handling and Edge Cases", () => {
       .accounts({
         governanceConfig: governanceConfigPda,
         committeeTokenMint: committeeTokenMint,
+        usdcTokenMint: committeeTokenMint,
         authority: authority.publicKey,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -158,16 +164,97 @@ describe("Error Handling and Edge Cases", () => {
       .accounts({
         tokenVault: governanceTokenVaultPda,
         mint: committeeTokenMint,
+        governanceConfig: governanceConfigPda,
         governanceAuthority: governanceAuthorityPda,
-        payer: authority.publicKey,
+        authority: authority.publicKey,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
-        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
       .signers([authority])
       .rpc();
   });
 
+  const proposalPdaFor = (proposalId: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  // The voters vote yes, then the proposal (proposed by member1) is finalized past its
+  // voting end; returns the voting end
+  const voteAndFinalize = async (proposalId: anchor.BN, voters: [Keypair, PublicKey][]) => {
+    const proposalPda = proposalPdaFor(proposalId);
+    const votePdas: PublicKey[] = [];
+    for (const [member, memberTokenAccount] of voters) {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .castVote(proposalId, { yes: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: votePda,
+          governanceConfig: governanceConfigPda,
+          voter: member.publicKey,
+          voterTokenAccount: memberTokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member])
+        .rpc();
+      votePdas.push(votePda);
+    }
+
+    const votingEnd = (await fetchProposal(program, proposalPda)).votingEnd;
+    await program.methods
+      .finalizeProposal(proposalId, votingEnd.addn(1))
+      .accounts({
+        proposal: proposalPda,
+        governanceConfig: governanceConfigPda,
+        committeeTokenMint: committeeTokenMint,
+        proposerTokenAccount: member1TokenAccount,
+        governanceTokenVault: governanceTokenVaultPda,
+        nativeDepositVault: null,
+        depositEscrow: null,
+        proposer: member1.publicKey,
+        governanceAuthority: governanceAuthorityPda,
+        depositMint: committeeTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        proposalOutcome: PublicKey.findProgramAddressSync(
+          [Buffer.from("proposal_outcome"), proposalId.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        )[0],
+        payer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        ...[
+          [member1, member1TokenAccount],
+          [member2, member2TokenAccount],
+        ].flatMap(([member, tokenAccount]: [Keypair, PublicKey]) => [
+          {
+            pubkey: PublicKey.findProgramAddressSync(
+              [Buffer.from("committee_member"), member.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            isWritable: false,
+            isSigner: false,
+          },
+          { pubkey: tokenAccount, isWritable: false, isSigner: false },
+        ]),
+        ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+      ])
+      .signers([authority])
+      .rpc();
+    return votingEnd;
+  };
+
   describe("Initialization Errors", () => {
     it("Should fail to initialize with invalid participation threshold", async () => {
       const [invalidConfigPda] = PublicKey.findProgramAddressSync(
@@ -267,6 +354,221 @@ describe("Error Handling and Edge Cases", () => {
         expect(error.toString()).to.include("InvalidFeeRate");
       }
     });
+
+    it("Should fail to initialize with approval threshold of 0", async () => {
+      const [invalidConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_config_invalid4")],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeGovernance(
+            new anchor.BN(100 * 10 ** 6),
+            new anchor.BN(60),
+            5000,
+            0, // Any single yes vote would pass
+            3000,
+            250,
+            true
+          )
+          .accounts({
+            governanceConfig: invalidConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed with ApprovalThresholdOutOfRange");
+      } catch (error) {
+        expect(error.toString()).to.include("ApprovalThresholdOutOfRange");
+      }
+    });
+
+    it("Should fail to initialize with approval threshold of 10000", async () => {
+      const [invalidConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_config_invalid5")],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeGovernance(
+            new anchor.BN(100 * 10 ** 6),
+            new anchor.BN(60),
+            5000,
+            10000, // Approval is strict, so nothing could pass
+            3000,
+            250,
+            true
+          )
+          .accounts({
+            governanceConfig: invalidConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed with ApprovalThresholdOutOfRange");
+      } catch (error) {
+        expect(error.toString()).to.include("ApprovalThresholdOutOfRange");
+      }
+    });
+
+    it("Should fail to initialize with veto threshold below the minimum", async () => {
+      const [invalidConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_config_invalid6")],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeGovernance(
+            new anchor.BN(100 * 10 ** 6),
+            new anchor.BN(60),
+            5000,
+            6000,
+            499, // Below 500 (5%)
+            250,
+            true
+          )
+          .accounts({
+            governanceConfig: invalidConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed with VetoThresholdTooLow");
+      } catch (error) {
+        expect(error.toString()).to.include("VetoThresholdTooLow");
+      }
+    });
+
+    it("Should fail to initialize with veto threshold of 0", async () => {
+      const [invalidConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_config_invalid7")],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeGovernance(
+            new anchor.BN(100 * 10 ** 6),
+            new anchor.BN(60),
+            5000,
+            6000,
+            0, // Every proposal would be vetoed
+            250,
+            true
+          )
+          .accounts({
+            governanceConfig: invalidConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed with VetoThresholdTooLow");
+      } catch (error) {
+        expect(error.toString()).to.include("VetoThresholdTooLow");
+      }
+    });
+
+    it("Should fail to initialize production with an ungoverned committee mint", async () => {
+      const [invalidConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_config_invalid8")],
+        program.programId
+      );
+      // Neither authority of this mint is the governance authority PDA
+      const ungovernedMint = await createMint(
+        provider.connection,
+        authority,
+        authority.publicKey,
+        authority.publicKey,
+        9
+      );
+
+      try {
+        await program.methods
+          .initializeGovernance(
+            new anchor.BN(100 * 10 ** 6),
+            new anchor.BN(86400),
+            5000,
+            6000,
+            3000,
+            250,
+            false
+          )
+          .accounts({
+            governanceConfig: invalidConfigPda,
+            committeeTokenMint: ungovernedMint,
+            usdcTokenMint: committeeTokenMint,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed with CommitteeMintNotAllowed");
+      } catch (error) {
+        expect(error.toString()).to.include("CommitteeMintNotAllowed");
+      }
+    });
+
+    it("Should fail to initialize with a deposit mint of more than 12 decimals", async () => {
+      const [invalidConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_config_invalid9")],
+        program.programId
+      );
+      const wideMint = await createMint(
+        provider.connection,
+        authority,
+        authority.publicKey,
+        null,
+        18
+      );
+
+      try {
+        await program.methods
+          .initializeGovernance(
+            new anchor.BN(100),
+            new anchor.BN(60),
+            5000,
+            6000,
+            3000,
+            250,
+            true
+          )
+          .accounts({
+            governanceConfig: invalidConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            usdcTokenMint: wideMint,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed with InvalidMintDecimals");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidMintDecimals");
+      }
+    });
   });
 
   describe("Committee Management Errors", () => {
@@ -320,6 +622,23 @@ describe("Error Handling and Edge Cases", () => {
         expect(error.toString()).to.include("Unauthorized");
       }
     });
+
+    it("Should fail to migrate a member that has no legacy slot", async () => {
+      try {
+        await program.methods
+          .migrateCommitteeMember(member1.publicKey) // Already has a committee member account
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed with MemberNotFound");
+      } catch (error) {
+        expect(error.toString()).to.include("MemberNotFound");
+      }
+    });
   });
 
   describe("Proposal Creation Errors", () => {
@@ -348,7 +667,7 @@ describe("Error Handling and Edge Cases", () => {
         .rpc();
 
       const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
-      const proposalId = governanceConfig.proposalCounter;
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
 
       const [proposalPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
@@ -358,16 +677,24 @@ describe("Error Handling and Edge Cases", () => {
       try {
         await program.methods
           .createProposal(
+            proposalId,
             "Test Proposal with No Tokens",
             "This proposal should fail because the proposer has no tokens for deposit.",
             { configUpdate: {} },
-            null
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
           )
           .accounts({
             proposal: proposalPda,
             governanceConfig: governanceConfigPda,
             proposer: poorMember.publicKey,
             proposerTokenAccount: poorMemberTokenAccount,
+            depositMint: committeeTokenMint,
             governanceTokenVault: governanceTokenVaultPda,
             governanceAuthority: governanceAuthorityPda,
             systemProgram: SystemProgram.programId,
@@ -384,7 +711,7 @@ describe("Error Handling and Edge Cases", () => {
 
     it("Should fail to create proposal with empty title", async () => {
       const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
-      const proposalId = governanceConfig.proposalCounter;
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
 
       const [proposalPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
@@ -394,16 +721,24 @@ describe("Error Handling and Edge Cases", () => {
       try {
         await program.methods
           .createProposal(
+            proposalId,
             "", // Empty title
             "This proposal should fail because the title is empty.",
             { configUpdate: {} },
-            null
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
           )
           .accounts({
             proposal: proposalPda,
             governanceConfig: governanceConfigPda,
             proposer: member1.publicKey,
             proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
             governanceTokenVault: governanceTokenVaultPda,
             governanceAuthority: governanceAuthorityPda,
             systemProgram: SystemProgram.programId,
@@ -419,6 +754,2706 @@ describe("Error Handling and Edge Cases", () => {
     });
   });
 
+  describe("Evidence URL Validation", () => {
+    const slashData = (evidenceUrls: string[]) => ({
+      slash: {
+        0: {
+          merchantAddress: nonMember.publicKey,
+          productAddress: null,
+          orderAddress: null,
+          violationType: "counterfeit",
+          evidenceUrls,
+          slashAmount: new anchor.BN(1_000_000),
+        },
+      },
+    });
+
+    let merchantSlashHistoryPda: PublicKey;
+
+    before(async () => {
+      [merchantSlashHistoryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("merchant_slash_history"), nonMember.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeMerchantSlashHistory(nonMember.publicKey)
+        .accounts({
+          merchantSlashHistory: merchantSlashHistoryPda,
+          payer: member1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member1])
+        .rpc();
+    });
+
+    const createSlashProposal = async (evidenceUrls: string[], withHistory = true) => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Slash Proposal Evidence Test",
+          "Slash proposal used to exercise evidence URL validation.",
+          { slashMerchant: {} },
+          {
+            executionData: slashData(evidenceUrls),
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          merchantSlashHistory: withHistory ? merchantSlashHistoryPda : null,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      return proposalPda;
+    };
+
+    it("Should fail to create slash proposal without evidence", async () => {
+      try {
+        await createSlashProposal([]);
+        expect.fail("Should have failed with InsufficientEvidenceUrls");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientEvidenceUrls");
+      }
+    });
+
+    it("Should fail to create slash proposal with an invalid evidence URL", async () => {
+      try {
+        await createSlashProposal([
+          "https://evidence.example.com/photo.png",
+          "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+          "not a url",
+        ]);
+        expect.fail("Should have failed with InvalidUrlFormat");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidUrlFormat");
+      }
+    });
+
+    it("Should fail to create slash proposal with a malformed IPFS CID", async () => {
+      try {
+        await createSlashProposal(["ipfs://not-a-cid"]);
+        expect.fail("Should have failed with InvalidUrlFormat");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidUrlFormat");
+      }
+    });
+
+    it("Should fail to create slash proposal with too many evidence URLs", async () => {
+      const urls = Array.from({ length: 11 }, (_, i) => `https://evidence.example.com/${i}`);
+      try {
+        await createSlashProposal(urls);
+        expect.fail("Should have failed with TooManyEvidenceUrls");
+      } catch (error) {
+        expect(error.toString()).to.include("TooManyEvidenceUrls");
+      }
+    });
+
+    it("Should fail to create slash proposal without the merchant history account", async () => {
+      try {
+        await createSlashProposal(["https://evidence.example.com/photo.png"], false);
+        expect.fail("Should have failed with MerchantSlashHistoryRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("MerchantSlashHistoryRequired");
+      }
+    });
+
+    it("Should create slash proposal with valid evidence URLs", async () => {
+      const proposalPda = await createSlashProposal([
+        "https://evidence.example.com/photo.png",
+        "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U",
+      ]);
+
+      // No prior failed filings: deposit is not escalated
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.depositAmount.toString()).to.equal(
+        governanceConfig.proposalDeposit.toString()
+      );
+    });
+  });
+
+  describe("Dispute Order Validation", () => {
+    const orderProgram = anchor.workspace.GovernanceMockOrder as Program<GovernanceMockOrder>;
+    const orderId = new anchor.BN(1);
+    let orderPda: PublicKey;
+
+    const setOrderProgram = (orderProgramId: PublicKey | null) =>
+      program.methods
+        .setOrderProgram(orderProgramId)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const createDisputeProposal = async (
+      user: PublicKey,
+      merchant: PublicKey,
+      disputeOrder: PublicKey | null
+    ) => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Dispute Order Test",
+          "Dispute proposal used to exercise order party validation.",
+          { disputeArbitration: {} },
+          {
+            executionData: {
+              dispute: {
+                0: {
+                  userAddress: user,
+                  merchantAddress: merchant,
+                  orderAddress: orderPda,
+                  disputeType: "item_not_received",
+                  evidenceUrls: ["https://evidence.example.com/tracking.png"],
+                  requestedResolution: "Refund the order",
+                  arbitrationDecision: null,
+                  proposedDecision: null,
+                },
+              },
+            },
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          disputeOrder,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      return proposalPda;
+    };
+
+    before(async () => {
+      // member2 buys from nonMember
+      [orderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("order"), member2.publicKey.toBuffer(), orderId.toArrayLike(Buffer, "le", 8)],
+        orderProgram.programId
+      );
+      await orderProgram.methods
+        .createOrder(orderId, nonMember.publicKey)
+        .accounts({
+          order: orderPda,
+          user: member2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member2])
+        .rpc();
+
+      await setOrderProgram(orderProgram.programId);
+    });
+
+    after(async () => {
+      await setOrderProgram(null);
+    });
+
+    it("Should fail to create dispute proposal with parties not on the order", async () => {
+      try {
+        await createDisputeProposal(member1.publicKey, nonMember.publicKey, orderPda);
+        expect.fail("Should have failed with InvalidDisputeParties");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidDisputeParties");
+      }
+    });
+
+    it("Should fail to create dispute proposal without the order account", async () => {
+      try {
+        await createDisputeProposal(member2.publicKey, nonMember.publicKey, null);
+        expect.fail("Should have failed with InvalidDisputeParties");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidDisputeParties");
+      }
+    });
+
+    it("Should fail to create dispute proposal with a non-order account", async () => {
+      try {
+        await createDisputeProposal(member2.publicKey, nonMember.publicKey, governanceConfigPda);
+        expect.fail("Should have failed with InvalidDisputeParties");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidDisputeParties");
+      }
+    });
+
+    it("Should create dispute proposal when the parties match the order", async () => {
+      const proposalPda = await createDisputeProposal(
+        member2.publicKey,
+        nonMember.publicKey,
+        orderPda
+      );
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.executionData.dispute[0].orderAddress.toString()).to.equal(
+        orderPda.toString()
+      );
+    });
+  });
+
+  describe("CPI Proposal Creation", () => {
+    const orderProgram = anchor.workspace.GovernanceMockOrder as Program<GovernanceMockOrder>;
+    const cpiCaller = anchor.workspace.GovernanceCpiCaller as Program<GovernanceCpiCaller>;
+    const orderId = new anchor.BN(2);
+    const [cpiCallerAllowlistPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("cpi_caller_allowlist")],
+      program.programId
+    );
+    const [escalationAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escalation_authority")],
+      orderProgram.programId
+    );
+    const [callerProposerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proposer")],
+      cpiCaller.programId
+    );
+    let orderPda: PublicKey;
+    let escalationTokenAccount: PublicKey;
+    let callerProposerTokenAccount: PublicKey;
+
+    const setCpiCaller = (programId: PublicKey, allowed: boolean) =>
+      program.methods
+        .setCpiCaller(programId, allowed)
+        .accounts({
+          cpiCallerAllowlist: cpiCallerAllowlistPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const nextProposal = async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      return { proposalId, proposalPda };
+    };
+
+    // Governance accounts the calling programs forward for a proposer PDA
+    const governanceAccounts = (proposalPda: PublicKey, proposer: PublicKey) => ({
+      proposal: proposalPda,
+      governanceConfig: governanceConfigPda,
+      proposerPolicy: PublicKey.findProgramAddressSync(
+        [Buffer.from("proposer_policy")],
+        program.programId
+      )[0],
+      proposerState: PublicKey.findProgramAddressSync(
+        [Buffer.from("proposer_state"), proposer.toBuffer()],
+        program.programId
+      )[0],
+      depositMint: committeeTokenMint,
+      governanceTokenVault: governanceTokenVaultPda,
+      governanceAuthority: governanceAuthorityPda,
+      governanceStats: PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_stats")],
+        program.programId
+      )[0],
+      cpiCallerAllowlist: cpiCallerAllowlistPda,
+      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      governanceProgram: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+
+    const escalate = async () => {
+      const { proposalId, proposalPda } = await nextProposal();
+      await orderProgram.methods
+        .escalateDispute(
+          proposalId,
+          ["https://evidence.example.com/chat-log.png"],
+          "Refund the order"
+        )
+        .accounts({
+          order: orderPda,
+          user: member2.publicKey,
+          escalationAuthority: escalationAuthorityPda,
+          escalationTokenAccount,
+          ...governanceAccounts(proposalPda, escalationAuthorityPda),
+        })
+        .signers([member2])
+        .rpc();
+      return proposalPda;
+    };
+
+    // Proposer PDAs pay the proposal rent and deposit from their own token accounts
+    const fundProposer = async (proposer: PublicKey) => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(proposer, 2 * LAMPORTS_PER_SOL)
+      );
+      const tokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        authority,
+        committeeTokenMint,
+        proposer,
+        true
+      );
+      await mintTo(
+        provider.connection,
+        authority,
+        committeeTokenMint,
+        tokenAccount.address,
+        authority,
+        1000 * 10 ** 9
+      );
+      return tokenAccount.address;
+    };
+
+    before(async () => {
+      [orderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("order"), member2.publicKey.toBuffer(), orderId.toArrayLike(Buffer, "le", 8)],
+        orderProgram.programId
+      );
+      await orderProgram.methods
+        .createOrder(orderId, nonMember.publicKey)
+        .accounts({
+          order: orderPda,
+          user: member2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member2])
+        .rpc();
+
+      escalationTokenAccount = await fundProposer(escalationAuthorityPda);
+      callerProposerTokenAccount = await fundProposer(callerProposerPda);
+
+      await program.methods
+        .createCpiCallerAllowlist()
+        .accounts({
+          cpiCallerAllowlist: cpiCallerAllowlistPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should refuse to allowlist the governance program itself", async () => {
+      try {
+        await setCpiCaller(program.programId, true);
+        expect.fail("Should have failed with InvalidCpiCaller");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidCpiCaller");
+      }
+    });
+
+    it("Should reject a calling program that is not allowlisted", async () => {
+      await setCpiCaller(orderProgram.programId, true);
+      const { proposalId, proposalPda } = await nextProposal();
+      try {
+        await cpiCaller.methods
+          .createProposal(proposalId)
+          .accounts({
+            proposer: callerProposerPda,
+            proposerTokenAccount: callerProposerTokenAccount,
+            ...governanceAccounts(proposalPda, callerProposerPda),
+          })
+          .rpc();
+        expect.fail("Should have failed with CpiCallerNotAllowed");
+      } catch (error) {
+        expect(error.toString()).to.include("CpiCallerNotAllowed");
+      }
+    });
+
+    it("Should create a dispute proposal through an allowlisted program", async () => {
+      const depositBefore = await provider.connection.getTokenAccountBalance(
+        escalationTokenAccount
+      );
+      const proposalPda = await escalate();
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.proposer.toString()).to.equal(escalationAuthorityPda.toString());
+      expect(proposal.proposalType).to.deep.equal({ disputeArbitration: {} });
+      expect(proposal.executionData.dispute[0].orderAddress.toString()).to.equal(
+        orderPda.toString()
+      );
+      expect(proposal.executionData.dispute[0].userAddress.toString()).to.equal(
+        member2.publicKey.toString()
+      );
+      const depositAfter = await provider.connection.getTokenAccountBalance(escalationTokenAccount);
+      expect(
+        new anchor.BN(depositBefore.value.amount).sub(new anchor.BN(depositAfter.value.amount)).toString()
+      ).to.equal(proposal.depositAmount.toString());
+    });
+
+    it("Should return the created proposal ID and address to the calling program", async () => {
+      await setCpiCaller(cpiCaller.programId, true);
+      const { proposalId, proposalPda } = await nextProposal();
+      const signature = await cpiCaller.methods
+        .createProposal(proposalId)
+        .accounts({
+          proposer: callerProposerPda,
+          proposerTokenAccount: callerProposerTokenAccount,
+          ...governanceAccounts(proposalPda, callerProposerPda),
+        })
+        .rpc({ commitment: "confirmed" });
+      await setCpiCaller(cpiCaller.programId, false);
+
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      // Return data the governance program set for its caller, as logged by the runtime
+      const governanceReturn = tx.meta.logMessages
+        .find((log) => log.startsWith(`Program return: ${program.programId.toString()} `))
+        .split(" ")[3];
+      const returnData = Buffer.from(governanceReturn, "base64");
+
+      // The first 40 bytes are the id (u64 LE) and the proposal PDA
+      expect(new anchor.BN(returnData.subarray(0, 8), "le").toString()).to.equal(
+        proposalId.toString()
+      );
+      expect(new PublicKey(returnData.subarray(8, 40)).toString()).to.equal(
+        proposalPda.toString()
+      );
+
+      // The caller checked the decoded return data and passed it on unchanged
+      const [callerReturn] = tx.meta.returnData.data;
+      expect(tx.meta.returnData.programId.toString()).to.equal(cpiCaller.programId.toString());
+      expect(Buffer.from(callerReturn, "base64").equals(returnData)).to.equal(true);
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.proposer.toString()).to.equal(callerProposerPda.toString());
+    });
+
+    it("Should reject the calling program once it is removed from the allowlist", async () => {
+      await setCpiCaller(orderProgram.programId, false);
+      try {
+        await escalate();
+        expect.fail("Should have failed with CpiCallerNotAllowed");
+      } catch (error) {
+        expect(error.toString()).to.include("CpiCallerNotAllowed");
+      }
+    });
+  });
+
+  describe("Proposal Dependency Validation", () => {
+    const createDependentProposal = async (dependsOn: anchor.BN | null) => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Dependent Proposal",
+          "Proposal that references another proposal as a dependency.",
+          { ruleUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      return proposalId;
+    };
+
+    it("Should fail to create a proposal that depends on itself", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      try {
+        await createDependentProposal(governanceConfig.proposalCounter.add(new anchor.BN(1)));
+        expect.fail("Should have failed with InvalidProposalDependency");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidProposalDependency");
+      }
+    });
+
+    it("Should fail to create a proposal that depends on a later proposal", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      try {
+        await createDependentProposal(governanceConfig.proposalCounter.add(new anchor.BN(5)));
+        expect.fail("Should have failed with InvalidProposalDependency");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidProposalDependency");
+      }
+    });
+
+    // Both members vote yes, so the proposal passes
+    const passProposal = (proposalId: anchor.BN) =>
+      voteAndFinalize(proposalId, [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ]);
+
+    const executeProposal = (
+      proposalId: anchor.BN,
+      dependencyProposal: PublicKey | null,
+      executedAt: anchor.BN
+    ) =>
+      program.methods
+        .executeProposal(proposalId, executedAt)
+        .accounts({
+          proposal: proposalPdaFor(proposalId),
+          governanceConfig: governanceConfigPda,
+          dependencyProposal,
+          committeeMember: null,
+          memberStake: null,
+          payer: authority.publicKey,
+          systemProgram: null,
+          upgradeProgram: null,
+          upgradeProgramData: null,
+          upgradeBuffer: null,
+          governanceAuthority: governanceAuthorityPda,
+          bpfLoaderUpgradeable: null,
+          rent: null,
+          clock: null,
+          executionAdapter: null,
+        })
+        .signers([authority])
+        .rpc();
+
+    let dependencyId: anchor.BN;
+    let dependentId: anchor.BN;
+    let executedAt: anchor.BN;
+
+    it("Should fail to execute a proposal before its dependency is executed", async () => {
+      dependencyId = await createDependentProposal(null);
+      dependentId = await createDependentProposal(dependencyId);
+
+      const proposal = await fetchProposal(program, proposalPdaFor(dependentId));
+      expect(proposal.dependsOn.toString()).to.equal(dependencyId.toString());
+
+      await passProposal(dependencyId);
+      executedAt = (await passProposal(dependentId)).addn(60);
+
+      try {
+        await executeProposal(dependentId, proposalPdaFor(dependencyId), executedAt);
+        expect.fail("Should have failed before the dependency was executed");
+      } catch (error) {
+        expect(error.toString()).to.include("DependencyNotExecuted");
+      }
+      const dependent = await fetchProposal(program, proposalPdaFor(dependentId));
+      expect(dependent.status).to.deep.equal({ passed: {} });
+    });
+
+    it("Should execute a proposal once its dependency is executed", async () => {
+      await executeProposal(dependencyId, null, executedAt);
+      await executeProposal(dependentId, proposalPdaFor(dependencyId), executedAt);
+
+      const dependent = await fetchProposal(program, proposalPdaFor(dependentId));
+      expect(dependent.status).to.deep.equal({ executed: {} });
+    });
+  });
+
+  describe("Proposer Policy", () => {
+    let proposerPolicyPda: PublicKey;
+
+    const createPolicyTestProposal = async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Policy Test Proposal",
+          "Proposal used to exercise the proposer ban list.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member2.publicKey,
+          proposerPolicy: proposerPolicyPda,
+          proposerTokenAccount: member2TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member2])
+        .rpc();
+    };
+
+    const setBan = async (banned: boolean) => {
+      await program.methods
+        .setProposerBan(member2.publicKey, banned)
+        .accounts({
+          proposerPolicy: proposerPolicyPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    };
+
+    before(async () => {
+      [proposerPolicyPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposer_policy")],
+        program.programId
+      );
+
+      await program.methods
+        .createProposerPolicy(false)
+        .accounts({
+          proposerPolicy: proposerPolicyPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should reject proposals from a banned proposer", async () => {
+      await setBan(true);
+
+      const policy = await program.account.proposerPolicy.fetch(proposerPolicyPda);
+      expect(policy.deniedProposers.map((key) => key.toString())).to.include(
+        member2.publicKey.toString()
+      );
+
+      try {
+        await createPolicyTestProposal();
+        expect.fail("Should have failed with ProposerBanned");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposerBanned");
+      }
+    });
+
+    it("Should accept proposals again after unban", async () => {
+      await setBan(false);
+      await createPolicyTestProposal();
+    });
+
+    it("Should reject unlisted proposers in allowlist-only mode", async () => {
+      await program.methods
+        .setAllowlistOnly(true)
+        .accounts({
+          proposerPolicy: proposerPolicyPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      try {
+        await createPolicyTestProposal();
+        expect.fail("Should have failed with ProposerNotAllowed");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposerNotAllowed");
+      }
+
+      await program.methods
+        .setAllowlistOnly(false)
+        .accounts({
+          proposerPolicy: proposerPolicyPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should fail to ban by non-authority", async () => {
+      try {
+        await program.methods
+          .setProposerBan(member1.publicKey, true)
+          .accounts({
+            proposerPolicy: proposerPolicyPda,
+            governanceConfig: governanceConfigPda,
+            authority: member1.publicKey,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Proposal ID Race", () => {
+    it("Should reject a proposal built against a stale counter", async () => {
+      const nextProposalId = await program.methods
+        .getNextProposalId()
+        .accounts({ governanceConfig: governanceConfigPda })
+        .view();
+
+      // A PDA derived from an ID that is no longer next (e.g. after a counter bump)
+      const staleProposalId = nextProposalId.add(new anchor.BN(1000));
+      const [stalePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), staleProposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createProposal(
+            staleProposalId,
+            "Stale Proposal",
+            "Proposal built against a stale proposal counter.",
+            { configUpdate: {} },
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
+          )
+          .accounts({
+            proposal: stalePda,
+            governanceConfig: governanceConfigPda,
+            proposer: member1.publicKey,
+            proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with ProposalIdMismatch");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalIdMismatch");
+      }
+    });
+
+    it("Should return the created proposal, deposit and voting window as return data", async () => {
+      const proposalId = await program.methods
+        .getNextProposalId()
+        .accounts({ governanceConfig: governanceConfigPda })
+        .view();
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const tokenBalance = async (account: PublicKey) =>
+        new anchor.BN((await provider.connection.getTokenAccountBalance(account)).value.amount);
+      const balanceBefore = await tokenBalance(member1TokenAccount);
+
+      const signature = await program.methods
+        .createProposal(
+          proposalId,
+          "Return Data Proposal",
+          "Proposal used to check create_proposal return data.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc({ commitment: "confirmed" });
+
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const [encoded] = tx.meta.returnData.data;
+      const returnData = Buffer.from(encoded, "base64");
+
+      // Layout: id (u64 LE), proposal pubkey (32 bytes), deposit_charged (u64 LE),
+      // voting_start (i64 LE), voting_end (i64 LE)
+      expect(returnData.length).to.equal(64);
+      expect(new anchor.BN(returnData.subarray(0, 8), "le").toString()).to.equal(
+        proposalId.toString()
+      );
+      expect(new PublicKey(returnData.subarray(8, 40)).toString()).to.equal(
+        proposalPda.toString()
+      );
+      const depositCharged = new anchor.BN(returnData.subarray(40, 48), "le");
+      const votingStart = new anchor.BN(returnData.subarray(48, 56), "le");
+      const votingEnd = new anchor.BN(returnData.subarray(56, 64), "le");
+
+      // The deposit charged is what left the proposer's account and what the proposal holds
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const depositMint = governanceConfig.depositMints.find((entry) =>
+        entry.mint.equals(committeeTokenMint)
+      );
+      expect(depositCharged.toString()).to.equal(depositMint.minDeposit.toString());
+      expect(balanceBefore.sub(await tokenBalance(member1TokenAccount)).toString()).to.equal(
+        depositCharged.toString()
+      );
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.depositAmount.toString()).to.equal(depositCharged.toString());
+      expect(proposal.votingStart.toString()).to.equal(votingStart.toString());
+      expect(proposal.votingEnd.toString()).to.equal(votingEnd.toString());
+      expect(votingEnd.sub(votingStart).toString()).to.equal(
+        governanceConfig.votingPeriod.toString()
+      );
+    });
+
+    it("Should refuse to update the proposal counter while proposals are pending", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.pendingProposalCount.toNumber()).to.be.greaterThan(0);
+
+      try {
+        await program.methods
+          .updateProposalCounter(
+            governanceConfig.proposalCounter.add(new anchor.BN(10)),
+            "Skip IDs reserved by the previous deployment"
+          )
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with PendingProposalsExist");
+      } catch (error) {
+        expect(error.toString()).to.include("PendingProposalsExist");
+      }
+    });
+
+    it("Should refuse to move the proposal counter back or too far ahead", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+
+      for (const newCounter of [
+        governanceConfig.proposalCounter.sub(new anchor.BN(1)),
+        governanceConfig.proposalCounter.add(new anchor.BN(1001)),
+      ]) {
+        try {
+          await program.methods
+            .updateProposalCounter(newCounter, "Out of range counter update")
+            .accounts({
+              governanceConfig: governanceConfigPda,
+              authority: authority.publicKey,
+            })
+            .signers([authority])
+            .rpc();
+          expect.fail("Should have failed with InvalidProposalCounter");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidProposalCounter");
+        }
+      }
+    });
+
+    it("Should refuse a proposal counter update reason that is too long", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+
+      try {
+        await program.methods
+          .updateProposalCounter(governanceConfig.proposalCounter, "x".repeat(101))
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with ProposalCounterReasonTooLong");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalCounterReasonTooLong");
+      }
+    });
+  });
+
+  describe("Proposal Resubmission", () => {
+    it("Should fail to resubmit a proposal that has not failed on participation", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const priorProposalId = governanceConfig.proposalCounter;
+      const proposalId = priorProposalId.add(new anchor.BN(1));
+
+      const [priorProposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), priorProposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      const priorProposal = await fetchProposal(program, priorProposalPda);
+      const proposer = priorProposal.proposer.equals(member1.publicKey) ? member1 : member2;
+      const proposerTokenAccount = proposer === member1 ? member1TokenAccount : member2TokenAccount;
+
+      try {
+        await program.methods
+          .resubmitProposal(proposalId, priorProposalId)
+          .accounts({
+            proposal: proposalPda,
+            priorProposal: priorProposalPda,
+            governanceConfig: governanceConfigPda,
+            proposer: proposer.publicKey,
+            proposerTokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([proposer])
+          .rpc();
+        expect.fail("Should have failed with ProposalNotResubmittable");
+      } catch (error) {
+        // Still pending, so it cannot have failed on participation
+        expect(error.toString()).to.include("ProposalNotResubmittable");
+      }
+    });
+
+    const resubmit = async (priorProposalId: anchor.BN) => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+      await program.methods
+        .resubmitProposal(proposalId, priorProposalId)
+        .accounts({
+          proposal: proposalPdaFor(proposalId),
+          priorProposal: proposalPdaFor(priorProposalId),
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return proposalId;
+    };
+
+    // Only member1 (1000 of 2500 tokens) votes yes: approved, but short of quorum
+    const failOnParticipation = (proposalId: anchor.BN) =>
+      voteAndFinalize(proposalId, [[member1, member1TokenAccount]]);
+
+    let resubmittedId: anchor.BN;
+
+    it("Should resubmit a proposal that failed only on participation at a discount", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const priorProposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+      await program.methods
+        .createProposal(
+          priorProposalId,
+          "Quorum Failure Proposal",
+          "Proposal that is approved by its voters but misses the participation threshold.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPdaFor(priorProposalId),
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      await failOnParticipation(priorProposalId);
+
+      const priorProposal = await fetchProposal(program, proposalPdaFor(priorProposalId));
+      expect(priorProposal.status).to.deep.equal({ rejected: {} });
+      // Eligibility is judged against the thresholds the proposal was finalized under
+      expect(priorProposal.participationThreshold).to.equal(governanceConfig.participationThreshold);
+      expect(priorProposal.approvalThreshold).to.equal(governanceConfig.approvalThreshold);
+
+      resubmittedId = await resubmit(priorProposalId);
+
+      const resubmitted = await fetchProposal(program, proposalPdaFor(resubmittedId));
+      expect(resubmitted.resubmittedFrom.toString()).to.equal(priorProposalId.toString());
+      expect(resubmitted.depositAmount.toString()).to.equal(
+        governanceConfig.depositMints[0].minDeposit
+          .muln(governanceConfig.resubmissionDepositRate)
+          .divn(10000)
+          .toString()
+      );
+      const prior = await fetchProposal(program, proposalPdaFor(priorProposalId));
+      expect(prior.resubmittedAs.toString()).to.equal(resubmittedId.toString());
+    });
+
+    it("Should fail to resubmit a resubmission", async () => {
+      // The resubmission fails on participation again, but gets no second discount
+      await failOnParticipation(resubmittedId);
+      const resubmitted = await fetchProposal(program, proposalPdaFor(resubmittedId));
+      expect(resubmitted.status).to.deep.equal({ rejected: {} });
+
+      try {
+        await resubmit(resubmittedId);
+        expect.fail("Should have failed with ProposalNotResubmittable");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalNotResubmittable");
+      }
+    });
+  });
+
+  describe("Deposit Mints", () => {
+    let secondMint: PublicKey;
+    let secondVaultPda: PublicKey;
+    let member1SecondMintAccount: PublicKey;
+
+    const createWithDepositAccount = async (
+      proposerTokenAccount: PublicKey,
+      depositMint: PublicKey,
+      vault: PublicKey
+    ) => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Second Mint Proposal",
+          "Proposal paying its deposit in a secondary deposit mint.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount,
+          depositMint,
+          governanceTokenVault: vault,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      return proposalPda;
+    };
+
+    before(async () => {
+      secondMint = await createMint(provider.connection, authority, authority.publicKey, null, 6);
+      [secondVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_token_vault"), secondMint.toBuffer()],
+        program.programId
+      );
+      member1SecondMintAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        member1,
+        secondMint,
+        member1.publicKey
+      );
+      await mintTo(
+        provider.connection,
+        authority,
+        secondMint,
+        member1SecondMintAccount,
+        authority,
+        1000 * 10 ** 6
+      );
+    });
+
+    it("Should fail to initialize a vault for a mint that is not accepted", async () => {
+      try {
+        await program.methods
+          .initializeTokenVault()
+          .accounts({
+            tokenVault: secondVaultPda,
+            mint: secondMint,
+            governanceConfig: governanceConfigPda,
+            governanceAuthority: governanceAuthorityPda,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with DepositMintNotAccepted");
+      } catch (error) {
+        expect(error.toString()).to.include("DepositMintNotAccepted");
+      }
+    });
+
+    it("Should accept deposits in a second registered mint", async () => {
+      await program.methods
+        .setDepositMint(new anchor.BN(50))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          depositMint: secondMint,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      await program.methods
+        .initializeTokenVault()
+        .accounts({
+          tokenVault: secondVaultPda,
+          mint: secondMint,
+          governanceConfig: governanceConfigPda,
+          governanceAuthority: governanceAuthorityPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc();
+
+      const proposalPda = await createWithDepositAccount(
+        member1SecondMintAccount,
+        secondMint,
+        secondVaultPda
+      );
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.depositMint.toString()).to.equal(secondMint.toString());
+      expect(proposal.depositAmount.toNumber()).to.equal(50 * 10 ** 6);
+    });
+
+    it("Should fail to initialize an existing vault again", async () => {
+      try {
+        await program.methods
+          .initializeTokenVault()
+          .accounts({
+            tokenVault: secondVaultPda,
+            mint: secondMint,
+            governanceConfig: governanceConfigPda,
+            governanceAuthority: governanceAuthorityPda,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed because the vault already exists");
+      } catch (error) {
+        expect(error.toString()).to.include("already in use");
+      }
+    });
+
+    it("Should fail when a non-authority initializes a vault", async () => {
+      const otherMint = await createMint(provider.connection, authority, authority.publicKey, null, 6);
+      const [otherVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_token_vault"), otherMint.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .setDepositMint(new anchor.BN(50))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          depositMint: otherMint,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      try {
+        await program.methods
+          .initializeTokenVault()
+          .accounts({
+            tokenVault: otherVaultPda,
+            mint: otherMint,
+            governanceConfig: governanceConfigPda,
+            governanceAuthority: governanceAuthorityPda,
+            authority: member1.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Should refuse to remove a mint that still holds locked deposits", async () => {
+      try {
+        await program.methods
+          .removeDepositMint()
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            depositMint: secondMint,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with OutstandingFunds");
+      } catch (error) {
+        expect(error.toString()).to.include("OutstandingFunds");
+      }
+    });
+
+    it("Should reject deposits after the mint is removed", async () => {
+      const thirdMint = await createMint(provider.connection, authority, authority.publicKey, null, 6);
+      const [thirdVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_token_vault"), thirdMint.toBuffer()],
+        program.programId
+      );
+      const member1ThirdMintAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        member1,
+        thirdMint,
+        member1.publicKey
+      );
+      await mintTo(provider.connection, authority, thirdMint, member1ThirdMintAccount, authority, 1000 * 10 ** 6);
+
+      await program.methods
+        .setDepositMint(new anchor.BN(50))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          depositMint: thirdMint,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      await program.methods
+        .removeDepositMint()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          depositMint: thirdMint,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      try {
+        await createWithDepositAccount(member1ThirdMintAccount, thirdMint, thirdVaultPda);
+        expect.fail("Should have failed with DepositMintNotAccepted");
+      } catch (error) {
+        expect(error.toString()).to.include("DepositMintNotAccepted");
+      }
+    });
+  });
+
+  describe("Committee Fee Withdrawal", () => {
+    let destinationTokenAccount: PublicKey;
+
+    before(async () => {
+      destinationTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        authority,
+        committeeTokenMint,
+        authority.publicKey
+      );
+    });
+
+    it("Should track pending deposits as locked", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const vault = await provider.connection.getTokenAccountBalance(governanceTokenVaultPda);
+      const locked = governanceConfig.depositMints[0].totalDepositsLocked;
+
+      expect(locked.toString()).to.equal(vault.value.amount);
+    });
+
+    it("Should fail to withdraw funds still owed as refundable deposits", async () => {
+      try {
+        await program.methods
+          .withdrawCommitteeFees(new anchor.BN(1))
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            governanceTokenVault: governanceTokenVaultPda,
+            mint: committeeTokenMint,
+            destinationTokenAccount,
+            governanceAuthority: governanceAuthorityPda,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with InsufficientWithdrawableFees");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientWithdrawableFees");
+      }
+    });
+
+    it("Should not unlock deposit funds through a donation", async () => {
+      const donation = 5 * 10 ** 6;
+      const before = await program.account.governanceConfig.fetch(governanceConfigPda);
+
+      await program.methods
+        .donateToTreasury(new anchor.BN(donation), "Grant pool top-up")
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          governanceTokenVault: governanceTokenVaultPda,
+          mint: committeeTokenMint,
+          donorTokenAccount: member1TokenAccount,
+          donor: member1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      const after = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(after.depositMints[0].totalDepositsLocked.toString()).to.equal(
+        before.depositMints[0].totalDepositsLocked.toString()
+      );
+      expect(
+        after.depositMints[0].cumulativeDonations.sub(before.depositMints[0].cumulativeDonations).toNumber()
+      ).to.equal(donation);
+
+      // Only the donation itself is withdrawable
+      const withdraw = (amount: number) =>
+        program.methods
+          .withdrawCommitteeFees(new anchor.BN(amount))
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            governanceTokenVault: governanceTokenVaultPda,
+            mint: committeeTokenMint,
+            destinationTokenAccount,
+            governanceAuthority: governanceAuthorityPda,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+      try {
+        await withdraw(donation + 1);
+        expect.fail("Should have failed with InsufficientWithdrawableFees");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientWithdrawableFees");
+      }
+      await withdraw(donation);
+    });
+
+    it("Should sweep untracked vault surplus into the treasury", async () => {
+      const surplus = 3 * 10 ** 6;
+      await transfer(
+        provider.connection,
+        member1,
+        member1TokenAccount,
+        governanceTokenVaultPda,
+        member1,
+        surplus
+      );
+
+      const withdraw = (amount: number) =>
+        program.methods
+          .withdrawCommitteeFees(new anchor.BN(amount))
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            governanceTokenVault: governanceTokenVaultPda,
+            mint: committeeTokenMint,
+            destinationTokenAccount,
+            governanceAuthority: governanceAuthorityPda,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+      // A direct transfer is not attributed to the treasury until reconciled
+      try {
+        await withdraw(surplus);
+        expect.fail("Should have failed with InsufficientWithdrawableFees");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientWithdrawableFees");
+      }
+
+      const before = await program.account.governanceConfig.fetch(governanceConfigPda);
+      await program.methods
+        .reconcileVault()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          governanceTokenVault: governanceTokenVaultPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const after = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(
+        after.depositMints[0].treasuryBalance.sub(before.depositMints[0].treasuryBalance).toNumber()
+      ).to.equal(surplus);
+      expect(after.depositMints[0].totalDepositsLocked.toString()).to.equal(
+        before.depositMints[0].totalDepositsLocked.toString()
+      );
+
+      await withdraw(surplus);
+    });
+
+    it("Should fail when a non-authority reconciles a vault", async () => {
+      try {
+        await program.methods
+          .reconcileVault()
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            governanceTokenVault: governanceTokenVaultPda,
+            authority: member1.publicKey,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Should fail when a non-authority withdraws fees", async () => {
+      try {
+        await program.methods
+          .withdrawCommitteeFees(new anchor.BN(1))
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            governanceTokenVault: governanceTokenVaultPda,
+            mint: committeeTokenMint,
+            destinationTokenAccount,
+            governanceAuthority: governanceAuthorityPda,
+            authority: member1.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Close Guards", () => {
+    it("Should not close the config while deposits are outstanding", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.depositMints[0].totalDepositsLocked.toNumber()).to.be.greaterThan(0);
+
+      try {
+        await program.methods
+          .closeGovernanceConfig()
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed while deposits are outstanding");
+      } catch (error) {
+        expect(error.toString()).to.match(/PendingProposalsExist|OutstandingFunds/);
+      }
+
+      // Config is still there
+      await program.account.governanceConfig.fetch(governanceConfigPda);
+    });
+
+    it("Should not close a vault that still holds deposits", async () => {
+      try {
+        await program.methods
+          .closeTokenVault()
+          .accounts({
+            tokenVault: governanceTokenVaultPda,
+            governanceConfig: governanceConfigPda,
+            governanceAuthority: governanceAuthorityPda,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with VaultNotEmpty");
+      } catch (error) {
+        expect(error.toString()).to.include("VaultNotEmpty");
+      }
+    });
+  });
+
+  describe("Authority Transfer", () => {
+    const newAuthority = Keypair.generate();
+
+    // Any config update works as a probe of admin access; all-null changes nothing
+    const touchConfig = (signer: Keypair) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod: null,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode: null,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled: null,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement: null,
+          memberStakeCooldown: null,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize: null,
+          maxActiveProposals: null,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos: null,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    const acceptAuthorityTransfer = (signer: Keypair) =>
+      program.methods
+        .acceptAuthorityTransfer()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          ruleRegistry: null,
+          pendingAuthority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    const proposeAuthorityTransfer = (signer: Keypair, target: PublicKey) =>
+      program.methods
+        .proposeAuthorityTransfer(target)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(newAuthority.publicKey, LAMPORTS_PER_SOL)
+      );
+    });
+
+    it("Should keep the old authority in control until the transfer is accepted", async () => {
+      await proposeAuthorityTransfer(authority, newAuthority.publicKey);
+
+      let governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.pendingAuthority.toString()).to.equal(
+        newAuthority.publicKey.toString()
+      );
+      expect(governanceConfig.authority.toString()).to.equal(authority.publicKey.toString());
+
+      // Old authority still has access, the pending one does not yet
+      await touchConfig(authority);
+      try {
+        await touchConfig(newAuthority);
+        expect.fail("Pending authority should not have access before accepting");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      // Only the pending authority can accept
+      try {
+        await acceptAuthorityTransfer(nonMember);
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await acceptAuthorityTransfer(newAuthority);
+
+      governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.authority.toString()).to.equal(newAuthority.publicKey.toString());
+      expect(governanceConfig.pendingAuthority).to.equal(null);
+
+      // The old authority loses access only now
+      try {
+        await touchConfig(authority);
+        expect.fail("Old authority should have lost access");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+      await touchConfig(newAuthority);
+
+      // Hand control back for the remaining tests
+      await proposeAuthorityTransfer(newAuthority, authority.publicKey);
+      await acceptAuthorityTransfer(authority);
+      governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.authority.toString()).to.equal(authority.publicKey.toString());
+    });
+
+    it("Should cancel a pending transfer", async () => {
+      await proposeAuthorityTransfer(authority, newAuthority.publicKey);
+      await program.methods
+        .cancelAuthorityTransfer()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.pendingAuthority).to.equal(null);
+
+      try {
+        await acceptAuthorityTransfer(newAuthority);
+        expect.fail("Should have failed with NoPendingAuthorityTransfer");
+      } catch (error) {
+        expect(error.toString()).to.include("NoPendingAuthorityTransfer");
+      }
+    });
+
+    it("Should hand over only the config admin role once it is held separately", async () => {
+      const configAdmin = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(configAdmin.publicKey, LAMPORTS_PER_SOL)
+      );
+      const setConfigAdmin = (newAdmin: PublicKey, signer: Keypair) =>
+        program.methods
+          .setAdminRole({ config: {} }, newAdmin)
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: signer.publicKey,
+          })
+          .signers([signer])
+          .rpc();
+      await setConfigAdmin(configAdmin.publicKey, authority);
+
+      // The governance authority no longer holds the config admin role it would hand over
+      try {
+        await proposeAuthorityTransfer(authority, newAuthority.publicKey);
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await proposeAuthorityTransfer(configAdmin, newAuthority.publicKey);
+      await acceptAuthorityTransfer(newAuthority);
+
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.configAdmin.toString()).to.equal(newAuthority.publicKey.toString());
+      expect(governanceConfig.authority.toString()).to.equal(authority.publicKey.toString());
+      expect(governanceConfig.ruleAdmin.toString()).to.equal(authority.publicKey.toString());
+      expect(governanceConfig.treasuryAdmin.toString()).to.equal(authority.publicKey.toString());
+
+      // Hand the config admin role back for the remaining tests
+      await setConfigAdmin(authority.publicKey, newAuthority);
+    });
+  });
+
+  describe("Admin Multisig", () => {
+    const admin2 = Keypair.generate();
+    const admin3 = Keypair.generate();
+    const [adminSetPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_set")],
+      program.programId
+    );
+
+    const configUpdate = (feeRate: number | null) => ({
+      proposalDeposit: null,
+      votingPeriod: null,
+      participationThreshold: null,
+      approvalThreshold: null,
+      vetoThreshold: null,
+      feeRate,
+      testMode: null,
+      resubmissionDepositRate: null,
+      slashEscalationRate: null,
+      maxSlashDepositMultiplier: null,
+      confiscationPolicy: null,
+      depositEscrowEnabled: null,
+      depositSlashSchedule: null,
+      inactivityThreshold: null,
+      memberStakeRequirement: null,
+      memberStakeCooldown: null,
+      stakeBasedVotingPower: null,
+      maxCommitteeSize: null,
+      maxActiveProposals: null,
+      maxRuleEffectiveDelay: null,
+      strictUrlValidation: null,
+      emitMemos: null,
+      configTimelock: null,
+      waiveDepositForCommittee: null,
+      votingPowerToleranceBps: null,
+      heartbeatInterval: null,
+      rateRounding: null,
+      participationBonusBps: null,
+      participationBonusWindow: null,
+      maxMemberVotingPower: null,
+      maxLockDuration: null,
+      maxLockMultiplierBps: null,
+    });
+
+    const adminActionPda = (actionId: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("admin_action"), actionId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+    const proposeAdminAction = async (proposer: Keypair, action: object) => {
+      const adminSet = await program.account.adminSet.fetch(adminSetPda);
+      const actionId = adminSet.actionCounter.add(new anchor.BN(1));
+      await program.methods
+        .proposeAdminAction(action as any)
+        .accounts({
+          adminSet: adminSetPda,
+          adminAction: adminActionPda(actionId),
+          admin: proposer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([proposer])
+        .rpc();
+      return actionId;
+    };
+
+    const approveAdminAction = (approver: Keypair, actionId: anchor.BN) =>
+      program.methods
+        .approveAdminAction(actionId)
+        .accounts({
+          adminSet: adminSetPda,
+          adminAction: adminActionPda(actionId),
+          admin: approver.publicKey,
+        })
+        .signers([approver])
+        .rpc();
+
+    const [pendingConfigUpdatePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_config_update")],
+      program.programId
+    );
+
+    // Config update actions queue the update, so they take the pending update account
+    const executeAdminAction = (
+      actionId: anchor.BN,
+      pendingConfigUpdate: PublicKey | null = null
+    ) =>
+      program.methods
+        .executeAdminAction(actionId)
+        .accounts({
+          adminSet: adminSetPda,
+          adminAction: adminActionPda(actionId),
+          governanceConfig: governanceConfigPda,
+          executor: nonMember.publicKey,
+          pendingConfigUpdate,
+          governanceTokenVault: null,
+          mint: null,
+          destinationTokenAccount: null,
+          governanceAuthority: governanceAuthorityPda,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([nonMember])
+        .rpc();
+
+    const applyConfigUpdate = () =>
+      program.methods
+        .applyConfigUpdate()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          pendingConfigUpdate: pendingConfigUpdatePda,
+          queuedBy: nonMember.publicKey,
+        })
+        .rpc();
+
+    const setConfigTimelock = (configTimelock: anchor.BN) =>
+      program.methods
+        .updateGovernanceConfig({ ...configUpdate(null), configTimelock })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    before(async () => {
+      for (const admin of [admin2, admin3]) {
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(admin.publicKey, LAMPORTS_PER_SOL)
+        );
+      }
+    });
+
+    it("Should queue a config update only after 2 of 3 admins approve", async () => {
+      // A two-second timelock keeps the test fast
+      const configTimelockBefore = (
+        await program.account.governanceConfig.fetch(governanceConfigPda)
+      ).configTimelock;
+      await setConfigTimelock(new anchor.BN(2));
+
+      await program.methods
+        .initializeAdminSet([authority.publicKey, admin2.publicKey, admin3.publicKey], 2)
+        .accounts({
+          adminSet: adminSetPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      let governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.adminMultisigEnabled).to.equal(true);
+      const feeRateBefore = governanceConfig.feeRate;
+
+      // The single authority can no longer update the config without an approved admin action
+      try {
+        await program.methods
+          .updateGovernanceConfig(configUpdate(300))
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with AdminMultisigRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("AdminMultisigRequired");
+      }
+
+      // Nor take any other single-admin action
+      const singleAdminActions = [
+        program.methods
+          .updateProposalCounter(governanceConfig.proposalCounter, "Single admin counter update")
+          .accounts({ governanceConfig: governanceConfigPda, authority: authority.publicKey }),
+        program.methods
+          .proposeAuthorityTransfer(admin2.publicKey)
+          .accounts({ governanceConfig: governanceConfigPda, authority: authority.publicKey }),
+        program.methods
+          .closeGovernanceConfig()
+          .accounts({ governanceConfig: governanceConfigPda, authority: authority.publicKey }),
+        program.methods.setCpiCaller(admin2.publicKey, true).accounts({
+          cpiCallerAllowlist: PublicKey.findProgramAddressSync(
+            [Buffer.from("cpi_caller_allowlist")],
+            program.programId
+          )[0],
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        }),
+      ];
+      for (const action of singleAdminActions) {
+        try {
+          await action.signers([authority]).rpc();
+          expect.fail("Should have failed with AdminMultisigRequired");
+        } catch (error) {
+          expect(error.toString()).to.include("AdminMultisigRequired");
+        }
+      }
+
+      const actionId = await proposeAdminAction(admin2, {
+        updateConfig: { 0: configUpdate(300) },
+      });
+
+      // One approval (the proposer's) is below the threshold
+      try {
+        await executeAdminAction(actionId);
+        expect.fail("Should have failed with InsufficientAdminApprovals");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientAdminApprovals");
+      }
+
+      // Non-admins and repeat approvals do not count
+      try {
+        await approveAdminAction(nonMember, actionId);
+        expect.fail("Should have failed with NotAdmin");
+      } catch (error) {
+        expect(error.toString()).to.include("NotAdmin");
+      }
+      try {
+        await approveAdminAction(admin2, actionId);
+        expect.fail("Should have failed with AdminActionAlreadyApproved");
+      } catch (error) {
+        expect(error.toString()).to.include("AdminActionAlreadyApproved");
+      }
+
+      await approveAdminAction(admin3, actionId);
+
+      // The queued update account is supplied exactly for config update actions
+      try {
+        await executeAdminAction(actionId);
+        expect.fail("Should have failed with PendingConfigUpdateMismatch");
+      } catch (error) {
+        expect(error.toString()).to.include("PendingConfigUpdateMismatch");
+      }
+      await executeAdminAction(actionId, pendingConfigUpdatePda);
+
+      const adminAction = await program.account.adminAction.fetch(adminActionPda(actionId));
+      expect(adminAction.executed).to.equal(true);
+
+      // The approved update waits out the config timelock like any queued update
+      const pending = await program.account.pendingConfigUpdate.fetch(pendingConfigUpdatePda);
+      expect(pending.update.feeRate).to.equal(300);
+      expect(pending.queuedBy.toString()).to.equal(nonMember.publicKey.toString());
+      expect(pending.eta.toNumber()).to.equal(pending.queuedAt.toNumber() + 2);
+      governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.feeRate).to.equal(feeRateBefore);
+      try {
+        await applyConfigUpdate();
+        expect.fail("Should have failed with ConfigUpdateTimelocked");
+      } catch (error) {
+        expect(error.toString()).to.include("ConfigUpdateTimelocked");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await applyConfigUpdate();
+      governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.feeRate).to.equal(300);
+      expect(await provider.connection.getAccountInfo(pendingConfigUpdatePda)).to.equal(null);
+
+      try {
+        await executeAdminAction(actionId, pendingConfigUpdatePda);
+        expect.fail("Should have failed with AdminActionAlreadyExecuted");
+      } catch (error) {
+        expect(error.toString()).to.include("AdminActionAlreadyExecuted");
+      }
+
+      // Return to single-authority mode and restore the fee rate for the remaining tests
+      const restoreId = await proposeAdminAction(authority, { restoreSingleAuthority: {} });
+      await approveAdminAction(admin3, restoreId);
+      await executeAdminAction(restoreId);
+
+      await program.methods
+        .updateGovernanceConfig(configUpdate(feeRateBefore))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      await setConfigTimelock(configTimelockBefore);
+      governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.adminMultisigEnabled).to.equal(false);
+      expect(governanceConfig.feeRate).to.equal(feeRateBefore);
+    });
+
+    it("Should refuse every remaining single-admin instruction in multisig mode without an approved action", async () => {
+      const [proposerPolicyPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposer_policy")],
+        program.programId
+      );
+      const extraMint = await createMint(
+        provider.connection,
+        authority,
+        authority.publicKey,
+        null,
+        6
+      );
+      const [extraVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_token_vault"), extraMint.toBuffer()],
+        program.programId
+      );
+
+      // Set up a queued update and an accepted mint without a vault while single-admin
+      await program.methods
+        .queueConfigUpdate(configUpdate(null))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          pendingConfigUpdate: pendingConfigUpdatePda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      await program.methods
+        .setDepositMint(new anchor.BN(1))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          depositMint: extraMint,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      // A vote on a proposal that is no longer pending
+      let closableVote: PublicKey | null = null;
+      let closableProposal: PublicKey | null = null;
+      for (const vote of await program.account.vote.all()) {
+        const proposal = proposalPdaFor(vote.account.proposalId);
+        if (!("pending" in (await fetchProposal(program, proposal)).status)) {
+          closableVote = vote.publicKey;
+          closableProposal = proposal;
+          break;
+        }
+      }
+      expect(closableVote).to.not.equal(null);
+
+      await program.methods
+        .configureAdminSet([authority.publicKey, admin2.publicKey, admin3.publicKey], 2)
+        .accounts({
+          adminSet: adminSetPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const adminOnly = { governanceConfig: governanceConfigPda, authority: authority.publicKey };
+      const singleAdminActions = [
+        program.methods
+          .setDepositMint(new anchor.BN(2))
+          .accounts({ ...adminOnly, depositMint: extraMint }),
+        program.methods.removeDepositMint().accounts({ ...adminOnly, depositMint: extraMint }),
+        program.methods.setDepositKind({ splToken: {} } as any, new anchor.BN(0)).accounts(adminOnly),
+        program.methods.setOrderProgram(null).accounts(adminOnly),
+        program.methods.cancelConfigUpdate().accounts({
+          ...adminOnly,
+          pendingConfigUpdate: pendingConfigUpdatePda,
+          queuedBy: authority.publicKey,
+        }),
+        program.methods.setMemberActive(member1.publicKey, false).accounts({
+          ...adminOnly,
+          committeeMember: PublicKey.findProgramAddressSync(
+            [Buffer.from("committee_member"), member1.publicKey.toBuffer()],
+            program.programId
+          )[0],
+        }),
+        program.methods
+          .setAllowlistOnly(true)
+          .accounts({ ...adminOnly, proposerPolicy: proposerPolicyPda }),
+        program.methods
+          .setProposerBan(nonMember.publicKey, true)
+          .accounts({ ...adminOnly, proposerPolicy: proposerPolicyPda }),
+        program.methods.initializeTokenVault().accounts({
+          ...adminOnly,
+          tokenVault: extraVaultPda,
+          mint: extraMint,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        }),
+        program.methods.closeTokenVault().accounts({
+          ...adminOnly,
+          tokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        }),
+        program.methods.closeVote().accounts({
+          ...adminOnly,
+          vote: closableVote,
+          proposal: closableProposal,
+        }),
+      ];
+      for (const action of singleAdminActions) {
+        try {
+          await action.signers([authority]).rpc();
+          expect.fail("Should have failed with AdminMultisigRequired");
+        } catch (error) {
+          expect(error.toString()).to.include("AdminMultisigRequired");
+        }
+      }
+
+      // Return to single-authority mode and undo the setup
+      const restoreId = await proposeAdminAction(authority, { restoreSingleAuthority: {} });
+      await approveAdminAction(admin2, restoreId);
+      await executeAdminAction(restoreId);
+
+      await program.methods
+        .cancelConfigUpdate()
+        .accounts({
+          ...adminOnly,
+          pendingConfigUpdate: pendingConfigUpdatePda,
+          queuedBy: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      await program.methods
+        .removeDepositMint()
+        .accounts({ ...adminOnly, depositMint: extraMint })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should run a gated instruction once with the admin action approving exactly that call", async () => {
+      let governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const orderProgramBefore = governanceConfig.orderProgram;
+      const orderProgram = Keypair.generate().publicKey;
+
+      await program.methods
+        .configureAdminSet([authority.publicKey, admin2.publicKey, admin3.publicKey], 2)
+        .accounts({
+          adminSet: adminSetPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const setOrderProgram = (
+        signer: Keypair,
+        actionId: anchor.BN,
+        orderProgramId: PublicKey | null
+      ) =>
+        program.methods
+          .setOrderProgram(orderProgramId)
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: signer.publicKey,
+            adminSet: adminSetPda,
+            adminAction: adminActionPda(actionId),
+          })
+          .signers([signer])
+          .rpc();
+
+      const actionId = await proposeAdminAction(admin2, {
+        setOrderProgram: { 0: orderProgram },
+      });
+
+      try {
+        await setOrderProgram(admin2, actionId, orderProgram);
+        expect.fail("Should have failed with InsufficientAdminApprovals");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientAdminApprovals");
+      }
+      await approveAdminAction(admin3, actionId);
+
+      // Only the approved arguments, and only called by an admin of the set
+      try {
+        await setOrderProgram(admin2, actionId, Keypair.generate().publicKey);
+        expect.fail("Should have failed with AdminActionMismatch");
+      } catch (error) {
+        expect(error.toString()).to.include("AdminActionMismatch");
+      }
+      try {
+        await setOrderProgram(nonMember, actionId, orderProgram);
+        expect.fail("Should have failed with NotAdmin");
+      } catch (error) {
+        expect(error.toString()).to.include("NotAdmin");
+      }
+
+      // The instruction carries out its own action; execute_admin_action does not
+      try {
+        await executeAdminAction(actionId);
+        expect.fail("Should have failed with AdminActionMismatch");
+      } catch (error) {
+        expect(error.toString()).to.include("AdminActionMismatch");
+      }
+
+      await setOrderProgram(admin2, actionId, orderProgram);
+      governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.orderProgram.toString()).to.equal(orderProgram.toString());
+      const adminAction = await program.account.adminAction.fetch(adminActionPda(actionId));
+      expect(adminAction.executed).to.equal(true);
+
+      try {
+        await setOrderProgram(admin2, actionId, orderProgram);
+        expect.fail("Should have failed with AdminActionAlreadyExecuted");
+      } catch (error) {
+        expect(error.toString()).to.include("AdminActionAlreadyExecuted");
+      }
+
+      // Restore the order program through the admin set, then return to single-authority mode
+      const resetId = await proposeAdminAction(admin3, {
+        setOrderProgram: { 0: orderProgramBefore },
+      });
+      await approveAdminAction(authority, resetId);
+      await setOrderProgram(admin3, resetId, orderProgramBefore);
+
+      const restoreId = await proposeAdminAction(authority, { restoreSingleAuthority: {} });
+      await approveAdminAction(admin2, restoreId);
+      await executeAdminAction(restoreId);
+      governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.adminMultisigEnabled).to.equal(false);
+      expect(String(governanceConfig.orderProgram)).to.equal(String(orderProgramBefore));
+    });
+  });
+
+  describe("Config Updates", () => {
+    const updateConfig = (changes: object) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod: null,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode: null,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled: null,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement: null,
+          memberStakeCooldown: null,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize: null,
+          maxActiveProposals: null,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos: null,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+          ...changes,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const updateThresholds = (approvalThreshold: number | null, vetoThreshold: number | null) =>
+      updateConfig({ approvalThreshold, vetoThreshold });
+
+    it("Should reject unusable approval thresholds on update", async () => {
+      for (const approvalThreshold of [0, 10000]) {
+        try {
+          await updateThresholds(approvalThreshold, null);
+          expect.fail("Should have failed with ApprovalThresholdOutOfRange");
+        } catch (error) {
+          expect(error.toString()).to.include("ApprovalThresholdOutOfRange");
+        }
+      }
+    });
+
+    it("Should reject a veto threshold below the minimum on update", async () => {
+      try {
+        await updateThresholds(null, 499);
+        expect.fail("Should have failed with VetoThresholdTooLow");
+      } catch (error) {
+        expect(error.toString()).to.include("VetoThresholdTooLow");
+      }
+    });
+
+    it("Should emit the previous and new values of changed fields", async () => {
+      const before = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const events: any[] = [];
+      const listener = program.addEventListener("governanceConfigUpdated", (event) => {
+        events.push(event);
+      });
+
+      // The veto threshold is resubmitted unchanged and must not appear in the event
+      await updateConfig({
+        approvalThreshold: before.approvalThreshold + 500,
+        vetoThreshold: before.vetoThreshold,
+        feeRate: before.feeRate + 50,
+      });
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(events).to.have.length(1);
+      const [event] = events;
+      expect(event.updater.toString()).to.equal(authority.publicKey.toString());
+      expect(event.previous.approvalThreshold).to.equal(before.approvalThreshold);
+      expect(event.new.approvalThreshold).to.equal(before.approvalThreshold + 500);
+      expect(event.previous.feeRate).to.equal(before.feeRate);
+      expect(event.new.feeRate).to.equal(before.feeRate + 50);
+      expect(event.previous.vetoThreshold).to.equal(null);
+      expect(event.new.vetoThreshold).to.equal(null);
+      expect(event.new.votingPeriod).to.equal(null);
+
+      await updateConfig({ approvalThreshold: before.approvalThreshold, feeRate: before.feeRate });
+    });
+
+    it("Should accept thresholds at the edges of the usable range", async () => {
+      await updateThresholds(9999, 500);
+      let governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.approvalThreshold).to.equal(9999);
+      expect(governanceConfig.vetoThreshold).to.equal(500);
+
+      await updateThresholds(6000, 3000);
+      governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.approvalThreshold).to.equal(6000);
+    });
+  });
+
+  describe("Role Separation", () => {
+    const ruleAdmin = Keypair.generate();
+
+    const setAdminRole = (role: object, newAdmin: PublicKey, signer: Keypair) =>
+      program.methods
+        .setAdminRole(role as any, newAdmin)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(ruleAdmin.publicKey, LAMPORTS_PER_SOL)
+      );
+      await setAdminRole({ rule: {} }, ruleAdmin.publicKey, authority);
+    });
+
+    after(async () => {
+      await setAdminRole({ rule: {} }, authority.publicKey, authority);
+    });
+
+    it("Should assign a role without touching the others", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.ruleAdmin.toString()).to.equal(ruleAdmin.publicKey.toString());
+      expect(governanceConfig.configAdmin.toString()).to.equal(authority.publicKey.toString());
+      expect(governanceConfig.treasuryAdmin.toString()).to.equal(authority.publicKey.toString());
+    });
+
+    it("Should not let the rule admin update the fee rate", async () => {
+      try {
+        await program.methods
+          .updateGovernanceConfig({
+            proposalDeposit: null,
+            votingPeriod: null,
+            participationThreshold: null,
+            approvalThreshold: null,
+            vetoThreshold: null,
+            feeRate: 500,
+            testMode: null,
+            resubmissionDepositRate: null,
+            slashEscalationRate: null,
+            maxSlashDepositMultiplier: null,
+            confiscationPolicy: null,
+            depositEscrowEnabled: null,
+            depositSlashSchedule: null,
+            inactivityThreshold: null,
+            memberStakeRequirement: null,
+            memberStakeCooldown: null,
+            stakeBasedVotingPower: null,
+            maxCommitteeSize: null,
+            maxActiveProposals: null,
+            maxRuleEffectiveDelay: null,
+            strictUrlValidation: null,
+            emitMemos: null,
+            configTimelock: null,
+            waiveDepositForCommittee: null,
+            votingPowerToleranceBps: null,
+            heartbeatInterval: null,
+            rateRounding: null,
+            participationBonusBps: null,
+            participationBonusWindow: null,
+            maxMemberVotingPower: null,
+            maxLockDuration: null,
+            maxLockMultiplierBps: null,
+          })
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: ruleAdmin.publicKey,
+          })
+          .signers([ruleAdmin])
+          .rpc();
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Should only let the config admin assign roles", async () => {
+      try {
+        await setAdminRole({ treasury: {} }, ruleAdmin.publicKey, ruleAdmin);
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Config Migration", () => {
+    it("Should initialize at the current schema and refuse to migrate again", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(governanceConfig.schemaVersion).to.equal(18);
+      // New deployments check the content address of ipfs:// and ar:// URLs
+      expect(governanceConfig.strictUrlValidation).to.equal(true);
+      // Deposit memos are opt-in
+      expect(governanceConfig.emitMemos).to.equal(false);
+      // No order program is registered, so dispute parties are not checked
+      expect(governanceConfig.orderProgram).to.be.null;
+      // The participation bonus is off
+      expect(governanceConfig.participationBonusBps).to.equal(0);
+      // Rates round down to whole basis points
+      expect(governanceConfig.rateRounding).to.deep.equal({ floor: {} });
+
+      const sizeBefore = (await provider.connection.getAccountInfo(governanceConfigPda)).data
+        .length;
+      try {
+        await program.methods
+          .migrateGovernanceConfig()
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with ConfigAlreadyMigrated");
+      } catch (error) {
+        expect(error.toString()).to.include("ConfigAlreadyMigrated");
+      }
+      const sizeAfter = (await provider.connection.getAccountInfo(governanceConfigPda)).data.length;
+      expect(sizeAfter).to.equal(sizeBefore);
+    });
+  });
+
+  describe("Governance State Summary", () => {
+    const cpiCaller = anchor.workspace.GovernanceCpiCaller as Program<GovernanceCpiCaller>;
+
+    it("Should return the governance state summary as return data", async () => {
+      const summary = await program.methods
+        .getGovernanceState()
+        .accounts({ governanceConfig: governanceConfigPda, ruleRegistry: null })
+        .view();
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+
+      expect(summary.schemaVersion).to.equal(config.schemaVersion);
+      expect(summary.migrationRequired).to.equal(false);
+      expect(summary.proposalCounter.toString()).to.equal(config.proposalCounter.toString());
+      expect(summary.pendingProposalCount.toString()).to.equal(
+        config.pendingProposalCount.toString()
+      );
+      expect(summary.committeeMemberCount).to.equal(config.committeeMemberCount);
+      expect(summary.committeeEpoch.toString()).to.equal(config.committeeEpoch.toString());
+      expect(summary.participationThreshold).to.equal(config.participationThreshold);
+      expect(summary.approvalThreshold).to.equal(config.approvalThreshold);
+      expect(summary.vetoThreshold).to.equal(config.vetoThreshold);
+      expect(summary.feeRate).to.equal(config.feeRate);
+      const locked = config.depositMints.reduce(
+        (total, mint) => total.add(mint.totalDepositsLocked),
+        new anchor.BN(0)
+      );
+      expect(summary.totalDepositsLocked.toString()).to.equal(locked.toString());
+      expect(summary.testMode).to.equal(config.testMode);
+      expect(summary.proposalsPaused).to.equal(false);
+      expect(summary.ruleRegistryVersion).to.equal(null);
+      expect(summary.ruleDocumentCount).to.equal(null);
+    });
+
+    it("Should include rule registry details when the registry is passed", async () => {
+      const [ruleRegistryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("rule_registry")],
+        program.programId
+      );
+      const registry = await program.account.ruleRegistry.fetchNullable(ruleRegistryPda);
+      if (!registry) {
+        return;
+      }
+
+      const summary = await program.methods
+        .getGovernanceState()
+        .accounts({ governanceConfig: governanceConfigPda, ruleRegistry: ruleRegistryPda })
+        .view();
+      expect(summary.ruleRegistryVersion).to.equal(registry.version);
+      expect(summary.ruleDocumentCount).to.equal(registry.ruleDocuments.length);
+    });
+
+    it("Should pass the summary to a CPI caller", async () => {
+      const direct = await program.methods
+        .getGovernanceState()
+        .accounts({ governanceConfig: governanceConfigPda, ruleRegistry: null })
+        .view();
+      const viaCpi = await cpiCaller.methods
+        .readGovernanceState()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          ruleRegistry: null,
+          governanceProgram: program.programId,
+        })
+        .view();
+
+      expect(viaCpi.proposalCounter.toString()).to.equal(direct.proposalCounter.toString());
+      expect(viaCpi.committeeMemberCount).to.equal(direct.committeeMemberCount);
+      expect(viaCpi.totalDepositsLocked.toString()).to.equal(
+        direct.totalDepositsLocked.toString()
+      );
+      expect(viaCpi.proposalsPaused).to.equal(direct.proposalsPaused);
+      expect(viaCpi.schemaVersion).to.equal(direct.schemaVersion);
+    });
+  });
+
+  describe("Program Constants", () => {
+    // Constants as the IDL json lists them, values in their Rust debug format
+    const idlConstants = () =>
+      Object.fromEntries(program.idl.constants.map((constant) => [constant.name, constant]));
+    const seedBytes = (seed: string) => `[${[...Buffer.from(seed)].join(", ")}]`;
+
+    it("Should export the PDA seeds in the IDL", async () => {
+      const constants = idlConstants();
+      for (const [name, seed] of [
+        ["governanceConfigSeed", "governance_config"],
+        ["governanceAuthoritySeed", "governance_authority"],
+        ["governanceTokenVaultSeed", "governance_token_vault"],
+        ["ruleRegistrySeed", "rule_registry"],
+        ["proposalSeed", "proposal"],
+        ["voteSeed", "vote"],
+        ["proposalOutcomeSeed", "proposal_outcome"],
+        ["committeeMemberSeed", "committee_member"],
+      ]) {
+        expect(constants[name], name).to.not.equal(undefined);
+        expect(constants[name].type).to.equal("bytes");
+        expect(constants[name].value).to.equal(seedBytes(seed));
+      }
+
+      const [derivedConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from(JSON.parse(constants.governanceConfigSeed.value))],
+        program.programId
+      );
+      expect(derivedConfig.equals(governanceConfigPda)).to.equal(true);
+    });
+
+    it("Should export the length limits in the IDL", async () => {
+      const constants = idlConstants();
+      for (const [name, value] of [
+        ["maxProposalTitleBytes", "100"],
+        ["maxProposalDescriptionBytes", "800"],
+        ["maxEvidenceUrls", "10"],
+        ["maxEvidenceUrlLength", "500"],
+        ["maxUrlLength", "500"],
+        ["maxHashLength", "64"],
+        ["maxCommitteeMembers", "32"],
+        ["maxRuleDocuments", "50"],
+      ]) {
+        expect(constants[name], name).to.not.equal(undefined);
+        expect(constants[name].type).to.equal("u32");
+        expect(constants[name].value).to.equal(value);
+      }
+    });
+
+    it("Should return the program constants and current account sizes", async () => {
+      const constants = idlConstants();
+      const returned = await program.methods.getProgramConstants().accounts({}).view();
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+
+      expect(returned.governanceConfigSchemaVersion).to.equal(config.schemaVersion);
+      expect(returned.eventSchemaVersion).to.equal(Number(constants.eventSchemaVersion.value));
+      expect(returned.maxProposalTitleBytes).to.equal(
+        Number(constants.maxProposalTitleBytes.value)
+      );
+      expect(returned.maxProposalDescriptionBytes).to.equal(
+        Number(constants.maxProposalDescriptionBytes.value)
+      );
+      expect(returned.maxCommitteeMembers).to.equal(Number(constants.maxCommitteeMembers.value));
+      expect(returned.maxRuleDocuments).to.equal(Number(constants.maxRuleDocuments.value));
+
+      const configAccount = await provider.connection.getAccountInfo(governanceConfigPda);
+      expect(returned.governanceConfigAccountSize).to.equal(configAccount.data.length);
+      expect(returned.proposalAccountSize).to.be.greaterThan(returned.voteAccountSize);
+      expect(returned.voteAccountSize).to.be.greaterThan(8);
+      expect(returned.proposalOutcomeAccountSize).to.be.greaterThan(8);
+      expect(returned.committeeMemberAccountSize).to.be.greaterThan(8);
+    });
+  });
+
+  describe("Error Codes", () => {
+    // Error codes by name (the IDL may camelCase the names)
+    const errorCodes = () =>
+      Object.fromEntries(program.idl.errors.map((error) => [error.name.toLowerCase(), error.code]));
+
+    it("Should keep the codes of published error variants", async () => {
+      const codes = errorCodes();
+      for (const [name, code] of [
+        ["GovernanceNotInitialized", 6000],
+        ["InvalidThreshold", 6011],
+        ["ProposalNotFound", 6021],
+        ["AlreadyVoted", 6045],
+        ["CommitteeFull", 6053],
+        ["ProposerBanned", 6074],
+        ["RuleRegistryNotFound", 6079],
+        ["InvalidMerchantAddress", 6098],
+        ["InvalidDisputeParties", 6106],
+        ["InvalidTokenMint", 6111],
+        ["ArithmeticOverflow", 6135],
+        ["InvalidTimestamp", 6138],
+        ["InvalidAccountOwner", 6141],
+        ["ExecutionFailed", 6147],
+        ["InvalidInput", 6153],
+        ["OperationNotAllowed", 6154],
+        ["FeatureNotImplemented", 6155],
+      ] as [string, number][]) {
+        expect(codes[name.toLowerCase()], name).to.equal(code);
+      }
+    });
+
+    it("Should append new error variants after the published ones", async () => {
+      const codes = errorCodes();
+      for (const name of [
+        "GovernancePaused",
+        "ProposalCooldownActive",
+        "DepositTooLarge",
+        "StaleConfigVersion",
+        "DuplicateVoteAccount",
+        "CommitteeTooSmall",
+        "TestModeRequired",
+      ]) {
+        expect(codes[name.toLowerCase()], name).to.be.greaterThan(codes.featurenotimplemented);
+      }
+    });
+  });
+
   describe("Voting Errors", () => {
     let proposalPda: PublicKey;
     let proposalId: anchor.BN;
@@ -426,7 +3461,7 @@ describe("Error Handling and Edge Cases", () => {
     before(async () => {
       // Create a proposal for voting error tests
       const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
-      proposalId = governanceConfig.proposalCounter;
+      proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
 
       [proposalPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
@@ -435,16 +3470,24 @@ describe("Error Handling and Edge Cases", () => {
 
       await program.methods
         .createProposal(
+          proposalId,
           "Test Proposal for Voting Errors",
           "This proposal is created specifically for testing voting error conditions.",
           { configUpdate: {} },
-          null
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
         )
         .accounts({
           proposal: proposalPda,
           governanceConfig: governanceConfigPda,
           proposer: member1.publicKey,
           proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
           systemProgram: SystemProgram.programId,
@@ -479,6 +3522,7 @@ describe("Error Handling and Edge Cases", () => {
             voter: member1.publicKey,
             voterTokenAccount: member1TokenAccount,
             committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
           .signers([member1])
@@ -533,6 +3577,7 @@ describe("Error Handling and Edge Cases", () => {
             voter: zeroTokenMember.publicKey,
             voterTokenAccount: zeroTokenAccount,
             committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
           .signers([zeroTokenMember])
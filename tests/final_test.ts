@@ -9,6 +9,7 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { fetchProposal } from "./utils/proposal";
 
 describe("Final Governance System Test", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -133,7 +134,7 @@ describe("Final Governance System Test", () => {
     // Step 3: Create rule registry
     console.log("\n=== Step 3: Create Rule Registry ===");
     const createRegistryTx = await program.methods
-      .createRuleRegistry()
+      .createRuleRegistry("")
       .accounts({
         ruleRegistry: ruleRegistryPda,
         governanceConfig: governanceConfigPda,
@@ -158,10 +159,9 @@ describe("Final Governance System Test", () => {
         tokenVault: governanceTokenVaultPda,
         mint: committeeTokenMint,
         governanceAuthority: governanceAuthorityPda,
-        payer: authority.publicKey,
+        authority: authority.publicKey,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
-        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
       .signers([authority])
       .rpc();
@@ -237,12 +237,19 @@ describe("Final Governance System Test", () => {
     };
 
     const createProposalTx = await program.methods
-      .createProposal(title, description, proposalType, executionData)
+      .createProposal(nextProposalId, title, description, proposalType, {
+        executionData,
+        customDepositRaw: null,
+        dependsOn: null,
+        options: null,
+        recusedMembers: null,
+      })
       .accounts({
         proposal: proposalPda,
         governanceConfig: governanceConfigPda,
         proposer: member1.publicKey,
         proposerTokenAccount: member1TokenAccount,
+        depositMint: committeeTokenMint,
         governanceTokenVault: governanceTokenVaultPda,
         governanceAuthority: governanceAuthorityPda,
         systemProgram: SystemProgram.programId,
@@ -254,7 +261,7 @@ describe("Final Governance System Test", () => {
     console.log("✅ Proposal created:", createProposalTx);
 
     // Verify proposal was created
-    const proposal = await program.account.proposal.fetch(proposalPda);
+    const proposal = await fetchProposal(program, proposalPda);
     const actualProposalId = proposal.id;
     console.log("Actual proposal ID:", actualProposalId.toString());
     expect(proposal.proposer.toString()).to.equal(member1.publicKey.toString());
@@ -290,6 +297,7 @@ describe("Final Governance System Test", () => {
         voter: member1.publicKey,
         voterTokenAccount: member1TokenAccount,
         committeeTokenMint: committeeTokenMint,
+        committeeTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([member1])
@@ -305,6 +313,7 @@ describe("Final Governance System Test", () => {
         voter: member2.publicKey,
         voterTokenAccount: member2TokenAccount,
         committeeTokenMint: committeeTokenMint,
+        committeeTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([member2])
@@ -320,7 +329,7 @@ describe("Final Governance System Test", () => {
     expect(vote2.voteType).to.deep.equal({ yes: {} });
 
     // Verify proposal vote counts
-    const proposalAfterVoting = await program.account.proposal.fetch(proposalPda);
+    const proposalAfterVoting = await fetchProposal(program, proposalPda);
     console.log("Yes votes:", proposalAfterVoting.yesVotes.toString());
     console.log("Total votes:", proposalAfterVoting.totalVotes.toString());
 
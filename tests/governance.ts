@@ -10,6 +10,7 @@ import {
   getAssociatedTokenAddress,
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { fetchProposal } from "./utils/proposal";
 
 describe("Governance System", () => {
   // Configure the client to use the local cluster.
@@ -266,11 +267,15 @@ describe("Governance System", () => {
       const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
       expect(governanceConfig.committeeMemberCount).to.equal(3);
 
-      const members = governanceConfig.committeeMembers.filter((member) => member !== null);
-      expect(members).to.have.lengthOf(3);
-      expect(members.map((m) => m.toString())).to.include(member1.publicKey.toString());
-      expect(members.map((m) => m.toString())).to.include(member2.publicKey.toString());
-      expect(members.map((m) => m.toString())).to.include(member3.publicKey.toString());
+      for (const member of [member1, member2, member3]) {
+        const [committeeMemberPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("committee_member"), member.publicKey.toBuffer()],
+          program.programId
+        );
+        const committeeMember = await program.account.committeeMember.fetch(committeeMemberPda);
+        expect(committeeMember.member.toString()).to.equal(member.publicKey.toString());
+        expect(committeeMember.voteCount.toNumber()).to.equal(0);
+      }
     });
 
     it("Should fail to add member by non-authority", async () => {
@@ -304,15 +309,19 @@ describe("Governance System", () => {
       const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
       expect(governanceConfig.committeeMemberCount).to.equal(2);
 
-      const members = governanceConfig.committeeMembers.filter((member) => member !== null);
-      expect(members.map((m) => m.toString())).to.not.include(member3.publicKey.toString());
+      const [committeeMemberPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("committee_member"), member3.publicKey.toBuffer()],
+        program.programId
+      );
+      const committeeMemberInfo = await provider.connection.getAccountInfo(committeeMemberPda);
+      expect(committeeMemberInfo).to.be.null;
     });
   });
 
   describe("Rule Registry Management", () => {
     it("Should create rule registry successfully", async () => {
       await program.methods
-        .createRuleRegistry()
+        .createRuleRegistry("")
         .accounts({
           ruleRegistry: ruleRegistryPda,
           governanceConfig: governanceConfigPda,
@@ -336,7 +345,7 @@ describe("Governance System", () => {
       const hash = "abc123def456789012345678901234567890123456789012345678901234567890";
 
       await program.methods
-        .addRuleDocument(category, title, url, hash)
+        .addRuleDocument(category, title, url, hash, null, false)
         .accounts({
           ruleRegistry: ruleRegistryPda,
           authority: authority.publicKey,
@@ -354,6 +363,8 @@ describe("Governance System", () => {
       expect(document.title).to.equal(title);
       expect(document.url).to.equal(url);
       expect(document.hash).to.equal(hash);
+      expect(document.id).to.equal(1);
+      expect(ruleRegistry.nextDocumentId).to.equal(2);
     });
 
     it("Should update rule document successfully", async () => {
@@ -361,7 +372,7 @@ describe("Governance System", () => {
       const newHash = "def456abc123789012345678901234567890123456789012345678901234567890";
 
       await program.methods
-        .updateRuleDocument(0, newUrl, newHash)
+        .updateRuleDocument(1, newUrl, newHash, null)
         .accounts({
           ruleRegistry: ruleRegistryPda,
           authority: authority.publicKey,
@@ -379,7 +390,7 @@ describe("Governance System", () => {
 
     it("Should remove rule document successfully", async () => {
       await program.methods
-        .removeRuleDocument(0)
+        .removeRuleDocument(1)
         .accounts({
           ruleRegistry: ruleRegistryPda,
           authority: authority.publicKey,
@@ -393,6 +404,703 @@ describe("Governance System", () => {
       expect(ruleRegistry.version).to.equal(4);
     });
 
+    it("Should size the registry from its content across add and remove", async () => {
+      const hash = "a".repeat(64);
+      const snapshot = async () => ({
+        size: (await provider.connection.getAccountInfo(ruleRegistryPda)).data.length,
+        balance: await provider.connection.getBalance(authority.publicKey),
+      });
+      const addDocument = (title: string) =>
+        program.methods
+          .addRuleDocument(
+            "trading_rules",
+            title,
+            "https://example.com/rules/trading.pdf",
+            hash,
+            null,
+            false
+          )
+          .accounts({
+            ruleRegistry: ruleRegistryPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+      const removeDocument = async () =>
+        program.methods
+          .removeRuleDocument(
+            (await program.account.ruleRegistry.fetch(ruleRegistryPda)).ruleDocuments[0].id
+          )
+          .accounts({
+            ruleRegistry: ruleRegistryPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+      // An empty registry holds one document's worth of headroom, far below the maximum
+      const empty = await snapshot();
+      expect(empty.size).to.be.lessThan(2000);
+
+      await addDocument("Trading Rules v1.0");
+      const one = await snapshot();
+      expect(one.size).to.be.greaterThan(empty.size);
+      expect(one.balance).to.be.lessThan(empty.balance);
+
+      await addDocument("Trading Rules v2.0");
+      const two = await snapshot();
+      expect(two.size).to.be.greaterThan(one.size);
+      expect(two.balance).to.be.lessThan(one.balance);
+
+      // Removal shrinks the account and refunds the rent to the authority
+      await removeDocument();
+      const removed = await snapshot();
+      expect(removed.size).to.equal(one.size);
+      expect(removed.balance).to.be.greaterThan(two.balance);
+
+      await removeDocument();
+      const emptied = await snapshot();
+      expect(emptied.size).to.equal(empty.size);
+      expect(emptied.balance).to.be.greaterThan(removed.balance);
+    });
+
+    it("Should accept documents up to the registry limit and reject the next one", async () => {
+      const maxRuleDocuments = 50;
+      const addDocument = (index: number) =>
+        program.methods
+          .addRuleDocument(
+            "platform_policies",
+            `Platform Policy ${index}`,
+            `https://example.com/rules/policy-${index}.pdf`,
+            "b".repeat(64),
+            null,
+            false
+          )
+          .accounts({
+            ruleRegistry: ruleRegistryPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+      const existing = (await program.account.ruleRegistry.fetch(ruleRegistryPda)).ruleDocuments
+        .length;
+      for (let index = existing; index < maxRuleDocuments; index++) {
+        await addDocument(index);
+      }
+      const full = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      expect(full.ruleDocuments).to.have.lengthOf(maxRuleDocuments);
+
+      try {
+        await addDocument(maxRuleDocuments);
+        expect.fail("Should have failed with TooManyRuleDocuments");
+      } catch (error) {
+        expect(error.toString()).to.include("TooManyRuleDocuments");
+      }
+
+      // Empty the registry again for the remaining tests
+      for (const document of full.ruleDocuments) {
+        await program.methods
+          .removeRuleDocument(document.id)
+          .accounts({
+            ruleRegistry: ruleRegistryPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+      }
+    });
+
+    it("Should keep addressing documents by ID after a middle document is removed", async () => {
+      const accounts = { ruleRegistry: ruleRegistryPda, authority: authority.publicKey };
+      for (const title of ["Dispute Rules A", "Dispute Rules B", "Dispute Rules C"]) {
+        await program.methods
+          .addRuleDocument(
+            "dispute_resolution",
+            title,
+            "https://example.com/rules/disputes.pdf",
+            "c".repeat(64),
+            null,
+            false
+          )
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      }
+      const [first, middle, last] = (
+        await program.account.ruleRegistry.fetch(ruleRegistryPda)
+      ).ruleDocuments.slice(-3);
+      expect(middle.id).to.equal(first.id + 1);
+      expect(last.id).to.equal(middle.id + 1);
+
+      await program.methods
+        .removeRuleDocument(middle.id)
+        .accounts(accounts)
+        .signers([authority])
+        .rpc();
+
+      // The last document moved up a position but keeps its ID
+      const newUrl = "https://example.com/rules/disputes-v2.pdf";
+      await program.methods
+        .updateRuleDocument(last.id, newUrl, null, null)
+        .accounts(accounts)
+        .signers([authority])
+        .rpc();
+
+      const registry = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      const byId = (id: number) => registry.ruleDocuments.find((doc) => doc.id === id);
+      expect(byId(middle.id)).to.be.undefined;
+      expect(byId(last.id).title).to.equal("Dispute Rules C");
+      expect(byId(last.id).url).to.equal(newUrl);
+      expect(byId(first.id).url).to.equal("https://example.com/rules/disputes.pdf");
+
+      try {
+        await program.methods
+          .updateRuleDocument(middle.id, newUrl, null, null)
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with RuleDocumentNotFound");
+      } catch (error) {
+        expect(error.toString()).to.include("RuleDocumentNotFound");
+      }
+
+      // IDs are never reused
+      await program.methods
+        .addRuleDocument(
+          "dispute_resolution",
+          "Dispute Rules D",
+          "https://example.com/rules/disputes.pdf",
+          "c".repeat(64),
+          null,
+          false
+        )
+        .accounts(accounts)
+        .signers([authority])
+        .rpc();
+      const grown = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      expect(grown.ruleDocuments[grown.ruleDocuments.length - 1].id).to.equal(last.id + 1);
+
+      for (const id of [first.id, last.id, last.id + 1]) {
+        await program.methods.removeRuleDocument(id).accounts(accounts).signers([authority]).rpc();
+      }
+    });
+
+    it("Should keep scheduled documents out of the effective filter until they apply", async () => {
+      const accounts = { ruleRegistry: ruleRegistryPda, authority: authority.publicKey };
+      const category = "violation_definitions";
+      const now = Math.floor(Date.now() / 1000);
+      const tomorrow = new anchor.BN(now + 24 * 60 * 60);
+      const addDocument = (title: string, effectiveFrom: anchor.BN | null) =>
+        program.methods
+          .addRuleDocument(
+            category,
+            title,
+            "https://example.com/rules/violations.pdf",
+            "d".repeat(64),
+            effectiveFrom,
+            false
+          )
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+
+      await addDocument("Violations Current", null);
+      await addDocument("Violations Next", tomorrow);
+
+      const find = (onlyEffective: boolean) =>
+        program.methods
+          .findDocumentsByCategory(category, onlyEffective)
+          .accounts({ ruleRegistry: ruleRegistryPda })
+          .view();
+      const all = await find(false);
+      expect(all.map((doc) => doc.title)).to.deep.equal(["Violations Current", "Violations Next"]);
+      expect(all[1].effectiveFrom.toString()).to.equal(tomorrow.toString());
+      const effective = await find(true);
+      expect(effective.map((doc) => doc.title)).to.deep.equal(["Violations Current"]);
+
+      // Dates beyond the configured horizon (365 days by default) are rejected
+      try {
+        await addDocument("Violations Far", new anchor.BN(now + 2 * 365 * 24 * 60 * 60));
+        expect.fail("Should have failed with InvalidEffectiveDate");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidEffectiveDate");
+      }
+
+      for (const doc of all) {
+        await program.methods
+          .removeRuleDocument(doc.id)
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      }
+    });
+
+    it("Should add a batch of documents as a single registry version", async () => {
+      const accounts = { ruleRegistry: ruleRegistryPda, authority: authority.publicKey };
+      const batch = Array.from({ length: 8 }, (_, index) => ({
+        category: "product_standards",
+        title: `Bootstrap Standard ${index}`,
+        url: `https://example.com/rules/bootstrap-${index}.pdf`,
+        hash: "e".repeat(64),
+        effectiveFrom: null,
+        allowCustomCategory: false,
+      }));
+      const before = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+
+      // One bad document rejects the whole batch
+      try {
+        await program.methods
+          .addRuleDocumentsBatch([...batch, { ...batch[0] }])
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with DuplicateRuleDocument");
+      } catch (error) {
+        expect(error.toString()).to.include("DuplicateRuleDocument");
+      }
+      const unchanged = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      expect(unchanged.ruleDocuments).to.have.lengthOf(before.ruleDocuments.length);
+      expect(unchanged.version).to.equal(before.version);
+
+      await program.methods
+        .addRuleDocumentsBatch(batch)
+        .accounts(accounts)
+        .signers([authority])
+        .rpc();
+
+      const after = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      expect(after.version).to.equal(before.version + 1);
+      const added = after.ruleDocuments.slice(before.ruleDocuments.length);
+      expect(added.map((doc) => doc.title)).to.deep.equal(batch.map((input) => input.title));
+      expect(added.map((doc) => doc.id)).to.deep.equal(
+        batch.map((_, index) => before.nextDocumentId + index)
+      );
+
+      for (const doc of added) {
+        await program.methods
+          .removeRuleDocument(doc.id)
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      }
+    });
+
+    it("Should page through documents within the return data limit", async () => {
+      const accounts = { ruleRegistry: ruleRegistryPda, authority: authority.publicKey };
+      const category = "platform_policies";
+      const batch = Array.from({ length: 6 }, (_, index) => ({
+        category,
+        title: `Paged Policy ${index}`,
+        url: `https://example.com/rules/paged-policy-${index}.pdf`,
+        hash: "f".repeat(64),
+        effectiveFrom: null,
+        allowCustomCategory: false,
+      }));
+      await program.methods
+        .addRuleDocumentsBatch(batch)
+        .accounts(accounts)
+        .signers([authority])
+        .rpc();
+
+      const getPage = (offset: number, limit: number) =>
+        program.methods
+          .getRuleDocumentsPage(offset, limit, category, false)
+          .accounts({ ruleRegistry: ruleRegistryPda })
+          .view();
+
+      // Page 2 of 3
+      const page = await getPage(2, 2);
+      expect(page.total).to.equal(6);
+      expect(page.offset).to.equal(2);
+      expect(page.documents.map((doc) => doc.title)).to.deep.equal([
+        "Paged Policy 2",
+        "Paged Policy 3",
+      ]);
+
+      const lastPage = await getPage(4, 2);
+      expect(lastPage.documents.map((doc) => doc.title)).to.deep.equal([
+        "Paged Policy 4",
+        "Paged Policy 5",
+      ]);
+      const pastEnd = await getPage(6, 2);
+      expect(pastEnd.total).to.equal(6);
+      expect(pastEnd.documents).to.have.lengthOf(0);
+
+      // All six documents do not fit in 1024 bytes of return data
+      try {
+        await getPage(0, 6);
+        expect.fail("Should have failed with RuleDocumentPageTooLarge");
+      } catch (error) {
+        const message = [
+          error.toString(),
+          ...(error.logs ?? []),
+          ...(error.simulationResponse?.logs ?? []),
+        ].join("\n");
+        expect(message).to.include("RuleDocumentPageTooLarge");
+      }
+
+      const registry = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      for (const doc of registry.ruleDocuments.filter((doc) => doc.category === category)) {
+        await program.methods
+          .removeRuleDocument(doc.id)
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      }
+    });
+
+    it("Should confine category admins to their own category", async () => {
+      const legal = member1;
+      const productOps = member2;
+      const setCategoryAdmin = (category: string, admin: PublicKey | null, signer = authority) =>
+        program.methods
+          .setCategoryAdmin(category, admin)
+          .accounts({ ruleRegistry: ruleRegistryPda, authority: signer.publicKey })
+          .signers([signer])
+          .rpc();
+      const addDocument = (category: string, title: string, signer: Keypair) =>
+        program.methods
+          .addRuleDocument(
+            category,
+            title,
+            "https://example.com/rules/delegated.pdf",
+            "1".repeat(64),
+            null,
+            false
+          )
+          .accounts({ ruleRegistry: ruleRegistryPda, authority: signer.publicKey })
+          .signers([signer])
+          .rpc();
+      const documentId = async (title: string) =>
+        (await program.account.ruleRegistry.fetch(ruleRegistryPda)).ruleDocuments.find(
+          (doc) => doc.title === title
+        ).id;
+      const expectUnauthorized = async (attempt: Promise<string>) => {
+        try {
+          await attempt;
+          expect.fail("Should have failed with Unauthorized");
+        } catch (error) {
+          expect(error.toString()).to.include("Unauthorized");
+        }
+      };
+
+      // Only the rule admin delegates categories
+      await expectUnauthorized(setCategoryAdmin("dispute_resolution", legal.publicKey, legal));
+      await setCategoryAdmin("dispute_resolution", legal.publicKey);
+      await setCategoryAdmin("product_standards", productOps.publicKey);
+      const registry = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      expect(registry.categoryAdmins.map((entry) => entry.category)).to.deep.equal([
+        "dispute_resolution",
+        "product_standards",
+      ]);
+
+      await addDocument("dispute_resolution", "Delegated Disputes", legal);
+      await addDocument("product_standards", "Delegated Products", productOps);
+      await expectUnauthorized(addDocument("product_standards", "Legal Products", legal));
+
+      const disputesId = await documentId("Delegated Disputes");
+      const productsId = await documentId("Delegated Products");
+      const updateDocument = (id: number, signer: Keypair) =>
+        program.methods
+          .updateRuleDocument(id, "https://example.com/rules/delegated-v2.pdf", null, null)
+          .accounts({ ruleRegistry: ruleRegistryPda, authority: signer.publicKey })
+          .signers([signer])
+          .rpc();
+      const removeDocument = (id: number, signer: Keypair) =>
+        program.methods
+          .removeRuleDocument(id)
+          .accounts({ ruleRegistry: ruleRegistryPda, authority: signer.publicKey })
+          .signers([signer])
+          .rpc();
+
+      await expectUnauthorized(updateDocument(productsId, legal));
+      await expectUnauthorized(removeDocument(productsId, legal));
+      await expectUnauthorized(removeDocument(disputesId, productOps));
+      await updateDocument(disputesId, legal);
+
+      // Removing the delegation returns the category to the rule admin alone
+      await setCategoryAdmin("dispute_resolution", null);
+      await expectUnauthorized(removeDocument(disputesId, legal));
+      await removeDocument(disputesId, authority);
+      await removeDocument(productsId, productOps);
+      await setCategoryAdmin("product_standards", null);
+      const cleared = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      expect(cleared.categoryAdmins).to.have.lengthOf(0);
+    });
+
+    it("Should normalize known categories and require a flag for custom ones", async () => {
+      const accounts = { ruleRegistry: ruleRegistryPda, authority: authority.publicKey };
+      const addDocument = (category: string, title: string, allowCustomCategory: boolean) =>
+        program.methods
+          .addRuleDocument(
+            category,
+            title,
+            "https://example.com/rules/categories.pdf",
+            "2".repeat(64),
+            null,
+            allowCustomCategory
+          )
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      const find = (category: string) =>
+        program.methods
+          .findDocumentsByCategory(category, false)
+          .accounts({ ruleRegistry: ruleRegistryPda })
+          .view();
+
+      await addDocument("TradingRules", "Normalized Trading A", false);
+      await addDocument("trading-rules", "Normalized Trading B", false);
+      const trading = await find("TRADING_RULES");
+      expect(trading.map((doc) => doc.title)).to.deep.equal([
+        "Normalized Trading A",
+        "Normalized Trading B",
+      ]);
+      expect(trading.every((doc) => doc.category === "trading_rules")).to.equal(true);
+
+      // Spelling variants of a known category are duplicates of each other
+      try {
+        await addDocument("Trading Rules", "Normalized Trading A", false);
+        expect.fail("Should have failed with DuplicateRuleDocument");
+      } catch (error) {
+        expect(error.toString()).to.include("DuplicateRuleDocument");
+      }
+
+      try {
+        await addDocument("community_guidelines", "Custom Guidelines", false);
+        expect.fail("Should have failed with UnknownRuleCategory");
+      } catch (error) {
+        expect(error.toString()).to.include("UnknownRuleCategory");
+      }
+      await addDocument("community_guidelines", "Custom Guidelines", true);
+      const custom = await find("Community_Guidelines");
+      expect(custom.map((doc) => doc.category)).to.deep.equal(["community_guidelines"]);
+
+      for (const doc of [...trading, ...custom]) {
+        await program.methods
+          .removeRuleDocument(doc.id)
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      }
+    });
+
+    it("Should record the versions replaced by each update in order", async () => {
+      const accounts = { ruleRegistry: ruleRegistryPda, authority: authority.publicKey };
+      const url = (version: number) => `https://example.com/rules/history-v${version}.pdf`;
+      await program.methods
+        .addRuleDocument("trading_rules", "History Rules", url(0), "0".repeat(64), null, false)
+        .accounts(accounts)
+        .signers([authority])
+        .rpc();
+      const registry = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      const id = registry.ruleDocuments[registry.ruleDocuments.length - 1].id;
+      expect(await program.methods.getDocumentHistory(id).view()).to.deep.equal([]);
+
+      for (const version of [1, 2, 3]) {
+        await program.methods
+          .updateRuleDocument(id, url(version), String(version).repeat(64), null)
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      }
+
+      const history = await program.methods.getDocumentHistory(id).view();
+      expect(history.map((revision) => revision.url)).to.deep.equal([url(0), url(1), url(2)]);
+      expect(history.map((revision) => revision.hash)).to.deep.equal(
+        ["0", "1", "2"].map((digit) => digit.repeat(64))
+      );
+      const updatedAt = history.map((revision) => revision.updatedAt.toNumber());
+      expect(updatedAt).to.deep.equal([...updatedAt].sort((a, b) => a - b));
+
+      // The current version stays on the document itself
+      const updated = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      const current = updated.ruleDocuments.find((doc) => doc.id === id);
+      expect(current.url).to.equal(url(3));
+      expect(current.hash).to.equal("3".repeat(64));
+
+      await program.methods.removeRuleDocument(id).accounts(accounts).signers([authority]).rpc();
+    });
+
+    it("Should keep rules management working across an authority rotation", async () => {
+      const accounts = { ruleRegistry: ruleRegistryPda, authority: authority.publicKey };
+      const newAuthority = Keypair.generate();
+      const transfer = async (from: Keypair, to: Keypair, ruleRegistry: PublicKey | null) => {
+        await program.methods
+          .proposeAuthorityTransfer(to.publicKey)
+          .accounts({ governanceConfig: governanceConfigPda, authority: from.publicKey })
+          .signers([from])
+          .rpc();
+        await program.methods
+          .acceptAuthorityTransfer()
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            ruleRegistry,
+            pendingAuthority: to.publicKey,
+          })
+          .signers([to])
+          .rpc();
+      };
+      const addDocument = (title: string) =>
+        program.methods
+          .addRuleDocument(
+            "trading_rules",
+            title,
+            "https://example.com/rules/rotation.pdf",
+            "4".repeat(64),
+            null,
+            false
+          )
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      const registryAuthority = async () =>
+        (await program.account.ruleRegistry.fetch(ruleRegistryPda)).authority.toString();
+
+      // Accepting with the registry carries it along
+      await transfer(authority, newAuthority, ruleRegistryPda);
+      expect(await registryAuthority()).to.equal(newAuthority.publicKey.toString());
+      await addDocument("Rotation Rules A");
+
+      // Accepting without it leaves the registry stale until it is synced
+      await transfer(newAuthority, authority, null);
+      expect(await registryAuthority()).to.equal(newAuthority.publicKey.toString());
+      try {
+        await addDocument("Rotation Rules B");
+        expect.fail("Should have failed with RuleRegistryAuthorityMismatch");
+      } catch (error) {
+        expect(error.toString()).to.include("RuleRegistryAuthorityMismatch");
+      }
+
+      await program.methods
+        .syncRuleRegistryAuthority()
+        .accounts({ ruleRegistry: ruleRegistryPda, governanceConfig: governanceConfigPda })
+        .rpc();
+      expect(await registryAuthority()).to.equal(authority.publicKey.toString());
+      await addDocument("Rotation Rules B");
+
+      const registry = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      for (const doc of registry.ruleDocuments.filter((doc) => doc.title.startsWith("Rotation"))) {
+        await program.methods
+          .removeRuleDocument(doc.id)
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      }
+    });
+
+    it("Should keep documents of named registries apart", async () => {
+      const registryPda = (name: string) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("rule_registry"), Buffer.from(name)],
+          program.programId
+        )[0];
+      // The original registry is the one with the empty name
+      expect(registryPda("").toString()).to.equal(ruleRegistryPda.toString());
+
+      const names = ["marketplace-a", "marketplace-b"];
+      for (const name of names) {
+        await program.methods
+          .createRuleRegistry(name)
+          .accounts({
+            ruleRegistry: registryPda(name),
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+        await program.methods
+          .addRuleDocument(
+            "trading_rules",
+            `Trading Rules for ${name}`,
+            `https://example.com/rules/${name}.pdf`,
+            "5".repeat(64),
+            null,
+            false
+          )
+          .accounts({ ruleRegistry: registryPda(name), authority: authority.publicKey })
+          .signers([authority])
+          .rpc();
+      }
+
+      for (const name of names) {
+        const registry = await program.account.ruleRegistry.fetch(registryPda(name));
+        expect(registry.name).to.equal(name);
+        expect(registry.ruleDocuments.map((doc) => doc.title)).to.deep.equal([
+          `Trading Rules for ${name}`,
+        ]);
+      }
+      const original = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+      expect(original.name).to.equal("");
+      expect(original.ruleDocuments.some((doc) => doc.title.includes("marketplace"))).to.equal(
+        false
+      );
+
+      // Document IDs are per registry: removing ID 1 from one leaves the other untouched
+      const [first] = (await program.account.ruleRegistry.fetch(registryPda(names[0])))
+        .ruleDocuments;
+      await program.methods
+        .removeRuleDocument(first.id)
+        .accounts({ ruleRegistry: registryPda(names[0]), authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+      const other = await program.account.ruleRegistry.fetch(registryPda(names[1]));
+      expect(other.ruleDocuments).to.have.lengthOf(1);
+    });
+
+    it("Should check the content address of ipfs:// and ar:// document URLs", async () => {
+      const accounts = { ruleRegistry: ruleRegistryPda, authority: authority.publicKey };
+      const cidV0 = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+      const cidV1 = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+      const arweaveTxId = "bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U";
+      const cases: [string, boolean][] = [
+        [`ipfs://${cidV0}`, true],
+        [`ipfs://${cidV0}/rules/trading.pdf`, true],
+        [`ipfs://${cidV1}`, true],
+        [`ar://${arweaveTxId}`, true],
+        ["https://example.com/rules/trading.pdf", true],
+        ["ipfs://not-a-cid", false],
+        [`ipfs://${cidV0.slice(0, -1)}`, false],
+        [`ipfs://${cidV0.slice(0, -1)}0`, false],
+        [`ipfs://${cidV1.slice(0, -1)}1`, false],
+        [`ipfs://${cidV1.toUpperCase()}`, false],
+        [`ipfs://${cidV1.slice(0, 40)}`, false],
+        [`ar://${arweaveTxId.slice(0, -2)}`, false],
+        [`ar://${arweaveTxId.slice(0, -1)}+`, false],
+        ["https:///rules/trading.pdf", false],
+        ["https://", false],
+      ];
+
+      for (const [index, [url, valid]] of cases.entries()) {
+        const add = () =>
+          program.methods
+            .addRuleDocument("trading_rules", `URL Case ${index}`, url, "6".repeat(64), null, false)
+            .accounts(accounts)
+            .signers([authority])
+            .rpc();
+        if (!valid) {
+          try {
+            await add();
+            expect.fail(`${url} should have failed with InvalidUrlFormat`);
+          } catch (error) {
+            expect(error.toString()).to.include("InvalidUrlFormat");
+          }
+          continue;
+        }
+        await add();
+        const registry = await program.account.ruleRegistry.fetch(ruleRegistryPda);
+        const added = registry.ruleDocuments[registry.ruleDocuments.length - 1];
+        expect(added.url).to.equal(url);
+        await program.methods
+          .removeRuleDocument(added.id)
+          .accounts(accounts)
+          .signers([authority])
+          .rpc();
+      }
+    });
+
     it("Should fail to add rule document by non-authority", async () => {
       try {
         await program.methods
@@ -400,7 +1108,9 @@ describe("Governance System", () => {
             "test_category",
             "Test Document",
             "https://example.com/test.pdf",
-            "1234567890123456789012345678901234567890123456789012345678901234"
+            "1234567890123456789012345678901234567890123456789012345678901234",
+            null,
+            false
           )
           .accounts({
             ruleRegistry: ruleRegistryPda,
@@ -424,10 +1134,9 @@ describe("Governance System", () => {
           tokenVault: governanceTokenVaultPda,
           mint: committeeTokenMint,
           governanceAuthority: governanceAuthorityPda,
-          payer: authority.publicKey,
+          authority: authority.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
-          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
         .signers([authority])
         .rpc();
@@ -443,9 +1152,9 @@ describe("Governance System", () => {
     let proposalId: anchor.BN;
 
     before(async () => {
-      // Get current proposal counter
+      // Get the next proposal ID
       const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
-      proposalId = governanceConfig.proposalCounter;
+      proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
 
       [proposalPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
@@ -471,12 +1180,19 @@ describe("Governance System", () => {
       };
 
       await program.methods
-        .createProposal(title, description, proposalType, executionData)
+        .createProposal(proposalId, title, description, proposalType, {
+          executionData,
+          customDepositRaw: null,
+          dependsOn: null,
+          options: null,
+          recusedMembers: null,
+        })
         .accounts({
           proposal: proposalPda,
           governanceConfig: governanceConfigPda,
           proposer: member1.publicKey,
           proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
           systemProgram: SystemProgram.programId,
@@ -486,7 +1202,7 @@ describe("Governance System", () => {
         .rpc();
 
       // Verify proposal was created
-      const proposal = await program.account.proposal.fetch(proposalPda);
+      const proposal = await fetchProposal(program, proposalPda);
       expect(proposal.id.toString()).to.equal(proposalId.toString());
       expect(proposal.proposer.toString()).to.equal(member1.publicKey.toString());
       expect(proposal.title).to.equal(title);
@@ -517,23 +1233,33 @@ describe("Governance System", () => {
         200 * 10 ** 9 // 200 tokens for deposit
       );
 
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const nonMemberProposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
       const [nonMemberProposalPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("proposal"), new anchor.BN(999).toArrayLike(Buffer, "le", 8)],
+        [Buffer.from("proposal"), nonMemberProposalId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
       await program.methods
         .createProposal(
+          nonMemberProposalId,
           "Non-member Proposal",
           "This proposal is created by a non-committee member to test the new permission system.",
           { configUpdate: {} },
-          null
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
         )
         .accounts({
           proposal: nonMemberProposalPda,
           governanceConfig: governanceConfigPda,
           proposer: nonMember.publicKey,
           proposerTokenAccount: nonMemberTokenAccount,
+          depositMint: committeeTokenMint,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
           systemProgram: SystemProgram.programId,
@@ -543,15 +1269,17 @@ describe("Governance System", () => {
         .rpc();
 
       // Verify proposal was created
-      const proposal = await program.account.proposal.fetch(nonMemberProposalPda);
+      const proposal = await fetchProposal(program, nonMemberProposalPda);
       expect(proposal.proposer.toString()).to.equal(nonMember.publicKey.toString());
       expect(proposal.title).to.equal("Non-member Proposal");
       expect(proposal.status).to.deep.equal({ active: {} });
     });
 
     it("Should fail to create proposal with title too long", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const invalidProposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
       const [invalidProposalPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("proposal"), new anchor.BN(998).toArrayLike(Buffer, "le", 8)],
+        [Buffer.from("proposal"), invalidProposalId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -561,16 +1289,24 @@ describe("Governance System", () => {
       try {
         await program.methods
           .createProposal(
+            invalidProposalId,
             longTitle, // Too long (> 100 characters)
             "This proposal should fail because the title is too long.",
             { configUpdate: {} },
-            null
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
           )
           .accounts({
             proposal: invalidProposalPda,
             governanceConfig: governanceConfigPda,
             proposer: member1.publicKey,
             proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
             governanceTokenVault: governanceTokenVaultPda,
             governanceAuthority: governanceAuthorityPda,
             systemProgram: SystemProgram.programId,
@@ -586,23 +1322,33 @@ describe("Governance System", () => {
     });
 
     it("Should create proposal with empty title successfully", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const emptyTitleProposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
       const [emptyTitleProposalPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("proposal"), new anchor.BN(997).toArrayLike(Buffer, "le", 8)],
+        [Buffer.from("proposal"), emptyTitleProposalId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
       await program.methods
         .createProposal(
+          emptyTitleProposalId,
           "", // Empty title is now allowed
           "This proposal tests that empty titles are now allowed.",
           { configUpdate: {} },
-          null
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
         )
         .accounts({
           proposal: emptyTitleProposalPda,
           governanceConfig: governanceConfigPda,
           proposer: member1.publicKey,
           proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
           systemProgram: SystemProgram.programId,
@@ -612,14 +1358,16 @@ describe("Governance System", () => {
         .rpc();
 
       // Verify proposal was created with empty title
-      const proposal = await program.account.proposal.fetch(emptyTitleProposalPda);
+      const proposal = await fetchProposal(program, emptyTitleProposalPda);
       expect(proposal.title).to.equal("");
       expect(proposal.status).to.deep.equal({ pending: {} });
     });
 
     it("Should fail to create proposal with description too long", async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const invalidProposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
       const [invalidProposalPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("proposal"), new anchor.BN(996).toArrayLike(Buffer, "le", 8)],
+        [Buffer.from("proposal"), invalidProposalId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -629,16 +1377,24 @@ describe("Governance System", () => {
       try {
         await program.methods
           .createProposal(
+            invalidProposalId,
             "Valid Title",
             longDescription, // Too long (> 800 characters)
             { configUpdate: {} },
-            null
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
           )
           .accounts({
             proposal: invalidProposalPda,
             governanceConfig: governanceConfigPda,
             proposer: member1.publicKey,
             proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
             governanceTokenVault: governanceTokenVaultPda,
             governanceAuthority: governanceAuthorityPda,
             systemProgram: SystemProgram.programId,
@@ -652,6 +1408,85 @@ describe("Governance System", () => {
         expect(error.toString()).to.include("InvalidProposalDescriptionLength");
       }
     });
+
+    describe("Multibyte UTF-8 content", () => {
+      const createWithTitle = async (title: string) => {
+        const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+        const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+        const [proposalPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        );
+
+        await program.methods
+          .createProposal(
+            proposalId,
+            title,
+            "Proposal exercising byte-based title limits.",
+            { configUpdate: {} },
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
+          )
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            proposer: member1.publicKey,
+            proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc();
+
+        return fetchProposal(program, proposalPda);
+      };
+
+      it("Should accept a CJK title just under the byte limit", async () => {
+        const title = "治".repeat(33); // 99 bytes
+        const proposal = await createWithTitle(title);
+        expect(proposal.title).to.equal(title);
+      });
+
+      it("Should reject a CJK title just over the byte limit", async () => {
+        try {
+          await createWithTitle("治".repeat(34)); // 102 bytes, 34 characters
+          expect.fail("Should have failed with InvalidProposalTitleLength");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidProposalTitleLength");
+        }
+      });
+
+      it("Should accept an emoji title exactly at the byte limit", async () => {
+        const title = "🚀".repeat(25); // 100 bytes
+        const proposal = await createWithTitle(title);
+        expect(proposal.title).to.equal(title);
+      });
+
+      it("Should reject an emoji title one character over the byte limit", async () => {
+        try {
+          await createWithTitle("🚀".repeat(26)); // 104 bytes
+          expect.fail("Should have failed with InvalidProposalTitleLength");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidProposalTitleLength");
+        }
+      });
+
+      it("Should reject a title carrying a lossy-decoding replacement character", async () => {
+        try {
+          await createWithTitle("Truncated " + "\uFFFD");
+          expect.fail("Should have failed with InvalidProposalTitleEncoding");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidProposalTitleEncoding");
+        }
+      });
+    });
   });
 
   describe("Voting System", () => {
@@ -663,7 +1498,7 @@ describe("Governance System", () => {
     before(async () => {
       // Create a new proposal for voting tests
       const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
-      proposalId = governanceConfig.proposalCounter;
+      proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
 
       [proposalPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
@@ -691,16 +1526,24 @@ describe("Governance System", () => {
       // Create proposal for voting
       await program.methods
         .createProposal(
+          proposalId,
           "Voting Test Proposal",
           "This is a test proposal for testing the voting functionality of the governance system.",
           { configUpdate: {} },
-          null
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
         )
         .accounts({
           proposal: proposalPda,
           governanceConfig: governanceConfigPda,
           proposer: member1.publicKey,
           proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
           systemProgram: SystemProgram.programId,
@@ -722,6 +1565,7 @@ describe("Governance System", () => {
           voter: member1.publicKey,
           voterTokenAccount: member1TokenAccount,
           committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([member1])
@@ -735,7 +1579,7 @@ describe("Governance System", () => {
       expect(vote.votingPower.toString()).to.equal("1000000000000"); // 1000 tokens * 10^9
 
       // Verify proposal vote counts updated
-      const proposal = await program.account.proposal.fetch(proposalPda);
+      const proposal = await fetchProposal(program, proposalPda);
       expect(proposal.yesVotes.toString()).to.equal("1000000000000");
       expect(proposal.totalVotes.toString()).to.equal("1000000000000");
     });
@@ -752,6 +1596,7 @@ describe("Governance System", () => {
           voter: member2.publicKey,
           voterTokenAccount: member2TokenAccount,
           committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([member2])
@@ -763,7 +1608,7 @@ describe("Governance System", () => {
       expect(vote.votingPower.toString()).to.equal("1500000000000"); // 1500 tokens * 10^9
 
       // Verify proposal vote counts updated
-      const proposal = await program.account.proposal.fetch(proposalPda);
+      const proposal = await fetchProposal(program, proposalPda);
       expect(proposal.yesVotes.toString()).to.equal("1000000000000");
       expect(proposal.noVotes.toString()).to.equal("1500000000000");
       expect(proposal.totalVotes.toString()).to.equal("2500000000000");
@@ -780,6 +1625,7 @@ describe("Governance System", () => {
             voter: member1.publicKey,
             voterTokenAccount: member1TokenAccount,
             committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
           .signers([member1])
@@ -811,6 +1657,7 @@ describe("Governance System", () => {
             voter: nonMember.publicKey,
             voterTokenAccount: authorityTokenAccount, // Using authority's account
             committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
           .signers([nonMember])
@@ -1,14 +1,38 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Governance } from "../target/types/governance";
-import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+  AddressLookupTableProgram,
+  TransactionMessage,
+  VersionedTransaction,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  ComputeBudgetProgram,
+} from "@solana/web3.js";
+import {
+  burn,
+  closeAccount,
   createMint,
   createAssociatedTokenAccount,
+  getAccount,
+  getMint,
   mintTo,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { fetchProposal } from "./utils/proposal";
+import { createHash } from "crypto";
+import * as fs from "fs";
+import * as path from "path";
 
 describe("Proposal Finalization and Execution", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -185,23 +209,122 @@ describe("Proposal Finalization and Execution", () => {
         tokenVault: governanceTokenVaultPda,
         mint: committeeTokenMint,
         governanceAuthority: governanceAuthorityPda,
-        payer: authority.publicKey,
+        authority: authority.publicKey,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
-        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
       .signers([authority])
       .rpc();
   });
 
+  const committeeMemberPda = (member: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("committee_member"), member.toBuffer()],
+      program.programId
+    )[0];
+
+  const proposalOutcomePda = (proposalId: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal_outcome"), proposalId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const proposerStatePda = (proposer: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("proposer_state"), proposer.toBuffer()],
+      program.programId
+    )[0];
+
+  const memberStakePda = (member: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("member_stake"), member.toBuffer()],
+      program.programId
+    )[0];
+
+  // (CommitteeMember PDA, member token account) pair per committee member
+  const committeeRemainingAccounts = (isWritable = false) =>
+    [
+      [member1, member1TokenAccount],
+      [member2, member2TokenAccount],
+      [member3, member3TokenAccount],
+    ].flatMap(([member, tokenAccount]: [Keypair, PublicKey]) => [
+      { pubkey: committeeMemberPda(member.publicKey), isWritable: false, isSigner: false },
+      { pubkey: tokenAccount, isWritable, isSigner: false },
+    ]);
+
+  // Configuration update with only the given fields set
+  const configUpdate = (fields: object) => ({
+    proposalDeposit: null,
+    votingPeriod: null,
+    participationThreshold: null,
+    approvalThreshold: null,
+    vetoThreshold: null,
+    feeRate: null,
+    testMode: null,
+    resubmissionDepositRate: null,
+    slashEscalationRate: null,
+    maxSlashDepositMultiplier: null,
+    confiscationPolicy: null,
+    depositEscrowEnabled: null,
+    depositSlashSchedule: null,
+    inactivityThreshold: null,
+    memberStakeRequirement: null,
+    memberStakeCooldown: null,
+    stakeBasedVotingPower: null,
+    maxCommitteeSize: null,
+    maxActiveProposals: null,
+    maxRuleEffectiveDelay: null,
+    strictUrlValidation: null,
+    emitMemos: null,
+    configTimelock: null,
+    waiveDepositForCommittee: null,
+    votingPowerToleranceBps: null,
+    heartbeatInterval: null,
+    rateRounding: null,
+    participationBonusBps: null,
+    participationBonusWindow: null,
+    maxMemberVotingPower: null,
+    maxLockDuration: null,
+    maxLockMultiplierBps: null,
+    ...fields,
+  });
+
+  // Every governance event a transaction emitted, in log order
+  // (send the transaction with commitment "confirmed" so it can be fetched right away)
+  const decodeEvents = async (signature: string) => {
+    const transaction = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(program.programId, program.coder);
+    return Array.from(parser.parseLogs(transaction.meta.logMessages));
+  };
+
   describe("Proposal Finalization", () => {
     let proposalPda: PublicKey;
     let proposalId: anchor.BN;
+    let lockedBefore: string;
+
+    const totalDepositsLocked = async () => {
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      return governanceConfig.depositMints[0].totalDepositsLocked.toString();
+    };
+
+    const verifyVaultSolvency = () =>
+      program.methods
+        .verifyVaultSolvency()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          governanceTokenVault: governanceTokenVaultPda,
+        })
+        .rpc();
 
     beforeEach(async () => {
+      lockedBefore = await totalDepositsLocked();
+
       // Get current proposal counter
       const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
-      proposalId = governanceConfig.proposalCounter;
+      proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
 
       [proposalPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
@@ -213,16 +336,24 @@ describe("Proposal Finalization and Execution", () => {
       // Create proposal
       await program.methods
         .createProposal(
+          proposalId,
           "Test Passed Proposal",
           "This proposal should pass with majority approval from committee members.",
           { configUpdate: {} },
-          null
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
         )
         .accounts({
           proposal: proposalPda,
           governanceConfig: governanceConfigPda,
           proposer: member1.publicKey,
           proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
           systemProgram: SystemProgram.programId,
@@ -259,6 +390,7 @@ describe("Proposal Finalization and Execution", () => {
           voter: member1.publicKey,
           voterTokenAccount: member1TokenAccount,
           committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([member1])
@@ -273,6 +405,7 @@ describe("Proposal Finalization and Execution", () => {
           voter: member2.publicKey,
           voterTokenAccount: member2TokenAccount,
           committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([member2])
@@ -283,7 +416,7 @@ describe("Proposal Finalization and Execution", () => {
 
       // Finalize proposal
       await program.methods
-        .finalizeProposal(proposalId)
+        .finalizeProposal(proposalId, null)
         .accounts({
           proposal: proposalPda,
           governanceConfig: governanceConfigPda,
@@ -291,35 +424,61 @@ describe("Proposal Finalization and Execution", () => {
           proposerTokenAccount: member1TokenAccount,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
           tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
         })
-        .remainingAccounts([
-          { pubkey: member1TokenAccount, isWritable: false, isSigner: false },
-          { pubkey: member2TokenAccount, isWritable: false, isSigner: false },
-          { pubkey: member3TokenAccount, isWritable: false, isSigner: false },
-        ])
+        .remainingAccounts(committeeRemainingAccounts())
         .signers([authority])
         .rpc();
 
       // Verify proposal status
-      const proposal = await program.account.proposal.fetch(proposalPda);
+      const proposal = await fetchProposal(program, proposalPda);
       expect(proposal.status).to.deep.equal({ passed: {} });
+
+      // Verify deposit accounting
+      expect(proposal.depositSettled).to.equal(true);
+      expect(proposal.depositConfiscated.toNumber()).to.equal(0);
+      expect(proposal.depositSlashRate).to.equal(1000);
+      expect(proposal.depositFee.toString()).to.equal(
+        proposal.depositAmount.muln(1000).divn(10000).toString()
+      );
+      expect(
+        proposal.depositRefunded
+          .add(proposal.depositFee)
+          .add(proposal.depositConfiscated)
+          .toString()
+      ).to.equal(proposal.depositAmount.toString());
+
+      // Locked deposits return to their prior value once the deposit is settled
+      expect(await totalDepositsLocked()).to.equal(lockedBefore);
+      await verifyVaultSolvency();
     });
 
     it("Should finalize proposal with rejected status", async () => {
       // Create proposal
       await program.methods
         .createProposal(
+          proposalId,
           "Test Rejected Proposal",
           "This proposal should be rejected due to insufficient approval votes.",
           { configUpdate: {} },
-          null
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
         )
         .accounts({
           proposal: proposalPda,
           governanceConfig: governanceConfigPda,
           proposer: member1.publicKey,
           proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
           systemProgram: SystemProgram.programId,
@@ -356,6 +515,7 @@ describe("Proposal Finalization and Execution", () => {
           voter: member1.publicKey,
           voterTokenAccount: member1TokenAccount,
           committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([member1])
@@ -370,6 +530,7 @@ describe("Proposal Finalization and Execution", () => {
           voter: member2.publicKey,
           voterTokenAccount: member2TokenAccount,
           committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([member2])
@@ -380,7 +541,7 @@ describe("Proposal Finalization and Execution", () => {
 
       // Finalize proposal
       await program.methods
-        .finalizeProposal(proposalId)
+        .finalizeProposal(proposalId, null)
         .accounts({
           proposal: proposalPda,
           governanceConfig: governanceConfigPda,
@@ -388,19 +549,8365 @@ describe("Proposal Finalization and Execution", () => {
           proposerTokenAccount: member1TokenAccount,
           governanceTokenVault: governanceTokenVaultPda,
           governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
           tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
         })
-        .remainingAccounts([
-          { pubkey: member1TokenAccount, isWritable: false, isSigner: false },
-          { pubkey: member2TokenAccount, isWritable: false, isSigner: false },
-          { pubkey: member3TokenAccount, isWritable: false, isSigner: false },
-        ])
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      // Verify proposal status
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ rejected: {} });
+
+      // Verify deposit accounting
+      expect(proposal.depositSettled).to.equal(true);
+      expect(proposal.depositConfiscated.toNumber()).to.equal(0);
+      // No approval at all falls below the floor and takes the elevated slash
+      expect(proposal.depositSlashRate).to.equal(5000);
+      expect(proposal.depositFee.toString()).to.equal(
+        proposal.depositAmount.muln(5000).divn(10000).toString()
+      );
+      expect(
+        proposal.depositRefunded
+          .add(proposal.depositFee)
+          .add(proposal.depositConfiscated)
+          .toString()
+      ).to.equal(proposal.depositAmount.toString());
+
+      // Locked deposits return to their prior value once the deposit is settled
+      expect(await totalDepositsLocked()).to.equal(lockedBefore);
+      await verifyVaultSolvency();
+    });
+
+    it("Should take only the fee from a rejected proposal at or above the approval floor", async () => {
+      const setDepositSlashSchedule = (depositSlashSchedule: object) =>
+        program.methods
+          .updateGovernanceConfig({
+            proposalDeposit: null,
+            votingPeriod: null,
+            participationThreshold: null,
+            approvalThreshold: null,
+            vetoThreshold: null,
+            feeRate: null,
+            testMode: null,
+            resubmissionDepositRate: null,
+            slashEscalationRate: null,
+            maxSlashDepositMultiplier: null,
+            confiscationPolicy: null,
+            depositEscrowEnabled: null,
+            depositSlashSchedule: depositSlashSchedule as any,
+            inactivityThreshold: null,
+            memberStakeRequirement: null,
+            memberStakeCooldown: null,
+            stakeBasedVotingPower: null,
+            maxCommitteeSize: null,
+            maxActiveProposals: null,
+            maxRuleEffectiveDelay: null,
+            strictUrlValidation: null,
+            emitMemos: null,
+            configTimelock: null,
+            waiveDepositForCommittee: null,
+            votingPowerToleranceBps: null,
+            heartbeatInterval: null,
+            rateRounding: null,
+            participationBonusBps: null,
+            participationBonusWindow: null,
+            maxMemberVotingPower: null,
+            maxLockDuration: null,
+            maxLockMultiplierBps: null,
+          })
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+      const defaultSchedule = {
+        passedRate: 1000,
+        rejectedRate: 1000,
+        lowApprovalFloor: 2000,
+        lowApprovalRejectedRate: 5000,
+        vetoedRate: 10000,
+      };
+      // A rejection can never be slashed less than a pass
+      try {
+        await setDepositSlashSchedule({ ...defaultSchedule, rejectedRate: 500 });
+        expect.fail("Should have failed with InvalidSlashSchedule");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidSlashSchedule");
+      }
+
+      // A zero floor puts every rejection in the fee-only band
+      await setDepositSlashSchedule({ ...defaultSchedule, lowApprovalFloor: 0 });
+
+      // Create proposal
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Test Floor Rejected Proposal",
+          "This proposal should be rejected due to insufficient approval votes.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      // Cast votes - majority rejection
+      const [vote1Pda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member1.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      const [vote2Pda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member2.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .castVote(proposalId, { no: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: vote1Pda,
+          governanceConfig: governanceConfigPda,
+          voter: member1.publicKey,
+          voterTokenAccount: member1TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member1])
+        .rpc();
+
+      await program.methods
+        .castVote(proposalId, { no: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: vote2Pda,
+          governanceConfig: governanceConfigPda,
+          voter: member2.publicKey,
+          voterTokenAccount: member2TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member2])
+        .rpc();
+
+      // Wait for voting period to end
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      // Finalize proposal
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
         .signers([authority])
         .rpc();
 
       // Verify proposal status
-      const proposal = await program.account.proposal.fetch(proposalPda);
+      const proposal = await fetchProposal(program, proposalPda);
       expect(proposal.status).to.deep.equal({ rejected: {} });
+
+      // Verify deposit accounting
+      expect(proposal.depositSettled).to.equal(true);
+      expect(proposal.depositConfiscated.toNumber()).to.equal(0);
+      expect(proposal.depositSlashRate).to.equal(1000);
+      expect(proposal.depositFee.toString()).to.equal(
+        proposal.depositAmount.muln(1000).divn(10000).toString()
+      );
+      expect(
+        proposal.depositRefunded
+          .add(proposal.depositFee)
+          .add(proposal.depositConfiscated)
+          .toString()
+      ).to.equal(proposal.depositAmount.toString());
+
+      // Locked deposits return to their prior value once the deposit is settled
+      expect(await totalDepositsLocked()).to.equal(lockedBefore);
+      await verifyVaultSolvency();
+
+      await setDepositSlashSchedule(defaultSchedule);
+    });
+
+    it("Should charge a pending proposal the fee schedule it was created under", async () => {
+      const updateFees = (feeRate: number, depositSlashSchedule: object) =>
+        program.methods
+          .updateGovernanceConfig({
+            proposalDeposit: null,
+            votingPeriod: null,
+            participationThreshold: null,
+            approvalThreshold: null,
+            vetoThreshold: null,
+            feeRate,
+            testMode: null,
+            resubmissionDepositRate: null,
+            slashEscalationRate: null,
+            maxSlashDepositMultiplier: null,
+            confiscationPolicy: null,
+            depositEscrowEnabled: null,
+            depositSlashSchedule: depositSlashSchedule as any,
+            inactivityThreshold: null,
+            memberStakeRequirement: null,
+            memberStakeCooldown: null,
+            stakeBasedVotingPower: null,
+            maxCommitteeSize: null,
+            maxActiveProposals: null,
+            maxRuleEffectiveDelay: null,
+            strictUrlValidation: null,
+            emitMemos: null,
+            configTimelock: null,
+            waiveDepositForCommittee: null,
+            votingPowerToleranceBps: null,
+            heartbeatInterval: null,
+            rateRounding: null,
+            participationBonusBps: null,
+            participationBonusWindow: null,
+            maxMemberVotingPower: null,
+            maxLockDuration: null,
+            maxLockMultiplierBps: null,
+          })
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const originalFeeRate = config.feeRate;
+      const originalSchedule = config.depositSlashSchedule;
+      const pendingProposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [pendingProposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), pendingProposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          pendingProposalId,
+          "Test Fee Snapshot Proposal",
+          "This proposal keeps the fee schedule it was created under.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: pendingProposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      // Raise the fee while the proposal is pending; the update reports it
+      const events: any[] = [];
+      const listener = program.addEventListener("governanceConfigUpdated", (event) => {
+        events.push(event);
+      });
+      await updateFees(2000, { ...originalSchedule, passedRate: 2000, rejectedRate: 2000 });
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      expect(events).to.have.length(1);
+      expect(events[0].pendingProposalCount.toNumber()).to.be.greaterThan(0);
+
+      for (const voter of [member1, member2]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            pendingProposalId.toArrayLike(Buffer, "le", 8),
+            voter.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(pendingProposalId, { yes: {} })
+          .accounts({
+            proposal: pendingProposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: voter.publicKey,
+            voterTokenAccount: voter === member1 ? member1TokenAccount : member2TokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await program.methods
+        .finalizeProposal(pendingProposalId, null)
+        .accounts({
+          proposal: pendingProposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(pendingProposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      const proposal = await fetchProposal(program, pendingProposalPda);
+      expect(proposal.status).to.deep.equal({ passed: {} });
+      expect(proposal.feeRate).to.equal(originalFeeRate);
+      expect(proposal.depositSlashSchedule).to.deep.equal(originalSchedule);
+      expect(proposal.depositSlashRate).to.equal(originalSchedule.passedRate);
+      expect(proposal.depositFee.toString()).to.equal(
+        proposal.depositAmount.muln(originalSchedule.passedRate).divn(10000).toString()
+      );
+
+      await updateFees(originalFeeRate, originalSchedule);
+    });
+
+    it("Should finalize proposal with vetoed status", async () => {
+      // Create proposal
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Test Vetoed Proposal",
+          "This proposal should be vetoed and have its deposit confiscated.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      // Cast votes - veto
+      const [vote1Pda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member1.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      const [vote2Pda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member2.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .castVote(proposalId, { noWithVeto: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: vote1Pda,
+          governanceConfig: governanceConfigPda,
+          voter: member1.publicKey,
+          voterTokenAccount: member1TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member1])
+        .rpc();
+
+      await program.methods
+        .castVote(proposalId, { noWithVeto: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: vote2Pda,
+          governanceConfig: governanceConfigPda,
+          voter: member2.publicKey,
+          voterTokenAccount: member2TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member2])
+        .rpc();
+
+      // Wait for voting period to end
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      // Finalize proposal
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      // Verify proposal status
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ vetoed: {} });
+
+      // Verify deposit accounting
+      expect(proposal.depositSettled).to.equal(true);
+      expect(proposal.depositConfiscated.toString()).to.equal(proposal.depositAmount.toString());
+      expect(proposal.depositSlashRate).to.equal(10000);
+      expect(proposal.depositBurned.toNumber()).to.equal(0);
+      expect(
+        proposal.depositRefunded
+          .add(proposal.depositFee)
+          .add(proposal.depositConfiscated)
+          .toString()
+      ).to.equal(proposal.depositAmount.toString());
+
+      // Locked deposits return to their prior value once the deposit is settled
+      expect(await totalDepositsLocked()).to.equal(lockedBefore);
+      await verifyVaultSolvency();
+
+      // A second settlement is a no-op: a retried finalize fails and the crank skips it
+      const settledState = async () => {
+        const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+        const vault = await getAccount(provider.connection, governanceTokenVaultPda);
+        return {
+          totalDepositsLocked: governanceConfig.depositMints[0].totalDepositsLocked.toString(),
+          treasuryBalance: governanceConfig.depositMints[0].treasuryBalance.toString(),
+          vaultBalance: vault.amount.toString(),
+        };
+      };
+      const stateBefore = await settledState();
+
+      try {
+        await program.methods
+          .finalizeProposal(proposalId, null)
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            proposerTokenAccount: member1TokenAccount,
+            governanceTokenVault: governanceTokenVaultPda,
+            governanceAuthority: governanceAuthorityPda,
+            depositMint: committeeTokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            proposalOutcome: proposalOutcomePda(proposalId),
+            payer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(committeeRemainingAccounts())
+          .signers([authority])
+          .rpc();
+        expect.fail("Should not finalize a settled proposal twice");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalNotActive");
+      }
+
+      await program.methods
+        .crankFinalize([{ proposalId, voteCount: 0 }], null)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          { pubkey: proposalPda, isWritable: true, isSigner: false },
+          { pubkey: proposalOutcomePda(proposalId), isWritable: true, isSigner: false },
+          { pubkey: member1TokenAccount, isWritable: true, isSigner: false },
+          { pubkey: proposerStatePda(member1.publicKey), isWritable: true, isSigner: false },
+        ])
+        .signers([authority])
+        .rpc();
+
+      expect(await settledState()).to.deep.equal(stateBefore);
+      const resettled = await fetchProposal(program, proposalPda);
+      expect(resettled.depositConfiscated.toString()).to.equal(
+        proposal.depositConfiscated.toString()
+      );
+      expect(resettled.depositRefunded.toString()).to.equal(proposal.depositRefunded.toString());
+    });
+
+    it("Should burn the confiscated deposit under the Burn policy", async () => {
+      const setConfiscationPolicy = (confiscationPolicy: object) =>
+        program.methods
+          .updateGovernanceConfig({
+            proposalDeposit: null,
+            votingPeriod: null,
+            participationThreshold: null,
+            approvalThreshold: null,
+            vetoThreshold: null,
+            feeRate: null,
+            testMode: null,
+            resubmissionDepositRate: null,
+            slashEscalationRate: null,
+            maxSlashDepositMultiplier: null,
+            confiscationPolicy: confiscationPolicy as any,
+            depositEscrowEnabled: null,
+            depositSlashSchedule: null,
+            inactivityThreshold: null,
+            memberStakeRequirement: null,
+            memberStakeCooldown: null,
+            stakeBasedVotingPower: null,
+            maxCommitteeSize: null,
+            maxActiveProposals: null,
+            maxRuleEffectiveDelay: null,
+            strictUrlValidation: null,
+            emitMemos: null,
+            configTimelock: null,
+            waiveDepositForCommittee: null,
+            votingPowerToleranceBps: null,
+            heartbeatInterval: null,
+            rateRounding: null,
+            participationBonusBps: null,
+            participationBonusWindow: null,
+            maxMemberVotingPower: null,
+            maxLockDuration: null,
+            maxLockMultiplierBps: null,
+          })
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+      await setConfiscationPolicy({ burn: {} });
+
+      // Create proposal
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Test Burned Deposit Proposal",
+          "This proposal should be vetoed and have its deposit burned.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      // Cast votes - veto
+      const [vote1Pda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member1.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      const [vote2Pda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member2.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .castVote(proposalId, { noWithVeto: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: vote1Pda,
+          governanceConfig: governanceConfigPda,
+          voter: member1.publicKey,
+          voterTokenAccount: member1TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member1])
+        .rpc();
+
+      await program.methods
+        .castVote(proposalId, { noWithVeto: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: vote2Pda,
+          governanceConfig: governanceConfigPda,
+          voter: member2.publicKey,
+          voterTokenAccount: member2TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member2])
+        .rpc();
+
+      // Wait for voting period to end
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      // Finalize proposal
+      const supplyBefore = (await provider.connection.getTokenSupply(committeeTokenMint)).value.amount;
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      // Verify proposal status
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ vetoed: {} });
+
+      // Verify deposit accounting
+      expect(proposal.depositSettled).to.equal(true);
+      expect(proposal.depositConfiscated.toString()).to.equal(proposal.depositAmount.toString());
+      expect(proposal.depositBurned.toString()).to.equal(proposal.depositAmount.toString());
+      const supplyAfter = (await provider.connection.getTokenSupply(committeeTokenMint)).value.amount;
+      expect((BigInt(supplyBefore) - BigInt(supplyAfter)).toString()).to.equal(
+        proposal.depositAmount.toString()
+      );
+      expect(
+        proposal.depositRefunded
+          .add(proposal.depositFee)
+          .add(proposal.depositConfiscated)
+          .toString()
+      ).to.equal(proposal.depositAmount.toString());
+
+      // Locked deposits return to their prior value once the deposit is settled
+      expect(await totalDepositsLocked()).to.equal(lockedBefore);
+      await verifyVaultSolvency();
+
+      await setConfiscationPolicy({ keepInTreasury: {} });
+    });
+
+    it("Should record every vault movement of a create-finalize cycle", async () => {
+      const entries: any[] = [];
+      const listener = program.addEventListener("treasuryTransfer", (event) => {
+        if (event.proposalId && event.proposalId.eq(proposalId)) {
+          entries.push(event);
+        }
+      });
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Test Ledger Proposal",
+          "This proposal records its deposit movements in the treasury ledger.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      for (const member of [member1, member2]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        const voterTokenAccount = member === member1 ? member1TokenAccount : member2TokenAccount;
+
+        await program.methods
+          .castVote(proposalId, { approve: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      // Give the websocket a moment to deliver the logs
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(entries.map((entry) => Object.keys(entry.reason)[0])).to.deep.equal([
+        "proposalDeposit",
+        "depositRefund",
+        "committeeFee",
+      ]);
+      expect(entries.map((entry) => Object.keys(entry.direction)[0])).to.deep.equal([
+        "in",
+        "out",
+        "internal",
+      ]);
+      expect(entries[0].amount.toString()).to.equal(proposal.depositAmount.toString());
+      expect(entries[0].counterparty.toBase58()).to.equal(member1TokenAccount.toBase58());
+      expect(entries[1].amount.toString()).to.equal(proposal.depositRefunded.toString());
+      expect(entries[1].counterparty.toBase58()).to.equal(member1TokenAccount.toBase58());
+      expect(entries[2].amount.toString()).to.equal(proposal.depositFee.toString());
+
+      // Balances are read after each transfer
+      expect(entries[1].vaultBalanceAfter.toString()).to.equal(
+        entries[0].vaultBalanceAfter.sub(proposal.depositRefunded).toString()
+      );
+      const vault = await provider.connection.getTokenAccountBalance(governanceTokenVaultPda);
+      expect(entries[2].vaultBalanceAfter.toString()).to.equal(vault.value.amount);
+    });
+  });
+
+  describe("Committee Fee Distribution", () => {
+    const memberAccounts = () => [member1TokenAccount, member2TokenAccount, member3TokenAccount];
+
+    const balances = async () =>
+      Promise.all(
+        memberAccounts().map(async (account) =>
+          BigInt((await provider.connection.getTokenAccountBalance(account)).value.amount)
+        )
+      );
+
+    const distribute = (amount: number, mode: object) =>
+      program.methods
+        .distributeFees(new anchor.BN(amount), mode as any)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          governanceTokenVault: governanceTokenVaultPda,
+          mint: committeeTokenMint,
+          governanceAuthority: governanceAuthorityPda,
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(committeeRemainingAccounts(true))
+        .signers([authority])
+        .rpc();
+
+    it("Should split fees by vote participation", async () => {
+      const weights = await Promise.all(
+        [member1, member2, member3].map(async (member) => {
+          const committeeMember = await program.account.committeeMember.fetch(
+            committeeMemberPda(member.publicKey)
+          );
+          return BigInt(committeeMember.voteCount.toString());
+        })
+      );
+      const totalWeight = weights.reduce((a, b) => a + b, BigInt(0));
+      // member1 and member2 voted on every finalized proposal, member3 never voted
+      expect(weights[2]).to.equal(BigInt(0));
+      expect(weights[0] > BigInt(0)).to.equal(true);
+
+      const amount = 1001;
+      const before = await balances();
+      await distribute(amount, { participation: {} });
+      const after = await balances();
+
+      weights.forEach((weight, i) => {
+        expect(after[i] - before[i]).to.equal((BigInt(amount) * weight) / totalWeight);
+      });
+    });
+
+    it("Should split fees equally and keep rounding dust in the vault", async () => {
+      const vaultBefore = BigInt(
+        (await provider.connection.getTokenAccountBalance(governanceTokenVaultPda)).value.amount
+      );
+      const before = await balances();
+      await distribute(1001, { equal: {} });
+      const after = await balances();
+      const vaultAfter = BigInt(
+        (await provider.connection.getTokenAccountBalance(governanceTokenVaultPda)).value.amount
+      );
+
+      after.forEach((balance, i) => expect(balance - before[i]).to.equal(BigInt(333)));
+      expect(vaultBefore - vaultAfter).to.equal(BigInt(999));
+    });
+
+    it("Should fail when recipient accounts are not the members' token accounts", async () => {
+      try {
+        await program.methods
+          .distributeFees(new anchor.BN(100), { equal: {} })
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            governanceTokenVault: governanceTokenVaultPda,
+            mint: committeeTokenMint,
+            governanceAuthority: governanceAuthorityPda,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            [
+              [member1, member2TokenAccount],
+              [member2, member1TokenAccount],
+              [member3, member3TokenAccount],
+            ].flatMap(([member, tokenAccount]: [Keypair, PublicKey]) => [
+              { pubkey: committeeMemberPda(member.publicKey), isWritable: false, isSigner: false },
+              { pubkey: tokenAccount, isWritable: true, isSigner: false },
+            ])
+          )
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with InvalidFeeRecipients");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidFeeRecipients");
+      }
+    });
+  });
+
+  describe("Refund Claims", () => {
+    it("Should leave the refund claimable when the proposer account is closed", async () => {
+      const proposer = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(proposer.publicKey, 2 * LAMPORTS_PER_SOL)
+      );
+      const proposerTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        proposer,
+        committeeTokenMint,
+        proposer.publicKey
+      );
+      await mintTo(
+        provider.connection,
+        authority,
+        committeeTokenMint,
+        proposerTokenAccount,
+        authority,
+        200 * 10 ** 6
+      );
+
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const lockedBefore = governanceConfig.depositMints[0].totalDepositsLocked.toString();
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [refundClaimPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("refund_claim"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Test Refund Claim Proposal",
+          "This proposal's proposer closes their token account before finalization.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: proposer.publicKey,
+          proposerTokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([proposer])
+        .rpc();
+
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+
+      // Proposer empties and closes their token account
+      const remaining = (await provider.connection.getTokenAccountBalance(proposerTokenAccount)).value
+        .amount;
+      await burn(
+        provider.connection,
+        proposer,
+        proposerTokenAccount,
+        committeeTokenMint,
+        proposer,
+        BigInt(remaining)
+      );
+      await closeAccount(
+        provider.connection,
+        proposer,
+        proposerTokenAccount,
+        proposer.publicKey,
+        proposer
+      );
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: null,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          refundClaim: refundClaimPda,
+          payer: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          proposalOutcome: proposalOutcomePda(proposalId),
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      const proposal = await fetchProposal(program, proposalPda);
+      const refundClaim = await program.account.refundClaim.fetch(refundClaimPda);
+      expect(refundClaim.proposer.toString()).to.equal(proposer.publicKey.toString());
+      expect(refundClaim.amount.toString()).to.equal(proposal.depositRefunded.toString());
+
+      // Unclaimed refund stays locked
+      let config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.depositMints[0].totalDepositsLocked.toString()).to.equal(
+        new anchor.BN(lockedBefore).add(refundClaim.amount).toString()
+      );
+
+      // Proposer claims into a fresh token account
+      const newTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        proposer,
+        committeeTokenMint,
+        proposer.publicKey
+      );
+      await program.methods
+        .claimRefund(proposalId)
+        .accounts({
+          refundClaim: refundClaimPda,
+          governanceConfig: governanceConfigPda,
+          destinationTokenAccount: newTokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          mint: committeeTokenMint,
+          governanceAuthority: governanceAuthorityPda,
+          proposer: proposer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([proposer])
+        .rpc();
+
+      const balance = await provider.connection.getTokenAccountBalance(newTokenAccount);
+      expect(balance.value.amount).to.equal(refundClaim.amount.toString());
+      expect(await provider.connection.getAccountInfo(refundClaimPda)).to.equal(null);
+
+      config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.depositMints[0].totalDepositsLocked.toString()).to.equal(lockedBefore);
+    });
+  });
+
+  describe("Token-2022 Deposits", () => {
+    it("Should run the proposal lifecycle with a Token-2022 deposit mint", async () => {
+      const mint2022 = await createMint(
+        provider.connection,
+        authority,
+        authority.publicKey,
+        null,
+        6,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const [vault2022Pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_token_vault"), mint2022.toBuffer()],
+        program.programId
+      );
+      const member1Account2022 = await createAssociatedTokenAccount(
+        provider.connection,
+        member1,
+        mint2022,
+        member1.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await mintTo(
+        provider.connection,
+        authority,
+        mint2022,
+        member1Account2022,
+        authority,
+        1000 * 10 ** 6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await program.methods
+        .setDepositMint(new anchor.BN(100))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          depositMint: mint2022,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      await program.methods
+        .initializeTokenVault()
+        .accounts({
+          tokenVault: vault2022Pda,
+          mint: mint2022,
+          governanceConfig: governanceConfigPda,
+          governanceAuthority: governanceAuthorityPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc();
+
+      const governanceConfig = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = governanceConfig.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Token-2022 Deposit Proposal",
+          "This proposal pays its deposit with a Token-2022 mint.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1Account2022,
+          depositMint: mint2022,
+          governanceTokenVault: vault2022Pda,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      const balanceBefore = (await provider.connection.getTokenAccountBalance(member1Account2022))
+        .value.amount;
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1Account2022,
+          governanceTokenVault: vault2022Pda,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: mint2022,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ passed: {} });
+      expect(proposal.depositSettled).to.equal(true);
+
+      const balanceAfter = (await provider.connection.getTokenAccountBalance(member1Account2022))
+        .value.amount;
+      expect((BigInt(balanceAfter) - BigInt(balanceBefore)).toString()).to.equal(
+        proposal.depositRefunded.toString()
+      );
+    });
+  });
+
+  describe("Deposit Escrow Mode", () => {
+    const setDepositEscrowEnabled = (depositEscrowEnabled: boolean) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod: null,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode: null,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement: null,
+          memberStakeCooldown: null,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize: null,
+          maxActiveProposals: null,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos: null,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    after(async () => {
+      await setDepositEscrowEnabled(false);
+    });
+
+    it("Should hold the deposit in a per-proposal escrow and close it at finalization", async () => {
+      await setDepositEscrowEnabled(true);
+
+      let config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const lockedBefore = config.depositMints[0].totalDepositsLocked.toString();
+      const treasuryBefore = config.depositMints[0].treasuryBalance;
+      const vaultBefore = BigInt(
+        (await provider.connection.getTokenAccountBalance(governanceTokenVaultPda)).value.amount
+      );
+
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [depositEscrowPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal_escrow"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Escrowed Deposit Proposal",
+          "This proposal holds its deposit in its own escrow account.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          depositEscrow: depositEscrowPda,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      // The deposit sits in the escrow, not in the pooled vault
+      let proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.depositEscrowed).to.equal(true);
+      const escrowBalance = await provider.connection.getTokenAccountBalance(depositEscrowPda);
+      expect(escrowBalance.value.amount).to.equal(proposal.depositAmount.toString());
+      const vaultAfterCreate = BigInt(
+        (await provider.connection.getTokenAccountBalance(governanceTokenVaultPda)).value.amount
+      );
+      expect(vaultAfterCreate).to.equal(vaultBefore);
+      config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.depositMints[0].totalDepositsLocked.toString()).to.equal(lockedBefore);
+
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      const proposerBalanceBefore = BigInt(
+        (await provider.connection.getTokenAccountBalance(member1TokenAccount)).value.amount
+      );
+      const escrowRent = (await provider.connection.getAccountInfo(depositEscrowPda)).lamports;
+      const proposerLamportsBefore = await provider.connection.getBalance(member1.publicKey);
+
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          depositEscrow: depositEscrowPda,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ passed: {} });
+      expect(proposal.depositSettled).to.equal(true);
+
+      // Refund came out of the escrow, the fee moved into the pooled vault
+      const proposerBalanceAfter = BigInt(
+        (await provider.connection.getTokenAccountBalance(member1TokenAccount)).value.amount
+      );
+      expect((proposerBalanceAfter - proposerBalanceBefore).toString()).to.equal(
+        proposal.depositRefunded.toString()
+      );
+      const vaultAfter = BigInt(
+        (await provider.connection.getTokenAccountBalance(governanceTokenVaultPda)).value.amount
+      );
+      expect((vaultAfter - vaultBefore).toString()).to.equal(proposal.depositFee.toString());
+
+      config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.depositMints[0].totalDepositsLocked.toString()).to.equal(lockedBefore);
+      expect(config.depositMints[0].treasuryBalance.sub(treasuryBefore).toString()).to.equal(
+        proposal.depositFee.toString()
+      );
+
+      // Escrow is closed and its rent returned to the proposer
+      expect(await provider.connection.getAccountInfo(depositEscrowPda)).to.equal(null);
+      const proposerLamportsAfter = await provider.connection.getBalance(member1.publicKey);
+      expect(proposerLamportsAfter - proposerLamportsBefore).to.equal(escrowRent);
+    });
+
+    it("Should fail to create an escrowed proposal without the escrow account", async () => {
+      await setDepositEscrowEnabled(true);
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createProposal(
+            proposalId,
+            "Missing Escrow Proposal",
+            "This proposal omits its escrow account.",
+            { configUpdate: {} },
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
+          )
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            proposer: member1.publicKey,
+            proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            depositEscrow: null,
+            governanceAuthority: governanceAuthorityPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with DepositEscrowMismatch");
+      } catch (error) {
+        expect(error.toString()).to.include("DepositEscrowMismatch");
+      }
+    });
+  });
+
+  describe("Native SOL Deposits", () => {
+    const nativeDepositMint = SystemProgram.programId;
+    const nativeMinDeposit = new anchor.BN(LAMPORTS_PER_SOL / 10);
+    const [nativeDepositVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("native_deposit_vault")],
+      program.programId
+    );
+
+    const setDepositKind = (depositKind: object) =>
+      program.methods
+        .setDepositKind(depositKind as any, nativeMinDeposit)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const nativeDepositEntry = async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      return config.depositMints.find((depositMint) => depositMint.mint.equals(nativeDepositMint));
+    };
+
+    before(async () => {
+      await program.methods
+        .initializeNativeDepositVault()
+        .accounts({
+          nativeDepositVault: nativeDepositVaultPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    after(async () => {
+      await setDepositKind({ splToken: {} });
+    });
+
+    it("Should take and refund a lamport deposit without any token accounts", async () => {
+      await setDepositKind({ nativeSol: {} });
+
+      let config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.depositKind).to.deep.equal({ nativeSol: {} });
+      const lockedBefore = (await nativeDepositEntry()).totalDepositsLocked.toString();
+
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      const vaultLamportsBefore = await provider.connection.getBalance(nativeDepositVaultPda);
+
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Native SOL Deposit Proposal",
+          "This proposal posts its deposit in lamports.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: null,
+          depositMint: null,
+          governanceTokenVault: null,
+          nativeDepositVault: nativeDepositVaultPda,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: null,
+        })
+        .signers([member1])
+        .rpc();
+
+      let proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.depositMint.equals(nativeDepositMint)).to.equal(true);
+      expect(proposal.depositAmount.toString()).to.equal(nativeMinDeposit.toString());
+      const vaultLamportsAfterCreate = await provider.connection.getBalance(nativeDepositVaultPda);
+      expect(vaultLamportsAfterCreate - vaultLamportsBefore).to.equal(nativeMinDeposit.toNumber());
+      const lockedAfterCreate = (await nativeDepositEntry()).totalDepositsLocked;
+      expect(lockedAfterCreate.sub(new anchor.BN(lockedBefore)).toString()).to.equal(
+        nativeMinDeposit.toString()
+      );
+
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      const treasuryBefore = (await nativeDepositEntry()).treasuryBalance;
+      const proposerLamportsBefore = await provider.connection.getBalance(member1.publicKey);
+
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: null,
+          governanceTokenVault: null,
+          nativeDepositVault: nativeDepositVaultPda,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: null,
+          tokenProgram: null,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ passed: {} });
+      expect(proposal.depositSettled).to.equal(true);
+
+      // Same fee math as token deposits: the refund is paid in lamports, the fee stays in the vault
+      const proposerLamportsAfter = await provider.connection.getBalance(member1.publicKey);
+      expect(proposerLamportsAfter - proposerLamportsBefore).to.equal(
+        proposal.depositRefunded.toNumber()
+      );
+      expect(proposal.depositFee.toString()).to.equal(nativeMinDeposit.divn(10).toString());
+      const vaultLamportsAfter = await provider.connection.getBalance(nativeDepositVaultPda);
+      expect(vaultLamportsAfter - vaultLamportsBefore).to.equal(proposal.depositFee.toNumber());
+
+      const entry = await nativeDepositEntry();
+      expect(entry.totalDepositsLocked.toString()).to.equal(lockedBefore);
+      expect(entry.treasuryBalance.sub(treasuryBefore).toString()).to.equal(
+        proposal.depositFee.toString()
+      );
+    });
+
+    it("Should fail to create a token deposit proposal in native SOL mode", async () => {
+      await setDepositKind({ nativeSol: {} });
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createProposal(
+            proposalId,
+            "Token Deposit In Native Mode",
+            "This proposal offers a token deposit while the deployment takes SOL.",
+            { configUpdate: {} },
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
+          )
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            proposer: member1.publicKey,
+            proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            governanceAuthority: governanceAuthorityPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with NativeDepositAccountsRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("NativeDepositAccountsRequired");
+      }
+    });
+  });
+
+  describe("Committee Member Status", () => {
+    const setMemberActive = (member: PublicKey, active: boolean) =>
+      program.methods
+        .setMemberActive(member, active)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(member),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const createProposal = async (title: string) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "This proposal checks how suspended committee members are counted.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    const castVote = async (proposalId: anchor.BN, proposalPda: PublicKey) => {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member3.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .castVote(proposalId, { yes: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: votePda,
+          governanceConfig: governanceConfigPda,
+          voter: member3.publicKey,
+          voterTokenAccount: member3TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member3])
+        .rpc();
+      return votePda;
+    };
+
+    const finalize = (proposalId: anchor.BN, proposalPda: PublicKey, votePdas: PublicKey[]) =>
+      program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+    const memberTermAccounts = () => ({
+      governanceConfig: governanceConfigPda,
+      committeeMember: committeeMemberPda(member3.publicKey),
+      authority: authority.publicKey,
+    });
+
+    const chainTime = async () =>
+      provider.connection.getBlockTime(await provider.connection.getSlot());
+
+    it("Should store committee member metadata", async () => {
+      await program.methods
+        .setMemberDisplayName(member3.publicKey, "Member Three")
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(member3.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const committeeMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(member3.publicKey)
+      );
+      expect(committeeMember.displayName).to.equal("Member Three");
+      expect(committeeMember.active).to.equal(true);
+      expect(committeeMember.joinedAt.toNumber()).to.be.greaterThan(0);
+
+      try {
+        await program.methods
+          .setMemberDisplayName(member3.publicKey, "x".repeat(33))
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            committeeMember: committeeMemberPda(member3.publicKey),
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with InvalidMemberDisplayName");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidMemberDisplayName");
+      }
+    });
+
+    it("Should keep a suspended member's prior votes but reject new ones", async () => {
+      const earlier = await createProposal("Voted Before Suspension");
+      const later = await createProposal("Proposed During Suspension");
+      const earlierVotePda = await castVote(earlier.proposalId, earlier.proposalPda);
+
+      await setMemberActive(member3.publicKey, false);
+      const committeeMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(member3.publicKey)
+      );
+      expect(committeeMember.active).to.equal(false);
+
+      try {
+        await castVote(later.proposalId, later.proposalPda);
+        expect.fail("Should have failed with MemberSuspended");
+      } catch (error) {
+        expect(error.toString()).to.include("MemberSuspended");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await finalize(earlier.proposalId, earlier.proposalPda, [earlierVotePda]);
+      await finalize(later.proposalId, later.proposalPda, []);
+
+      const vote = await program.account.vote.fetch(earlierVotePda);
+      const proposal = await fetchProposal(program, earlier.proposalPda);
+      expect(proposal.yesVotes.toString()).to.equal(
+        vote.tokenBalanceSnapshot.div(new anchor.BN(10 ** 9)).toString()
+      );
+
+      await setMemberActive(member3.publicKey, true);
+    });
+
+    it("Should reject a term end in the past", async () => {
+      try {
+        await program.methods
+          .setMemberTerm(member3.publicKey, new anchor.BN((await chainTime()) - 1))
+          .accounts(memberTermAccounts())
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with InvalidMemberTerm");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidMemberTerm");
+      }
+    });
+
+    it("Should accept votes until the term ends and renew it afterwards", async () => {
+      const termEnd = (await chainTime()) + 4;
+      await program.methods
+        .setMemberTerm(member3.publicKey, new anchor.BN(termEnd))
+        .accounts(memberTermAccounts())
+        .signers([authority])
+        .rpc();
+
+      // One second before expiry the member still votes
+      const beforeExpiry = await createProposal("Voted Before Term End");
+      while ((await chainTime()) < termEnd - 1) {
+        await new Promise((resolve) => setTimeout(resolve, 200));
+      }
+      const beforeExpiryVotePda = await castVote(beforeExpiry.proposalId, beforeExpiry.proposalPda);
+
+      // One second after expiry the member is refused
+      while ((await chainTime()) < termEnd + 1) {
+        await new Promise((resolve) => setTimeout(resolve, 200));
+      }
+      const afterExpiry = await createProposal("Proposed After Term End");
+      try {
+        await castVote(afterExpiry.proposalId, afterExpiry.proposalPda);
+        expect.fail("Should have failed with MemberTermExpired");
+      } catch (error) {
+        expect(error.toString()).to.include("MemberTermExpired");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await finalize(beforeExpiry.proposalId, beforeExpiry.proposalPda, [beforeExpiryVotePda]);
+      await finalize(afterExpiry.proposalId, afterExpiry.proposalPda, []);
+
+      await program.methods
+        .renewMemberTerm(member3.publicKey)
+        .accounts(memberTermAccounts())
+        .signers([authority])
+        .rpc();
+      const committeeMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(member3.publicKey)
+      );
+      // An expired term restarts from the renewal time
+      expect(committeeMember.termEnd.toNumber()).to.be.greaterThan(
+        (await chainTime()) + 179 * 24 * 60 * 60
+      );
+    });
+  });
+
+  describe("Total Voting Power Sync", () => {
+    const sync = (remainingAccounts = committeeRemainingAccounts()) =>
+      program.methods
+        .syncTotalVotingPower()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+
+    it("Should sync the total voting power that finalize computes", async () => {
+      let config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const snapshotAtCreation = config.totalVotingPower.toString();
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Synced Voting Power Proposal",
+          "This proposal compares the synced voting power with finalization.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      let proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.totalVotingPowerSnapshot.toString()).to.equal(snapshotAtCreation);
+
+      // Sync after the deposit left the proposer's balance, as finalize will see it
+      await sync();
+      config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.totalVotingPowerSyncedAt.toNumber()).to.be.greaterThan(0);
+      const balances = await Promise.all(
+        [member1TokenAccount, member2TokenAccount, member3TokenAccount].map(async (account) =>
+          BigInt((await provider.connection.getTokenAccountBalance(account)).value.amount)
+        )
+      );
+      const expected = balances.reduce(
+        (sum, balance) => sum + balance / BigInt(10 ** 9),
+        BigInt(0)
+      );
+      expect(config.totalVotingPower.toString()).to.equal(expected.toString());
+
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member2.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .castVote(proposalId, { yes: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: votePda,
+          governanceConfig: governanceConfigPda,
+          voter: member2.publicKey,
+          voterTokenAccount: member2TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member2])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          { pubkey: votePda, isWritable: false, isSigner: false },
+        ])
+        .signers([authority])
+        .rpc();
+
+      // Participation was measured against the same total the sync recorded
+      proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.participationRate).to.equal(
+        proposal.totalVotes.muln(10000).div(config.totalVotingPower).toNumber()
+      );
+    });
+
+    it("Should reject token accounts that are not the members' canonical ATAs", async () => {
+      const remainingAccounts = committeeRemainingAccounts();
+      [remainingAccounts[1].pubkey, remainingAccounts[3].pubkey] = [
+        remainingAccounts[3].pubkey,
+        remainingAccounts[1].pubkey,
+      ];
+      try {
+        await sync(remainingAccounts);
+        expect.fail("Should have failed with InvalidTokenAccount");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidTokenAccount");
+      }
+    });
+  });
+
+  describe("Manual Total Voting Power", () => {
+    const update = (totalVotingPower: anchor.BN) =>
+      program.methods
+        .updateTotalVotingPower(totalVotingPower)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+
+    const syncedTotal = async () => {
+      await program.methods
+        .syncTotalVotingPower()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .rpc();
+      return (await program.account.governanceConfig.fetch(governanceConfigPda)).totalVotingPower;
+    };
+
+    after(async () => {
+      await syncedTotal();
+    });
+
+    it("Should not exceed the committee token supply", async () => {
+      const mint = await getMint(provider.connection, committeeTokenMint);
+      const supplyVotingPower = new anchor.BN((mint.supply / BigInt(10 ** mint.decimals)).toString());
+      try {
+        await update(supplyVotingPower.addn(1));
+        expect.fail("Should have failed with VotingPowerExceedsSupply");
+      } catch (error) {
+        expect(error.toString()).to.include("VotingPowerExceedsSupply");
+      }
+
+      await update(supplyVotingPower);
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.totalVotingPower.toString()).to.equal(supplyVotingPower.toString());
+    });
+
+    it("Should warn when the value diverges from member balances beyond the tolerance", async () => {
+      const computed = await syncedTotal();
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.votingPowerToleranceBps).to.equal(500);
+
+      // Within 5% of the member balances: no warning
+      const withinTolerance = computed.muln(9600).divn(10000);
+      let events = await decodeEvents(await update(withinTolerance));
+      expect(events.map((event) => event.name)).to.not.include("totalVotingPowerDivergence");
+
+      // An admin typo is still accepted in test mode, but flagged
+      events = await decodeEvents(await update(new anchor.BN(1)));
+      const divergence = events.find((event) => event.name === "totalVotingPowerDivergence");
+      expect(divergence).to.not.be.undefined;
+      expect(divergence.data.totalVotingPower.toNumber()).to.equal(1);
+      expect(divergence.data.computedVotingPower.toString()).to.equal(computed.toString());
+      expect(divergence.data.toleranceBps).to.equal(500);
+    });
+  });
+
+  describe("Committee Member Inactivity", () => {
+    const createAndFinalize = async (title: string) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "This proposal finalizes without any committee votes.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member3.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      return [
+        { pubkey: proposalPda, isWritable: false, isSigner: false },
+        { pubkey: votePda, isWritable: false, isSigner: false },
+      ];
+    };
+
+    const flagInactive = (missed: object[]) =>
+      program.methods
+        .flagInactiveMember(member3.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(member3.publicKey),
+          memberTokenAccount: member3TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(missed as any)
+        .rpc();
+
+    it("Should suspend a member after three missed proposals until they reactivate", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.inactivityThreshold).to.equal(3);
+
+      // Proposals only count when opened after the member's last vote
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      const missed: object[] = [];
+      for (const title of ["Missed Proposal 1", "Missed Proposal 2", "Missed Proposal 3"]) {
+        missed.push(...(await createAndFinalize(title)));
+      }
+
+      try {
+        await flagInactive(missed.slice(0, 4));
+        expect.fail("Should have failed with InsufficientMissedProposals");
+      } catch (error) {
+        expect(error.toString()).to.include("InsufficientMissedProposals");
+      }
+
+      // The same proposal cannot be shown twice
+      try {
+        await flagInactive([...missed.slice(0, 4), ...missed.slice(0, 2)]);
+        expect.fail("Should have failed with InvalidInactivityEvidence");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidInactivityEvidence");
+      }
+
+      const memberPower = BigInt(
+        (await provider.connection.getTokenAccountBalance(member3TokenAccount)).value.amount
+      ) / BigInt(10 ** 9);
+      const powerBefore = BigInt(config.totalVotingPower.toString());
+      await flagInactive(missed);
+
+      let committeeMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(member3.publicKey)
+      );
+      expect(committeeMember.active).to.equal(false);
+      expect(committeeMember.suspendedForInactivity).to.equal(true);
+      let updated = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(updated.totalVotingPower.toString()).to.equal((powerBefore - memberPower).toString());
+
+      await program.methods
+        .reactivateCommitteeMember()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(member3.publicKey),
+          member: member3.publicKey,
+          memberTokenAccount: member3TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member3])
+        .rpc();
+
+      committeeMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(member3.publicKey)
+      );
+      expect(committeeMember.active).to.equal(true);
+      expect(committeeMember.suspendedForInactivity).to.equal(false);
+      updated = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(updated.totalVotingPower.toString()).to.equal(powerBefore.toString());
+
+      // The miss window restarted, so the old proposals no longer count
+      try {
+        await flagInactive(missed);
+        expect.fail("Should have failed with InvalidInactivityEvidence");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidInactivityEvidence");
+      }
+    });
+  });
+
+  describe("Governance Limits", () => {
+    const newcomer = Keypair.generate();
+
+    const setLimits = (maxCommitteeSize: number | null, maxActiveProposals: number | null) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod: null,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode: null,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled: null,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement: null,
+          memberStakeCooldown: null,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize,
+          maxActiveProposals,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos: null,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const createProposal = async (title: string) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "This proposal checks the active proposal limit.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    const expectError = async (action: () => Promise<unknown>, errorName: string) => {
+      try {
+        await action();
+        expect.fail(`Should have failed with ${errorName}`);
+      } catch (error) {
+        expect(error.toString()).to.include(errorName);
+      }
+    };
+
+    after(async () => {
+      await setLimits(32, 50);
+    });
+
+    it("Should cap the committee at max_committee_size", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const memberCount = config.committeeMemberCount;
+      expect(config.maxCommitteeSize).to.equal(32);
+
+      for (const maxCommitteeSize of [0, memberCount - 1, 33]) {
+        await expectError(() => setLimits(maxCommitteeSize, null), "InvalidGovernanceLimit");
+      }
+
+      const addNewcomer = () =>
+        program.methods
+          .addCommitteeMember(newcomer.publicKey)
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            committeeMember: committeeMemberPda(newcomer.publicKey),
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+      await setLimits(memberCount, null);
+      await expectError(addNewcomer, "CommitteeFull");
+
+      await setLimits(memberCount + 1, null);
+      const events: any[] = [];
+      const listener = program.addEventListener("committeeSizeLimitReached", (event) => {
+        events.push(event);
+      });
+      await addNewcomer();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      expect(events).to.have.length(1);
+      expect(events[0].memberCount).to.equal(memberCount + 1);
+
+      await program.methods
+        .removeCommitteeMember(newcomer.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(newcomer.publicKey),
+          memberStake: memberStakePda(newcomer.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should refuse new proposals once max_active_proposals are pending", async () => {
+      await expectError(() => setLimits(null, 0), "InvalidGovernanceLimit");
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const pending = config.pendingProposalCount.toNumber();
+      await setLimits(null, pending + 1);
+
+      const events: any[] = [];
+      const listener = program.addEventListener("proposalLimitReached", (event) => {
+        events.push(event);
+      });
+      const { proposalId, proposalPda } = await createProposal("Last Proposal Under The Limit");
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      expect(events).to.have.length(1);
+      expect(events[0].activeProposals.toNumber()).to.equal(pending + 1);
+
+      await expectError(
+        () => createProposal("Proposal Over The Limit"),
+        "ActiveProposalLimitReached"
+      );
+      const getState = () =>
+        program.methods
+          .getGovernanceState()
+          .accounts({ governanceConfig: governanceConfigPda, ruleRegistry: null })
+          .view();
+      const full = await getState();
+      expect(full.pendingProposalCount.toNumber()).to.equal(pending + 1);
+      expect(full.proposalsPaused).to.equal(true);
+
+      // Finalizing frees a slot again
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+      const updated = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(updated.pendingProposalCount.toNumber()).to.equal(pending);
+      const freed = await getState();
+      expect(freed.pendingProposalCount.toNumber()).to.equal(pending);
+      expect(freed.proposalsPaused).to.equal(false);
+    });
+  });
+
+  describe("Committee Epochs", () => {
+    const newcomer = Keypair.generate();
+
+    const committeeEpoch = async () =>
+      (await program.account.governanceConfig.fetch(governanceConfigPda)).committeeEpoch.toNumber();
+
+    it("Should advance the committee epoch on every membership change", async () => {
+      const events: any[] = [];
+      const listener = program.addEventListener("committeeChanged", (event) => {
+        events.push(event);
+      });
+      const startEpoch = await committeeEpoch();
+
+      await program.methods
+        .addCommitteeMember(newcomer.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(newcomer.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      expect(await committeeEpoch()).to.equal(startEpoch + 1);
+
+      for (const active of [false, true]) {
+        await program.methods
+          .setMemberActive(newcomer.publicKey, active)
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            committeeMember: committeeMemberPda(newcomer.publicKey),
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+      }
+      expect(await committeeEpoch()).to.equal(startEpoch + 3);
+
+      await program.methods
+        .removeCommitteeMember(newcomer.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(newcomer.publicKey),
+          memberStake: memberStakePda(newcomer.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      expect(await committeeEpoch()).to.equal(startEpoch + 4);
+
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+      expect(events.map((event) => event.epoch.toNumber())).to.deep.equal([
+        startEpoch + 1,
+        startEpoch + 2,
+        startEpoch + 3,
+        startEpoch + 4,
+      ]);
+      expect(events.map((event) => Object.keys(event.kind)[0])).to.deep.equal([
+        "added",
+        "suspended",
+        "reactivated",
+        "removed",
+      ]);
+      expect(events.every((event) => event.member.equals(newcomer.publicKey))).to.equal(true);
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(events[3].memberCount).to.equal(config.committeeMemberCount);
+    });
+
+    it("Should record the committee epoch on new proposals", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Committee Epoch Proposal",
+          "This proposal records the committee epoch it was created under.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.committeeEpoch.toString()).to.equal(config.committeeEpoch.toString());
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+    });
+  });
+
+  describe("Committee Alternates", () => {
+    const primary = Keypair.generate();
+    const alternate = Keypair.generate();
+    let alternateTokenAccount: PublicKey;
+
+    const createProposal = async (title: string) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "This proposal checks which committee an alternate belongs to.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    const castAlternateVote = async (proposalId: anchor.BN, proposalPda: PublicKey) => {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          alternate.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .castVote(proposalId, { yes: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: votePda,
+          governanceConfig: governanceConfigPda,
+          voter: alternate.publicKey,
+          voterTokenAccount: alternateTokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([alternate])
+        .rpc();
+      return votePda;
+    };
+
+    // The fourth seat is the alternate's once activated
+    const finalize = (proposalId: anchor.BN, proposalPda: PublicKey, votePdas: PublicKey[]) =>
+      program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          {
+            pubkey: committeeMemberPda(alternate.publicKey),
+            isWritable: false,
+            isSigner: false,
+          },
+          { pubkey: alternateTokenAccount, isWritable: false, isSigner: false },
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+    const activateAlternate = () =>
+      program.methods
+        .activateAlternate(primary.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          primaryMember: committeeMemberPda(primary.publicKey),
+          alternateMember: committeeMemberPda(alternate.publicKey),
+          primaryStake: memberStakePda(primary.publicKey),
+          alternateStake: null,
+          alternate: alternate.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([alternate])
+        .rpc();
+
+    before(async () => {
+      for (const [keypair, amount] of [
+        [primary, 100],
+        [alternate, 400],
+      ] as [Keypair, number][]) {
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(keypair.publicKey, 2 * LAMPORTS_PER_SOL)
+        );
+        const tokenAccount = await createAssociatedTokenAccount(
+          provider.connection,
+          keypair,
+          committeeTokenMint,
+          keypair.publicKey
+        );
+        await mintTo(
+          provider.connection,
+          authority,
+          committeeTokenMint,
+          tokenAccount,
+          authority,
+          amount * 10 ** 9
+        );
+        if (keypair === alternate) {
+          alternateTokenAccount = tokenAccount;
+        }
+      }
+
+      await program.methods
+        .addCommitteeMember(primary.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(primary.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      await program.methods
+        .setMemberAlternate(primary.publicKey, alternate.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(primary.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .removeCommitteeMember(alternate.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(alternate.publicKey),
+          memberStake: memberStakePda(alternate.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should seat the alternate only for proposals created after the takeover", async () => {
+      try {
+        await activateAlternate();
+        expect.fail("Should have failed with PrimaryMemberActive");
+      } catch (error) {
+        expect(error.toString()).to.include("PrimaryMemberActive");
+      }
+
+      // While the primary holds the seat the alternate cannot vote
+      const earlier = await createProposal("Created Before Takeover");
+      try {
+        await castAlternateVote(earlier.proposalId, earlier.proposalPda);
+        expect.fail("Should have failed with NotCommitteeMember");
+      } catch (error) {
+        expect(error.toString()).to.include("NotCommitteeMember");
+      }
+
+      await program.methods
+        .setMemberActive(primary.publicKey, false)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(primary.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      const primaryMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(primary.publicKey)
+      );
+      const configBefore = await program.account.governanceConfig.fetch(governanceConfigPda);
+
+      // The takeover must land in a later second than the earlier proposal
+      await new Promise((resolve) => setTimeout(resolve, 1500));
+      await activateAlternate();
+
+      const alternateMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(alternate.publicKey)
+      );
+      expect(alternateMember.termEnd.toString()).to.equal(primaryMember.termEnd.toString());
+      expect(alternateMember.substitutesFor.toString()).to.equal(primary.publicKey.toString());
+      expect(
+        await provider.connection.getAccountInfo(committeeMemberPda(primary.publicKey))
+      ).to.equal(null);
+      const configAfter = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(configAfter.committeeMemberCount).to.equal(configBefore.committeeMemberCount);
+
+      // Older proposals keep their original committee
+      try {
+        await castAlternateVote(earlier.proposalId, earlier.proposalPda);
+        expect.fail("Should have failed with AlternateNotInSnapshot");
+      } catch (error) {
+        expect(error.toString()).to.include("AlternateNotInSnapshot");
+      }
+
+      const later = await createProposal("Created After Takeover");
+      const laterVotePda = await castAlternateVote(later.proposalId, later.proposalPda);
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await finalize(earlier.proposalId, earlier.proposalPda, []);
+      await finalize(later.proposalId, later.proposalPda, [laterVotePda]);
+
+      const earlierProposal = await fetchProposal(program, earlier.proposalPda);
+      expect(earlierProposal.participationRate).to.equal(0);
+      const laterProposal = await fetchProposal(program, later.proposalPda);
+      expect(laterProposal.yesVotes.toString()).to.equal("400");
+      expect(laterProposal.participationRate).to.be.greaterThan(0);
+    });
+  });
+
+  describe("Member Stakes", () => {
+    const staker = Keypair.generate();
+    const stakeRequirement = new anchor.BN(200 * 10 ** 9);
+    let stakerTokenAccount: PublicKey;
+    let stakeVaultPda: PublicKey;
+
+    const setStakeConfig = (
+      memberStakeRequirement: anchor.BN,
+      memberStakeCooldown: anchor.BN
+    ) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod: null,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode: null,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled: null,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement,
+          memberStakeCooldown,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize: null,
+          maxActiveProposals: null,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos: null,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const addStaker = (memberStake: PublicKey | null) =>
+      program.methods
+        .addCommitteeMember(staker.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(staker.publicKey),
+          memberStake,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const withdrawStake = () =>
+      program.methods
+        .withdrawMemberStake()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          memberStake: memberStakePda(staker.publicKey),
+          committeeMember: committeeMemberPda(staker.publicKey),
+          stakeVault: stakeVaultPda,
+          memberTokenAccount: stakerTokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          governanceAuthority: governanceAuthorityPda,
+          member: staker.publicKey,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([staker])
+        .rpc();
+
+    const expectStakeLocked = async () => {
+      try {
+        await withdrawStake();
+        expect.fail("Should have failed with MemberStakeLocked");
+      } catch (error) {
+        expect(error.toString()).to.include("MemberStakeLocked");
+      }
+    };
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(staker.publicKey, 2 * LAMPORTS_PER_SOL)
+      );
+      stakerTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        staker,
+        committeeTokenMint,
+        staker.publicKey
+      );
+      await mintTo(
+        provider.connection,
+        authority,
+        committeeTokenMint,
+        stakerTokenAccount,
+        authority,
+        300 * 10 ** 9
+      );
+      [stakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("member_stake_vault"), staker.publicKey.toBuffer()],
+        program.programId
+      );
+      await setStakeConfig(stakeRequirement, new anchor.BN(2));
+    });
+
+    after(async () => {
+      await setStakeConfig(new anchor.BN(0), new anchor.BN(7 * 24 * 60 * 60));
+    });
+
+    it("Should seat only staked members and release the stake after the cooldown", async () => {
+      try {
+        await addStaker(null);
+        expect.fail("Should have failed with MemberStakeRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("MemberStakeRequired");
+      }
+
+      await program.methods
+        .stakeForMembership()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          memberStake: memberStakePda(staker.publicKey),
+          stakeVault: stakeVaultPda,
+          memberTokenAccount: stakerTokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          governanceAuthority: governanceAuthorityPda,
+          member: staker.publicKey,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([staker])
+        .rpc();
+
+      const memberStake = await program.account.memberStake.fetch(
+        memberStakePda(staker.publicKey)
+      );
+      expect(memberStake.amount.toString()).to.equal(stakeRequirement.toString());
+      const vaultBalance = await provider.connection.getTokenAccountBalance(stakeVaultPda);
+      expect(vaultBalance.value.amount).to.equal(stakeRequirement.toString());
+
+      // The stake stays locked for the cooldown after staking
+      await expectStakeLocked();
+
+      await addStaker(memberStakePda(staker.publicKey));
+      const committeeMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(staker.publicKey)
+      );
+      expect(committeeMember.stakedAmount.toString()).to.equal(stakeRequirement.toString());
+
+      // A seated member cannot withdraw even once the staking cooldown has passed
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await expectStakeLocked();
+
+      await program.methods
+        .removeCommitteeMember(staker.publicKey)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(staker.publicKey),
+          memberStake: memberStakePda(staker.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      // Removal restarts the cooldown
+      await expectStakeLocked();
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await withdrawStake();
+
+      const stakerBalance = await provider.connection.getTokenAccountBalance(stakerTokenAccount);
+      expect(stakerBalance.value.amount).to.equal((300 * 10 ** 9).toString());
+      expect(
+        await provider.connection.getAccountInfo(memberStakePda(staker.publicKey))
+      ).to.equal(null);
+      expect(await provider.connection.getAccountInfo(stakeVaultPda)).to.equal(null);
+    });
+  });
+
+  describe("Program Upgrade Proposals", () => {
+    // The CPI caller test program doubles as the dummy upgradeable program
+    const LOADER_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+    const BUFFER_METADATA_SIZE = 37;
+    const WRITE_CHUNK_SIZE = 900;
+    const dummyProgramId = anchor.workspace.GovernanceCpiCaller.programId as PublicKey;
+    const [dummyProgramData] = PublicKey.findProgramAddressSync(
+      [dummyProgramId.toBuffer()],
+      LOADER_ID
+    );
+    const payer = ((provider as anchor.AnchorProvider).wallet as anchor.Wallet).payer;
+    let buffer: Keypair;
+
+    const u32 = (value: number) => {
+      const bytes = Buffer.alloc(4);
+      bytes.writeUInt32LE(value);
+      return bytes;
+    };
+    const loaderInstruction = (data: Buffer, keys: anchor.web3.AccountMeta[]) =>
+      new TransactionInstruction({ programId: LOADER_ID, keys, data });
+    // SetAuthority (instruction 4): account, current authority, new authority
+    const setAuthority = (account: PublicKey, newAuthority: PublicKey) =>
+      provider.sendAndConfirm(
+        new Transaction().add(
+          loaderInstruction(u32(4), [
+            { pubkey: account, isSigner: false, isWritable: true },
+            { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+            { pubkey: newAuthority, isSigner: false, isWritable: false },
+          ])
+        )
+      );
+
+    const writeBuffer = async (programBytes: Buffer) => {
+      const bufferAccount = Keypair.generate();
+      const space = BUFFER_METADATA_SIZE + programBytes.length;
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: bufferAccount.publicKey,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+            space,
+            programId: LOADER_ID,
+          }),
+          // InitializeBuffer (instruction 0)
+          loaderInstruction(u32(0), [
+            { pubkey: bufferAccount.publicKey, isSigner: false, isWritable: true },
+            { pubkey: payer.publicKey, isSigner: false, isWritable: false },
+          ])
+        ),
+        [bufferAccount]
+      );
+
+      // Write (instruction 1): offset (u32) and bytes (u64 length prefix)
+      const writes: Promise<string>[] = [];
+      for (let offset = 0; offset < programBytes.length; offset += WRITE_CHUNK_SIZE) {
+        const chunk = programBytes.subarray(offset, offset + WRITE_CHUNK_SIZE);
+        const length = Buffer.alloc(8);
+        length.writeBigUInt64LE(BigInt(chunk.length));
+        writes.push(
+          provider.sendAndConfirm(
+            new Transaction().add(
+              loaderInstruction(Buffer.concat([u32(1), u32(offset), length, chunk]), [
+                { pubkey: bufferAccount.publicKey, isSigner: false, isWritable: true },
+                { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+              ])
+            )
+          )
+        );
+        if (writes.length === 20) {
+          await Promise.all(writes.splice(0));
+        }
+      }
+      await Promise.all(writes);
+      return bufferAccount;
+    };
+
+    const createUpgradeProposal = async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Program Upgrade",
+          "This proposal upgrades the dummy program from a governance-owned buffer.",
+          { programUpgrade: {} },
+          {
+            executionData: {
+              programUpgrade: { 0: { programId: dummyProgramId, buffer: buffer.publicKey } },
+            } as any,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          upgradeBuffer: buffer.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    before(async () => {
+      const programBytes = fs.readFileSync(
+        path.join(__dirname, "../target/deploy/governance_cpi_caller.so")
+      );
+      buffer = await writeBuffer(programBytes);
+    });
+
+    it("Should reject an upgrade buffer the governance authority does not own", async () => {
+      try {
+        await createUpgradeProposal();
+        expect.fail("Should have failed with InvalidUpgradeBuffer");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidUpgradeBuffer");
+      }
+    });
+
+    it("Should upgrade the program once the proposal passes", async () => {
+      await setAuthority(buffer.publicKey, governanceAuthorityPda);
+      await setAuthority(dummyProgramData, governanceAuthorityPda);
+      const programDataBefore = await provider.connection.getAccountInfo(dummyProgramData);
+      const deploySlotBefore = programDataBefore.data.readBigUInt64LE(4);
+
+      const { proposalId, proposalPda } = await createUpgradeProposal();
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+      await program.methods
+        .executeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          dependencyProposal: null,
+          committeeMember: null,
+          memberStake: null,
+          payer: authority.publicKey,
+          systemProgram: null,
+          upgradeProgram: dummyProgramId,
+          upgradeProgramData: dummyProgramData,
+          upgradeBuffer: buffer.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          bpfLoaderUpgradeable: LOADER_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .signers([authority])
+        .rpc();
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ executed: {} });
+      // ProgramData: variant (u32), deploy slot (u64), upgrade authority (Option<Pubkey>)
+      const programDataAfter = await provider.connection.getAccountInfo(dummyProgramData);
+      expect(programDataAfter.data.readBigUInt64LE(4) > deploySlotBefore).to.equal(true);
+      const upgradeAuthority = new PublicKey(programDataAfter.data.subarray(13, 45));
+      expect(upgradeAuthority.equals(governanceAuthorityPda)).to.equal(true);
+      expect(await provider.connection.getAccountInfo(buffer.publicKey)).to.equal(null);
+    });
+  });
+
+  describe("Execution Adapters", () => {
+    const adapterProgram = anchor.workspace.GovernanceReferenceAdapter as Program<any>;
+    const [registryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("execution_adapter_registry")],
+      program.programId
+    );
+    const [adapterAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("adapter_authority")],
+      program.programId
+    );
+
+    const setAdapter = (adapter: PublicKey | null) =>
+      program.methods
+        .setExecutionAdapter({ configUpdate: {} }, adapter)
+        .accounts({
+          executionAdapterRegistry: registryPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    // Creates a config update proposal, passes it with member1 and member2, and finalizes it
+    const passConfigProposal = async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Adapter Proposal",
+          "This proposal is executed through the registered execution adapter.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    const execute = (
+      proposalId: anchor.BN,
+      proposalPda: PublicKey,
+      adapter: PublicKey | null,
+      remainingAccounts: anchor.web3.AccountMeta[]
+    ) =>
+      program.methods
+        .executeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          dependencyProposal: null,
+          committeeMember: null,
+          memberStake: null,
+          payer: authority.publicKey,
+          systemProgram: null,
+          upgradeProgram: null,
+          upgradeProgramData: null,
+          upgradeBuffer: null,
+          governanceAuthority: governanceAuthorityPda,
+          bpfLoaderUpgradeable: null,
+          rent: null,
+          clock: null,
+          executionAdapterRegistry: registryPda,
+          executionAdapter: adapter,
+          adapterAuthority: adapter ? adapterAuthorityPda : null,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([authority])
+        .rpc();
+
+    before(async () => {
+      await program.methods
+        .createExecutionAdapterRegistry()
+        .accounts({
+          executionAdapterRegistry: registryPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should only let the config admin register adapters", async () => {
+      try {
+        await program.methods
+          .setExecutionAdapter({ configUpdate: {} }, adapterProgram.programId)
+          .accounts({
+            executionAdapterRegistry: registryPda,
+            governanceConfig: governanceConfigPda,
+            authority: member1.publicKey,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Should hand executed proposals to the registered adapter", async () => {
+      await setAdapter(adapterProgram.programId);
+      const registry = await program.account.executionAdapterRegistry.fetch(registryPda);
+      expect(registry.adapters).to.have.length(1);
+      expect(registry.adapters[0].programId.equals(adapterProgram.programId)).to.equal(true);
+
+      const { proposalId, proposalPda } = await passConfigProposal();
+      const [adapterCallPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("adapter_call"), proposalPda.toBuffer()],
+        adapterProgram.programId
+      );
+
+      // A registered adapter cannot be skipped by leaving it out
+      try {
+        await execute(proposalId, proposalPda, null, []);
+        expect.fail("Should have failed with InvalidExecutionAdapter");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidExecutionAdapter");
+      }
+
+      await execute(proposalId, proposalPda, adapterProgram.programId, [
+        { pubkey: adapterCallPda, isWritable: true, isSigner: false },
+        { pubkey: authority.publicKey, isWritable: true, isSigner: true },
+        { pubkey: SystemProgram.programId, isWritable: false, isSigner: false },
+      ]);
+
+      const adapterCall = await adapterProgram.account.adapterCall.fetch(adapterCallPda);
+      expect(adapterCall.proposal.equals(proposalPda)).to.equal(true);
+      expect(adapterCall.proposalId.toString()).to.equal(proposalId.toString());
+      expect(adapterCall.proposalType).to.deep.equal({ configUpdate: {} });
+      expect(adapterCall.executionDataLen).to.equal(0);
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ executed: {} });
+    });
+
+    it("Should hold queued adapter registrations for the config timelock", async () => {
+      const applyAdapter = () =>
+        program.methods
+          .applyExecutionAdapter({ configUpdate: {} })
+          .accounts({ executionAdapterRegistry: registryPda })
+          .rpc();
+
+      try {
+        await applyAdapter();
+        expect.fail("Should have failed with ExecutionAdapterNotQueued");
+      } catch (error) {
+        expect(error.toString()).to.include("ExecutionAdapterNotQueued");
+      }
+
+      await program.methods
+        .queueExecutionAdapter({ configUpdate: {} }, null)
+        .accounts({
+          executionAdapterRegistry: registryPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      let registry = await program.account.executionAdapterRegistry.fetch(registryPda);
+      expect(registry.pendingAdapters).to.have.length(1);
+      expect(registry.pendingAdapters[0].programId).to.equal(null);
+      expect(registry.pendingAdapters[0].eta.toNumber()).to.equal(
+        registry.updatedAt.toNumber() + config.configTimelock.toNumber()
+      );
+
+      // The default two-day timelock keeps the registered adapter in place
+      try {
+        await applyAdapter();
+        expect.fail("Should have failed with ConfigUpdateTimelocked");
+      } catch (error) {
+        expect(error.toString()).to.include("ConfigUpdateTimelocked");
+      }
+      registry = await program.account.executionAdapterRegistry.fetch(registryPda);
+      expect(registry.adapters).to.have.length(1);
+      expect(registry.adapters[0].programId.equals(adapterProgram.programId)).to.equal(true);
+    });
+
+    it("Should execute log-only once the adapter is removed", async () => {
+      await setAdapter(null);
+      const registry = await program.account.executionAdapterRegistry.fetch(registryPda);
+      expect(registry.adapters).to.have.length(0);
+      // An immediate test-mode change drops the queued one
+      expect(registry.pendingAdapters).to.have.length(0);
+
+      const { proposalId, proposalPda } = await passConfigProposal();
+      await execute(proposalId, proposalPda, null, []);
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ executed: {} });
+    });
+  });
+
+  describe("Proposal Outcomes", () => {
+    const consumerProgram = anchor.workspace.GovernanceCpiCaller as Program<any>;
+
+    // Creates a proposal, has member1 and member2 cast the given vote, and finalizes it
+    const finalizeWithVotes = async (voteType: object) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Outcome Proposal",
+          "This proposal publishes its outcome for other programs to read.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, voteType)
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      const finalize = (proposalOutcome: PublicKey | null) =>
+        program.methods
+          .finalizeProposal(proposalId, null)
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            proposerTokenAccount: member1TokenAccount,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            proposer: member1.publicKey,
+            governanceAuthority: governanceAuthorityPda,
+            depositMint: committeeTokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            proposalOutcome,
+            payer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts([
+            ...committeeRemainingAccounts(),
+            ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+          ])
+          .signers([authority])
+          .rpc();
+
+      try {
+        await finalize(null);
+        expect.fail("Should have failed with ProposalOutcomeRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalOutcomeRequired");
+      }
+      await finalize(proposalOutcomePda(proposalId));
+      return proposalId;
+    };
+
+    const requireProposalPassed = (proposalId: anchor.BN) =>
+      consumerProgram.methods
+        .requireProposalPassed(proposalId)
+        .accounts({ proposalOutcome: proposalOutcomePda(proposalId) })
+        .rpc();
+
+    it("Should publish a compact outcome a consumer program can read", async () => {
+      const proposalId = await finalizeWithVotes({ yes: {} });
+      const outcomePda = proposalOutcomePda(proposalId);
+
+      const proposal = await fetchProposal(program, 
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        )[0]
+      );
+      const outcome = await program.account.proposalOutcome.fetch(outcomePda);
+      expect(outcome.id.toString()).to.equal(proposalId.toString());
+      expect(outcome.status).to.deep.equal({ passed: {} });
+      expect(outcome.yes.toString()).to.equal(proposal.yesVotes.toString());
+      expect(outcome.no.toNumber()).to.equal(0);
+      expect(outcome.abstain.toNumber()).to.equal(0);
+      expect(outcome.veto.toNumber()).to.equal(0);
+      expect(outcome.totalPower.gt(outcome.yes)).to.equal(true);
+      expect(outcome.finalizedAt.toNumber()).to.be.greaterThan(0);
+
+      const outcomeAccount = await provider.connection.getAccountInfo(outcomePda);
+      expect(outcomeAccount.owner.equals(program.programId)).to.equal(true);
+      expect(outcomeAccount.data.length).to.be.lessThan(100);
+
+      await requireProposalPassed(proposalId);
+    });
+
+    it("Should let a consumer program refuse a proposal that did not pass", async () => {
+      const proposalId = await finalizeWithVotes({ no: {} });
+      const outcome = await program.account.proposalOutcome.fetch(proposalOutcomePda(proposalId));
+      expect(outcome.status).to.deep.equal({ rejected: {} });
+      expect(outcome.yes.toNumber()).to.equal(0);
+      expect(outcome.no.toNumber()).to.be.greaterThan(0);
+
+      try {
+        await requireProposalPassed(proposalId);
+        expect.fail("Should have failed with ProposalNotPassed");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalNotPassed");
+      }
+    });
+
+    it("Should return a proposal summary a consumer program can read through CPI", async () => {
+      const proposalId = await finalizeWithVotes({ yes: {} });
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const proposal = await fetchProposal(program, proposalPda);
+      const outcome = await program.account.proposalOutcome.fetch(proposalOutcomePda(proposalId));
+
+      const summary = await program.methods
+        .getProposalSummary(proposalId)
+        .accounts({ proposal: proposalPda })
+        .view();
+      expect(summary.id.toString()).to.equal(proposalId.toString());
+      expect(summary.status).to.deep.equal({ passed: {} });
+      expect(summary.proposalType).to.deep.equal({ configUpdate: {} });
+      expect(summary.arbitrationDecision).to.equal(null);
+      expect(summary.executedAt).to.equal(null);
+      expect(summary.yes.toString()).to.equal(proposal.yesVotes.toString());
+      expect(summary.no.toNumber()).to.equal(0);
+      expect(summary.veto.toNumber()).to.equal(0);
+      expect(summary.totalPower.toString()).to.equal(outcome.totalPower.toString());
+
+      const simulated = await program.methods
+        .getProposalSummary(proposalId)
+        .accounts({ proposal: proposalPda })
+        .simulate();
+      const returnLog = simulated.raw.find((log) =>
+        log.startsWith(`Program return: ${program.programId.toBase58()} `)
+      );
+      const returnData = Buffer.from(returnLog.split(" ").pop(), "base64");
+      expect(returnData.length).to.be.lessThan(200);
+
+      const viaCpi = await consumerProgram.methods
+        .readProposalSummary(proposalId)
+        .accounts({ proposal: proposalPda, governanceProgram: program.programId })
+        .view();
+      expect(viaCpi.id.toString()).to.equal(summary.id.toString());
+      expect(viaCpi.status).to.deep.equal(summary.status);
+      expect(viaCpi.yes.toString()).to.equal(summary.yes.toString());
+      expect(viaCpi.totalPower.toString()).to.equal(summary.totalPower.toString());
+
+      expect(
+        await program.methods.hasPassed(proposalId).accounts({ proposal: proposalPda }).view()
+      ).to.equal(true);
+      await consumerProgram.methods
+        .requireHasPassed(proposalId)
+        .accounts({ proposal: proposalPda, governanceProgram: program.programId })
+        .rpc();
+    });
+
+    it("Should report a rejected proposal as not passed through CPI", async () => {
+      const proposalId = await finalizeWithVotes({ no: {} });
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      const summary = await program.methods
+        .getProposalSummary(proposalId)
+        .accounts({ proposal: proposalPda })
+        .view();
+      expect(summary.status).to.deep.equal({ rejected: {} });
+      expect(summary.yes.toNumber()).to.equal(0);
+      expect(summary.no.toNumber()).to.be.greaterThan(0);
+      expect(
+        await program.methods.hasPassed(proposalId).accounts({ proposal: proposalPda }).view()
+      ).to.equal(false);
+
+      try {
+        await consumerProgram.methods
+          .requireHasPassed(proposalId)
+          .accounts({ proposal: proposalPda, governanceProgram: program.programId })
+          .rpc();
+        expect.fail("Should have failed with ProposalNotPassed");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalNotPassed");
+      }
+    });
+  });
+
+  describe("Voting Power Queries", () => {
+    // Creates a proposal with the given votes from member1 and member2
+    // Returns the proposal and the remaining accounts finalize takes for it
+    const openProposalWithVotes = async (title: string, voteTypes: [object, object]) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "This proposal checks read-only tallies against finalization.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount, voteType] of [
+        [member1, member1TokenAccount, voteTypes[0]],
+        [member2, member2TokenAccount, voteTypes[1]],
+      ] as [Keypair, PublicKey, object][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, voteType)
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+      const remainingAccounts = [
+        ...committeeRemainingAccounts(),
+        ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+      ];
+      return { proposalId, proposalPda, remainingAccounts };
+    };
+
+    const readAccounts = (proposalPda: PublicKey) => ({
+      proposal: proposalPda,
+      governanceConfig: governanceConfigPda,
+      committeeTokenMint,
+    });
+
+    const finalize = (
+      proposalId: anchor.BN,
+      proposalPda: PublicKey,
+      remainingAccounts: { pubkey: PublicKey; isWritable: boolean; isSigner: boolean }[]
+    ) =>
+      program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([authority])
+        .rpc();
+
+    it("Should report the tally finalize then records, without touching the proposal", async () => {
+      const { proposalId, proposalPda, remainingAccounts } = await openProposalWithVotes(
+        "Query Proposal",
+        [{ yes: {} }, { no: {} }]
+      );
+      const query = program.methods
+        .queryVotingPower(proposalId)
+        .accounts(readAccounts(proposalPda))
+        .remainingAccounts(remainingAccounts);
+
+      // Sending the query leaves the proposal account byte-for-byte unchanged
+      const before = (await provider.connection.getAccountInfo(proposalPda)).data;
+      await query.rpc();
+      const after = (await provider.connection.getAccountInfo(proposalPda)).data;
+      expect(after.equals(before)).to.equal(true);
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      const queried = await query.view();
+      expect(queried.proposalId.toString()).to.equal(proposalId.toString());
+      expect(queried.yesVotes.toNumber()).to.be.greaterThan(0);
+      expect(queried.noVotes.toNumber()).to.be.greaterThan(0);
+
+      await finalize(proposalId, proposalPda, remainingAccounts);
+
+      const proposal = await fetchProposal(program, proposalPda);
+      const outcome = await program.account.proposalOutcome.fetch(proposalOutcomePda(proposalId));
+      expect(queried.totalVotingPower.toString()).to.equal(outcome.totalPower.toString());
+      expect(queried.yesVotes.toString()).to.equal(proposal.yesVotes.toString());
+      expect(queried.noVotes.toString()).to.equal(proposal.noVotes.toString());
+      expect(queried.abstainVotes.toString()).to.equal(proposal.abstainVotes.toString());
+      expect(queried.vetoVotes.toString()).to.equal(proposal.vetoVotes.toString());
+      expect(queried.totalVotes.toString()).to.equal(proposal.totalVotes.toString());
+      expect(queried.participationRate).to.equal(proposal.participationRate);
+      expect(queried.approvalRate).to.equal(proposal.approvalRate);
+      expect(queried.projectedStatus).to.deep.equal(proposal.status);
+    });
+
+    it("Should preview the status finalize sets once voting ends", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const { proposalId, proposalPda, remainingAccounts } = await openProposalWithVotes(
+        "Preview Proposal",
+        [{ yes: {} }, { noWithVeto: {} }]
+      );
+      const preview = () =>
+        program.methods
+          .previewFinalize(proposalId)
+          .accounts(readAccounts(proposalPda))
+          .remainingAccounts(remainingAccounts)
+          .view();
+
+      // Available before voting_end, when finalize itself still refuses
+      const early = await preview();
+      expect(early.votingEnded).to.equal(false);
+      try {
+        await finalize(proposalId, proposalPda, remainingAccounts);
+        expect.fail("Should have failed with VotingPeriodNotEnded");
+      } catch (error) {
+        expect(error.toString()).to.include("VotingPeriodNotEnded");
+      }
+
+      const before = (await provider.connection.getAccountInfo(proposalPda)).data;
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      const atEnd = await preview();
+      expect(atEnd.votingEnded).to.equal(true);
+      expect(atEnd.status).to.deep.equal(early.status);
+      expect((await provider.connection.getAccountInfo(proposalPda)).data.equals(before)).to.equal(
+        true
+      );
+
+      // Margins are rate minus threshold
+      expect(atEnd.participationMargin).to.equal(
+        atEnd.participationRate - config.participationThreshold
+      );
+      expect(atEnd.approvalMargin).to.equal(atEnd.approvalRate - config.approvalThreshold);
+      expect(atEnd.vetoMargin).to.equal(atEnd.vetoRate - config.vetoThreshold);
+
+      await finalize(proposalId, proposalPda, remainingAccounts);
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(atEnd.status).to.deep.equal(proposal.status);
+      expect(atEnd.participationRate).to.equal(proposal.participationRate);
+      expect(atEnd.approvalRate).to.equal(proposal.approvalRate);
+
+      // Finalized proposals have nothing left to preview
+      try {
+        await preview();
+        expect.fail("Should have failed with ProposalNotActive");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalNotActive");
+      }
+    });
+  });
+
+  describe("Proposal Index", () => {
+    const [proposalIndexPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal_index")],
+      program.programId
+    );
+
+    const createIndexedProposal = async (title: string) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "This proposal checks the on-chain proposal index.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          proposalIndex: proposalIndexPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda, votePdas: [] as PublicKey[] };
+    };
+
+    const castVotes = async (
+      proposal: { proposalId: anchor.BN; proposalPda: PublicKey; votePdas: PublicKey[] },
+      voteType: object
+    ) => {
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposal.proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposal.proposalId, voteType)
+          .accounts({
+            proposal: proposal.proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        proposal.votePdas.push(votePda);
+      }
+    };
+
+    const finalizeIndexed = (proposal: {
+      proposalId: anchor.BN;
+      proposalPda: PublicKey;
+      votePdas: PublicKey[];
+    }) =>
+      program.methods
+        .finalizeProposal(proposal.proposalId, null)
+        .accounts({
+          proposal: proposal.proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposal.proposalId),
+          proposalIndex: proposalIndexPda,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...proposal.votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+    const page = (list: object, offset: number, limit: number) =>
+      program.methods
+        .getProposalIndexPage(list, offset, limit)
+        .accounts({ proposalIndex: proposalIndexPda })
+        .view();
+
+    const ids = (result: { ids: anchor.BN[] }) => result.ids.map((id) => id.toString());
+
+    // Later tests create proposals without the index account
+    after(async () => {
+      await program.methods
+        .closeProposalIndex()
+        .accounts({
+          proposalIndex: proposalIndexPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.proposalIndexEnabled).to.equal(false);
+    });
+
+    it("Should list proposal IDs by status and page through them", async () => {
+      await program.methods
+        .initializeProposalIndex()
+        .accounts({
+          proposalIndex: proposalIndexPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.proposalIndexEnabled).to.equal(true);
+
+      // While enabled, creating a proposal without the index fails
+      try {
+        const proposalId = config.proposalCounter.add(new anchor.BN(1));
+        await program.methods
+          .createProposal(
+            proposalId,
+            "Unindexed",
+            "No index account.",
+            { configUpdate: {} },
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
+          )
+          .accounts({
+            proposal: PublicKey.findProgramAddressSync(
+              [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+              program.programId
+            )[0],
+            governanceConfig: governanceConfigPda,
+            proposer: member1.publicKey,
+            proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            governanceAuthority: governanceAuthorityPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc();
+        expect.fail("Should have failed with ProposalIndexRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalIndexRequired");
+      }
+
+      // Five proposals; the first two are voted on and finalized
+      const passing = await createIndexedProposal("Indexed Proposal 1");
+      await castVotes(passing, { yes: {} });
+      const failing = await createIndexedProposal("Indexed Proposal 2");
+      await castVotes(failing, { no: {} });
+      const open = [];
+      for (let i = 3; i <= 5; i++) {
+        open.push(await createIndexedProposal(`Indexed Proposal ${i}`));
+      }
+      const created = [passing, failing, ...open].map((p) => p.proposalId.toString());
+
+      const allPending = await page({ pending: {} }, 0, 10);
+      expect(allPending.total).to.equal(5);
+      expect(ids(allPending)).to.deep.equal(created);
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await finalizeIndexed(passing);
+      await finalizeIndexed(failing);
+      const passed = await fetchProposal(program, passing.proposalPda);
+      const rejected = await fetchProposal(program, failing.proposalPda);
+      expect(passed.status).to.deep.equal({ passed: {} });
+      expect(rejected.status).to.deep.equal({ rejected: {} });
+
+      const pending = await page({ pending: {} }, 0, 10);
+      expect(pending.total).to.equal(3);
+      expect(ids(pending)).to.deep.equal(created.slice(2));
+      expect(ids(await page({ passed: {} }, 0, 10))).to.deep.equal([created[0]]);
+      expect(ids(await page({ closed: {} }, 0, 10))).to.deep.equal([created[1]]);
+
+      // Pages follow offset and limit; offsets past the end return no IDs
+      const second = await page({ pending: {} }, 1, 1);
+      expect(second.total).to.equal(3);
+      expect(second.offset).to.equal(1);
+      expect(ids(second)).to.deep.equal([created[3]]);
+      expect(ids(await page({ pending: {} }, 2, 10))).to.deep.equal([created[4]]);
+      expect(ids(await page({ pending: {} }, 5, 10))).to.deep.equal([]);
+
+      // Leave no proposals pending for the tests that follow
+      for (const proposal of open) {
+        await finalizeIndexed(proposal);
+      }
+      expect((await page({ pending: {} }, 0, 10)).total).to.equal(0);
+      expect(ids(await page({ closed: {} }, 0, 10))).to.deep.equal([
+        created[1],
+        ...created.slice(2),
+      ]);
+    });
+  });
+
+  describe("Governance Statistics", () => {
+    const [governanceStatsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("governance_stats")],
+      program.programId
+    );
+
+    const readStats = () =>
+      program.methods.getGovernanceStats().accounts({ governanceStats: governanceStatsPda }).view();
+
+    it("Should count a proposal through creation, voting, finalization and execution", async () => {
+      const before = await readStats();
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Statistics Proposal",
+          "This proposal drives the governance statistics counters.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          governanceStats: governanceStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            governanceStats: governanceStatsPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      const voted = await readStats();
+      expect(voted.proposalsCreated[3].sub(before.proposalsCreated[3]).toNumber()).to.equal(1);
+      expect(voted.votesCast.sub(before.votesCast).toNumber()).to.equal(2);
+      const created = await fetchProposal(program, proposalPda);
+      expect(voted.totalDeposits.sub(before.totalDeposits).toString()).to.equal(
+        created.depositAmount.toString()
+      );
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          governanceStats: governanceStatsPda,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+      const finalized = await readStats();
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ passed: {} });
+      expect(finalized.proposalsPassed.sub(before.proposalsPassed).toNumber()).to.equal(1);
+      expect(finalized.proposalsRejected.toString()).to.equal(before.proposalsRejected.toString());
+      expect(finalized.proposalsVetoed.toString()).to.equal(before.proposalsVetoed.toString());
+      expect(finalized.totalFees.sub(before.totalFees).toString()).to.equal(
+        proposal.depositFee.toString()
+      );
+      expect(finalized.totalRefunds.sub(before.totalRefunds).toString()).to.equal(
+        proposal.depositRefunded.toString()
+      );
+
+      await program.methods
+        .executeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          dependencyProposal: null,
+          committeeMember: null,
+          memberStake: null,
+          payer: authority.publicKey,
+          systemProgram: null,
+          upgradeProgram: null,
+          upgradeProgramData: null,
+          upgradeBuffer: null,
+          governanceAuthority: governanceAuthorityPda,
+          bpfLoaderUpgradeable: null,
+          rent: null,
+          clock: null,
+          executionAdapter: null,
+          governanceStats: governanceStatsPda,
+        })
+        .signers([authority])
+        .rpc();
+
+      const executed = await readStats();
+      expect(executed.proposalsExecuted.sub(before.proposalsExecuted).toNumber()).to.equal(1);
+      expect(executed.proposalsPassed.toString()).to.equal(finalized.proposalsPassed.toString());
+      expect(executed.votesCast.toString()).to.equal(voted.votesCast.toString());
+      expect(executed.updatedAt.toNumber()).to.be.at.least(finalized.updatedAt.toNumber());
+    });
+  });
+
+  describe("Event Schema", () => {
+    // Event name, plus the reason for treasury ledger entries
+    const eventLabel = (event: { name: string; data: any }) =>
+      event.name === "treasuryTransfer"
+        ? `treasuryTransfer:${Object.keys(event.data.reason)[0]}`
+        : event.name;
+
+    it("Should emit the versioned lifecycle event sequence", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const events: { name: string; data: any }[] = [];
+
+      const createSignature = await program.methods
+        .createProposal(
+          proposalId,
+          "Event Proposal",
+          "This proposal records the lifecycle event sequence.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc({ commitment: "confirmed" });
+      events.push(...(await decodeEvents(createSignature)));
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        const voteSignature = await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc({ commitment: "confirmed" });
+        events.push(...(await decodeEvents(voteSignature)));
+        votePdas.push(votePda);
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      const finalizeSignature = await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+      events.push(...(await decodeEvents(finalizeSignature)));
+
+      const executeSignature = await program.methods
+        .executeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          dependencyProposal: null,
+          committeeMember: null,
+          memberStake: null,
+          payer: authority.publicKey,
+          systemProgram: null,
+          upgradeProgram: null,
+          upgradeProgramData: null,
+          upgradeBuffer: null,
+          governanceAuthority: governanceAuthorityPda,
+          bpfLoaderUpgradeable: null,
+          rent: null,
+          clock: null,
+          executionAdapter: null,
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+      events.push(...(await decodeEvents(executeSignature)));
+
+      // The finalize ledger entries follow the deposit split
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ executed: {} });
+      expect(events.map(eventLabel)).to.deep.equal([
+        "treasuryTransfer:proposalDeposit",
+        "proposalCreated",
+        "voteCast",
+        "voteCast",
+        "proposalFinalized",
+        ...(proposal.depositRefunded.gtn(0) ? ["treasuryTransfer:depositRefund"] : []),
+        ...(proposal.depositFee.gtn(0) ? ["treasuryTransfer:committeeFee"] : []),
+        "proposalExecuted",
+      ]);
+
+      // Events of the standardized set carry the layout version and the deployment
+      for (const event of events) {
+        expect(event.data.schemaVersion).to.equal(3);
+        expect(event.data.governanceConfig.equals(governanceConfigPda)).to.equal(true);
+      }
+      const [, created, firstVote] = events;
+      expect(created.data.proposalId.toString()).to.equal(proposalId.toString());
+      expect(created.data.resubmissionOf).to.equal(null);
+      expect(firstVote.data.voter.equals(member1.publicKey)).to.equal(true);
+      expect(firstVote.data.vote.equals(votePdas[0])).to.equal(true);
+      const finalized = events.find((event) => event.name === "proposalFinalized");
+      expect(finalized.data.status).to.deep.equal({ passed: {} });
+      expect(finalized.data.yesVotes.toString()).to.equal(proposal.yesVotes.toString());
+    });
+  });
+
+  describe("Clock Override", () => {
+    it("Should run a test_mode lifecycle without waiting out the voting period", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.testMode).to.equal(true);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Clock Override Proposal",
+          "This proposal is finalized and executed at an overridden time.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      const votingEnd = (await fetchProposal(program, proposalPda)).votingEnd;
+      const finalize = (clockOverride: anchor.BN) =>
+        program.methods
+          .finalizeProposal(proposalId, clockOverride)
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            proposerTokenAccount: member1TokenAccount,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            proposer: member1.publicKey,
+            governanceAuthority: governanceAuthorityPda,
+            depositMint: committeeTokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            proposalOutcome: proposalOutcomePda(proposalId),
+            payer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts([
+            ...committeeRemainingAccounts(),
+            ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+          ])
+          .signers([authority])
+          .rpc();
+
+      // The override is the time finalization checks against voting_end
+      try {
+        await finalize(votingEnd);
+        expect.fail("Should have failed with VotingPeriodNotEnded");
+      } catch (error) {
+        expect(error.toString()).to.include("VotingPeriodNotEnded");
+      }
+      await finalize(votingEnd.addn(1));
+
+      const executedAt = votingEnd.addn(60);
+      await program.methods
+        .executeProposal(proposalId, executedAt)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          dependencyProposal: null,
+          committeeMember: null,
+          memberStake: null,
+          payer: authority.publicKey,
+          systemProgram: null,
+          upgradeProgram: null,
+          upgradeProgramData: null,
+          upgradeBuffer: null,
+          governanceAuthority: governanceAuthorityPda,
+          bpfLoaderUpgradeable: null,
+          rent: null,
+          clock: null,
+          executionAdapter: null,
+        })
+        .signers([authority])
+        .rpc();
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ executed: {} });
+      expect(proposal.executedAt.toString()).to.equal(executedAt.toString());
+    });
+  });
+
+  describe("Signed Votes", () => {
+    const relayer = Keypair.generate();
+    let proposalId: anchor.BN;
+    let proposalPda: PublicKey;
+
+    const votePda = (member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalId.toArrayLike(Buffer, "le", 8), member.toBuffer()],
+        program.programId
+      )[0];
+
+    const memberNoncePda = (member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("member_nonce"), member.toBuffer()],
+        program.programId
+      )[0];
+
+    // The member's current nonce (0 before the first relayed action)
+    const currentNonce = async (member: PublicKey) =>
+      (await program.account.memberNonce.fetchNullable(memberNoncePda(member)))?.nonce ??
+      new anchor.BN(0);
+
+    const signedMessage = async (
+      voter: PublicKey,
+      voteType: object,
+      slotsValid = 100,
+      nonce?: anchor.BN
+    ) => ({
+      governanceConfig: governanceConfigPda,
+      proposalId,
+      voteType: voteType as any,
+      expirySlot: new anchor.BN((await provider.connection.getSlot()) + slotsValid),
+      voter,
+      nonce: nonce ?? (await currentNonce(voter)),
+    });
+
+    const createProposal = async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      proposalId = config.proposalCounter.add(new anchor.BN(1));
+      [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Signed Vote Proposal",
+          "This proposal receives votes relayed on behalf of committee members.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+    };
+
+    // Relay `message` with an ed25519 proof by `signer` over `signed`, paid for by the relayer
+    const relay = (
+      message: any,
+      signer: Keypair,
+      [voter, voterTokenAccount]: [Keypair, PublicKey],
+      signed: any = message
+    ) =>
+      program.methods
+        .castVoteSigned(message)
+        .accounts({
+          proposal: proposalPda,
+          vote: votePda(voter.publicKey),
+          governanceConfig: governanceConfigPda,
+          voter: voter.publicKey,
+          memberNonce: memberNoncePda(voter.publicKey),
+          voterTokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          payer: relayer.publicKey,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: signer.secretKey,
+            message: program.coder.types.encode("SignedVoteMessage", signed),
+          }),
+        ])
+        .signers([relayer])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(relayer.publicKey, LAMPORTS_PER_SOL)
+      );
+      await createProposal();
+    });
+
+    it("Should record a relayed vote for the signing member", async () => {
+      const voterBalance = await provider.connection.getBalance(member3.publicKey);
+      const message = await signedMessage(member3.publicKey, { no: {} });
+      await relay(message, member3, [member3, member3TokenAccount]);
+
+      const vote = await program.account.vote.fetch(votePda(member3.publicKey));
+      expect(vote.voter.equals(member3.publicKey)).to.equal(true);
+      expect(vote.voteType).to.deep.equal({ no: {} });
+      expect(vote.proposalId.toString()).to.equal(proposalId.toString());
+      // The relayer paid the fees and the vote account rent
+      expect(await provider.connection.getBalance(member3.publicKey)).to.equal(voterBalance);
+
+      try {
+        await relay(message, member3, [member3, member3TokenAccount]);
+        expect.fail("Should have refused the replayed message");
+      } catch (error) {
+        expect(error.toString()).to.include("already in use");
+      }
+    });
+
+    it("Should refuse messages with a mismatched signature", async () => {
+      const signed = await signedMessage(member2.publicKey, { yes: {} });
+      const tampered = { ...signed, voteType: { noWithVeto: {} } };
+      for (const [message, signer] of [
+        [signed, member1],
+        [tampered, member2],
+      ] as [any, Keypair][]) {
+        try {
+          await relay(message, signer, [member2, member2TokenAccount], signed);
+          expect.fail("Should have failed with InvalidVoteSignature");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidVoteSignature");
+        }
+      }
+    });
+
+    it("Should refuse expired messages", async () => {
+      const message = await signedMessage(member2.publicKey, { yes: {} }, -1);
+      try {
+        await relay(message, member2, [member2, member2TokenAccount]);
+        expect.fail("Should have failed with VoteMessageExpired");
+      } catch (error) {
+        expect(error.toString()).to.include("VoteMessageExpired");
+      }
+    });
+
+    it("Should refuse messages signed for a used or future nonce", async () => {
+      // member3's relayed vote used up nonce 0
+      const nonce = await currentNonce(member3.publicKey);
+      expect(nonce.toNumber()).to.equal(1);
+
+      await createProposal();
+      for (const staleOrFuture of [nonce.subn(1), nonce.addn(1)]) {
+        const message = await signedMessage(member3.publicKey, { yes: {} }, 100, staleOrFuture);
+        try {
+          await relay(message, member3, [member3, member3TokenAccount]);
+          expect.fail("Should have failed with InvalidVoteNonce");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidVoteNonce");
+        }
+      }
+
+      await relay(
+        await signedMessage(member3.publicKey, { yes: {} }),
+        member3,
+        [member3, member3TokenAccount]
+      );
+      expect((await currentNonce(member3.publicKey)).toNumber()).to.equal(2);
+    });
+  });
+
+  describe("Compute Units", () => {
+    // Compute units a confirmed transaction consumed
+    const computeUnits = async (signature: string) => {
+      const transaction = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      return transaction.meta.computeUnitsConsumed;
+    };
+
+    it("Should report the compute units of create, vote and finalize", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote"),
+          proposalId.toArrayLike(Buffer, "le", 8),
+          member2.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const title = "Compute Proposal";
+      const description = "This proposal measures the compute units of its lifecycle.";
+
+      const createSignature = await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          description,
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc({ commitment: "confirmed" });
+
+      const voteSignature = await program.methods
+        .castVote(proposalId, { yes: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: votePda,
+          governanceConfig: governanceConfigPda,
+          voter: member2.publicKey,
+          voterTokenAccount: member2TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member2])
+        .rpc({ commitment: "confirmed" });
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      const finalizeSignature = await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          { pubkey: votePda, isWritable: false, isSigner: false },
+        ])
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+
+      for (const [label, signature] of [
+        ["create_proposal", createSignature],
+        ["cast_vote", voteSignature],
+        ["finalize_proposal", finalizeSignature],
+      ]) {
+        const units = await computeUnits(signature);
+        console.log(`      ${label}: ${units} compute units`);
+        expect(units).to.be.below(200_000);
+      }
+
+      // The fixed-size buffers decode back to the submitted text
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.title).to.equal(title);
+      expect(proposal.description).to.equal(description);
+      expect(proposal.executionData).to.equal(null);
+      expect(proposal.status).to.not.deep.equal({ pending: {} });
+    });
+
+    it("Should finalize with 10 members and 10 votes within the compute budget", async () => {
+      // Seven extra members bring the committee to ten; they are removed again below
+      const extraMembers: [Keypair, PublicKey][] = [];
+      for (let i = 0; i < 7; i++) {
+        const member = Keypair.generate();
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(member.publicKey, LAMPORTS_PER_SOL)
+        );
+        const tokenAccount = await createAssociatedTokenAccount(
+          provider.connection,
+          member,
+          committeeTokenMint,
+          member.publicKey
+        );
+        await mintTo(
+          provider.connection,
+          authority,
+          committeeTokenMint,
+          tokenAccount,
+          authority,
+          100 * 10 ** 9
+        );
+        await program.methods
+          .addCommitteeMember(member.publicKey)
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            committeeMember: committeeMemberPda(member.publicKey),
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        extraMembers.push([member, tokenAccount]);
+      }
+      const members: [Keypair, PublicKey][] = [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+        [member3, member3TokenAccount],
+        ...extraMembers,
+      ];
+
+      try {
+        const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+        const proposalId = config.proposalCounter.add(new anchor.BN(1));
+        const [proposalPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        );
+        await program.methods
+          .createProposal(
+            proposalId,
+            "Ten Member Proposal",
+            "This proposal measures finalization with a ten member committee.",
+            { configUpdate: {} },
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
+          )
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            proposer: member1.publicKey,
+            proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            governanceAuthority: governanceAuthorityPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc();
+
+        const votePdas: PublicKey[] = [];
+        for (const [member, tokenAccount] of members) {
+          const [votePda] = PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("vote"),
+              proposalId.toArrayLike(Buffer, "le", 8),
+              member.publicKey.toBuffer(),
+            ],
+            program.programId
+          );
+          await program.methods
+            .castVote(proposalId, { yes: {} })
+            .accounts({
+              proposal: proposalPda,
+              vote: votePda,
+              governanceConfig: governanceConfigPda,
+              voter: member.publicKey,
+              voterTokenAccount: tokenAccount,
+              committeeTokenMint: committeeTokenMint,
+              committeeTokenProgram: TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([member])
+            .rpc();
+          votePdas.push(votePda);
+        }
+
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+        const finalizeInstruction = await program.methods
+          .finalizeProposal(proposalId, null)
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            proposerTokenAccount: member1TokenAccount,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            proposer: member1.publicKey,
+            governanceAuthority: governanceAuthorityPda,
+            depositMint: committeeTokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            proposalOutcome: proposalOutcomePda(proposalId),
+            payer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts([
+            ...members.flatMap(([member, tokenAccount]) => [
+              { pubkey: committeeMemberPda(member.publicKey), isWritable: false, isSigner: false },
+              { pubkey: tokenAccount, isWritable: false, isSigner: false },
+            ]),
+            ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+          ])
+          .instruction();
+
+        // 30 remaining accounts do not fit a legacy transaction; they go through a lookup table
+        const [createTable, lookupTable] = AddressLookupTableProgram.createLookupTable({
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          recentSlot: await provider.connection.getSlot("finalized"),
+        });
+        await provider.sendAndConfirm(new Transaction().add(createTable), [authority]);
+        const remainingKeys = finalizeInstruction.keys.slice(-30).map((key) => key.pubkey);
+        for (const addresses of [remainingKeys.slice(0, 15), remainingKeys.slice(15)]) {
+          const extendTable = AddressLookupTableProgram.extendLookupTable({
+            lookupTable,
+            authority: authority.publicKey,
+            payer: authority.publicKey,
+            addresses,
+          });
+          await provider.sendAndConfirm(new Transaction().add(extendTable), [authority]);
+        }
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        const lookupTableAccount = (await provider.connection.getAddressLookupTable(lookupTable))
+          .value;
+
+        const message = new TransactionMessage({
+          payerKey: authority.publicKey,
+          recentBlockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+          instructions: [finalizeInstruction],
+        }).compileToV0Message([lookupTableAccount]);
+        const transaction = new VersionedTransaction(message);
+        transaction.sign([authority]);
+        const signature = await provider.connection.sendTransaction(transaction);
+        await provider.connection.confirmTransaction(signature, "confirmed");
+
+        const units = await computeUnits(signature);
+        console.log(`      finalize_proposal (10 members, 10 votes): ${units} compute units`);
+        expect(units).to.be.below(200_000);
+        const proposal = await fetchProposal(program, proposalPda);
+        expect(proposal.status).to.deep.equal({ passed: {} });
+        expect(proposal.totalVotes.toString()).to.equal(proposal.yesVotes.toString());
+      } finally {
+        for (const [member] of extraMembers) {
+          await program.methods
+            .removeCommitteeMember(member.publicKey)
+            .accounts({
+              governanceConfig: governanceConfigPda,
+              committeeMember: committeeMemberPda(member.publicKey),
+              memberStake: memberStakePda(member.publicKey),
+              authority: authority.publicKey,
+            })
+            .signers([authority])
+            .rpc();
+        }
+      }
+    });
+  });
+
+  describe("Deposit Memos", () => {
+    const memoProgramId = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLrfcxEr");
+
+    const setEmitMemos = (emitMemos: boolean) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod: null,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode: null,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled: null,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement: null,
+          memberStakeCooldown: null,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize: null,
+          maxActiveProposals: null,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    // Memo texts the memo program logged in a confirmed transaction
+    const memos = async (signature: string) => {
+      const transaction = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const logs = transaction.meta.logMessages;
+      expect(logs).to.include(`Program ${memoProgramId.toBase58()} invoke [2]`);
+      return logs.filter((log) => log.startsWith("Program log: Memo"));
+    };
+
+    before(async () => {
+      await setEmitMemos(true);
+    });
+
+    after(async () => {
+      await setEmitMemos(false);
+    });
+
+    it("Should attach memos to the deposit, refund and fee movements", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.emitMemos).to.equal(true);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const create = (memoProgram: PublicKey | null) =>
+        program.methods
+          .createProposal(
+            proposalId,
+            "Memo Proposal",
+            "This proposal's deposit movements carry reconciliation memos.",
+            { configUpdate: {} },
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
+          )
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            proposer: member1.publicKey,
+            proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            governanceAuthority: governanceAuthorityPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram,
+          })
+          .signers([member1])
+          .rpc({ commitment: "confirmed" });
+
+      try {
+        await create(null);
+        expect.fail("Should have failed with MemoProgramRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("MemoProgramRequired");
+      }
+      const createSignature = await create(memoProgramId);
+      const deposit = (await fetchProposal(program, proposalPda)).depositAmount;
+      expect((await memos(createSignature)).join("\n")).to.include(
+        `proposal:${proposalId}:deposit:${deposit}`
+      );
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      const votingEnd = (await fetchProposal(program, proposalPda)).votingEnd;
+      const finalizeSignature = await program.methods
+        .finalizeProposal(proposalId, votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          memoProgram: memoProgramId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ passed: {} });
+      const finalizeMemos = (await memos(finalizeSignature)).join("\n");
+      expect(finalizeMemos).to.include(
+        `proposal:${proposalId}:refund:${proposal.depositRefunded}`
+      );
+      expect(finalizeMemos).to.include(`proposal:${proposalId}:fee:${proposal.depositFee}`);
+    });
+  });
+
+  describe("Proposal Archive", () => {
+    const title = "Archived Proposal";
+    const description = "This proposal is rejected, archived and closed.";
+    let proposalId: anchor.BN;
+    let proposalPda: PublicKey;
+    let proposalArchivePda: PublicKey;
+
+    const closeProposal = (skipArchive: boolean, proposalArchive: PublicKey | null) =>
+      program.methods
+        .closeProposal(proposalId, skipArchive)
+        .accounts({
+          proposal: proposalPda,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          proposalArchive,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          authority: member1.publicKey,
+        })
+        .signers([member1])
+        .rpc();
+
+    before(async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      proposalId = config.proposalCounter.add(new anchor.BN(1));
+      [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      [proposalArchivePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal_archive"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          description,
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+    });
+
+    it("Should refuse to archive a pending proposal", async () => {
+      try {
+        await program.methods
+          .archiveProposal(proposalId)
+          .accounts({
+            proposal: proposalPda,
+            proposalArchive: proposalArchivePda,
+            payer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with ProposalNotArchivable");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalNotArchivable");
+      }
+    });
+
+    it("Should archive a rejected proposal, close it and keep the summary", async () => {
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { no: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      const votingEnd = (await fetchProposal(program, proposalPda)).votingEnd;
+      await program.methods
+        .finalizeProposal(proposalId, votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ rejected: {} });
+
+      // Closing without an archive takes an explicit skip
+      try {
+        await closeProposal(false, null);
+        expect.fail("Should have failed with ProposalArchiveRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalArchiveRequired");
+      }
+
+      await program.methods
+        .archiveProposal(proposalId)
+        .accounts({
+          proposal: proposalPda,
+          proposalArchive: proposalArchivePda,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const proposalRent = (await provider.connection.getAccountInfo(proposalPda)).lamports;
+      const archiveInfo = await provider.connection.getAccountInfo(proposalArchivePda);
+      expect(archiveInfo.data.length).to.be.lessThan(200);
+      expect(archiveInfo.lamports).to.be.lessThan(proposalRent * 0.2);
+
+      const balanceBefore = await provider.connection.getBalance(member1.publicKey);
+      await closeProposal(false, proposalArchivePda);
+      expect(await provider.connection.getAccountInfo(proposalPda)).to.be.null;
+      expect(await provider.connection.getAccountInfo(proposalOutcomePda(proposalId))).to.be.null;
+      expect(await provider.connection.getBalance(member1.publicKey)).to.be.greaterThan(
+        balanceBefore
+      );
+
+      const archive = await program.account.proposalArchive.fetch(proposalArchivePda);
+      expect(archive.id.toString()).to.equal(proposalId.toString());
+      expect(archive.proposer.toString()).to.equal(member1.publicKey.toString());
+      expect(archive.proposalType).to.deep.equal({ configUpdate: {} });
+      expect(archive.status).to.deep.equal({ rejected: {} });
+      expect(archive.noVotes.toString()).to.equal(proposal.noVotes.toString());
+      expect(archive.yesVotes.toNumber()).to.equal(0);
+      expect(archive.depositAmount.toString()).to.equal(proposal.depositAmount.toString());
+      expect(archive.depositRefunded.toString()).to.equal(proposal.depositRefunded.toString());
+      expect(archive.depositFee.toString()).to.equal(proposal.depositFee.toString());
+      expect(archive.executedAt).to.be.null;
+
+      const titleLength = Buffer.alloc(4);
+      titleLength.writeUInt32LE(Buffer.byteLength(title));
+      const contentHash = createHash("sha256")
+        .update(titleLength)
+        .update(title)
+        .update(description)
+        .digest();
+      expect(Buffer.from(archive.contentHash).equals(contentHash)).to.equal(true);
+    });
+  });
+
+  describe("Crank Finalize", () => {
+    const proposalPda = (proposalId: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+    const votePda = (proposalId: anchor.BN, member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalId.toArrayLike(Buffer, "le", 8), member.toBuffer()],
+        program.programId
+      )[0];
+
+    const setVotingPeriod = (votingPeriod: anchor.BN) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode: null,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled: null,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement: null,
+          memberStakeCooldown: null,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize: null,
+          maxActiveProposals: null,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos: null,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    // Create a proposal by member1 and vote yes on it as member1
+    const createVotedProposal = async (title: string) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "This proposal is finalized by the keeper crank.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda(proposalId),
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      await program.methods
+        .castVote(proposalId, { yes: {} })
+        .accounts({
+          proposal: proposalPda(proposalId),
+          vote: votePda(proposalId, member1.publicKey),
+          governanceConfig: governanceConfigPda,
+          voter: member1.publicKey,
+          voterTokenAccount: member1TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member1])
+        .rpc();
+      return proposalId;
+    };
+
+    // Crank the proposals at `clockOverride`, each with member1's vote
+    const crank = (proposalIds: anchor.BN[], clockOverride: anchor.BN) =>
+      program.methods
+        .crankFinalize(
+          proposalIds.map((proposalId) => ({ proposalId, voteCount: 1 })),
+          clockOverride
+        )
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          governanceAuthority: governanceAuthorityPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...proposalIds.flatMap((proposalId) => [
+            { pubkey: proposalPda(proposalId), isWritable: true, isSigner: false },
+            { pubkey: proposalOutcomePda(proposalId), isWritable: true, isSigner: false },
+            { pubkey: member1TokenAccount, isWritable: true, isSigner: false },
+            { pubkey: proposerStatePda(member1.publicKey), isWritable: true, isSigner: false },
+            {
+              pubkey: votePda(proposalId, member1.publicKey),
+              isWritable: false,
+              isSigner: false,
+            },
+          ]),
+        ])
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 })])
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+
+    it("Should finalize the ready proposals and skip the one still voting", async () => {
+      const votingPeriod = (await program.account.governanceConfig.fetch(governanceConfigPda))
+        .votingPeriod;
+      const first = await createVotedProposal("Crank Proposal One");
+      const second = await createVotedProposal("Crank Proposal Two");
+      // The third proposal votes for an hour longer
+      await setVotingPeriod(votingPeriod.addn(3600));
+      const notReady = await createVotedProposal("Crank Proposal Three");
+      await setVotingPeriod(votingPeriod);
+
+      const secondEnd = (await fetchProposal(program, proposalPda(second))).votingEnd;
+      const signature = await crank([first, second, notReady], secondEnd.addn(1));
+
+      const finalizedIds = (await decodeEvents(signature))
+        .filter((event) => event.name === "proposalFinalized")
+        .map((event) => event.data.proposalId.toString());
+      expect(finalizedIds).to.deep.equal([first.toString(), second.toString()]);
+      for (const proposalId of [first, second]) {
+        const proposal = await fetchProposal(program, proposalPda(proposalId));
+        expect(proposal.status).to.not.deep.equal({ pending: {} });
+        expect(proposal.depositSettled).to.equal(true);
+        const outcome = await program.account.proposalOutcome.fetch(
+          proposalOutcomePda(proposalId)
+        );
+        expect(outcome.status).to.deep.equal(proposal.status);
+      }
+      const pending = await fetchProposal(program, proposalPda(notReady));
+      expect(pending.status).to.deep.equal({ pending: {} });
+      expect(await provider.connection.getAccountInfo(proposalOutcomePda(notReady))).to.be.null;
+
+      // Once its voting ends the third is finalized; the others are skipped as finalized
+      const retry = await crank([first, second, notReady], pending.votingEnd.addn(1));
+      const retried = (await decodeEvents(retry))
+        .filter((event) => event.name === "proposalFinalized")
+        .map((event) => event.data.proposalId.toString());
+      expect(retried).to.deep.equal([notReady.toString()]);
+    });
+
+    it("Should finalize a proposal whose outcome address was pre-funded", async () => {
+      const proposalId = await createVotedProposal("Crank Prefunded Outcome");
+      // Anyone can send lamports to the outcome PDA before it is created
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: provider.publicKey,
+            toPubkey: proposalOutcomePda(proposalId),
+            lamports: 1,
+          })
+        )
+      );
+
+      const votingEnd = (await fetchProposal(program, proposalPda(proposalId))).votingEnd;
+      const signature = await crank([proposalId], votingEnd.addn(1));
+
+      const finalizedIds = (await decodeEvents(signature))
+        .filter((event) => event.name === "proposalFinalized")
+        .map((event) => event.data.proposalId.toString());
+      expect(finalizedIds).to.deep.equal([proposalId.toString()]);
+      const outcome = await program.account.proposalOutcome.fetch(proposalOutcomePda(proposalId));
+      expect(outcome.id.toString()).to.equal(proposalId.toString());
+    });
+  });
+
+  describe("Participation Bonus", () => {
+    const members: [Keypair, PublicKey][] = [
+      [member1, member1TokenAccount],
+      [member2, member2TokenAccount],
+      [member3, member3TokenAccount],
+    ];
+    const voterStatsPda = (member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("voter_stats"), member.toBuffer()],
+        program.programId
+      )[0];
+    const proposalPda = (proposalId: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+    const votePda = (proposalId: anchor.BN, member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalId.toArrayLike(Buffer, "le", 8), member.toBuffer()],
+        program.programId
+      )[0];
+
+    // Committee accounts while the bonus is enabled: each member's pair plus voter stats
+    const committeeBonusAccounts = () =>
+      members.flatMap(([member, tokenAccount]) => [
+        { pubkey: committeeMemberPda(member.publicKey), isWritable: false, isSigner: false },
+        { pubkey: tokenAccount, isWritable: false, isSigner: false },
+        { pubkey: voterStatsPda(member.publicKey), isWritable: true, isSigner: false },
+      ]);
+
+    const setParticipationBonus = (bonusBps: number, window: number, cap: anchor.BN) =>
+      program.methods
+        .updateGovernanceConfig(
+          configUpdate({
+            participationBonusBps: bonusBps,
+            participationBonusWindow: window,
+            maxMemberVotingPower: cap,
+          })
+        )
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    // Create a proposal voted on by the given members
+    const createProposalWithVotes = async (title: string, votes: [Keypair, object][]) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "This proposal checks the participation bonus.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda(proposalId),
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      for (const [member, voteType] of votes) {
+        const [, voterTokenAccount] = members.find(([m]) => m === member);
+        await program.methods
+          .castVote(proposalId, voteType)
+          .accounts({
+            proposal: proposalPda(proposalId),
+            vote: votePda(proposalId, member.publicKey),
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+      const voteAccounts = votes.map(([member]) => ({
+        pubkey: votePda(proposalId, member.publicKey),
+        isWritable: false,
+        isSigner: false,
+      }));
+      return { proposalId, voteAccounts };
+    };
+
+    const finalizeAtVotingEnd = async (
+      proposalId: anchor.BN,
+      remainingAccounts: { pubkey: PublicKey; isWritable: boolean; isSigner: boolean }[]
+    ) => {
+      const votingEnd = (await fetchProposal(program, proposalPda(proposalId))).votingEnd;
+      await program.methods
+        .finalizeProposal(proposalId, votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda(proposalId),
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 })])
+        .signers([authority])
+        .rpc();
+    };
+
+    const queryVotes = (
+      proposalId: anchor.BN,
+      remainingAccounts: { pubkey: PublicKey; isWritable: boolean; isSigner: boolean }[]
+    ) =>
+      program.methods
+        .queryVotingPower(proposalId)
+        .accounts({
+          proposal: proposalPda(proposalId),
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint,
+        })
+        .remainingAccounts(remainingAccounts)
+        .view();
+
+    before(async () => {
+      for (const [member] of members) {
+        await program.methods
+          .initializeVoterStats(member.publicKey)
+          .accounts({
+            committeeMember: committeeMemberPda(member.publicKey),
+            voterStats: voterStatsPda(member.publicKey),
+            payer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+      }
+    });
+
+    after(async () => {
+      await setParticipationBonus(0, 0, new anchor.BN(0));
+    });
+
+    it("Should refuse a bonus without a window", async () => {
+      try {
+        await setParticipationBonus(1000, 0, new anchor.BN(0));
+        expect.fail("Should have failed with InvalidParticipationBonus");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidParticipationBonus");
+      }
+    });
+
+    it("Should record participation at finalization", async () => {
+      // +10% for members who voted on the last finalized proposal
+      await setParticipationBonus(1000, 1, new anchor.BN(0));
+      const { proposalId, voteAccounts } = await createProposalWithVotes("Bonus Warmup", [
+        [member1, { yes: {} }],
+      ]);
+      await finalizeAtVotingEnd(proposalId, [...committeeBonusAccounts(), ...voteAccounts]);
+
+      const voted = await program.account.voterStats.fetch(voterStatsPda(member1.publicKey));
+      expect(voted.recordedProposals).to.equal(1);
+      expect(voted.recentVotes.toNumber()).to.equal(1);
+      expect(voted.lastProposalId.toString()).to.equal(proposalId.toString());
+      const missed = await program.account.voterStats.fetch(voterStatsPda(member2.publicKey));
+      expect(missed.recordedProposals).to.equal(1);
+      expect(missed.recentVotes.toNumber()).to.equal(0);
+
+      // Pairs without voter stats no longer match the committee layout
+      try {
+        await queryVotes(proposalId, [...committeeRemainingAccounts(), ...voteAccounts]);
+        expect.fail("Should have failed with InvalidCommitteeAccounts");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidCommitteeAccounts");
+      }
+    });
+
+    it("Should tally the same balances differently with the bonus on and off", async () => {
+      const { proposalId, voteAccounts } = await createProposalWithVotes("Bonus Tally", [
+        [member1, { yes: {} }],
+        [member2, { no: {} }],
+      ]);
+
+      // Only member1 voted on the warmup proposal, so only their vote gets the bonus
+      const withBonus = await queryVotes(proposalId, [
+        ...committeeBonusAccounts(),
+        ...voteAccounts,
+      ]);
+      await setParticipationBonus(0, 0, new anchor.BN(0));
+      const withoutBonus = await queryVotes(proposalId, [
+        ...committeeRemainingAccounts(),
+        ...voteAccounts,
+      ]);
+      const rawYes = withoutBonus.yesVotes.toNumber();
+      expect(rawYes).to.be.greaterThan(0);
+      expect(withBonus.yesVotes.toNumber()).to.equal(Math.floor((rawYes * 11_000) / 10_000));
+      expect(withBonus.noVotes.toString()).to.equal(withoutBonus.noVotes.toString());
+      expect(withBonus.totalVotingPower.toNumber()).to.be.greaterThan(
+        withoutBonus.totalVotingPower.toNumber()
+      );
+
+      // A cap at member1's raw voting power leaves no room for the bonus
+      await setParticipationBonus(1000, 1, new anchor.BN(rawYes));
+      const capped = await queryVotes(proposalId, [...committeeBonusAccounts(), ...voteAccounts]);
+      expect(capped.yesVotes.toNumber()).to.equal(rawYes);
+
+      await setParticipationBonus(0, 0, new anchor.BN(0));
+      await finalizeAtVotingEnd(proposalId, [...committeeRemainingAccounts(), ...voteAccounts]);
+    });
+  });
+
+  describe("Token Lockups", () => {
+    const lockDuration = 10;
+    const lockupPda = (member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("lockup"), member.toBuffer()],
+        program.programId
+      )[0];
+    const lockupVaultPda = (member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("lockup_vault"), member.toBuffer()],
+        program.programId
+      )[0];
+    const proposalPda = (proposalId: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+    const votePda = (proposalId: anchor.BN, member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalId.toArrayLike(Buffer, "le", 8), member.toBuffer()],
+        program.programId
+      )[0];
+    const tokenBalance = async (account: PublicKey) =>
+      new anchor.BN((await provider.connection.getTokenAccountBalance(account)).value.amount);
+
+    // Committee accounts while lockups are enabled: each member's pair plus their lockup
+    const committeeLockupAccounts = () =>
+      [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+        [member3, member3TokenAccount],
+      ].flatMap(([member, tokenAccount]: [Keypair, PublicKey]) => [
+        { pubkey: committeeMemberPda(member.publicKey), isWritable: false, isSigner: false },
+        { pubkey: tokenAccount, isWritable: false, isSigner: false },
+        { pubkey: lockupPda(member.publicKey), isWritable: false, isSigner: false },
+      ]);
+
+    const setLockupParameters = (maxLockDuration: number, maxLockMultiplierBps: number) =>
+      program.methods
+        .updateGovernanceConfig(
+          configUpdate({
+            maxLockDuration: new anchor.BN(maxLockDuration),
+            maxLockMultiplierBps,
+          })
+        )
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const lockTokens = (amount: anchor.BN, duration: number) =>
+      program.methods
+        .lockTokens(amount, new anchor.BN(duration))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          lockup: lockupPda(member2.publicKey),
+          lockupVault: lockupVaultPda(member2.publicKey),
+          memberTokenAccount: member2TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          governanceAuthority: governanceAuthorityPda,
+          member: member2.publicKey,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member2])
+        .rpc();
+
+    const withdrawLockedTokens = () =>
+      program.methods
+        .withdrawLockedTokens()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          lockup: lockupPda(member2.publicKey),
+          lockupVault: lockupVaultPda(member2.publicKey),
+          memberTokenAccount: member2TokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          governanceAuthority: governanceAuthorityPda,
+          member: member2.publicKey,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member2])
+        .rpc();
+
+    const queryVotes = (
+      proposalId: anchor.BN,
+      remainingAccounts: { pubkey: PublicKey; isWritable: boolean; isSigner: boolean }[]
+    ) =>
+      program.methods
+        .queryVotingPower(proposalId)
+        .accounts({
+          proposal: proposalPda(proposalId),
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint,
+        })
+        .remainingAccounts(remainingAccounts)
+        .view();
+
+    after(async () => {
+      await setLockupParameters(0, 0);
+    });
+
+    it("Should refuse locks while lockups are disabled or too long", async () => {
+      try {
+        await lockTokens(new anchor.BN(10 ** 9), lockDuration);
+        expect.fail("Should have failed with LockupsDisabled");
+      } catch (error) {
+        expect(error.toString()).to.include("LockupsDisabled");
+      }
+
+      try {
+        await setLockupParameters(lockDuration, 30_000);
+        expect.fail("Should have failed with InvalidLockupParameters");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidLockupParameters");
+      }
+
+      await setLockupParameters(lockDuration, 20_000);
+      try {
+        await lockTokens(new anchor.BN(10 ** 9), lockDuration + 1);
+        expect.fail("Should have failed with InvalidLockDuration");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidLockDuration");
+      }
+    });
+
+    it("Should let a 2x-locked member outweigh an unlocked member with the same balance", async () => {
+      // member2 locks exactly member3's balance for the maximum duration (2x)
+      const balance = await tokenBalance(member3TokenAccount);
+      expect((await tokenBalance(member2TokenAccount)).gte(balance)).to.equal(true);
+      await lockTokens(balance, lockDuration);
+
+      const lockup = await program.account.lockup.fetch(lockupPda(member2.publicKey));
+      expect(lockup.amount.toString()).to.equal(balance.toString());
+      expect(lockup.unlockTime.sub(lockup.lockedAt).toNumber()).to.equal(lockDuration);
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Lockup Weight",
+          "This proposal checks the lockup voting power multiplier.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda(proposalId),
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      for (const [member, voterTokenAccount, voteType, lockupAccount] of [
+        [member2, member2TokenAccount, { yes: {} }, lockupPda(member2.publicKey)],
+        [member3, member3TokenAccount, { no: {} }, null],
+      ] as [Keypair, PublicKey, object, PublicKey | null][]) {
+        await program.methods
+          .castVote(proposalId, voteType as any)
+          .accounts({
+            proposal: proposalPda(proposalId),
+            vote: votePda(proposalId, member.publicKey),
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount,
+            lockup: lockupAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+      const voteAccounts = [member2, member3].map((member) => ({
+        pubkey: votePda(proposalId, member.publicKey),
+        isWritable: false,
+        isSigner: false,
+      }));
+
+      // The multiplier decays with the lock time left when the proposal opened
+      const locked = await queryVotes(proposalId, [...committeeLockupAccounts(), ...voteAccounts]);
+      const unlockedVotes = locked.noVotes.toNumber();
+      expect(unlockedVotes).to.equal(balance.div(new anchor.BN(10 ** 9)).toNumber());
+      expect(locked.yesVotes.toNumber()).to.be.greaterThan(unlockedVotes);
+      expect(locked.yesVotes.toNumber()).to.be.at.least(Math.floor(unlockedVotes * 1.5));
+      expect(locked.yesVotes.toNumber()).to.be.at.most(unlockedVotes * 2);
+
+      // Pairs without lockups no longer match the committee layout
+      try {
+        await queryVotes(proposalId, [...committeeRemainingAccounts(), ...voteAccounts]);
+        expect.fail("Should have failed with InvalidCommitteeAccounts");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidCommitteeAccounts");
+      }
+
+      // Another member's lockup cannot stand in for the member's own
+      const swapped = committeeLockupAccounts();
+      swapped[5] = swapped[8];
+      try {
+        await queryVotes(proposalId, [...swapped, ...voteAccounts]);
+        expect.fail("Should have failed with InvalidCommitteeAccounts");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidCommitteeAccounts");
+      }
+
+      try {
+        await withdrawLockedTokens();
+        expect.fail("Should have failed with TokensStillLocked");
+      } catch (error) {
+        expect(error.toString()).to.include("TokensStillLocked");
+      }
+
+      // Once the lock runs out the tokens come back and the lockup is closed
+      const lockedBalance = await tokenBalance(member2TokenAccount);
+      await new Promise((resolve) => setTimeout(resolve, (lockDuration + 1) * 1000));
+      await withdrawLockedTokens();
+      expect((await tokenBalance(member2TokenAccount)).toString()).to.equal(
+        lockedBalance.add(balance).toString()
+      );
+      expect(await provider.connection.getAccountInfo(lockupPda(member2.publicKey))).to.equal(null);
+      expect(
+        await provider.connection.getAccountInfo(lockupVaultPda(member2.publicKey))
+      ).to.equal(null);
+
+      await setLockupParameters(0, 0);
+      const votingEnd = (await fetchProposal(program, proposalPda(proposalId))).votingEnd;
+      await program.methods
+        .finalizeProposal(proposalId, votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda(proposalId),
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([...committeeRemainingAccounts(), ...voteAccounts])
+        .signers([authority])
+        .rpc();
+    });
+  });
+
+  describe("Vote Receipts", () => {
+    let proposalId: anchor.BN;
+    let proposalPda: PublicKey;
+    let votingPeriod: anchor.BN;
+    const votePda = (member: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalId.toArrayLike(Buffer, "le", 8), member.toBuffer()],
+        program.programId
+      )[0];
+    const getVote = (voter: PublicKey) =>
+      program.methods
+        .getVote(proposalId, voter)
+        .accounts({ vote: votePda(voter) })
+        .view();
+
+    const setVotingPeriod = (newVotingPeriod: anchor.BN) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod: newVotingPeriod,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode: null,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled: null,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement: null,
+          memberStakeCooldown: null,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize: null,
+          maxActiveProposals: null,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos: null,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    before(async () => {
+      // Keep voting open while the receipts are checked
+      votingPeriod = (await program.account.governanceConfig.fetch(governanceConfigPda))
+        .votingPeriod;
+      await setVotingPeriod(new anchor.BN(3600));
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      proposalId = config.proposalCounter.add(new anchor.BN(1));
+      [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Vote Receipt Proposal",
+          "This proposal checks vote receipts.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      for (const [member, voterTokenAccount, voteType] of [
+        [member1, member1TokenAccount, { yes: {} }],
+        [member2, member2TokenAccount, { no: {} }],
+      ] as [Keypair, PublicKey, object][]) {
+        await program.methods
+          .castVote(proposalId, voteType)
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda(member.publicKey),
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+    });
+
+    const closeVote = (voter: PublicKey) =>
+      program.methods
+        .closeVote()
+        .accounts({
+          vote: votePda(voter),
+          proposal: proposalPda,
+          authority: authority.publicKey,
+          governanceConfig: governanceConfigPda,
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+
+    const finalize = async () => {
+      const proposal = await fetchProposal(program, proposalPda);
+      if (!("pending" in proposal.status)) {
+        return;
+      }
+      await program.methods
+        .finalizeProposal(proposalId, proposal.votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...[member1, member2].map((member) => ({
+            pubkey: votePda(member.publicKey),
+            isWritable: false,
+            isSigner: false,
+          })),
+        ])
+        .signers([authority])
+        .rpc();
+    };
+
+    after(async () => {
+      await setVotingPeriod(votingPeriod);
+      await finalize();
+    });
+
+    it("Should return the recorded vote", async () => {
+      const receipt = await getVote(member1.publicKey);
+      const vote = await program.account.vote.fetch(votePda(member1.publicKey));
+      expect(receipt.voteType).to.deep.equal({ yes: {} });
+      expect(receipt.tokenBalanceSnapshot.toString()).to.equal(
+        vote.tokenBalanceSnapshot.toString()
+      );
+      expect(receipt.tokenBalanceSnapshot.toNumber()).to.be.greaterThan(0);
+      expect(receipt.timestamp.toString()).to.equal(vote.timestamp.toString());
+      expect(receipt.isRevoked).to.equal(false);
+    });
+
+    it("Should fail with VoteNotFound for a member who did not vote", async () => {
+      try {
+        await getVote(member3.publicKey);
+        expect.fail("Should have failed with VoteNotFound");
+      } catch (error) {
+        expect(error.toString()).to.include("VoteNotFound");
+      }
+    });
+
+    it("Should refuse to close votes while the proposal is Pending", async () => {
+      try {
+        await closeVote(member1.publicKey);
+        expect.fail("Should have failed with ProposalNotFinalized");
+      } catch (error) {
+        expect(error.toString()).to.include("ProposalNotFinalized");
+      }
+      expect(await provider.connection.getAccountInfo(votePda(member1.publicKey))).to.not.be.null;
+    });
+
+    it("Should close votes of a finalized proposal and emit VoteClosed", async () => {
+      await finalize();
+
+      const events = await decodeEvents(await closeVote(member1.publicKey));
+      expect(events.map((event) => event.name)).to.deep.equal(["voteClosed"]);
+      const closed = events[0].data;
+      expect(closed.proposalId.toString()).to.equal(proposalId.toString());
+      expect(closed.voter.toString()).to.equal(member1.publicKey.toString());
+      expect(closed.vote.toString()).to.equal(votePda(member1.publicKey).toString());
+      expect(closed.closedBy.toString()).to.equal(authority.publicKey.toString());
+      expect(await provider.connection.getAccountInfo(votePda(member1.publicKey))).to.be.null;
+    });
+  });
+
+  describe("Stored PDA Bumps", () => {
+    it("Should store the canonical authority and vault bumps", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const [, authorityBump] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_authority")],
+        program.programId
+      );
+      expect(config.authorityBump).to.equal(authorityBump);
+
+      for (const depositMint of config.depositMints) {
+        const [, vaultBump] = PublicKey.findProgramAddressSync(
+          [Buffer.from("governance_token_vault"), depositMint.mint.toBuffer()],
+          program.programId
+        );
+        const stored = config.tokenVaultBumps.find((entry) => entry.mint.equals(depositMint.mint));
+        expect(stored.bump).to.equal(vaultBump);
+      }
+    });
+
+    it("Should sign the deposit refund with the stored bumps", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Stored Bump Proposal",
+          "This proposal's refund is signed with the stored authority bump.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+
+      const balanceBefore = BigInt(
+        (await provider.connection.getTokenAccountBalance(member1TokenAccount)).value.amount
+      );
+      const votingEnd = (await fetchProposal(program, proposalPda)).votingEnd;
+      await program.methods
+        .finalizeProposal(proposalId, votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(committeeRemainingAccounts())
+        .signers([authority])
+        .rpc();
+
+      // Rejected for lack of participation; the refund transfer was signed by the authority PDA
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ rejected: {} });
+      const balanceAfter = BigInt(
+        (await provider.connection.getTokenAccountBalance(member1TokenAccount)).value.amount
+      );
+      expect((balanceAfter - balanceBefore).toString()).to.equal(
+        proposal.depositRefunded.toString()
+      );
+      expect(proposal.depositRefunded.toNumber()).to.be.greaterThan(0);
+    });
+  });
+
+  describe("Ratified Dispute Decisions", () => {
+    const createDispute = async (proposedDecision: object | null) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Partial Refund Dispute",
+          "Voters ratify a partial refund of the disputed order.",
+          { disputeArbitration: {} },
+          {
+            executionData: {
+              dispute: {
+                0: {
+                  userAddress: member2.publicKey,
+                  merchantAddress: member3.publicKey,
+                  orderAddress: Keypair.generate().publicKey,
+                  disputeType: "item_damaged",
+                  evidenceUrls: ["https://evidence.example.com/damage.png"],
+                  requestedResolution: "Refund part of the order",
+                  arbitrationDecision: null,
+                  proposedDecision,
+                },
+              },
+            },
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          disputeOrder: null,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    it("Should refuse a partial refund of nothing", async () => {
+      try {
+        await createDispute({ partialRefund: { 0: new anchor.BN(0) } });
+        expect.fail("Should have failed with InvalidArbitrationDecision");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidArbitrationDecision");
+      }
+    });
+
+    it("Should record the ratified partial refund at execution", async () => {
+      const { proposalId, proposalPda } = await createDispute({
+        partialRefund: { 0: new anchor.BN(2_000_000) },
+      });
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      // Nothing is recorded before execution
+      let summary = await program.methods
+        .getProposalSummary(proposalId)
+        .accounts({ proposal: proposalPda })
+        .view();
+      expect(summary.arbitrationDecision).to.equal(null);
+
+      const votingEnd = (await fetchProposal(program, proposalPda)).votingEnd;
+      await program.methods
+        .finalizeProposal(proposalId, votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+      await program.methods
+        .executeProposal(proposalId, votingEnd.addn(60))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          dependencyProposal: null,
+          committeeMember: null,
+          memberStake: null,
+          payer: authority.publicKey,
+          systemProgram: null,
+          upgradeProgram: null,
+          upgradeProgramData: null,
+          upgradeBuffer: null,
+          governanceAuthority: governanceAuthorityPda,
+          bpfLoaderUpgradeable: null,
+          rent: null,
+          clock: null,
+          executionAdapter: null,
+        })
+        .signers([authority])
+        .rpc();
+
+      summary = await program.methods
+        .getProposalSummary(proposalId)
+        .accounts({ proposal: proposalPda })
+        .view();
+      expect(summary.status).to.deep.equal({ executed: {} });
+      expect(summary.arbitrationDecision.partialRefund[0].toString()).to.equal("2000000");
+    });
+  });
+
+  describe("Execution Data Dry Run", () => {
+    const registryName = "dry_run";
+    const [registryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("rule_registry"), Buffer.from(registryName)],
+      program.programId
+    );
+    const [adapterRegistryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("execution_adapter_registry")],
+      program.programId
+    );
+    const ruleUpdate = (fields: object) => ({
+      ruleUpdate: {
+        0: {
+          registryName,
+          operation: { remove: {} },
+          documentId: null,
+          documentData: null,
+          effectiveFrom: null,
+          ...fields,
+        },
+      },
+    });
+    const dryRun = (proposalType: object, executionData: object | null, withRegistry = false) =>
+      program.methods
+        .validateExecutionData(proposalType as any, executionData as any)
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          ruleRegistry: withRegistry ? registryPda : null,
+          executionAdapterRegistry: adapterRegistryPda,
+        })
+        .view();
+
+    before(async () => {
+      await program.methods
+        .createRuleRegistry(registryName)
+        .accounts({
+          ruleRegistry: registryPda,
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    const cases: [string, object, object, object][] = [
+      [
+        "slash",
+        { slashMerchant: {} },
+        {
+          slash: {
+            0: {
+              merchantAddress: member3.publicKey,
+              productAddress: null,
+              orderAddress: null,
+              violationType: "counterfeit",
+              evidenceUrls: ["https://evidence.example.com/a.png", "https://evidence.example.com/b.png"],
+              slashAmount: new anchor.BN(1_000_000),
+            },
+          },
+        },
+        {
+          slash: {
+            0: {
+              merchantAddress: PublicKey.default,
+              productAddress: null,
+              orderAddress: null,
+              violationType: "counterfeit",
+              evidenceUrls: ["https://evidence.example.com/a.png", "https://evidence.example.com/b.png"],
+              slashAmount: new anchor.BN(1_000_000),
+            },
+          },
+        },
+      ],
+      [
+        "dispute",
+        { disputeArbitration: {} },
+        {
+          dispute: {
+            0: {
+              userAddress: member2.publicKey,
+              merchantAddress: member3.publicKey,
+              orderAddress: Keypair.generate().publicKey,
+              disputeType: "item_damaged",
+              evidenceUrls: ["https://evidence.example.com/damage.png"],
+              requestedResolution: "Refund the order",
+              arbitrationDecision: null,
+              proposedDecision: { refundUser: {} },
+            },
+          },
+        },
+        {
+          dispute: {
+            0: {
+              userAddress: member2.publicKey,
+              merchantAddress: member3.publicKey,
+              orderAddress: Keypair.generate().publicKey,
+              disputeType: "item_damaged",
+              evidenceUrls: ["https://evidence.example.com/damage.png"],
+              requestedResolution: "Refund the order",
+              arbitrationDecision: null,
+              proposedDecision: { partialRefund: { 0: new anchor.BN(0) } },
+            },
+          },
+        },
+      ],
+      [
+        "rule update",
+        { ruleUpdate: {} },
+        ruleUpdate({}),
+        ruleUpdate({ registryName: "r".repeat(33) }),
+      ],
+      [
+        "config update",
+        { configUpdate: {} },
+        { configUpdate: { 0: { configUpdate: configUpdate({ feeRate: 500 }) } } },
+        { configUpdate: { 0: { configUpdate: configUpdate({ feeRate: 10001 }) } } },
+      ],
+      [
+        "committee change",
+        { committeeChange: {} },
+        { committeeChange: { 0: { action: { add: {} }, member: Keypair.generate().publicKey } } },
+        { committeeChange: { 0: { action: { add: {} }, member: PublicKey.default } } },
+      ],
+      [
+        "program upgrade",
+        { programUpgrade: {} },
+        {
+          programUpgrade: {
+            0: { programId: Keypair.generate().publicKey, buffer: Keypair.generate().publicKey },
+          },
+        },
+        { programUpgrade: { 0: { programId: PublicKey.default, buffer: PublicKey.default } } },
+      ],
+    ];
+
+    for (const [name, proposalType, valid, invalid] of cases) {
+      it(`Should report a valid and an invalid ${name} payload`, async () => {
+        const accepted = await dryRun(proposalType, valid);
+        expect(accepted.valid).to.equal(true);
+        expect(accepted.payloadValid).to.equal(true);
+        expect(accepted.matchesProposalType).to.equal(true);
+
+        const refused = await dryRun(proposalType, invalid);
+        expect(refused.valid).to.equal(false);
+        expect(refused.payloadValid).to.equal(false);
+      });
+    }
+
+    it("Should check config update fields at creation and at execution", async () => {
+      const refused = await dryRun(
+        { configUpdate: {} },
+        { configUpdate: { 0: { configUpdate: configUpdate({ feeRate: 10001 }) } } }
+      );
+      expect(refused.payloadValid).to.equal(false);
+      expect(refused.configUpdateValid).to.equal(false);
+
+      // Turning test mode on is refused for proposals only; the execution-time
+      // validation still accepts it while test mode is set
+      const enablesTestMode = await dryRun(
+        { configUpdate: {} },
+        { configUpdate: { 0: { configUpdate: configUpdate({ testMode: true }) } } }
+      );
+      expect(enablesTestMode.payloadValid).to.equal(false);
+      expect(enablesTestMode.configUpdateValid).to.equal(true);
+      expect(enablesTestMode.valid).to.equal(false);
+    });
+
+    it("Should report a payload filed under another proposal type", async () => {
+      const result = await dryRun({ slashMerchant: {} }, ruleUpdate({}));
+      expect(result.payloadValid).to.equal(true);
+      expect(result.matchesProposalType).to.equal(false);
+      expect(result.valid).to.equal(false);
+    });
+
+    it("Should check rule updates against the registry's documents", async () => {
+      const missing = await dryRun({ ruleUpdate: {} }, ruleUpdate({ documentId: 999 }), true);
+      expect(missing.ruleRegistryValid).to.equal(false);
+      expect(missing.valid).to.equal(false);
+
+      const unchecked = await dryRun({ ruleUpdate: {} }, ruleUpdate({ documentId: 999 }));
+      expect(unchecked.ruleRegistryValid).to.equal(null);
+    });
+
+    it("Should report the adapter registered for the proposal type", async () => {
+      const registry = await program.account.executionAdapterRegistry.fetchNullable(
+        adapterRegistryPda
+      );
+      const registered = registry?.adapters.find((adapter) => "ruleUpdate" in adapter.proposalType);
+      const result = await dryRun({ ruleUpdate: {} }, ruleUpdate({}));
+      expect(result.executionAdapter?.toBase58() ?? null).to.equal(
+        registered?.programId.toBase58() ?? null
+      );
+    });
+  });
+
+  describe("Config Update Proposal Checks", () => {
+    const createConfigUpdateProposal = async (fields: object) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Config Update",
+          "This proposal updates the governance configuration.",
+          { configUpdate: {} },
+          {
+            executionData: { configUpdate: { 0: { configUpdate: configUpdate(fields) } } } as any,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+    };
+
+    const expectRefused = async (fields: object, errorName: string) => {
+      try {
+        await createConfigUpdateProposal(fields);
+        expect.fail(`Should have failed with ${errorName}`);
+      } catch (error) {
+        expect(error.toString()).to.include(errorName);
+      }
+    };
+
+    it("Should refuse a proposal turning test mode on", async () => {
+      await expectRefused({ testMode: true }, "ProposalEnablesTestMode");
+      await expectRefused(
+        { testMode: true, votingPeriod: new anchor.BN(30) },
+        "ProposalEnablesTestMode"
+      );
+    });
+
+    it("Should refuse thresholds that leave the veto unable to block an approval", async () => {
+      await expectRefused({ approvalThreshold: 8000, vetoThreshold: 3000 }, "ConflictingThresholds");
+
+      // Thresholds the update leaves unset are taken from the current config
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      await expectRefused(
+        { vetoThreshold: 10_000 - config.approvalThreshold + 1 },
+        "ConflictingThresholds"
+      );
+    });
+
+    it("Should refuse fields the execution-time validation would refuse", async () => {
+      await expectRefused({ feeRate: 10001 }, "InvalidFeeRate");
+      await expectRefused({ maxActiveProposals: 0 }, "InvalidGovernanceLimit");
+    });
+  });
+
+  describe("Proposer Reputation", () => {
+    const createProposal = async (title: string) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const signature = await program.methods
+        .createProposal(
+          proposalId,
+          title,
+          "Proposal used to track the proposer's record.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member2.publicKey,
+          proposerState: proposerStatePda(member2.publicKey),
+          proposerTokenAccount: member2TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member2])
+        .rpc({ commitment: "confirmed" });
+      return { proposalId, proposalPda, signature };
+    };
+
+    it("Should snapshot a veto onto the proposer's next proposal", async () => {
+      const { proposalId, proposalPda, signature } = await createProposal(
+        "Reputation Vetoed Proposal"
+      );
+      // The first proposal creates the proposer's state
+      const before = (await program.account.proposerState.fetch(proposerStatePda(member2.publicKey)))
+        .reputation;
+      const vetoed = await fetchProposal(program, proposalPda);
+      expect(vetoed.proposerVetoedCount).to.equal(before.vetoed);
+      const [created] = (await decodeEvents(signature)).filter(
+        (event) => event.name === "proposalCreated"
+      );
+      expect(created.data.proposerReputation.vetoed).to.equal(before.vetoed);
+
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { noWithVeto: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      await program.methods
+        .finalizeProposal(proposalId, vetoed.votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member2TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member2.publicKey,
+          proposerState: proposerStatePda(member2.publicKey),
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+      expect((await fetchProposal(program, proposalPda)).status).to.deep.equal({ vetoed: {} });
+
+      const after = (await program.account.proposerState.fetch(proposerStatePda(member2.publicKey)))
+        .reputation;
+      expect(after.vetoed).to.equal(before.vetoed + 1);
+      expect(after.passed).to.equal(before.passed);
+      expect(after.rejected).to.equal(before.rejected);
+
+      const next = await createProposal("Reputation Follow-up Proposal");
+      const followUp = await fetchProposal(program, next.proposalPda);
+      expect(followUp.proposerVetoedCount).to.equal(before.vetoed + 1);
+      expect(followUp.proposerPassedCount).to.equal(after.passed);
+      const [nextCreated] = (await decodeEvents(next.signature)).filter(
+        (event) => event.name === "proposalCreated"
+      );
+      expect(nextCreated.data.proposerReputation.vetoed).to.equal(before.vetoed + 1);
+    });
+  });
+
+  describe("Multi-Choice Proposals", () => {
+    const feeTiers = ["Tier A: 1%", "Tier B: 2.5%", "Tier C: 5%"];
+
+    const createProposal = async (options: string[] | null, executionData: any = null) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Choose Fee Tier",
+          "Pick the committee fee tier.",
+          { configUpdate: {} },
+          {
+            executionData,
+            customDepositRaw: null,
+            dependsOn: null,
+            options,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerState: proposerStatePda(member1.publicKey),
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    const castVote = async (
+      proposalId: anchor.BN,
+      proposalPda: PublicKey,
+      member: Keypair,
+      memberTokenAccount: PublicKey,
+      voteType: any
+    ) => {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalId.toArrayLike(Buffer, "le", 8), member.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .castVote(proposalId, voteType)
+        .accounts({
+          proposal: proposalPda,
+          vote: votePda,
+          governanceConfig: governanceConfigPda,
+          voter: member.publicKey,
+          voterTokenAccount: memberTokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member])
+        .rpc();
+      return votePda;
+    };
+
+    it("Should refuse malformed options", async () => {
+      for (const [options, executionData] of [
+        [["Only option"], null],
+        [["Tier A", "Tier A"], null],
+        [["Tier A", ""], null],
+        [["1", "2", "3", "4", "5", "6"], null],
+        [["x".repeat(33), "Tier B"], null],
+        [feeTiers, { configUpdate: { 0: { configUpdate: configUpdate({ feeRate: 100 }) } } }],
+      ] as [string[], any][]) {
+        try {
+          await createProposal(options, executionData);
+          expect.fail("Should have failed with InvalidProposalOptions");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidProposalOptions");
+        }
+      }
+    });
+
+    it("Should run a three-option vote end to end", async () => {
+      const { proposalId, proposalPda } = await createProposal(feeTiers);
+      const created = await fetchProposal(program, proposalPda);
+      expect(created.options).to.deep.equal(feeTiers);
+      expect(created.winningOption).to.equal(null);
+
+      // Multi-choice proposals take a valid choice or an abstention
+      for (const voteType of [{ yes: {} }, { noWithVeto: {} }, { choice: { 0: 3 } }]) {
+        try {
+          await castVote(proposalId, proposalPda, member1, member1TokenAccount, voteType);
+          expect.fail("Should have failed with InvalidVoteChoice");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidVoteChoice");
+        }
+      }
+
+      const votePdas = [
+        await castVote(proposalId, proposalPda, member1, member1TokenAccount, {
+          choice: { 0: 0 },
+        }),
+        await castVote(proposalId, proposalPda, member2, member2TokenAccount, {
+          choice: { 0: 1 },
+        }),
+        await castVote(proposalId, proposalPda, member3, member3TokenAccount, {
+          choice: { 0: 1 },
+        }),
+      ];
+      const receipt = await program.methods
+        .getVote(proposalId, member2.publicKey)
+        .accounts({ vote: votePdas[1] })
+        .view();
+      expect(receipt.voteType).to.deep.equal({ choice: { 0: 1 } });
+
+      await program.methods
+        .finalizeProposal(proposalId, created.votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          proposerState: proposerStatePda(member1.publicKey),
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+      const proposal = await fetchProposal(program, proposalPda);
+      const optionsTally = proposal.optionsTally.map((votes: anchor.BN) => votes.toNumber());
+      expect(optionsTally[1]).to.be.greaterThan(optionsTally[0]);
+      expect(optionsTally.slice(2)).to.deep.equal([0, 0, 0]);
+      expect(proposal.yesVotes.toNumber()).to.equal(0);
+      expect(proposal.totalVotes.toNumber()).to.equal(optionsTally[0] + optionsTally[1]);
+
+      // Approval is the winning option's share of all votes
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(proposal.approvalRate).to.equal(
+        Math.floor((optionsTally[1] * 10000) / proposal.totalVotes.toNumber())
+      );
+      expect(proposal.approvalRate).to.be.greaterThan(config.approvalThreshold);
+      expect(proposal.status).to.deep.equal({ passed: {} });
+      expect(proposal.winningOption).to.equal(1);
+
+      const summary = await program.methods
+        .getProposalSummary(proposalId)
+        .accounts({ proposal: proposalPda })
+        .view();
+      expect(summary.winningOption).to.equal(1);
+    });
+
+    it("Should refuse choices on binary proposals", async () => {
+      const { proposalId, proposalPda } = await createProposal(null);
+      try {
+        await castVote(proposalId, proposalPda, member1, member1TokenAccount, {
+          choice: { 0: 0 },
+        });
+        expect.fail("Should have failed with InvalidVoteChoice");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidVoteChoice");
+      }
+      await castVote(proposalId, proposalPda, member1, member1TokenAccount, { yes: {} });
+      expect((await fetchProposal(program, proposalPda)).options).to.deep.equal([]);
+    });
+  });
+
+  describe("Recused Members", () => {
+    const createProposal = async (
+      recusedMembers: PublicKey[] | null,
+      recusedAccounts = recusedMembers ?? []
+    ) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Dispute Involving A Member",
+          "A committee member's own merchant account is party to this dispute.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerState: proposerStatePda(member1.publicKey),
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          recusedAccounts.map((member) => ({
+            pubkey: committeeMemberPda(member),
+            isWritable: false,
+            isSigner: false,
+          }))
+        )
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    const castVote = async (
+      proposalId: anchor.BN,
+      proposalPda: PublicKey,
+      member: Keypair,
+      memberTokenAccount: PublicKey
+    ) => {
+      const [votePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalId.toArrayLike(Buffer, "le", 8), member.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .castVote(proposalId, { yes: {} })
+        .accounts({
+          proposal: proposalPda,
+          vote: votePda,
+          governanceConfig: governanceConfigPda,
+          voter: member.publicKey,
+          voterTokenAccount: memberTokenAccount,
+          committeeTokenMint: committeeTokenMint,
+          committeeTokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([member])
+        .rpc();
+      return votePda;
+    };
+
+    const queryTotalVotingPower = async (proposalId: anchor.BN, proposalPda: PublicKey) =>
+      (
+        await program.methods
+          .queryVotingPower(proposalId)
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            committeeTokenMint,
+          })
+          .remainingAccounts(committeeRemainingAccounts())
+          .view()
+      ).totalVotingPower.toNumber();
+
+    it("Should only recuse distinct current committee members", async () => {
+      const outsider = Keypair.generate().publicKey;
+      for (const [recusedMembers, recusedAccounts] of [
+        [[outsider], [outsider]],
+        [[member3.publicKey], []],
+        [[member3.publicKey], [member2.publicKey]],
+        [
+          [member3.publicKey, member3.publicKey],
+          [member3.publicKey, member3.publicKey],
+        ],
+      ] as [PublicKey[], PublicKey[]][]) {
+        try {
+          await createProposal(recusedMembers, recusedAccounts);
+          expect.fail("Should have failed with InvalidRecusedMembers");
+        } catch (error) {
+          expect(error.toString()).to.include("InvalidRecusedMembers");
+        }
+      }
+    });
+
+    it("Should refuse the recused member's vote and leave its balance out of the quorum", async () => {
+      const recused = await createProposal([member3.publicKey]);
+      const regular = await createProposal(null);
+      expect((await fetchProposal(program, recused.proposalPda)).recusedCount).to.equal(1);
+
+      try {
+        await castVote(recused.proposalId, recused.proposalPda, member3, member3TokenAccount);
+        expect.fail("Should have failed with VoterRecused");
+      } catch (error) {
+        expect(error.toString()).to.include("VoterRecused");
+      }
+
+      const member3Balance = await provider.connection.getTokenAccountBalance(member3TokenAccount);
+      const member3Power = Math.floor(Number(member3Balance.value.amount) / 10 ** 9);
+      const fullPower = await queryTotalVotingPower(regular.proposalId, regular.proposalPda);
+      const recusedPower = await queryTotalVotingPower(recused.proposalId, recused.proposalPda);
+      expect(member3Power).to.be.greaterThan(0);
+      expect(recusedPower).to.equal(fullPower - member3Power);
+
+      const votePdas = [
+        await castVote(recused.proposalId, recused.proposalPda, member1, member1TokenAccount),
+        await castVote(recused.proposalId, recused.proposalPda, member2, member2TokenAccount),
+      ];
+      const proposal = await fetchProposal(program, recused.proposalPda);
+      await program.methods
+        .finalizeProposal(recused.proposalId, proposal.votingEnd.addn(1))
+        .accounts({
+          proposal: recused.proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          proposerState: proposerStatePda(member1.publicKey),
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(recused.proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+      const finalized = await fetchProposal(program, recused.proposalPda);
+      expect(finalized.finalizedVotingPower.toNumber()).to.equal(recusedPower);
+      expect(finalized.participationRate).to.equal(10000);
+      expect(finalized.status).to.deep.equal({ passed: {} });
+    });
+  });
+
+  describe("Governance Heartbeat", () => {
+    let vaultPda: PublicKey;
+    const setHeartbeatInterval = (heartbeatInterval: anchor.BN) =>
+      program.methods
+        .updateGovernanceConfig(configUpdate({ heartbeatInterval }))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const touch = () =>
+      program.methods
+        .touchGovernance()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          governanceTokenVault: vaultPda,
+        })
+        .rpc({ commitment: "confirmed" });
+
+    before(async () => {
+      [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_token_vault"), committeeTokenMint.toBuffer()],
+        program.programId
+      );
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.heartbeatInterval.toNumber()).to.equal(3600);
+      // A two-second interval keeps the suite fast
+      await setHeartbeatInterval(new anchor.BN(2));
+    });
+
+    after(async () => {
+      await setHeartbeatInterval(new anchor.BN(3600));
+    });
+
+    it("Should refuse a zero heartbeat interval", async () => {
+      try {
+        await setHeartbeatInterval(new anchor.BN(0));
+        expect.fail("Should have failed with InvalidDuration");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidDuration");
+      }
+    });
+
+    it("Should emit the governance summary", async () => {
+      const events = await decodeEvents(await touch());
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const vault = await getAccount(provider.connection, vaultPda);
+
+      expect(events.map((event) => event.name)).to.deep.equal(["heartbeat"]);
+      const heartbeat = events[0].data;
+      expect(heartbeat.governanceConfig.toString()).to.equal(governanceConfigPda.toString());
+      expect(heartbeat.proposalCounter.toString()).to.equal(config.proposalCounter.toString());
+      expect(heartbeat.pendingProposalCount.toString()).to.equal(
+        config.pendingProposalCount.toString()
+      );
+      expect(heartbeat.committeeEpoch.toString()).to.equal(config.committeeEpoch.toString());
+      expect(heartbeat.vaultMint.toString()).to.equal(committeeTokenMint.toString());
+      expect(heartbeat.vaultBalance.toString()).to.equal(vault.amount.toString());
+      expect(heartbeat.timestamp.toString()).to.equal(config.lastHeartbeatAt.toString());
+    });
+
+    it("Should rate limit heartbeats to one per interval", async () => {
+      try {
+        await touch();
+        expect.fail("Should have failed with HeartbeatTooSoon");
+      } catch (error) {
+        expect(error.toString()).to.include("HeartbeatTooSoon");
+      }
+
+      const before = await program.account.governanceConfig.fetch(governanceConfigPda);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await touch();
+      const after = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(after.lastHeartbeatAt.toNumber()).to.be.at.least(before.lastHeartbeatAt.toNumber() + 2);
+    });
+  });
+
+  describe("Committee Deposit Waiver", () => {
+    before(async () => {
+      await program.methods
+        .updateGovernanceConfig(configUpdate({ waiveDepositForCommittee: true }))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .updateGovernanceConfig(configUpdate({ waiveDepositForCommittee: false }))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    const createWaivedProposal = async (proposerCommitteeMember: PublicKey | null) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Committee Housekeeping",
+          "Routine committee housekeeping filed without a deposit.",
+          { configUpdate: {} },
+          {
+            executionData: null,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerState: proposerStatePda(member1.publicKey),
+          proposerCommitteeMember,
+          proposerTokenAccount: null,
+          depositMint: null,
+          governanceTokenVault: null,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: null,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    it("Should still charge the deposit without the proposer's committee member account", async () => {
+      try {
+        await createWaivedProposal(null);
+        expect.fail("Should have failed with TokenAccountsRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("TokenAccountsRequired");
+      }
+    });
+
+    it("Should create and settle a committee member's proposal without a deposit", async () => {
+      const balanceBefore = await provider.connection.getTokenAccountBalance(member1TokenAccount);
+      const { proposalId, proposalPda } = await createWaivedProposal(
+        committeeMemberPda(member1.publicKey)
+      );
+
+      const created = await fetchProposal(program, proposalPda);
+      expect(created.depositAmount.toNumber()).to.equal(0);
+      expect(created.depositEscrowed).to.equal(false);
+      const balanceAfter = await provider.connection.getTokenAccountBalance(member1TokenAccount);
+      expect(balanceAfter.value.amount).to.equal(balanceBefore.value.amount);
+
+      const votePdas = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+        [member3, member3TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("vote"), proposalId.toArrayLike(Buffer, "le", 8), member.publicKey.toBuffer()],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      // No deposit accounts are needed to finalize a waived deposit
+      await program.methods
+        .finalizeProposal(proposalId, created.votingEnd.addn(1))
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: null,
+          governanceTokenVault: null,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: null,
+          proposerState: proposerStatePda(member1.publicKey),
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: null,
+          tokenProgram: null,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+      const finalized = await fetchProposal(program, proposalPda);
+      expect(finalized.status).to.deep.equal({ passed: {} });
+      expect(finalized.depositSettled).to.equal(true);
+      expect(finalized.depositAmount.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("Config Update Timelock", () => {
+    const [pendingConfigUpdatePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_config_update")],
+      program.programId
+    );
+
+    const queueConfigUpdate = (fields: object) =>
+      program.methods
+        .queueConfigUpdate(configUpdate(fields))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          pendingConfigUpdate: pendingConfigUpdatePda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+    const applyConfigUpdate = () =>
+      program.methods
+        .applyConfigUpdate()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          pendingConfigUpdate: pendingConfigUpdatePda,
+          queuedBy: authority.publicKey,
+        })
+        .rpc();
+
+    const getPendingConfigUpdate = () =>
+      program.methods
+        .getPendingConfigUpdate()
+        .accounts({ pendingConfigUpdate: pendingConfigUpdatePda })
+        .view();
+
+    before(async () => {
+      // A two-second timelock keeps the suite fast; it stays in place for the
+      // production-mode tests that follow
+      await program.methods
+        .updateGovernanceConfig(configUpdate({ configTimelock: new anchor.BN(2) }))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should expose a queued update through the read instruction", async () => {
+      expect(await getPendingConfigUpdate()).to.equal(null);
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      await queueConfigUpdate({ maxActiveProposals: config.maxActiveProposals + 1 });
+
+      const pending = await getPendingConfigUpdate();
+      expect(pending.update.maxActiveProposals).to.equal(config.maxActiveProposals + 1);
+      expect(pending.update.votingPeriod).to.equal(null);
+      expect(pending.queuedBy.toString()).to.equal(authority.publicKey.toString());
+      expect(pending.eta.toNumber()).to.equal(pending.queuedAt.toNumber() + 2);
+      expect(pending.ready).to.equal(false);
+    });
+
+    it("Should refuse to apply an update before its eta", async () => {
+      try {
+        await applyConfigUpdate();
+        expect.fail("Should have failed with ConfigUpdateTimelocked");
+      } catch (error) {
+        expect(error.toString()).to.include("ConfigUpdateTimelocked");
+      }
+
+      // Only one update is queued at a time
+      try {
+        await queueConfigUpdate({ emitMemos: false });
+        expect.fail("Should have failed with the pending update in place");
+      } catch (error) {
+        expect(error.toString()).to.include("already in use");
+      }
+    });
+
+    it("Should apply an update after its eta", async () => {
+      const before = await program.account.governanceConfig.fetch(governanceConfigPda);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      expect((await getPendingConfigUpdate()).ready).to.equal(true);
+
+      // Anyone can apply; the update is recorded as made by the admin that queued it
+      await applyConfigUpdate();
+
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.maxActiveProposals).to.equal(before.maxActiveProposals + 1);
+      expect(config.votingPeriod.toNumber()).to.equal(before.votingPeriod.toNumber());
+      expect(await provider.connection.getAccountInfo(pendingConfigUpdatePda)).to.equal(null);
+      expect(await getPendingConfigUpdate()).to.equal(null);
+    });
+
+    it("Should drop a cancelled update", async () => {
+      const before = await program.account.governanceConfig.fetch(governanceConfigPda);
+      await queueConfigUpdate({ maxActiveProposals: before.maxActiveProposals - 1 });
+
+      const cancelConfigUpdate = (signer: Keypair) =>
+        program.methods
+          .cancelConfigUpdate()
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            pendingConfigUpdate: pendingConfigUpdatePda,
+            queuedBy: authority.publicKey,
+            authority: signer.publicKey,
+          })
+          .signers([signer])
+          .rpc();
+
+      try {
+        await cancelConfigUpdate(member1);
+        expect.fail("Should have failed with Unauthorized");
+      } catch (error) {
+        expect(error.toString()).to.include("Unauthorized");
+      }
+
+      await cancelConfigUpdate(authority);
+      expect(await getPendingConfigUpdate()).to.equal(null);
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      try {
+        await applyConfigUpdate();
+        expect.fail("Should have failed with no pending update");
+      } catch (error) {
+        expect(error.toString()).to.include("AccountNotInitialized");
+      }
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.maxActiveProposals).to.equal(before.maxActiveProposals);
+    });
+  });
+
+  describe("Committee Change Proposals", () => {
+    const newMember = Keypair.generate();
+    let newMemberTokenAccount: PublicKey;
+
+    const setTestMode = (testMode: boolean) =>
+      program.methods
+        .updateGovernanceConfig({
+          proposalDeposit: null,
+          votingPeriod: null,
+          participationThreshold: null,
+          approvalThreshold: null,
+          vetoThreshold: null,
+          feeRate: null,
+          testMode,
+          resubmissionDepositRate: null,
+          slashEscalationRate: null,
+          maxSlashDepositMultiplier: null,
+          confiscationPolicy: null,
+          depositEscrowEnabled: null,
+          depositSlashSchedule: null,
+          inactivityThreshold: null,
+          memberStakeRequirement: null,
+          memberStakeCooldown: null,
+          stakeBasedVotingPower: null,
+          maxCommitteeSize: null,
+          maxActiveProposals: null,
+          maxRuleEffectiveDelay: null,
+          strictUrlValidation: null,
+          emitMemos: null,
+          configTimelock: null,
+          waiveDepositForCommittee: null,
+          votingPowerToleranceBps: null,
+          heartbeatInterval: null,
+          rateRounding: null,
+          participationBonusBps: null,
+          participationBonusWindow: null,
+          maxMemberVotingPower: null,
+          maxLockDuration: null,
+          maxLockMultiplierBps: null,
+        })
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const adminChange = (action: "add" | "remove") =>
+      (action === "add"
+        ? program.methods.addCommitteeMember(newMember.publicKey)
+        : program.methods.removeCommitteeMember(newMember.publicKey)
+      )
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          committeeMember: committeeMemberPda(newMember.publicKey),
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const createChangeProposal = async (action: object, member: PublicKey) => {
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      const proposalId = config.proposalCounter.add(new anchor.BN(1));
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      await program.methods
+        .createProposal(
+          proposalId,
+          "Committee Change",
+          "This proposal changes the committee membership.",
+          { committeeChange: {} },
+          {
+            executionData: { committeeChange: { 0: { action, member } } } as any,
+            customDepositRaw: null,
+            dependsOn: null,
+            options: null,
+            recusedMembers: null,
+          }
+        )
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          proposer: member1.publicKey,
+          proposerTokenAccount: member1TokenAccount,
+          depositMint: committeeTokenMint,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          governanceAuthority: governanceAuthorityPda,
+          committeeMember: committeeMemberPda(member),
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([member1])
+        .rpc();
+      return { proposalId, proposalPda };
+    };
+
+    // member1 and member2 approve, then the proposal is finalized with the given committee
+    const passProposal = async (
+      proposalId: anchor.BN,
+      proposalPda: PublicKey,
+      includeNewMember: boolean
+    ) => {
+      const votePdas: PublicKey[] = [];
+      for (const [member, memberTokenAccount] of [
+        [member1, member1TokenAccount],
+        [member2, member2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member.publicKey,
+            voterTokenAccount: memberTokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+        votePdas.push(votePda);
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      const newMemberAccounts = includeNewMember
+        ? [
+            {
+              pubkey: committeeMemberPda(newMember.publicKey),
+              isWritable: false,
+              isSigner: false,
+            },
+            { pubkey: newMemberTokenAccount, isWritable: false, isSigner: false },
+          ]
+        : [];
+      await program.methods
+        .finalizeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          committeeTokenMint: committeeTokenMint,
+          proposerTokenAccount: member1TokenAccount,
+          governanceTokenVault: governanceTokenVaultPda,
+          nativeDepositVault: null,
+          depositEscrow: null,
+          proposer: member1.publicKey,
+          governanceAuthority: governanceAuthorityPda,
+          depositMint: committeeTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          proposalOutcome: proposalOutcomePda(proposalId),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...committeeRemainingAccounts(),
+          ...newMemberAccounts,
+          ...votePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+        ])
+        .signers([authority])
+        .rpc();
+
+      const proposal = await fetchProposal(program, proposalPda);
+      expect(proposal.status).to.deep.equal({ passed: {} });
+    };
+
+    const executeChange = (proposalId: anchor.BN, proposalPda: PublicKey) =>
+      program.methods
+        .executeProposal(proposalId, null)
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig: governanceConfigPda,
+          dependencyProposal: null,
+          committeeMember: committeeMemberPda(newMember.publicKey),
+          memberStake: memberStakePda(newMember.publicKey),
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+    before(async () => {
+      newMemberTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        authority,
+        committeeTokenMint,
+        newMember.publicKey
+      );
+    });
+
+    it("Should allow both paths in test mode", async () => {
+      await adminChange("add");
+      await adminChange("remove");
+
+      const { proposalId, proposalPda } = await createChangeProposal(
+        { add: {} },
+        newMember.publicKey
+      );
+      await passProposal(proposalId, proposalPda, false);
+      await executeChange(proposalId, proposalPda);
+
+      const committeeMember = await program.account.committeeMember.fetch(
+        committeeMemberPda(newMember.publicKey)
+      );
+      expect(committeeMember.member.toString()).to.equal(newMember.publicKey.toString());
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.committeeMemberCount).to.equal(4);
+    });
+
+    it("Should reject committee changes that do not apply at creation", async () => {
+      try {
+        await createChangeProposal({ add: {} }, member1.publicKey);
+        expect.fail("Should have failed with MemberAlreadyExists");
+      } catch (error) {
+        expect(error.toString()).to.include("MemberAlreadyExists");
+      }
+
+      try {
+        await createChangeProposal({ remove: {} }, Keypair.generate().publicKey);
+        expect.fail("Should have failed with MemberNotFound");
+      } catch (error) {
+        expect(error.toString()).to.include("MemberNotFound");
+      }
+    });
+
+    // Clears test mode, so it runs right before the production mode tests
+    it("Should spend fewer compute units once the readable logs are off", async () => {
+      const computeUnits = async (signature: string) =>
+        (
+          await provider.connection.getTransaction(signature, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+          })
+        ).meta.computeUnitsConsumed;
+
+      // Create, vote on and finalize a proposal, returning the units of each step
+      const lifecycleUnits = async () => {
+        const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+        const proposalId = config.proposalCounter.add(new anchor.BN(1));
+        const [proposalPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        );
+        const [votePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("vote"),
+            proposalId.toArrayLike(Buffer, "le", 8),
+            member2.publicKey.toBuffer(),
+          ],
+          program.programId
+        );
+        const create = await program.methods
+          .createProposal(
+            proposalId,
+            "Log Cost Proposal",
+            "This proposal measures the cost of the operational logs.",
+            { configUpdate: {} },
+            {
+              executionData: null,
+              customDepositRaw: null,
+              dependsOn: null,
+              options: null,
+              recusedMembers: null,
+            }
+          )
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            proposer: member1.publicKey,
+            proposerTokenAccount: member1TokenAccount,
+            depositMint: committeeTokenMint,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            governanceAuthority: governanceAuthorityPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([member1])
+          .rpc({ commitment: "confirmed" });
+        const vote = await program.methods
+          .castVote(proposalId, { yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            vote: votePda,
+            governanceConfig: governanceConfigPda,
+            voter: member2.publicKey,
+            voterTokenAccount: member2TokenAccount,
+            committeeTokenMint: committeeTokenMint,
+            committeeTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member2])
+          .rpc({ commitment: "confirmed" });
+
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+        const finalize = await program.methods
+          .finalizeProposal(proposalId, null)
+          .accounts({
+            proposal: proposalPda,
+            governanceConfig: governanceConfigPda,
+            committeeTokenMint: committeeTokenMint,
+            proposerTokenAccount: member1TokenAccount,
+            governanceTokenVault: governanceTokenVaultPda,
+            nativeDepositVault: null,
+            depositEscrow: null,
+            proposer: member1.publicKey,
+            governanceAuthority: governanceAuthorityPda,
+            depositMint: committeeTokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            proposalOutcome: proposalOutcomePda(proposalId),
+            payer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts([
+            ...committeeRemainingAccounts(),
+            {
+              pubkey: committeeMemberPda(newMember.publicKey),
+              isWritable: false,
+              isSigner: false,
+            },
+            { pubkey: newMemberTokenAccount, isWritable: false, isSigner: false },
+            { pubkey: votePda, isWritable: false, isSigner: false },
+          ])
+          .signers([authority])
+          .rpc({ commitment: "confirmed" });
+
+        // The structured records are logged either way
+        const logs = (
+          await provider.connection.getTransaction(finalize, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+          })
+        ).meta.logMessages;
+        const records = logs
+          .filter((log) => log.startsWith("Program data: "))
+          .map((log) => Buffer.from(log.slice("Program data: ".length), "base64"));
+        expect(records.some((record) => record.subarray(0, 8).toString() === "tallysum")).to.equal(
+          true
+        );
+        expect(records.some((record) => record.subarray(0, 8).toString() === "depositm")).to.equal(
+          true
+        );
+
+        return {
+          create: await computeUnits(create),
+          vote: await computeUnits(vote),
+          finalize: await computeUnits(finalize),
+          readableLogs: logs.some((log) => log.includes("finalized with status")),
+        };
+      };
+
+      const readable = await lifecycleUnits();
+      expect(readable.readableLogs).to.equal(true);
+      await setTestMode(false);
+      const structured = await lifecycleUnits();
+      expect(structured.readableLogs).to.equal(false);
+
+      console.log(
+        `      compute units with / without readable logs: create ${readable.create} / ${structured.create}, ` +
+          `vote ${readable.vote} / ${structured.vote}, finalize ${readable.finalize} / ${structured.finalize}`
+      );
+      expect(structured.create).to.be.below(readable.create - 1000);
+      expect(structured.vote).to.be.below(readable.vote);
+      expect(structured.finalize).to.be.below(readable.finalize - 1000);
+    });
+
+    it("Should only allow the proposal path in production mode", async () => {
+      const { proposalId, proposalPda } = await createChangeProposal(
+        { remove: {} },
+        newMember.publicKey
+      );
+      await passProposal(proposalId, proposalPda, true);
+
+      if ((await program.account.governanceConfig.fetch(governanceConfigPda)).testMode) {
+        await setTestMode(false);
+      }
+      for (const action of ["remove", "add"] as const) {
+        try {
+          await adminChange(action);
+          expect.fail("Should have failed with CommitteeChangeRequiresProposal");
+        } catch (error) {
+          expect(error.toString()).to.include("CommitteeChangeRequiresProposal");
+        }
+      }
+
+      await executeChange(proposalId, proposalPda);
+      const committeeMemberInfo = await provider.connection.getAccountInfo(
+        committeeMemberPda(newMember.publicKey)
+      );
+      expect(committeeMemberInfo).to.be.null;
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.committeeMemberCount).to.equal(3);
+    });
+
+    // Production mode is one-way, so these run last in the suite
+    it("Should never re-enable test mode once it is cleared", async () => {
+      // Direct updates are test-mode only; the timelocked queue refuses the flag outright
+      try {
+        await setTestMode(true);
+        expect.fail("Should have failed with TestModeRequired");
+      } catch (error) {
+        expect(error.toString()).to.include("TestModeRequired");
+      }
+      try {
+        await program.methods
+          .queueConfigUpdate(configUpdate({ testMode: true }))
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Should have failed with TestModeLocked");
+      } catch (error) {
+        expect(error.toString()).to.include("TestModeLocked");
+      }
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.testMode).to.equal(false);
+    });
+
+    it("Should permanently lock production mode", async () => {
+      const lockProduction = () =>
+        program.methods
+          .lockProduction()
+          .accounts({
+            governanceConfig: governanceConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+      // The test voting period is too short for production
+      try {
+        await lockProduction();
+        expect.fail("Should have failed with InvalidVotingPeriod");
+      } catch (error) {
+        expect(error.toString()).to.include("InvalidVotingPeriod");
+      }
+
+      // Outside test mode the admin goes through the timelocked queue
+      await program.methods
+        .queueConfigUpdate(configUpdate({ votingPeriod: new anchor.BN(86400) }))
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await program.methods
+        .applyConfigUpdate()
+        .accounts({
+          governanceConfig: governanceConfigPda,
+          queuedBy: authority.publicKey,
+        })
+        .rpc();
+
+      await lockProduction();
+      const config = await program.account.governanceConfig.fetch(governanceConfigPda);
+      expect(config.testMode).to.equal(false);
+      expect(config.productionLockedAt.toNumber()).to.be.greaterThan(0);
+
+      try {
+        await lockProduction();
+        expect.fail("Should have failed with TestModeLocked");
+      } catch (error) {
+        expect(error.toString()).to.include("TestModeLocked");
+      }
     });
   });
 });
@@ -0,0 +1,60 @@
+import { Program } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { Governance } from "../../target/types/governance";
+
+// Proposal is a zero-copy account: text and execution data live in fixed-size
+// buffers with length fields, and enums and options are stored as plain integers.
+// fetchProposal decodes it back into the field shape of the other accounts.
+
+const lowerFirst = (name: string) => name.charAt(0).toLowerCase() + name.slice(1);
+
+const enumVariant = (program: Program<Governance>, typeName: string, index: number) => {
+  const type = program.idl.types.find((idlType) => idlType.name === typeName) as any;
+  return { [lowerFirst(type.type.variants[index].name)]: {} };
+};
+
+const text = (bytes: number[], length: number) =>
+  Buffer.from(bytes.slice(0, length)).toString("utf8");
+
+const optional = (value: any) => (value.isZero() ? null : value);
+
+// Must match MAX_PROPOSAL_OPTION_BYTES
+const OPTION_BYTES = 32;
+
+export async function fetchProposal(program: Program<Governance>, address: PublicKey) {
+  const raw: any = await program.account.proposal.fetch(address);
+  const executionData = raw.executionData.slice(0, raw.executionDataLen);
+
+  return {
+    ...raw,
+    proposalType: enumVariant(program, "ProposalType", raw.proposalType),
+    status: enumVariant(program, "ProposalStatus", raw.status),
+    title: text(raw.title, raw.titleLen),
+    description: text(raw.description, raw.descriptionLen),
+    executionResult:
+      raw.executionResultLen > 0 ? text(raw.executionResult, raw.executionResultLen) : null,
+    executionData:
+      executionData.length > 0
+        ? program.coder.types.decode("ExecutionData", Buffer.from(executionData))
+        : null,
+    options: raw.optionLens
+      .slice(0, raw.optionCount)
+      .map((length: number, index: number) =>
+        text(raw.options.slice(index * OPTION_BYTES), length)
+      ),
+    winningOption: raw.winningOption > 0 ? raw.winningOption - 1 : null,
+    dependsOn: optional(raw.dependsOn),
+    resubmittedAs: optional(raw.resubmittedAs),
+    resubmittedFrom: optional(raw.resubmittedFrom),
+    executedAt: optional(raw.executedAt),
+    depositEscrowed: raw.depositEscrowed !== 0,
+    depositSettled: raw.depositSettled !== 0,
+    depositSlashSchedule: {
+      passedRate: raw.slashPassedRate,
+      rejectedRate: raw.slashRejectedRate,
+      lowApprovalFloor: raw.slashLowApprovalFloor,
+      lowApprovalRejectedRate: raw.slashLowApprovalRejectedRate,
+      vetoedRate: raw.slashVetoedRate,
+    },
+  };
+}